@@ -19,6 +19,10 @@ pub use secret_key::{get_secret_key, SecretKeyError};
 mod payload_builder_args;
 pub use payload_builder_args::PayloadBuilderArgs;
 
+/// TxPoolArgs struct for configuring the transaction pool
+mod txpool_args;
+pub use txpool_args::TxPoolArgs;
+
 /// Stage related arguments
 mod stage_args;
 pub use stage_args::StageEnum;
@@ -8,7 +8,7 @@ use clap::{
 use futures::{FutureExt, TryFutureExt};
 use reth_network_api::{NetworkInfo, Peers};
 use reth_provider::{
-    BlockProviderIdExt, CanonStateSubscriptions, EvmEnvProvider, HeaderProvider,
+    BlockProviderIdExt, CanonStateSubscriptions, ChainSpecProvider, EvmEnvProvider, HeaderProvider,
     StateProviderFactory,
 };
 use reth_rpc::{
@@ -19,7 +19,7 @@ use reth_rpc::{
         },
         gas_oracle::GasPriceOracleConfig,
     },
-    JwtError, JwtSecret,
+    EthApi, JwtError, JwtSecret,
 };
 use reth_rpc_builder::{
     auth::{AuthServerConfig, AuthServerHandle},
@@ -133,6 +133,36 @@ pub struct RpcServerArgs {
     #[arg(long, value_name = "COUNT", default_value_t = RPC_DEFAULT_MAX_TRACING_REQUESTS)]
     pub rpc_max_tracing_requests: u32,
 
+    /// Number of blocks the `latest` block tag lags behind the tip of the chain.
+    ///
+    /// Serving `latest` a few blocks behind the tip keeps responses stable under frequent reorgs.
+    #[arg(long, value_name = "BLOCKS", default_value_t = 0)]
+    pub rpc_latest_block_lag: u64,
+
+    /// Maximum number of struct logs captured by `debug` tracing of a single transaction.
+    ///
+    /// Traces that exceed the limit are truncated.
+    #[arg(long, value_name = "COUNT")]
+    pub rpc_max_trace_steps_per_call: Option<usize>,
+
+    /// Maximum number of struct logs captured by `debug` tracing of a whole block.
+    ///
+    /// Traces that exceed the limit are truncated.
+    #[arg(long, value_name = "COUNT")]
+    pub rpc_max_trace_steps_per_block: Option<usize>,
+
+    /// Maximum number of `eth_` calls per second on the HTTP and WS servers.
+    #[arg(long, value_name = "COUNT")]
+    pub rpc_ratelimit_eth: Option<u32>,
+
+    /// Maximum number of `debug_` calls per second on the HTTP and WS servers.
+    #[arg(long, value_name = "COUNT")]
+    pub rpc_ratelimit_debug: Option<u32>,
+
+    /// Maximum number of `trace_` calls per second on the HTTP and WS servers.
+    #[arg(long, value_name = "COUNT")]
+    pub rpc_ratelimit_trace: Option<u32>,
+
     /// Gas price oracle configuration.
     #[clap(flatten)]
     pub gas_price_oracle: GasPriceOracleArgs,
@@ -148,6 +178,10 @@ pub struct RpcServerArgs {
     /// Max size for cached evm env data in megabytes.
     #[arg(long, default_value_t = DEFAULT_ENV_CACHE_SIZE_BYTES_MB)]
     pub env_cache_size: usize,
+
+    /// Save the fee history cache to the data directory on shutdown and restore it on startup.
+    #[arg(long)]
+    pub fee_history_cache_persist: bool,
 }
 
 impl RpcServerArgs {
@@ -190,6 +224,9 @@ impl RpcServerArgs {
     pub fn eth_config(&self) -> EthConfig {
         EthConfig::default()
             .max_tracing_requests(self.rpc_max_tracing_requests)
+            .latest_block_lag(self.rpc_latest_block_lag)
+            .max_trace_steps_per_call(self.rpc_max_trace_steps_per_call)
+            .max_trace_steps_per_block(self.rpc_max_trace_steps_per_block)
             .gpo_config(self.gas_price_oracle_config())
     }
 
@@ -235,7 +272,7 @@ impl RpcServerArgs {
     ///
     /// Returns the handles for the launched regular RPC server(s) (if any) and the server handle
     /// for the auth server that handles the `engine_` API that's accessed by the consensus
-    /// layer.
+    /// layer, as well as the [EthApi] instance that serves the `eth_` namespace.
     #[allow(clippy::too_many_arguments)]
     pub async fn start_servers<Provider, Pool, Network, Tasks, Events, Engine>(
         &self,
@@ -246,12 +283,13 @@ impl RpcServerArgs {
         events: Events,
         engine_api: Engine,
         jwt_secret: JwtSecret,
-    ) -> Result<(RpcServerHandle, AuthServerHandle), RpcError>
+    ) -> Result<(RpcServerHandle, AuthServerHandle, EthApi<Provider, Pool, Network>), RpcError>
     where
         Provider: BlockProviderIdExt
             + HeaderProvider
             + StateProviderFactory
             + EvmEnvProvider
+            + ChainSpecProvider
             + Clone
             + Unpin
             + 'static,
@@ -266,13 +304,14 @@ impl RpcServerArgs {
         let module_config = self.transport_rpc_module_config();
         debug!(target: "reth::cli", http=?module_config.http(), ws=?module_config.ws(), "Using RPC module config");
 
-        let (rpc_modules, auth_module) = RpcModuleBuilder::default()
+        let (rpc_modules, auth_module, mut registry) = RpcModuleBuilder::default()
             .with_provider(provider)
             .with_pool(pool)
             .with_network(network)
             .with_events(events)
             .with_executor(executor)
             .build_with_auth_server(module_config, engine_api);
+        let eth_api = registry.eth_api();
 
         let server_config = self.rpc_server_config();
         let launch_rpc = rpc_modules.start_server(server_config).map_ok(|handle| {
@@ -293,7 +332,9 @@ impl RpcServerArgs {
         });
 
         // launch servers concurrently
-        futures::future::try_join(launch_rpc, launch_auth).await
+        let (rpc_handle, auth_handle) = futures::future::try_join(launch_rpc, launch_auth).await?;
+
+        Ok((rpc_handle, auth_handle, eth_api))
     }
 
     /// Convenience function for starting a rpc server with configs which extracted from cli args.
@@ -310,6 +351,7 @@ impl RpcServerArgs {
             + HeaderProvider
             + StateProviderFactory
             + EvmEnvProvider
+            + ChainSpecProvider
             + Clone
             + Unpin
             + 'static,
@@ -345,6 +387,7 @@ impl RpcServerArgs {
             + HeaderProvider
             + StateProviderFactory
             + EvmEnvProvider
+            + ChainSpecProvider
             + Clone
             + Unpin
             + 'static,
@@ -442,6 +485,17 @@ impl RpcServerArgs {
             config = config.with_ws_address(socket_address).with_ws(self.http_ws_server_builder());
         }
 
+        let rate_limits = [
+            (RethRpcModule::Eth, self.rpc_ratelimit_eth),
+            (RethRpcModule::Debug, self.rpc_ratelimit_debug),
+            (RethRpcModule::Trace, self.rpc_ratelimit_trace),
+        ];
+        for (module, requests_per_second) in rate_limits {
+            if let Some(requests_per_second) = requests_per_second {
+                config = config.with_rate_limit(module, requests_per_second);
+            }
+        }
+
         if self.is_ipc_enabled() {
             config = config.with_ipc(self.ipc_server_builder()).with_ipc_endpoint(
                 self.ipcpath.as_ref().unwrap_or(&constants::DEFAULT_IPC_ENDPOINT.to_string()),
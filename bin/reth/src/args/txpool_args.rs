@@ -0,0 +1,44 @@
+//! Transaction pool arguments
+
+use clap::Args;
+use reth_transaction_pool::PoolConfig;
+
+/// Parameters for configuring the transaction pool
+#[derive(Debug, Args, PartialEq, Default)]
+pub struct TxPoolArgs {
+    /// Accept legacy transactions without a chain id, which aren't replay protected (pre
+    /// EIP-155).
+    #[arg(long = "txpool.allow-unprotected-txs", help_heading = "TxPool")]
+    pub allow_unprotected_txs: bool,
+}
+
+impl TxPoolArgs {
+    /// Returns the transaction pool configuration specified by the arguments.
+    pub fn pool_config(&self) -> PoolConfig {
+        PoolConfig::default().with_unprotected_txs(self.allow_unprotected_txs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[clap(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_parse_txpool_args() {
+        let args = CommandParser::<TxPoolArgs>::parse_from(["reth"]).args;
+        assert!(!args.pool_config().allow_unprotected_txs);
+
+        let args =
+            CommandParser::<TxPoolArgs>::parse_from(["reth", "--txpool.allow-unprotected-txs"])
+                .args;
+        assert!(args.pool_config().allow_unprotected_txs);
+    }
+}
@@ -169,13 +169,16 @@ impl ImportCommand {
                 .set(SenderRecoveryStage {
                     commit_threshold: config.stages.sender_recovery.commit_threshold,
                 })
-                .set(ExecutionStage::new(
-                    factory,
-                    ExecutionStageThresholds {
-                        max_blocks: config.stages.execution.max_blocks,
-                        max_changes: config.stages.execution.max_changes,
-                    },
-                )),
+                .set(
+                    ExecutionStage::new(
+                        factory,
+                        ExecutionStageThresholds {
+                            max_blocks: config.stages.execution.max_blocks,
+                            max_changes: config.stages.execution.max_changes,
+                        },
+                    )
+                    .with_receipt_retention(config.stages.execution.receipt_retention),
+                ),
             )
             .build(db, self.chain.clone());
 
@@ -64,6 +64,7 @@ impl Command {
             StorageHistory,
             HashedAccount,
             AccountsTrie,
+            StateRootCheckpoints,
             TxSenders,
             SyncStage,
             SyncStageProgress
@@ -215,6 +215,7 @@ impl Command {
                     HashedStorage,
                     AccountsTrie,
                     StoragesTrie,
+                    StateRootCheckpoints,
                     TxSenders,
                     SyncStage,
                     SyncStageProgress
@@ -276,6 +276,16 @@ impl<D> ChainPath<D> {
         self.0.join("known-peers.json").into()
     }
 
+    /// Returns the path to the file the transaction pool is saved to on shutdown.
+    pub fn txpool_transactions_path(&self) -> PathBuf {
+        self.0.join("txpool-transactions.rlp").into()
+    }
+
+    /// Returns the path to the file the fee history cache is saved to on shutdown.
+    pub fn fee_history_cache_path(&self) -> PathBuf {
+        self.0.join("fee-history-cache.json").into()
+    }
+
     /// Returns the path to the config file for this chain.
     pub fn config_path(&self) -> PathBuf {
         self.0.join("reth.toml").into()
@@ -35,6 +35,7 @@ use reth_interfaces::{
     consensus::Consensus,
     p2p::{
         bodies::{client::BodiesClient, downloader::BodyDownloader},
+        download::DownloadClient,
         either::EitherDownloader,
         headers::downloader::HeaderDownloader,
     },
@@ -45,17 +46,18 @@ use reth_primitives::{
     stage::StageId, BlockHashOrNumber, ChainSpec, Head, Header, SealedHeader, H256,
 };
 use reth_provider::{
-    providers::get_stage_checkpoint, BlockProvider, CanonStateSubscriptions, HeaderProvider,
-    ProviderFactory,
+    providers::get_stage_checkpoint, BlockProvider, BlockProviderIdExt, CanonStateSubscriptions,
+    EvmEnvProvider, HeaderProvider, ProviderFactory, StateProviderFactory,
 };
 use reth_revm::Factory;
 use reth_revm_inspectors::stack::Hook;
+use reth_rpc::EthApi;
 use reth_rpc_engine_api::EngineApi;
 use reth_staged_sync::utils::init::{init_db, init_genesis};
 use reth_stages::{
     prelude::*,
     stages::{
-        ExecutionStage, ExecutionStageThresholds, HeaderSyncMode, SenderRecoveryStage,
+        ExecutionStage, ExecutionStageThresholds, HeaderSyncMode, MerkleStage, SenderRecoveryStage,
         TotalDifficultyStage,
     },
 };
@@ -73,7 +75,7 @@ use tracing::*;
 use crate::{
     args::{
         utils::{genesis_value_parser, parse_socket_address},
-        PayloadBuilderArgs,
+        PayloadBuilderArgs, TxPoolArgs,
     },
     dirs::MaybePlatformPath,
     node::cl_events::ConsensusLayerHealthEvents,
@@ -134,12 +136,29 @@ pub struct Command {
     #[clap(flatten)]
     builder: PayloadBuilderArgs,
 
+    #[clap(flatten)]
+    txpool: TxPoolArgs,
+
     #[clap(flatten)]
     debug: DebugArgs,
 
     /// Automatically mine blocks for new transactions
     #[arg(long)]
     auto_mine: bool,
+
+    /// Record the state root of every block that is a multiple of the given interval, so that
+    /// historical state proofs can be checked against the nearest recorded root.
+    ///
+    /// Overrides `stages.merkle.state_root_checkpoint_interval` of the config file.
+    #[arg(long, value_name = "BLOCKS")]
+    state_root_checkpoint_interval: Option<u64>,
+
+    /// Check the database for leftovers after reverting canonical blocks, and abort the revert if
+    /// the check fails.
+    ///
+    /// Overrides `tree.check_unwind_consistency` of the config file.
+    #[arg(long)]
+    check_unwind_consistency: bool,
 }
 
 impl Command {
@@ -180,6 +199,13 @@ impl Command {
 
         self.init_trusted_nodes(&mut config);
 
+        if let Some(interval) = self.state_root_checkpoint_interval {
+            config.stages.merkle.state_root_checkpoint_interval = Some(interval);
+        }
+        if self.check_unwind_consistency {
+            config.tree.check_unwind_consistency = true;
+        }
+
         // configure blockchain tree
         let tree_externals = TreeExternals::new(
             db.clone(),
@@ -187,7 +213,8 @@ impl Command {
             Factory::new(self.chain.clone()),
             Arc::clone(&self.chain),
         );
-        let tree_config = BlockchainTreeConfig::default();
+        let tree_config = BlockchainTreeConfig::default()
+            .with_unwind_consistency_check(config.tree.check_unwind_consistency);
         // The size of the broadcast is twice the maximum reorg depth, because at maximum reorg
         // depth at least N blocks must be sent at once.
         let (canon_state_notification_sender, _receiver) =
@@ -202,9 +229,10 @@ impl Command {
         let factory = ProviderFactory::new(Arc::clone(&db), Arc::clone(&self.chain));
         let blockchain_db = BlockchainProvider::new(factory, blockchain_tree.clone())?;
 
+        let pool_config = self.txpool.pool_config();
         let transaction_pool = reth_transaction_pool::Pool::eth_pool(
             EthTransactionValidator::new(blockchain_db.clone(), Arc::clone(&self.chain)),
-            Default::default(),
+            pool_config,
         );
         info!(target: "reth::cli", "Transaction pool initialized");
 
@@ -227,6 +255,17 @@ impl Command {
             debug!(target: "reth::cli", "Spawned txpool maintenance task");
         }
 
+        // spawn txpool backup task, restores the transactions of the previous run and saves the
+        // transactions of this run on shutdown
+        {
+            let pool = transaction_pool.clone();
+            let transactions_path = data_dir.txpool_transactions_path();
+            ctx.task_executor.spawn_critical_with_signal("txpool backup task", |shutdown| {
+                run_txpool_backup_until_shutdown(shutdown, pool, transactions_path)
+            });
+            debug!(target: "reth::cli", "Spawned txpool backup task");
+        }
+
         info!(target: "reth::cli", "Connecting to P2P network");
         let network_secret_path =
             self.network.p2p_secret_key.clone().unwrap_or_else(|| data_dir.p2p_secret_path());
@@ -372,7 +411,7 @@ impl Command {
         let jwt_secret = self.rpc.jwt_secret(default_jwt_path)?;
 
         // Start RPC servers
-        let (_rpc_server, _auth_server) = self
+        let (_rpc_server, _auth_server, eth_api) = self
             .rpc
             .start_servers(
                 blockchain_db.clone(),
@@ -385,6 +424,23 @@ impl Command {
             )
             .await?;
 
+        // spawn fee history cache backup task, restores the cache of the previous run and saves
+        // the cache of this run on shutdown
+        if self.rpc.fee_history_cache_persist {
+            let fee_history_cache_path = data_dir.fee_history_cache_path();
+            ctx.task_executor.spawn_critical_with_signal(
+                "fee history cache backup task",
+                |shutdown| {
+                    run_fee_history_cache_backup_until_shutdown(
+                        shutdown,
+                        eth_api,
+                        fee_history_cache_path,
+                    )
+                },
+            );
+            debug!(target: "reth::cli", "Spawned fee history cache backup task");
+        }
+
         // Run consensus engine to completion
         let (tx, rx) = oneshot::channel();
         info!(target: "reth::cli", "Starting consensus engine");
@@ -433,13 +489,14 @@ impl Command {
             .into_task_with(task_executor);
 
         let body_downloader = BodiesDownloaderBuilder::from(config.stages.bodies)
-            .build(client, Arc::clone(&consensus), db.clone())
+            .build(client.clone(), Arc::clone(&consensus), db.clone())
             .into_task_with(task_executor);
 
         let pipeline = self
             .build_pipeline(
                 db,
                 config,
+                client,
                 header_downloader,
                 body_downloader,
                 consensus,
@@ -619,10 +676,11 @@ impl Command {
     }
 
     #[allow(clippy::too_many_arguments)]
-    async fn build_pipeline<DB, H, B>(
+    async fn build_pipeline<DB, Client, H, B>(
         &self,
         db: DB,
         config: &Config,
+        client: Client,
         header_downloader: H,
         body_downloader: B,
         consensus: Arc<dyn Consensus>,
@@ -631,6 +689,7 @@ impl Command {
     ) -> eyre::Result<Pipeline<DB>>
     where
         DB: Database + Clone + 'static,
+        Client: DownloadClient + 'static,
         H: HeaderDownloader + 'static,
         B: BodyDownloader + 'static,
     {
@@ -643,6 +702,12 @@ impl Command {
             builder = builder.with_max_block(max_block)
         }
 
+        if stage_conf.min_peers_to_start_sync > 0 {
+            let min_peers = stage_conf.min_peers_to_start_sync;
+            debug!(target: "reth::cli", min_peers, "Configuring builder to wait for peers");
+            builder = builder.with_min_peers(min_peers, client)
+        }
+
         let (tip_tx, tip_rx) = watch::channel(H256::zero());
         use reth_revm_inspectors::stack::InspectorStackConfig;
         let factory = reth_revm::Factory::new(self.chain.clone());
@@ -674,6 +739,7 @@ impl Command {
                     body_downloader,
                     factory.clone(),
                 )
+                .with_trust_stored_headers(stage_conf.headers.trust_stored_headers)
                 .set(
                     TotalDifficultyStage::new(consensus)
                         .with_commit_threshold(stage_conf.total_difficulty.commit_threshold),
@@ -681,12 +747,18 @@ impl Command {
                 .set(SenderRecoveryStage {
                     commit_threshold: stage_conf.sender_recovery.commit_threshold,
                 })
-                .set(ExecutionStage::new(
-                    factory,
-                    ExecutionStageThresholds {
-                        max_blocks: stage_conf.execution.max_blocks,
-                        max_changes: stage_conf.execution.max_changes,
-                    },
+                .set(
+                    ExecutionStage::new(
+                        factory,
+                        ExecutionStageThresholds {
+                            max_blocks: stage_conf.execution.max_blocks,
+                            max_changes: stage_conf.execution.max_changes,
+                        },
+                    )
+                    .with_receipt_retention(stage_conf.execution.receipt_retention),
+                )
+                .set(MerkleStage::execution_with_state_root_checkpoints(
+                    stage_conf.merkle.state_root_checkpoint_interval,
                 )),
             )
             .build(db, self.chain.clone());
@@ -728,6 +800,72 @@ async fn run_network_until_shutdown<C>(
     }
 }
 
+/// Restores the transactions saved by a previous run into the pool, then waits for a
+/// [Shutdown](reth_tasks::shutdown::Shutdown) signal and writes the pending and queued
+/// transactions of the pool to `transactions_file`.
+async fn run_txpool_backup_until_shutdown<Pool>(
+    shutdown: reth_tasks::shutdown::Shutdown,
+    pool: Pool,
+    transactions_file: PathBuf,
+) where
+    Pool: TransactionPool + 'static,
+{
+    match reth_transaction_pool::backup::load_transactions(&pool, &transactions_file).await {
+        Ok(num_transactions) => {
+            info!(target: "reth::cli", txpool_file=?transactions_file, num_transactions, "Restored transactions to the pool");
+        }
+        Err(err) => {
+            warn!(target: "reth::cli", ?err, txpool_file=?transactions_file, "Failed to restore transactions to the pool");
+        }
+    }
+
+    shutdown.await;
+
+    match reth_transaction_pool::backup::save_transactions(&pool, &transactions_file) {
+        Ok(num_transactions) => {
+            info!(target: "reth::cli", txpool_file=?transactions_file, num_transactions, "Wrote pool transactions to file");
+        }
+        Err(err) => {
+            warn!(target: "reth::cli", ?err, txpool_file=?transactions_file, "Failed to write pool transactions to file");
+        }
+    }
+}
+
+/// Restores the fee history cache saved by a previous run, if any, then waits for a
+/// [Shutdown](reth_tasks::shutdown::Shutdown) signal and writes the cached fee history entries to
+/// `cache_file`.
+async fn run_fee_history_cache_backup_until_shutdown<Provider, Pool, Network>(
+    shutdown: reth_tasks::shutdown::Shutdown,
+    eth_api: EthApi<Provider, Pool, Network>,
+    cache_file: PathBuf,
+) where
+    Provider: BlockProviderIdExt + StateProviderFactory + EvmEnvProvider + 'static,
+    Pool: TransactionPool + Clone + 'static,
+    Network: NetworkInfo + Send + Sync + 'static,
+{
+    if cache_file.exists() {
+        match eth_api.load_fee_history_cache(&cache_file).await {
+            Ok(num_entries) => {
+                info!(target: "reth::cli", fee_history_file=?cache_file, num_entries, "Restored fee history cache");
+            }
+            Err(err) => {
+                warn!(target: "reth::cli", ?err, fee_history_file=?cache_file, "Failed to restore fee history cache");
+            }
+        }
+    }
+
+    shutdown.await;
+
+    match eth_api.save_fee_history_cache(&cache_file).await {
+        Ok(()) => {
+            info!(target: "reth::cli", fee_history_file=?cache_file, "Wrote fee history cache to file");
+        }
+        Err(err) => {
+            warn!(target: "reth::cli", ?err, fee_history_file=?cache_file, "Failed to write fee history cache to file");
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
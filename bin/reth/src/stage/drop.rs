@@ -124,6 +124,7 @@ impl Command {
                 StageEnum::Merkle => {
                     tx.clear::<tables::AccountsTrie>()?;
                     tx.clear::<tables::StoragesTrie>()?;
+                    tx.clear::<tables::StateRootCheckpoints>()?;
                     tx.put::<tables::SyncStage>(
                         StageId::MerkleExecute.to_string(),
                         Default::default(),
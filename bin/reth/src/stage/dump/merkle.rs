@@ -123,6 +123,7 @@ async fn dry_run<DB: Database>(
             clean_threshold: u64::MAX, /* Forces updating the root instead of calculating
                                         * from
                                         * scratch */
+            state_root_checkpoint_interval: None,
         }
         .execute(
             &mut provider,
@@ -963,12 +963,24 @@ impl<DB: Database, C: Consensus, EF: ExecutorFactory> BlockchainTree<DB, C, EF>
 
             if let Some(old_canon_chain) = old_canon_chain {
                 // state action
+                // the reverted blocks are read from the database, so the notification always
+                // contains all of them, regardless of the reorg depth
                 chain_notification = CanonStateNotification::Reorg {
                     old: Arc::new(old_canon_chain.clone()),
                     new: Arc::new(new_canon_chain.clone()),
                 };
-                // insert old canon chain
-                self.insert_chain(AppendableChain::new(old_canon_chain));
+                let reverted = old_canon_chain.blocks().len() as u64;
+                if reverted <= self.config.max_reverted_blocks_in_memory() {
+                    // insert old canon chain
+                    self.insert_chain(AppendableChain::new(old_canon_chain));
+                } else {
+                    debug!(
+                        target: "blockchain_tree",
+                        reverted,
+                        max = self.config.max_reverted_blocks_in_memory(),
+                        "Not keeping reverted blocks of deep reorg in memory"
+                    );
+                }
             } else {
                 // error here to confirm that we are reverting nothing from db.
                 error!(target: "blockchain_tree", "Reverting nothing from db on block: #{:?}", block_hash);
@@ -1050,6 +1062,13 @@ impl<DB: Database, C: Consensus, EF: ExecutorFactory> BlockchainTree<DB, C, EF>
             .take_block_and_execution_range(self.externals.chain_spec.as_ref(), revert_range)
             .map_err(|e| BlockExecutionError::CanonicalRevert { inner: e.to_string() })?;
 
+        // abort the revert if it left the database inconsistent
+        if self.config.check_unwind_consistency() {
+            provider
+                .check_unwind_consistency(revert_until)
+                .map_err(|e| BlockExecutionError::CanonicalRevert { inner: e.to_string() })?;
+        }
+
         provider.commit()?;
 
         if blocks_and_execution.is_empty() {
@@ -1189,6 +1208,55 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn deep_reorg_notification() {
+        let data = BlockChainTestData::default_with_numbers(11, 12);
+        let (block1, exec1) = data.blocks[0].clone();
+        let (block2, exec2) = data.blocks[1].clone();
+        let genesis = data.genesis;
+
+        // test pops execution results from vector, so order is from last to first.
+        let externals = setup_externals(vec![exec1.clone(), exec2, exec1]);
+
+        // last finalized block would be number 9.
+        setup_genesis(externals.db.clone(), genesis);
+
+        // make tree that keeps at most one reverted block in memory
+        let config = BlockchainTreeConfig::new(1, 2, 3, 2).with_max_reverted_blocks_in_memory(1);
+        let (sender, mut canon_notif) = tokio::sync::broadcast::channel(10);
+        let mut tree =
+            BlockchainTree::new(externals, sender, config).expect("failed to create tree");
+        tree.finalize_block(10);
+
+        assert_eq!(tree.insert_block(block1.clone()).unwrap(), BlockStatus::Valid);
+        assert_eq!(tree.insert_block(block2.clone()).unwrap(), BlockStatus::Valid);
+        assert!(tree.make_canonical(&block2.hash()).is_ok());
+        assert_matches!(canon_notif.try_recv(), Ok(CanonStateNotification::Commit { .. }));
+
+        let mut block1a = block1.clone();
+        let block1a_hash = H256([0x33; 32]);
+        block1a.hash = block1a_hash;
+        assert_eq!(tree.insert_block(block1a.clone()).unwrap(), BlockStatus::Accepted);
+
+        // reorg two blocks deep, more than are kept in memory
+        assert!(tree.make_canonical(&block1a_hash).is_ok());
+
+        // the reverted blocks are read from the database and fully included
+        assert_matches!(canon_notif.try_recv(),
+            Ok(CanonStateNotification::Reorg{ old, new})
+            if *old.blocks() == BTreeMap::from([(block1.number,block1.clone()),(block2.number,block2.clone())])
+                && *new.blocks() == BTreeMap::from([(block1a.number,block1a.clone())]));
+
+        // but they are not kept in the tree
+        TreeTester::default()
+            .with_chain_num(0)
+            .with_block_to_chain(HashMap::from([]))
+            .with_fork_to_child(HashMap::from([]))
+            .assert(&tree);
+        assert!(tree.is_block_hash_canonical(&block1a_hash).unwrap());
+        assert!(!tree.is_block_hash_canonical(&block1.hash).unwrap());
+    }
+
     #[tokio::test]
     async fn sanity_path() {
         let data = BlockChainTestData::default_with_numbers(11, 12);
@@ -15,6 +15,15 @@ pub struct BlockchainTreeConfig {
     /// at least `additional_canonical_block_hashes`+`max_reorg_depth`, for eth that would be
     /// 256+64.
     num_of_additional_canonical_block_hashes: u64,
+    /// The maximum number of blocks reverted by a reorg that are kept in memory as a side chain.
+    ///
+    /// The reverted blocks are always read from the database, so reorg notifications contain all
+    /// of them. If a reorg reverts more blocks, they are not reinserted into the tree.
+    max_reverted_blocks_in_memory: u64,
+    /// Whether to check the database for leftovers after reverting canonical blocks.
+    ///
+    /// The revert is aborted if the check fails.
+    check_unwind_consistency: bool,
 }
 
 impl Default for BlockchainTreeConfig {
@@ -29,6 +38,9 @@ impl Default for BlockchainTreeConfig {
             num_of_additional_canonical_block_hashes: 256,
             // max unconnected blocks.
             max_unconnected_blocks: 200,
+            // Reverted chains can't be longer than a side chain.
+            max_reverted_blocks_in_memory: 65,
+            check_unwind_consistency: false,
         }
     }
 }
@@ -49,9 +61,26 @@ impl BlockchainTreeConfig {
             max_reorg_depth,
             num_of_additional_canonical_block_hashes,
             max_unconnected_blocks,
+            max_reverted_blocks_in_memory: max_blocks_in_chain,
+            check_unwind_consistency: false,
         }
     }
 
+    /// Set the maximum number of blocks reverted by a reorg that are kept in memory.
+    pub fn with_max_reverted_blocks_in_memory(
+        mut self,
+        max_reverted_blocks_in_memory: u64,
+    ) -> Self {
+        self.max_reverted_blocks_in_memory = max_reverted_blocks_in_memory;
+        self
+    }
+
+    /// Set whether the database is checked for leftovers after reverting canonical blocks.
+    pub fn with_unwind_consistency_check(mut self, check_unwind_consistency: bool) -> Self {
+        self.check_unwind_consistency = check_unwind_consistency;
+        self
+    }
+
     /// Return the maximum reorg depth.
     pub fn max_reorg_depth(&self) -> u64 {
         self.max_reorg_depth
@@ -72,4 +101,14 @@ impl BlockchainTreeConfig {
     pub fn max_unconnected_blocks(&self) -> usize {
         self.max_unconnected_blocks
     }
+
+    /// Return the maximum number of blocks reverted by a reorg that are kept in memory.
+    pub fn max_reverted_blocks_in_memory(&self) -> u64 {
+        self.max_reverted_blocks_in_memory
+    }
+
+    /// Return whether the database is checked for leftovers after reverting canonical blocks.
+    pub fn check_unwind_consistency(&self) -> bool {
+        self.check_unwind_consistency
+    }
 }
@@ -20,6 +20,8 @@ pub struct Config {
     pub peers: PeersConfig,
     /// Configuration for peer sessions.
     pub sessions: SessionsConfig,
+    /// Configuration for the blockchain tree.
+    pub tree: TreeConfig,
 }
 
 impl Config {
@@ -59,6 +61,12 @@ pub struct StageConfig {
     pub sender_recovery: SenderRecoveryConfig,
     /// Execution stage configuration.
     pub execution: ExecutionConfig,
+    /// Merkle stage configuration.
+    pub merkle: MerkleConfig,
+    /// The minimum number of connected peers required before the header and body stages are run.
+    ///
+    /// `0` disables the check.
+    pub min_peers_to_start_sync: usize,
 }
 
 /// Header stage configuration.
@@ -80,6 +88,11 @@ pub struct HeadersConfig {
     pub downloader_request_limit: u64,
     /// The maximum number of headers to download before committing progress to the database.
     pub commit_threshold: u64,
+    /// Whether the headers already stored in the database are trusted, so that they are only
+    /// checked for parent hash continuity once instead of being re-validated.
+    ///
+    /// Default: false
+    pub trust_stored_headers: bool,
 }
 
 impl Default for HeadersConfig {
@@ -90,6 +103,7 @@ impl Default for HeadersConfig {
             downloader_max_concurrent_requests: 100,
             downloader_min_concurrent_requests: 5,
             downloader_max_buffered_responses: 100,
+            trust_stored_headers: false,
         }
     }
 }
@@ -196,14 +210,39 @@ pub struct ExecutionConfig {
     pub max_blocks: Option<u64>,
     /// The maximum amount of state changes to keep in memory before the execution stage commits.
     pub max_changes: Option<u64>,
+    /// The number of most recent blocks to keep receipts for.
+    ///
+    /// If set, the execution stage deletes the receipts of older blocks at each commit.
+    pub receipt_retention: Option<u64>,
 }
 
 impl Default for ExecutionConfig {
     fn default() -> Self {
-        Self { max_blocks: Some(500_000), max_changes: Some(5_000_000) }
+        Self { max_blocks: Some(500_000), max_changes: Some(5_000_000), receipt_retention: None }
     }
 }
 
+/// Merkle stage configuration.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct MerkleConfig {
+    /// The interval of the blocks whose state roots are recorded as checkpoints for historical
+    /// state proofs.
+    ///
+    /// Default: None, no checkpoints are recorded
+    pub state_root_checkpoint_interval: Option<u64>,
+}
+
+/// Blockchain tree configuration.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct TreeConfig {
+    /// Whether to check the database for leftovers after reverting canonical blocks.
+    ///
+    /// Default: false, the database isn't checked
+    pub check_unwind_consistency: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::Config;
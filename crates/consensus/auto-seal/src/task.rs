@@ -151,6 +151,9 @@ where
                         mix_hash: Default::default(),
                         nonce: 0,
                         base_fee_per_gas,
+                        blob_gas_used: None,
+                        excess_blob_gas: None,
+                        parent_beacon_block_root: None,
                         extra_data: Default::default(),
                     };
 
@@ -1361,21 +1361,22 @@ mod tests {
         watch,
     };
 
-    type TestBeaconConsensusEngine = BeaconConsensusEngine<
+    type TestBlockchainProvider = BlockchainProvider<
         Arc<Env<WriteMap>>,
-        BlockchainProvider<
-            Arc<Env<WriteMap>>,
-            ShareableBlockchainTree<Arc<Env<WriteMap>>, TestConsensus, TestExecutorFactory>,
-        >,
-        NoopFullBlockClient,
+        ShareableBlockchainTree<Arc<Env<WriteMap>>, TestConsensus, TestExecutorFactory>,
     >;
 
+    type TestBeaconConsensusEngine =
+        BeaconConsensusEngine<Arc<Env<WriteMap>>, TestBlockchainProvider, NoopFullBlockClient>;
+
     struct TestEnv<DB> {
         db: DB,
         // Keep the tip receiver around, so it's not dropped.
         #[allow(dead_code)]
         tip_rx: watch::Receiver<H256>,
         engine_handle: BeaconConsensusEngineHandle,
+        /// A handle to the blockchain provider used by the engine.
+        blockchain: TestBlockchainProvider,
     }
 
     impl<DB> TestEnv<DB> {
@@ -1383,8 +1384,9 @@ mod tests {
             db: DB,
             tip_rx: watch::Receiver<H256>,
             engine_handle: BeaconConsensusEngineHandle,
+            blockchain: TestBlockchainProvider,
         ) -> Self {
-            Self { db, tip_rx, engine_handle }
+            Self { db, tip_rx, engine_handle, blockchain }
         }
 
         async fn send_new_payload(
@@ -1465,7 +1467,7 @@ mod tests {
         let (engine, handle) = BeaconConsensusEngine::new(
             NoopFullBlockClient::default(),
             pipeline,
-            blockchain_provider,
+            blockchain_provider.clone(),
             Box::<TokioTaskExecutor>::default(),
             Box::<NoopSyncStateUpdater>::default(),
             None,
@@ -1475,7 +1477,7 @@ mod tests {
         )
         .expect("failed to create consensus engine");
 
-        (engine, TestEnv::new(db, tip_rx, handle))
+        (engine, TestEnv::new(db, tip_rx, handle, blockchain_provider))
     }
 
     fn spawn_consensus_engine(
@@ -1636,6 +1638,7 @@ mod tests {
         use super::*;
         use reth_db::{tables, transaction::DbTxMut};
         use reth_interfaces::test_utils::generators::random_block;
+        use reth_provider::BlockIdProvider;
         use reth_rpc_types::engine::ForkchoiceUpdateError;
 
         #[tokio::test]
@@ -1717,6 +1720,62 @@ mod tests {
             assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
         }
 
+        #[tokio::test]
+        async fn valid_forkchoice_sets_safe_and_finalized() {
+            let chain_spec = Arc::new(
+                ChainSpecBuilder::default()
+                    .chain(MAINNET.chain)
+                    .genesis(MAINNET.genesis.clone())
+                    .paris_activated()
+                    .build(),
+            );
+            let (consensus_engine, env) = setup_consensus_engine(
+                chain_spec.clone(),
+                VecDeque::from([Ok(ExecOutput {
+                    done: true,
+                    checkpoint: StageCheckpoint::new(0),
+                })]),
+                Vec::default(),
+            );
+
+            let genesis = random_block(0, None, None, Some(0));
+            let block1 = random_block(1, Some(genesis.hash), None, Some(0));
+            let block2 = random_block(2, Some(block1.hash), None, Some(0));
+            insert_blocks(
+                env.db.as_ref(),
+                chain_spec.clone(),
+                [&genesis, &block1, &block2].into_iter(),
+            );
+            env.db
+                .update(|tx| {
+                    tx.put::<tables::SyncStage>(
+                        StageId::Finish.to_string(),
+                        StageCheckpoint::new(block2.number),
+                    )
+                })
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(env.blockchain.safe_block_num_hash().unwrap(), None);
+            assert_eq!(env.blockchain.finalized_block_num_hash().unwrap(), None);
+
+            let _engine_rx = spawn_consensus_engine(consensus_engine);
+
+            let forkchoice = ForkchoiceState {
+                head_block_hash: block2.hash,
+                safe_block_hash: block1.hash,
+                finalized_block_hash: genesis.hash,
+            };
+            let result = env.send_forkchoice_updated(forkchoice).await.unwrap();
+            assert!(result.is_valid());
+
+            assert_eq!(env.blockchain.safe_block_num_hash().unwrap(), Some(block1.num_hash()));
+            assert_eq!(
+                env.blockchain.finalized_block_num_hash().unwrap(),
+                Some(genesis.num_hash())
+            );
+        }
+
         #[tokio::test]
         async fn unknown_head_hash() {
             let chain_spec = Arc::new(
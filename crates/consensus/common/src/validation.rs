@@ -1,8 +1,9 @@
 //! Collection of methods for block validation.
 use reth_interfaces::{consensus::ConsensusError, Result as RethResult};
 use reth_primitives::{
-    constants, BlockNumber, ChainSpec, Hardfork, Header, InvalidTransactionError, SealedBlock,
-    SealedHeader, Transaction, TransactionSignedEcRecovered, TxEip1559, TxEip2930, TxLegacy,
+    constants, gas_limit::validate_gas_limit, BlockNumber, ChainSpec, Hardfork, Header,
+    InvalidTransactionError, SealedBlock, SealedHeader, Transaction, TransactionSignedEcRecovered,
+    TxEip2930, TxLegacy,
 };
 use reth_provider::{AccountProvider, HeaderProvider, WithdrawalsProvider};
 use std::{
@@ -51,6 +52,17 @@ pub fn validate_header_standalone(
         return Err(ConsensusError::WithdrawalsRootUnexpected)
     }
 
+    // EIP-4788: Beacon block root in the EVM
+    if chain_spec.fork(Hardfork::Cancun).active_at_timestamp(header.timestamp) &&
+        header.parent_beacon_block_root.is_none()
+    {
+        return Err(ConsensusError::ParentBeaconBlockRootMissing)
+    } else if !chain_spec.fork(Hardfork::Cancun).active_at_timestamp(header.timestamp) &&
+        header.parent_beacon_block_root.is_some()
+    {
+        return Err(ConsensusError::ParentBeaconBlockRootUnexpected)
+    }
+
     Ok(())
 }
 
@@ -80,12 +92,7 @@ pub fn validate_transaction_regarding_header(
             }
             Some(*chain_id)
         }
-        Transaction::Eip1559(TxEip1559 {
-            chain_id,
-            max_fee_per_gas,
-            max_priority_fee_per_gas,
-            ..
-        }) => {
+        Transaction::Eip1559(tx) => {
             // EIP-1559: Fee market change for ETH 1.0 chain https://eips.ethereum.org/EIPS/eip-1559
             if !chain_spec.fork(Hardfork::Berlin).active_at_block(at_block_number) {
                 return Err(InvalidTransactionError::Eip1559Disabled.into())
@@ -93,11 +100,14 @@ pub fn validate_transaction_regarding_header(
 
             // EIP-1559: add more constraints to the tx validation
             // https://github.com/ethereum/EIPs/pull/3594
-            if max_priority_fee_per_gas > max_fee_per_gas {
-                return Err(InvalidTransactionError::TipAboveFeeCap.into())
-            }
+            tx.validate_fees()?;
 
-            Some(*chain_id)
+            Some(tx.chain_id)
+        }
+        Transaction::Eip4844(_) => {
+            // EIP-4844: Shard Blob Transactions https://eips.ethereum.org/EIPS/eip-4844
+            // blob transactions can be decoded but their execution is not supported yet
+            return Err(InvalidTransactionError::TxTypeNotSupported.into())
         }
     };
     if let Some(chain_id) = chain_id {
@@ -274,34 +284,50 @@ pub fn validate_header_regarding_parent(
     }
 
     // Check gas limit, max diff between child/parent gas_limit should be  max_diff=parent_gas/1024
-    if child.gas_limit > parent_gas_limit {
-        if child.gas_limit - parent_gas_limit >= parent_gas_limit / 1024 {
-            return Err(ConsensusError::GasLimitInvalidIncrease {
+    if !validate_gas_limit(parent_gas_limit, child.gas_limit) {
+        return if child.gas_limit > parent_gas_limit {
+            Err(ConsensusError::GasLimitInvalidIncrease {
+                parent_gas_limit,
+                child_gas_limit: child.gas_limit,
+            })
+        } else {
+            Err(ConsensusError::GasLimitInvalidDecrease {
                 parent_gas_limit,
                 child_gas_limit: child.gas_limit,
             })
         }
-    } else if parent_gas_limit - child.gas_limit >= parent_gas_limit / 1024 {
-        return Err(ConsensusError::GasLimitInvalidDecrease {
-            parent_gas_limit,
-            child_gas_limit: child.gas_limit,
-        })
     }
 
     // EIP-1559 check base fee
-    if chain_spec.fork(Hardfork::London).active_at_block(child.number) {
-        let base_fee = child.base_fee_per_gas.ok_or(ConsensusError::BaseFeeMissing)?;
-
-        let expected_base_fee =
-            if chain_spec.fork(Hardfork::London).transitions_at_block(child.number) {
-                constants::EIP1559_INITIAL_BASE_FEE
-            } else {
-                // This BaseFeeMissing will not happen as previous blocks are checked to have them.
-                parent.next_block_base_fee().ok_or(ConsensusError::BaseFeeMissing)?
-            };
-        if expected_base_fee != base_fee {
-            return Err(ConsensusError::BaseFeeDiff { expected: expected_base_fee, got: base_fee })
-        }
+    validate_base_fee(parent, child, chain_spec)?;
+
+    Ok(())
+}
+
+/// Validate the EIP-1559 base fee of a block in regards to its parent.
+///
+/// The base fee of the London activation block is the initial base fee, every following block's
+/// base fee is derived from its parent. Blocks before London are not checked.
+pub fn validate_base_fee(
+    parent: &Header,
+    child: &Header,
+    chain_spec: &ChainSpec,
+) -> Result<(), ConsensusError> {
+    if !chain_spec.fork(Hardfork::London).active_at_block(child.number) {
+        return Ok(())
+    }
+
+    let base_fee = child.base_fee_per_gas.ok_or(ConsensusError::BaseFeeMissing)?;
+
+    let expected_base_fee = if chain_spec.fork(Hardfork::London).transitions_at_block(child.number)
+    {
+        constants::EIP1559_INITIAL_BASE_FEE
+    } else {
+        // This BaseFeeMissing will not happen as previous blocks are checked to have them.
+        parent.next_block_base_fee().ok_or(ConsensusError::BaseFeeMissing)?
+    };
+    if expected_base_fee != base_fee {
+        return Err(ConsensusError::BaseFeeDiff { expected: expected_base_fee, got: base_fee })
     }
 
     Ok(())
@@ -396,9 +422,9 @@ mod tests {
     use mockall::mock;
     use reth_interfaces::{Error::Consensus, Result};
     use reth_primitives::{
-        hex_literal::hex, proofs, Account, Address, BlockHash, BlockHashOrNumber, Bytes,
-        ChainSpecBuilder, Header, Signature, TransactionKind, TransactionSigned, Withdrawal,
-        MAINNET, U256,
+        basefee::calculate_next_block_base_fee, hex_literal::hex, proofs, Account, Address,
+        BlockHash, BlockHashOrNumber, Bytes, ChainSpecBuilder, Header, Signature, TransactionKind,
+        TransactionSigned, Withdrawal, H256, MAINNET, U256,
     };
     use std::ops::RangeBounds;
 
@@ -413,6 +439,8 @@ mod tests {
                 _id: BlockHashOrNumber,
                 _timestamp: u64,
             ) -> RethResult<Option<Vec<Withdrawal>>> ;
+
+            fn withdrawal_by_index(&self, _index: u64) -> Result<Option<Withdrawal>> ;
         }
     }
 
@@ -499,6 +527,10 @@ mod tests {
         fn latest_withdrawal(&self) -> Result<Option<Withdrawal>> {
             self.withdrawals_provider.latest_withdrawal()
         }
+
+        fn withdrawal_by_index(&self, index: u64) -> Result<Option<Withdrawal>> {
+            self.withdrawals_provider.withdrawal_by_index(index)
+        }
     }
 
     fn mock_tx(nonce: u64) -> TransactionSignedEcRecovered {
@@ -543,6 +575,9 @@ mod tests {
             nonce: 0x0000000000000000,
             base_fee_per_gas: 0x28f0001df.into(),
             withdrawals_root: None,
+            blob_gas_used: None,
+            excess_blob_gas: None,
+            parent_beacon_block_root: None,
         };
         // size: 0x9b5
 
@@ -724,4 +759,90 @@ mod tests {
 
         assert_eq!(validate_header_standalone(&header, &chain_spec), Ok(()));
     }
+
+    #[test]
+    fn base_fee_regarding_parent() {
+        let chain_spec = ChainSpecBuilder::mainnet().build();
+        let london = 12_965_000;
+        let initial_base_fee = constants::EIP1559_INITIAL_BASE_FEE;
+
+        // the London activation block has the initial base fee
+        let parent = Header { number: london - 1, gas_limit: 15_000_000, ..Default::default() };
+        let child = Header {
+            number: london,
+            gas_limit: 30_000_000,
+            gas_used: 20_000_000,
+            base_fee_per_gas: Some(initial_base_fee),
+            ..Default::default()
+        };
+        assert_eq!(validate_base_fee(&parent, &child, &chain_spec), Ok(()));
+        let invalid = Header { base_fee_per_gas: Some(initial_base_fee + 1), ..child.clone() };
+        assert_eq!(
+            validate_base_fee(&parent, &invalid, &chain_spec),
+            Err(ConsensusError::BaseFeeDiff {
+                expected: initial_base_fee,
+                got: initial_base_fee + 1
+            })
+        );
+
+        // the base fee of following blocks is derived from their parent
+        let parent = child;
+        let expected = calculate_next_block_base_fee(20_000_000, 30_000_000, initial_base_fee);
+        let child =
+            Header { number: london + 1, base_fee_per_gas: Some(expected), ..Default::default() };
+        assert_eq!(validate_base_fee(&parent, &child, &chain_spec), Ok(()));
+        let invalid = Header { base_fee_per_gas: Some(initial_base_fee), ..child.clone() };
+        assert_eq!(
+            validate_base_fee(&parent, &invalid, &chain_spec),
+            Err(ConsensusError::BaseFeeDiff { expected, got: initial_base_fee })
+        );
+        let missing = Header { base_fee_per_gas: None, ..child };
+        assert_eq!(
+            validate_base_fee(&parent, &missing, &chain_spec),
+            Err(ConsensusError::BaseFeeMissing)
+        );
+
+        // pre-London blocks have no base fee
+        let parent = Header { number: london - 2, ..Default::default() };
+        let child = Header { number: london - 1, ..Default::default() };
+        assert_eq!(validate_base_fee(&parent, &child, &chain_spec), Ok(()));
+    }
+
+    #[test]
+    fn pre_cancun_parent_beacon_block_root() {
+        let chain_spec = ChainSpecBuilder::mainnet().shanghai_activated().build();
+
+        let header = Header {
+            base_fee_per_gas: Some(1337u64),
+            withdrawals_root: Some(proofs::calculate_withdrawals_root(&[])),
+            ..Default::default()
+        };
+        assert_eq!(validate_header_standalone(&header.clone().seal_slow(), &chain_spec), Ok(()));
+
+        let header =
+            Header { parent_beacon_block_root: Some(H256::random()), ..header }.seal_slow();
+        assert_eq!(
+            validate_header_standalone(&header, &chain_spec),
+            Err(ConsensusError::ParentBeaconBlockRootUnexpected)
+        );
+    }
+
+    #[test]
+    fn post_cancun_parent_beacon_block_root() {
+        let chain_spec = ChainSpecBuilder::mainnet().cancun_activated().build();
+
+        let header = Header {
+            base_fee_per_gas: Some(1337u64),
+            withdrawals_root: Some(proofs::calculate_withdrawals_root(&[])),
+            ..Default::default()
+        };
+        assert_eq!(
+            validate_header_standalone(&header.clone().seal_slow(), &chain_spec),
+            Err(ConsensusError::ParentBeaconBlockRootMissing)
+        );
+
+        let header =
+            Header { parent_beacon_block_root: Some(H256::random()), ..header }.seal_slow();
+        assert_eq!(validate_header_standalone(&header, &chain_spec), Ok(()));
+    }
 }
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use reth_primitives::{
-    BlockHash, BlockNumber, Header, InvalidTransactionError, SealedBlock, SealedHeader, H256, U256,
+    BlockHash, BlockNumber, Bloom, Header, InvalidTransactionError, SealedBlock, SealedHeader,
+    H256, U256,
 };
 use std::fmt::Debug;
 
@@ -69,6 +70,10 @@ pub enum ConsensusError {
     BodyTransactionRootDiff { got: H256, expected: H256 },
     #[error("Block withdrawals root ({got:?}) is different from expected ({expected:?})")]
     BodyWithdrawalsRootDiff { got: H256, expected: H256 },
+    #[error("Block receipts root ({got:?}) is different from expected ({expected:?})")]
+    BodyReceiptsRootDiff { got: H256, expected: H256 },
+    #[error("Block logs bloom ({got:?}) is different from expected ({expected:?})")]
+    BodyLogsBloomDiff { got: Box<Bloom>, expected: Box<Bloom> },
     #[error("Block with [hash:{hash:?},number: {number}] is already known.")]
     BlockKnown { hash: BlockHash, number: BlockNumber },
     #[error("Block parent [hash:{hash:?}] is not known.")]
@@ -113,6 +118,12 @@ pub enum ConsensusError {
     WithdrawalIndexInvalid { got: u64, expected: u64 },
     #[error("Missing withdrawals")]
     BodyWithdrawalsMissing,
+    #[error("Missing parent beacon block root")]
+    ParentBeaconBlockRootMissing,
+    #[error("Unexpected parent beacon block root")]
+    ParentBeaconBlockRootUnexpected,
+    #[error("Failed to load the receipts of block #{number}: {error}")]
+    BlockReceiptsUnavailable { number: BlockNumber, error: Box<crate::Error> },
     /// Error for a transaction that violates consensus.
     #[error(transparent)]
     InvalidTransaction(#[from] InvalidTransactionError),
@@ -53,12 +53,21 @@ pub enum ProviderError {
     /// Block body wrong transaction count
     #[error("Stored block indices does not match transaction count")]
     BlockBodyTransactionCount,
+    /// The senders of a block are not stored
+    #[error("Senders of block #{0} not found")]
+    BlockSendersNotFound(BlockNumber),
+    /// The sender of a transaction could not be recovered from its signature
+    #[error("Unable to recover the sender of transaction id {tx_id}")]
+    SenderRecoveryFailed { tx_id: TxNumber },
     /// Thrown when the cache service task dropped
     #[error("cache service task stopped")]
     CacheServiceUnavailable,
     /// Thrown when we failed to lookup a block for the pending state
     #[error("Unknown block hash: {0:}")]
     UnknownBlockHash(H256),
+    /// Thrown when the block hash is known but not part of the canonical chain
+    #[error("Block hash {0:?} is not canonical")]
+    NonCanonicalBlockHash(BlockHash),
     /// Thrown when we were unable to find a state for a block hash
     #[error("No State found for block hash: {0:}")]
     StateForHashNotFound(H256),
@@ -1,14 +1,14 @@
 use crate::{
     errors::{EthHandshakeError, EthStreamError},
     message::{EthBroadcastMessage, ProtocolBroadcastMessage},
-    types::{EthMessage, ProtocolMessage, Status},
+    types::{EthMessage, ProtocolMessage, Status, UpgradeStatus, UpgradeStatusExtensions},
     CanDisconnect, DisconnectReason, EthVersion,
 };
 use futures::{ready, Sink, SinkExt, StreamExt};
 use pin_project::pin_project;
 use reth_primitives::{
     bytes::{Bytes, BytesMut},
-    ForkFilter, Chain,
+    Chain, ForkFilter,
 };
 use reth_rlp::Encodable;
 use std::{
@@ -50,10 +50,31 @@ where
     /// handshake is completed successfully. This also returns the `Status` message sent by the
     /// remote peer.
     pub async fn handshake(
-        mut self,
+        self,
         status: Status,
         fork_filter: ForkFilter,
     ) -> Result<(EthStream<S>, Status), EthStreamError> {
+        let (stream, their_status, _) = self
+            .handshake_with_upgrade_status(status, fork_filter, UpgradeStatusExtensions::default())
+            .await?;
+        Ok((stream, their_status))
+    }
+
+    /// Same as [`Self::handshake`], but advertises the given [`UpgradeStatusExtensions`] in the
+    /// `UpgradeStatus` message that is exchanged with BSC peers after the `Status` handshake.
+    ///
+    /// This also returns the `UpgradeStatus` message sent by a BSC peer, which is `None` for
+    /// other chains.
+    ///
+    /// BSC peers always answer the `Status` with an `UpgradeStatus`, so a BSC peer that sends any
+    /// other message instead is disconnected with [`DisconnectReason::ProtocolBreach`], like a
+    /// peer that doesn't start the handshake with a `Status`.
+    pub async fn handshake_with_upgrade_status(
+        mut self,
+        status: Status,
+        fork_filter: ForkFilter,
+        extensions: UpgradeStatusExtensions,
+    ) -> Result<(EthStream<S>, Status, Option<UpgradeStatus>), EthStreamError> {
         tracing::trace!(
             %status,
             "sending eth status to peer"
@@ -148,11 +169,36 @@ where
                 // the handshake
                 let mut stream = EthStream::new(version, self.inner);
 
-                // We send now the upgrade Status for BSC
-                if status.chain == Chain::Named(ethers_core::types::Chain::BinanceSmartChain) {
-                    let _ = stream.send(EthMessage::UpgradeStatus(crate::UpgradeStatus::default())).await;
+                // BSC peers exchange their upgrade status right after the status
+                if status.chain != Chain::Named(ethers_core::types::Chain::BinanceSmartChain) {
+                    return Ok((stream, resp, None))
                 }
-                Ok((stream, resp))
+
+                stream.send(EthMessage::UpgradeStatus(UpgradeStatus { extensions })).await?;
+
+                let their_upgrade_status = match stream.next().await {
+                    Some(Ok(EthMessage::UpgradeStatus(upgrade_status))) => upgrade_status,
+                    Some(Ok(_)) => {
+                        stream.inner_mut().disconnect(DisconnectReason::ProtocolBreach).await?;
+                        return Err(EthStreamError::EthHandshakeError(
+                            EthHandshakeError::NonStatusMessageInHandshake,
+                        ))
+                    }
+                    Some(Err(err)) => return Err(err),
+                    None => {
+                        stream
+                            .inner_mut()
+                            .disconnect(DisconnectReason::DisconnectRequested)
+                            .await?;
+                        return Err(EthStreamError::EthHandshakeError(EthHandshakeError::NoResponse))
+                    }
+                };
+                tracing::trace!(
+                    upgrade_status=?their_upgrade_status,
+                    "received eth upgrade status from peer"
+                );
+
+                Ok((stream, resp, Some(their_upgrade_status)))
             }
             _ => {
                 self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
@@ -322,7 +368,9 @@ mod tests {
         errors::{EthHandshakeError, EthStreamError},
         hello::HelloMessage,
         p2pstream::{ProtocolVersion, UnauthedP2PStream},
-        types::{broadcast::BlockHashNumber, EthMessage, EthVersion, Status},
+        types::{
+            broadcast::BlockHashNumber, EthMessage, EthVersion, Status, UpgradeStatusExtensions,
+        },
         EthStream, PassthroughCodec,
     };
     use ethers_core::types::Chain;
@@ -380,6 +428,67 @@ mod tests {
         handle.await.unwrap();
     }
 
+    #[tokio::test]
+    async fn can_handshake_with_upgrade_status() {
+        let genesis = H256::random();
+        let fork_filter = ForkFilter::new(Head::default(), genesis, Vec::new());
+
+        let status = Status {
+            version: EthVersion::Eth67 as u8,
+            chain: Chain::BinanceSmartChain.into(),
+            total_difficulty: U256::ZERO,
+            blockhash: H256::random(),
+            genesis,
+            // Pass the current fork id.
+            forkid: fork_filter.current(),
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let local_addr = listener.local_addr().unwrap();
+
+        let status_clone = status;
+        let fork_filter_clone = fork_filter.clone();
+        let handle = tokio::spawn(async move {
+            let (incoming, _) = listener.accept().await.unwrap();
+            let stream = PassthroughCodec::default().framed(incoming);
+            let (_, their_status, their_upgrade_status) = UnauthedEthStream::new(stream)
+                .handshake_with_upgrade_status(
+                    status_clone,
+                    fork_filter_clone,
+                    UpgradeStatusExtensions { disabled_peer_tx_broadcast: false },
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(their_status, status_clone);
+            assert_eq!(
+                their_upgrade_status.unwrap().extensions,
+                UpgradeStatusExtensions { disabled_peer_tx_broadcast: true }
+            );
+        });
+
+        let outgoing = TcpStream::connect(local_addr).await.unwrap();
+        let sink = PassthroughCodec::default().framed(outgoing);
+
+        let (_, their_status, their_upgrade_status) = UnauthedEthStream::new(sink)
+            .handshake_with_upgrade_status(
+                status,
+                fork_filter,
+                UpgradeStatusExtensions { disabled_peer_tx_broadcast: true },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(their_status, status);
+        assert_eq!(
+            their_upgrade_status.unwrap().extensions,
+            UpgradeStatusExtensions { disabled_peer_tx_broadcast: false }
+        );
+
+        // wait for it to finish
+        handle.await.unwrap();
+    }
+
     #[tokio::test]
     async fn pass_handshake_on_low_td_bitlen() {
         let genesis = H256::random();
@@ -252,6 +252,9 @@ mod test {
                     nonce: 0x0000000000000000u64,
                     base_fee_per_gas: None,
                     withdrawals_root: None,
+                    blob_gas_used: None,
+                    excess_blob_gas: None,
+                    parent_beacon_block_root: None,
                 },
             ]),
         }.encode(&mut data);
@@ -283,6 +286,9 @@ mod test {
                     nonce: 0x0000000000000000u64,
                     base_fee_per_gas: None,
                     withdrawals_root: None,
+                    blob_gas_used: None,
+                    excess_blob_gas: None,
+                    parent_beacon_block_root: None,
                 },
             ]),
         };
@@ -395,6 +401,9 @@ mod test {
                             nonce: 0x0000000000000000u64,
                             base_fee_per_gas: None,
                             withdrawals_root: None,
+                            blob_gas_used: None,
+                            excess_blob_gas: None,
+                            parent_beacon_block_root: None,
                         },
                     ],
                     withdrawals: None,
@@ -479,6 +488,9 @@ mod test {
                             nonce: 0x0000000000000000u64,
                             base_fee_per_gas: None,
                             withdrawals_root: None,
+                            blob_gas_used: None,
+                            excess_blob_gas: None,
+                            parent_beacon_block_root: None,
                         },
                     ],
                     withdrawals: None,
@@ -1,6 +1,6 @@
 //! Implements the `GetPooledTransactions` and `PooledTransactions` message types.
 use reth_codecs::derive_arbitrary;
-use reth_primitives::{TransactionSigned, H256};
+use reth_primitives::{PooledTransactionsElement, TransactionSigned, H256};
 use reth_rlp::{RlpDecodableWrapper, RlpEncodableWrapper};
 
 #[cfg(feature = "serde")]
@@ -31,23 +31,27 @@ where
 /// as the request's hashes. Hashes may be skipped, and the client should ensure that each body
 /// corresponds to a requested hash. Hashes may need to be re-requested if the bodies are not
 /// included in the response.
+///
+/// Blob transactions are sent together with their sidecar, see [PooledTransactionsElement].
 #[derive_arbitrary(rlp, 10)]
 #[derive(Clone, Debug, PartialEq, Eq, RlpEncodableWrapper, RlpDecodableWrapper, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PooledTransactions(
     /// The transaction bodies, each of which should correspond to a requested hash.
-    pub Vec<TransactionSigned>,
+    pub Vec<PooledTransactionsElement>,
 );
 
+/// Blob transactions can't be sent without their sidecar and are skipped.
 impl From<Vec<TransactionSigned>> for PooledTransactions {
     fn from(txs: Vec<TransactionSigned>) -> Self {
-        PooledTransactions(txs)
+        PooledTransactions(txs.into_iter().filter_map(|tx| tx.try_into().ok()).collect())
     }
 }
 
+/// Drops the sidecars of blob transactions.
 impl From<PooledTransactions> for Vec<TransactionSigned> {
     fn from(txs: PooledTransactions) -> Self {
-        txs.0
+        txs.0.into_iter().map(PooledTransactionsElement::into_transaction).collect()
     }
 }
 
@@ -56,7 +60,9 @@ mod test {
     use crate::{message::RequestPair, GetPooledTransactions, PooledTransactions};
     use hex_literal::hex;
     use reth_primitives::{
-        hex, Signature, Transaction, TransactionKind, TransactionSigned, TxEip1559, TxLegacy, U256,
+        hex, BlobTransaction, BlobTransactionSidecar, PooledTransactionsElement, Signature,
+        Transaction, TransactionKind, TransactionSigned, TxEip1559, TxEip4844, TxLegacy, H256,
+        U256,
     };
     use reth_rlp::{Decodable, Encodable};
     use std::str::FromStr;
@@ -506,4 +512,64 @@ mod test {
         assert_eq!(encoded_str.len(), expected_str.len());
         assert_eq!(encoded_str, expected_str);
     }
+
+    #[test]
+    fn pooled_transactions_with_blob_sidecar_roundtrip() {
+        let signature = Signature { odd_y_parity: true, r: U256::from(1), s: U256::from(2) };
+        let blob_tx = TransactionSigned::from_transaction_and_signature(
+            Transaction::Eip4844(TxEip4844 {
+                chain_id: 1,
+                nonce: 0,
+                gas_limit: 21_000,
+                max_fee_per_gas: 100,
+                max_priority_fee_per_gas: 10,
+                to: TransactionKind::Call(hex!("d3e8763675e4c425df46cc3b5c0f6cbdac396046").into()),
+                value: 0,
+                access_list: Default::default(),
+                max_fee_per_blob_gas: 20,
+                blob_versioned_hashes: vec![H256::from_low_u64_be(1)],
+                input: Default::default(),
+            }),
+            signature,
+        );
+        let legacy_tx = TransactionSigned::from_transaction_and_signature(
+            Transaction::Legacy(TxLegacy {
+                chain_id: Some(1),
+                nonce: 1,
+                gas_price: 100,
+                gas_limit: 21_000,
+                to: TransactionKind::Create,
+                value: 0,
+                input: Default::default(),
+            }),
+            signature,
+        );
+
+        let request = RequestPair::<PooledTransactions> {
+            request_id: 1,
+            message: PooledTransactions(vec![
+                BlobTransaction {
+                    transaction: blob_tx.clone(),
+                    sidecar: BlobTransactionSidecar {
+                        blobs: vec![[1u8; 131_072]],
+                        commitments: vec![[2u8; 48]],
+                        proofs: vec![[3u8; 48]],
+                    },
+                }
+                .into(),
+                PooledTransactionsElement::try_from(legacy_tx.clone()).unwrap(),
+            ]),
+        };
+
+        let mut encoded = vec![];
+        request.encode(&mut encoded);
+        assert_eq!(encoded.len(), request.length());
+
+        let decoded = RequestPair::<PooledTransactions>::decode(&mut &encoded[..]).unwrap();
+        assert_eq!(decoded, request);
+
+        // the sidecar is dropped when the transactions are handed to the pool
+        let transactions: Vec<TransactionSigned> = decoded.message.into();
+        assert_eq!(transactions, vec![blob_tx, legacy_tx]);
+    }
 }
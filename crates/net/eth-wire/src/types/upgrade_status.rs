@@ -25,8 +25,31 @@ pub struct UpgradeStatusExtensions {
 
 impl Default for UpgradeStatusExtensions {
     fn default() -> Self {
-        Self {
-            disabled_peer_tx_broadcast: false,
-        }
+        Self { disabled_peer_tx_broadcast: false }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EthMessage, EthVersion, ProtocolMessage};
+    use reth_rlp::{Decodable, Encodable};
+
+    #[test]
+    fn roundtrip_disabled_peer_tx_broadcast() {
+        let status = UpgradeStatus {
+            extensions: UpgradeStatusExtensions { disabled_peer_tx_broadcast: true },
+        };
+
+        let mut encoded = Vec::new();
+        status.encode(&mut encoded);
+        assert_eq!(encoded, vec![0xc2, 0xc1, 0x01]);
+        assert_eq!(UpgradeStatus::decode(&mut &encoded[..]).unwrap(), status);
+
+        let mut encoded = Vec::new();
+        ProtocolMessage::from(EthMessage::UpgradeStatus(status)).encode(&mut encoded);
+        let decoded =
+            ProtocolMessage::decode_message(EthVersion::Eth67, &mut &encoded[..]).unwrap();
+        assert_eq!(decoded.message, EthMessage::UpgradeStatus(status));
     }
 }
@@ -36,7 +36,7 @@ use futures::{Future, StreamExt};
 use parking_lot::Mutex;
 use reth_eth_wire::{
     capability::{Capabilities, CapabilityMessage},
-    DisconnectReason, EthVersion, Status,
+    DisconnectReason, EthVersion, Status, UpgradeStatus,
 };
 use reth_net_common::bandwidth_meter::BandwidthMeter;
 use reth_network_api::ReputationChangeKind;
@@ -665,6 +665,7 @@ where
                             version,
                             messages,
                             status,
+                            upgrade_status,
                             direction,
                         } => {
                             let total_active =
@@ -693,6 +694,7 @@ where
                                 capabilities,
                                 version,
                                 status,
+                                upgrade_status,
                                 messages,
                             });
                         }
@@ -908,6 +910,9 @@ pub enum NetworkEvent {
         messages: PeerRequestSender,
         /// The status of the peer to which a session was established.
         status: Status,
+        /// The `UpgradeStatus` of the peer to which a session was established, only sent by BSC
+        /// peers.
+        upgrade_status: Option<UpgradeStatus>,
         /// negotiated eth version of the session
         version: EthVersion,
     },
@@ -111,6 +111,18 @@ impl NetworkHandle {
         rx.await
     }
 
+    /// Returns `true` if the given peer asked to not receive transaction broadcasts in its
+    /// `UpgradeStatus`, see also [`PeerInfo::tx_broadcast_disabled`].
+    ///
+    /// Returns `false` if there's no active session to the peer.
+    pub async fn is_tx_broadcast_disabled(
+        &self,
+        peer_id: PeerId,
+    ) -> Result<bool, oneshot::error::RecvError> {
+        let peer = self.get_peer_by_id(peer_id).await?;
+        Ok(peer.map_or(false, |peer| peer.tx_broadcast_disabled))
+    }
+
     /// Returns the mode of the network, either pow, or pos
     pub fn mode(&self) -> &NetworkMode {
         &self.inner.network_mode
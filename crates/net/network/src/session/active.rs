@@ -94,6 +94,8 @@ pub(crate) struct ActiveSession {
     /// If an [ActiveSession] does not receive a response at all within this duration then it is
     /// considered a protocol violation and the session will initiate a drop.
     pub(crate) protocol_breach_request_timeout: Duration,
+    /// Tracks whether the remote peer keeps responding to our requests.
+    pub(crate) request_timeouts: RequestTimeoutTracker,
 }
 
 impl ActiveSession {
@@ -143,6 +145,7 @@ impl ActiveSession {
                     match req.request {
                         RequestState::Waiting(PeerRequest::$item { response, .. }) => {
                             let _ = response.send(Ok(message));
+                            self.request_timeouts.on_response(Instant::now(), false);
                             self.update_request_timeout(req.timestamp, Instant::now());
                         }
                         RequestState::Waiting(request) => {
@@ -150,6 +153,7 @@ impl ActiveSession {
                         }
                         RequestState::TimedOut => {
                             // request was already timed out internally
+                            self.request_timeouts.on_response(Instant::now(), true);
                             self.update_request_timeout(req.timestamp, Instant::now());
                         }
                     };
@@ -167,9 +171,9 @@ impl ActiveSession {
                 error: EthStreamError::EthHandshakeError(EthHandshakeError::StatusNotInHandshake),
                 message,
             },
-            EthMessage::UpgradeStatus(_) =>  {
-                // UpgradeStatus should be handled in handshake already, therefore only Ok
-                OnIncomingMessageOutcome::Ok 
+            EthMessage::UpgradeStatus(_) => {
+                // UpgradeStatus is exchanged in the handshake already, therefore only Ok
+                OnIncomingMessageOutcome::Ok
             }
             EthMessage::NewBlockHashes(msg) => {
                 self.try_emit_broadcast(PeerMessage::NewBlockHashes(msg)).into()
@@ -236,11 +240,10 @@ impl ActiveSession {
         let request_id = self.next_id();
         let msg = request.create_request_message(request_id);
         self.queued_outgoing.push_back(msg.into());
-        let req = InflightRequest {
-            request: RequestState::Waiting(request),
-            timestamp: Instant::now(),
-            deadline,
-        };
+        let now = Instant::now();
+        self.request_timeouts.on_request(now, !self.inflight_requests.is_empty());
+        let req =
+            InflightRequest { request: RequestState::Waiting(request), timestamp: now, deadline };
         self.inflight_requests.insert(request_id, req);
     }
 
@@ -421,6 +424,7 @@ impl ActiveSession {
                 if req.is_waiting() {
                     debug!(target: "net::session", ?id, remote_peer_id=?self.remote_peer_id, "timed out outgoing request");
                     req.timeout();
+                    self.request_timeouts.on_timeout();
                 } else if now - req.timestamp > self.protocol_breach_request_timeout {
                     return true
                 }
@@ -602,11 +606,20 @@ impl Future for ActiveSession {
                 if this.internal_request_timeout_interval.poll_tick(cx).is_ready() {
                     let _ = this.internal_request_timeout_interval.poll_tick(cx);
                     // check for timed out requests
-                    if this.check_timed_out_requests(Instant::now()) {
+                    let now = Instant::now();
+                    if this.check_timed_out_requests(now) {
                         let _ = this.to_session.clone().try_send(
                             ActiveSessionMessage::ProtocolBreach { peer_id: this.remote_peer_id },
                         );
                     }
+
+                    if this
+                        .request_timeouts
+                        .is_unresponsive(now, !this.inflight_requests.is_empty())
+                    {
+                        debug!(target: "net::session", remote_peer_id=?this.remote_peer_id, "disconnecting unresponsive peer");
+                        return this.try_disconnect(DisconnectReason::UselessPeer, cx)
+                    }
                 }
 
                 return Poll::Pending
@@ -661,6 +674,70 @@ impl InflightRequest {
     }
 }
 
+/// Tracks whether the remote peer keeps responding to the requests we send.
+///
+/// A peer is considered unresponsive if too many requests in a row timed out, or if it did not
+/// respond to any of the pending requests for too long.
+pub(crate) struct RequestTimeoutTracker {
+    /// Number of requests in a row that timed out.
+    consecutive_timeouts: u32,
+    /// When the peer last responded, or when we started waiting for a response.
+    last_response: Instant,
+    /// The number of requests in a row that may time out.
+    max_consecutive_timeouts: Option<u32>,
+    /// The maximum time without any response while requests are pending.
+    max_unresponsive_duration: Option<Duration>,
+}
+
+// === impl RequestTimeoutTracker ===
+
+impl RequestTimeoutTracker {
+    pub(crate) fn new(
+        max_consecutive_timeouts: Option<u32>,
+        max_unresponsive_duration: Option<Duration>,
+    ) -> Self {
+        Self {
+            consecutive_timeouts: 0,
+            last_response: Instant::now(),
+            max_consecutive_timeouts,
+            max_unresponsive_duration,
+        }
+    }
+
+    /// Called when a request is sent to the peer.
+    ///
+    /// If no other requests are pending, the peer was idle because we did not ask for anything,
+    /// so we only start waiting for a response now.
+    fn on_request(&mut self, now: Instant, has_inflight_requests: bool) {
+        if !has_inflight_requests {
+            self.last_response = now;
+        }
+    }
+
+    /// Called when the peer responded to a request, which may have already timed out.
+    fn on_response(&mut self, now: Instant, timed_out: bool) {
+        self.last_response = now;
+        if !timed_out {
+            self.consecutive_timeouts = 0;
+        }
+    }
+
+    /// Called when a request timed out internally.
+    fn on_timeout(&mut self) {
+        self.consecutive_timeouts += 1;
+    }
+
+    /// Returns `true` if the peer exceeded any of the configured limits.
+    fn is_unresponsive(&self, now: Instant, has_inflight_requests: bool) -> bool {
+        if self.max_consecutive_timeouts.map_or(false, |max| self.consecutive_timeouts >= max) {
+            return true
+        }
+        has_inflight_requests &&
+            self.max_unresponsive_duration
+                .map_or(false, |max| now.saturating_duration_since(self.last_response) > max)
+    }
+}
+
 /// All outcome variants when handling an incoming message
 enum OnIncomingMessageOutcome {
     /// Message successfully handled.
@@ -801,6 +878,7 @@ mod tests {
                 self.hello.clone(),
                 self.status,
                 self.fork_filter.clone(),
+                Default::default(),
             ));
 
             let mut stream = ReceiverStream::new(pending_sessions_rx);
@@ -843,6 +921,7 @@ mod tests {
                             INITIAL_REQUEST_TIMEOUT.as_millis() as u64,
                         )),
                         protocol_breach_request_timeout: PROTOCOL_BREACH_REQUEST_TIMEOUT,
+                        request_timeouts: RequestTimeoutTracker::new(None, None),
                     }
                 }
                 ev => {
@@ -1005,6 +1084,90 @@ mod tests {
         }
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_disconnect_unresponsive_peer() {
+        reth_tracing::init_test_tracing();
+
+        let mut builder = SessionBuilder::default();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let local_addr = listener.local_addr().unwrap();
+
+        let request_timeout = Duration::from_millis(100);
+
+        // the peer reads our requests but never responds
+        let (tx, rx) = oneshot::channel();
+        let fut = builder.with_client_stream(local_addr, move |mut client_stream| async move {
+            let mut num_requests = 0;
+            loop {
+                match client_stream.next().await.unwrap() {
+                    Ok(EthMessage::GetBlockBodies(_)) => num_requests += 1,
+                    Ok(_) => {}
+                    Err(err) => {
+                        tx.send((num_requests, err.as_disconnected())).unwrap();
+                        break
+                    }
+                }
+            }
+        });
+        tokio::task::spawn(fut);
+
+        let (incoming, _) = listener.accept().await.unwrap();
+        let mut session = builder.connect_incoming(incoming).await;
+        session.request_timeouts = RequestTimeoutTracker::new(Some(2), None);
+        session.internal_request_timeout_interval = tokio::time::interval_at(
+            tokio::time::Instant::now() + request_timeout,
+            request_timeout,
+        );
+
+        let mut responses = Vec::new();
+        for _ in 0..2 {
+            let (tx, rx) = oneshot::channel();
+            let req = PeerRequest::GetBlockBodies { request: GetBlockBodies(vec![]), response: tx };
+            session.on_internal_peer_request(req, Instant::now());
+            responses.push(rx);
+        }
+        tokio::spawn(session);
+
+        for rx in responses {
+            assert_eq!(rx.await.unwrap().unwrap_err(), RequestError::Timeout);
+        }
+
+        let (num_requests, reason) = rx.await.unwrap();
+        assert_eq!(num_requests, 2);
+        assert_eq!(reason, Some(DisconnectReason::UselessPeer));
+
+        let msg = builder.active_session_rx.next().await.unwrap();
+        match msg {
+            ActiveSessionMessage::Disconnected { .. } => {}
+            ev => unreachable!("{ev:?}"),
+        }
+    }
+
+    #[test]
+    fn unresponsive_peer_tracker() {
+        let start = Instant::now();
+        let max_unresponsive = Duration::from_secs(10);
+        let mut tracker = RequestTimeoutTracker::new(Some(3), Some(max_unresponsive));
+
+        // idle without pending requests is fine
+        assert!(!tracker.is_unresponsive(start + max_unresponsive * 2, false));
+
+        let now = start + max_unresponsive * 2;
+        tracker.on_request(now, false);
+        assert!(!tracker.is_unresponsive(now + max_unresponsive, true));
+        assert!(tracker.is_unresponsive(now + max_unresponsive * 2, true));
+
+        // a response resets the timeouts, late responses don't
+        tracker.on_timeout();
+        tracker.on_timeout();
+        tracker.on_response(now, true);
+        tracker.on_timeout();
+        assert!(tracker.is_unresponsive(now, true));
+        tracker.on_response(now, false);
+        assert!(!tracker.is_unresponsive(now, true));
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_keep_alive() {
         let mut builder = SessionBuilder::default();
@@ -38,6 +38,21 @@ pub struct SessionsConfig {
     /// `PROTOCOL_BREACH_REQUEST_TIMEOUT`) this is considered a protocol violation and results in a
     /// dropped session.
     pub protocol_breach_request_timeout: Duration,
+    /// The number of requests in a row that may time out _internally_ before the peer is
+    /// considered unresponsive and disconnected.
+    ///
+    /// By default, peers are not disconnected because of request timeouts.
+    pub max_consecutive_request_timeouts: Option<u32>,
+    /// The maximum time a peer may go without responding to any of our pending requests before it
+    /// is considered unresponsive and disconnected.
+    ///
+    /// By default, peers are not disconnected because of missing responses.
+    pub max_unresponsive_duration: Option<Duration>,
+    /// Whether to ask BSC peers to not broadcast transactions to us.
+    ///
+    /// This is advertised to the peer in the `UpgradeStatus` message after the `Status`
+    /// handshake.
+    pub disable_peer_tx_broadcast: bool,
 }
 
 impl Default for SessionsConfig {
@@ -56,6 +71,9 @@ impl Default for SessionsConfig {
             limits: Default::default(),
             initial_internal_request_timeout: INITIAL_REQUEST_TIMEOUT,
             protocol_breach_request_timeout: PROTOCOL_BREACH_REQUEST_TIMEOUT,
+            max_consecutive_request_timeouts: None,
+            max_unresponsive_duration: None,
+            disable_peer_tx_broadcast: false,
         }
     }
 }
@@ -70,6 +88,27 @@ impl SessionsConfig {
         self.session_event_buffer = n;
         self
     }
+
+    /// Sets the number of requests in a row that may time out before the peer is disconnected
+    /// with [DisconnectReason::UselessPeer](reth_eth_wire::DisconnectReason::UselessPeer).
+    pub fn with_max_consecutive_request_timeouts(mut self, n: u32) -> Self {
+        self.max_consecutive_request_timeouts = Some(n);
+        self
+    }
+
+    /// Sets the maximum time a peer may go without responding to pending requests before it is
+    /// disconnected with
+    /// [DisconnectReason::UselessPeer](reth_eth_wire::DisconnectReason::UselessPeer).
+    pub fn with_max_unresponsive_duration(mut self, duration: Duration) -> Self {
+        self.max_unresponsive_duration = Some(duration);
+        self
+    }
+
+    /// Sets whether BSC peers are asked to not broadcast transactions to us.
+    pub fn with_disable_peer_tx_broadcast(mut self, disable: bool) -> Self {
+        self.disable_peer_tx_broadcast = disable;
+        self
+    }
 }
 
 /// Limits for sessions.
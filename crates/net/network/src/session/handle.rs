@@ -7,7 +7,7 @@ use reth_ecies::{stream::ECIESStream, ECIESError};
 use reth_eth_wire::{
     capability::{Capabilities, CapabilityMessage},
     errors::EthStreamError,
-    DisconnectReason, EthStream, EthVersion, P2PStream, Status,
+    DisconnectReason, EthStream, EthVersion, P2PStream, Status, UpgradeStatus,
 };
 use reth_net_common::bandwidth_meter::MeteredStream;
 use reth_primitives::PeerId;
@@ -65,6 +65,8 @@ pub(crate) struct ActiveSessionHandle {
     pub(crate) client_version: Arc<String>,
     /// The address we're connected to
     pub(crate) remote_addr: SocketAddr,
+    /// Whether the peer asked to not receive transaction broadcasts in its `UpgradeStatus`.
+    pub(crate) tx_broadcast_disabled: bool,
 }
 
 // === impl ActiveSessionHandle ===
@@ -90,6 +92,10 @@ pub struct PeerInfo {
     pub remote_addr: SocketAddr,
     /// The direction of the session
     pub direction: Direction,
+    /// Whether the peer asked to not receive transaction broadcasts in its `UpgradeStatus`.
+    ///
+    /// This is always `false` for peers of chains other than BSC.
+    pub tx_broadcast_disabled: bool,
 }
 
 /// Events a pending session can produce.
@@ -107,6 +113,8 @@ pub(crate) enum PendingSessionEvent {
         peer_id: PeerId,
         capabilities: Arc<Capabilities>,
         status: Status,
+        /// The `UpgradeStatus` a BSC peer sent after the `Status` handshake.
+        upgrade_status: Option<UpgradeStatus>,
         conn: EthStream<P2PStream<ECIESStream<MeteredStream<TcpStream>>>>,
         direction: Direction,
         client_id: String,
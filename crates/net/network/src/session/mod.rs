@@ -2,7 +2,7 @@
 use crate::{
     message::PeerMessage,
     session::{
-        active::ActiveSession,
+        active::{ActiveSession, RequestTimeoutTracker},
         config::SessionCounter,
         handle::{
             ActiveSessionHandle, ActiveSessionMessage, PendingSessionEvent, PendingSessionHandle,
@@ -18,6 +18,7 @@ use reth_eth_wire::{
     capability::{Capabilities, CapabilityMessage},
     errors::EthStreamError,
     DisconnectReason, EthVersion, HelloMessage, Status, UnauthedEthStream, UnauthedP2PStream,
+    UpgradeStatus, UpgradeStatusExtensions,
 };
 use reth_metrics::common::mpsc::MeteredSender;
 use reth_net_common::{
@@ -66,6 +67,12 @@ pub(crate) struct SessionManager {
     /// If an [ActiveSession] does not receive a response at all within this duration then it is
     /// considered a protocol violation and the session will initiate a drop.
     protocol_breach_request_timeout: Duration,
+    /// The number of requests in a row an [ActiveSession] tolerates to time out before it
+    /// disconnects the peer.
+    max_consecutive_request_timeouts: Option<u32>,
+    /// The maximum time an [ActiveSession] waits for any response to pending requests before it
+    /// disconnects the peer.
+    max_unresponsive_duration: Option<Duration>,
     /// The secret key used for authenticating sessions.
     secret_key: SecretKey,
     /// The `Status` message to send to peers.
@@ -74,6 +81,8 @@ pub(crate) struct SessionManager {
     hello_message: HelloMessage,
     /// The [`ForkFilter`] used to validate the peer's `Status` message.
     fork_filter: ForkFilter,
+    /// The extensions to advertise in the `UpgradeStatus` message to BSC peers.
+    upgrade_status_extensions: UpgradeStatusExtensions,
     /// Size of the command buffer per session.
     session_command_buffer: usize,
     /// The executor for spawned tasks.
@@ -124,10 +133,15 @@ impl SessionManager {
             counter: SessionCounter::new(config.limits),
             initial_internal_request_timeout: config.initial_internal_request_timeout,
             protocol_breach_request_timeout: config.protocol_breach_request_timeout,
+            max_consecutive_request_timeouts: config.max_consecutive_request_timeouts,
+            max_unresponsive_duration: config.max_unresponsive_duration,
             secret_key,
             status,
             hello_message,
             fork_filter,
+            upgrade_status_extensions: UpgradeStatusExtensions {
+                disabled_peer_tx_broadcast: config.disable_peer_tx_broadcast,
+            },
             session_command_buffer: config.session_command_buffer,
             executor,
             pending_sessions: Default::default(),
@@ -209,6 +223,7 @@ impl SessionManager {
         let hello_message = self.hello_message.clone();
         let status = self.status;
         let fork_filter = self.fork_filter.clone();
+        let upgrade_status_extensions = self.upgrade_status_extensions;
         self.spawn(start_pending_incoming_session(
             disconnect_rx,
             session_id,
@@ -219,6 +234,7 @@ impl SessionManager {
             hello_message,
             status,
             fork_filter,
+            upgrade_status_extensions,
         ));
 
         let handle = PendingSessionHandle {
@@ -241,6 +257,7 @@ impl SessionManager {
             let hello_message = self.hello_message.clone();
             let fork_filter = self.fork_filter.clone();
             let status = self.status;
+            let upgrade_status_extensions = self.upgrade_status_extensions;
             let band_with_meter = self.bandwidth_meter.clone();
             self.spawn(start_pending_outbound_session(
                 disconnect_rx,
@@ -252,6 +269,7 @@ impl SessionManager {
                 hello_message,
                 status,
                 fork_filter,
+                upgrade_status_extensions,
                 band_with_meter,
             ));
 
@@ -391,6 +409,7 @@ impl SessionManager {
                 capabilities,
                 conn,
                 status,
+                upgrade_status,
                 direction,
                 client_id,
             } => {
@@ -453,6 +472,10 @@ impl SessionManager {
                     ),
                     internal_request_timeout: Arc::clone(&timeout),
                     protocol_breach_request_timeout: self.protocol_breach_request_timeout,
+                    request_timeouts: RequestTimeoutTracker::new(
+                        self.max_consecutive_request_timeouts,
+                        self.max_unresponsive_duration,
+                    ),
                 };
 
                 self.spawn(session);
@@ -468,6 +491,9 @@ impl SessionManager {
                     commands_to_session,
                     client_version: Arc::clone(&client_version),
                     remote_addr,
+                    tx_broadcast_disabled: upgrade_status
+                        .as_ref()
+                        .map_or(false, |status| status.extensions.disabled_peer_tx_broadcast),
                 };
 
                 self.active_sessions.insert(peer_id, handle);
@@ -480,6 +506,7 @@ impl SessionManager {
                     version,
                     capabilities,
                     status,
+                    upgrade_status,
                     messages,
                     direction,
                     timeout,
@@ -566,6 +593,7 @@ impl SessionManager {
                 remote_addr: session.remote_addr,
                 capabilities: session.capabilities.clone(),
                 client_version: session.client_version.clone(),
+                tx_broadcast_disabled: session.tx_broadcast_disabled,
             })
             .collect()
     }
@@ -580,6 +608,7 @@ impl SessionManager {
             remote_addr: session.remote_addr,
             capabilities: session.capabilities.clone(),
             client_version: session.client_version.clone(),
+            tx_broadcast_disabled: session.tx_broadcast_disabled,
         })
     }
 }
@@ -598,6 +627,8 @@ pub(crate) enum SessionEvent {
         /// negotiated eth version
         version: EthVersion,
         status: Status,
+        /// The `UpgradeStatus` a BSC peer sent after the `Status` handshake.
+        upgrade_status: Option<UpgradeStatus>,
         messages: PeerRequestSender,
         direction: Direction,
         timeout: Arc<AtomicU64>,
@@ -726,6 +757,7 @@ pub(crate) async fn start_pending_incoming_session(
     hello: HelloMessage,
     status: Status,
     fork_filter: ForkFilter,
+    upgrade_status_extensions: UpgradeStatusExtensions,
 ) {
     authenticate(
         disconnect_rx,
@@ -738,6 +770,7 @@ pub(crate) async fn start_pending_incoming_session(
         hello,
         status,
         fork_filter,
+        upgrade_status_extensions,
     )
     .await
 }
@@ -755,6 +788,7 @@ async fn start_pending_outbound_session(
     hello: HelloMessage,
     status: Status,
     fork_filter: ForkFilter,
+    upgrade_status_extensions: UpgradeStatusExtensions,
     bandwidth_meter: BandwidthMeter,
 ) {
     let stream = match TcpStream::connect(remote_addr).await {
@@ -782,6 +816,7 @@ async fn start_pending_outbound_session(
         hello,
         status,
         fork_filter,
+        upgrade_status_extensions,
     )
     .await
 }
@@ -799,6 +834,7 @@ async fn authenticate(
     hello: HelloMessage,
     status: Status,
     fork_filter: ForkFilter,
+    upgrade_status_extensions: UpgradeStatusExtensions,
 ) {
     let stream = match get_eciess_stream(stream, secret_key, direction).await {
         Ok(stream) => stream,
@@ -825,6 +861,7 @@ async fn authenticate(
         hello,
         status,
         fork_filter,
+        upgrade_status_extensions,
     )
     .boxed();
 
@@ -872,6 +909,7 @@ async fn authenticate_stream(
     hello: HelloMessage,
     status: Status,
     fork_filter: ForkFilter,
+    upgrade_status_extensions: UpgradeStatusExtensions,
 ) -> PendingSessionEvent {
     // conduct the p2p handshake and return the authenticated stream
     let (p2p_stream, their_hello) = match stream.handshake(hello).await {
@@ -891,7 +929,10 @@ async fn authenticate_stream(
     // Before trying status handshake, set up the version to shared_capability
     let status = Status { version: p2p_stream.shared_capability().version(), ..status };
     let eth_unauthed = UnauthedEthStream::new(p2p_stream);
-    let (eth_stream, their_status) = match eth_unauthed.handshake(status, fork_filter).await {
+    let (eth_stream, their_status, their_upgrade_status) = match eth_unauthed
+        .handshake_with_upgrade_status(status, fork_filter, upgrade_status_extensions)
+        .await
+    {
         Ok(stream_res) => stream_res,
         Err(err) => {
             return PendingSessionEvent::Disconnected {
@@ -908,6 +949,7 @@ async fn authenticate_stream(
         peer_id: their_hello.id,
         capabilities: Arc::new(Capabilities::from(their_hello.capabilities)),
         status: their_status,
+        upgrade_status: their_upgrade_status,
         conn: eth_stream,
         direction,
         client_id: their_hello.client_version,
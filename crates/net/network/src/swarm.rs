@@ -9,7 +9,7 @@ use futures::Stream;
 use reth_eth_wire::{
     capability::{Capabilities, CapabilityMessage},
     errors::EthStreamError,
-    DisconnectReason, EthVersion, Status,
+    DisconnectReason, EthVersion, Status, UpgradeStatus,
 };
 use reth_primitives::PeerId;
 use reth_provider::BlockProvider;
@@ -129,6 +129,7 @@ where
                 capabilities,
                 version,
                 status,
+                upgrade_status,
                 messages,
                 direction,
                 timeout,
@@ -148,6 +149,7 @@ where
                     version,
                     messages,
                     status,
+                    upgrade_status,
                     direction,
                 })
             }
@@ -399,6 +401,8 @@ pub(crate) enum SwarmEvent {
         version: EthVersion,
         messages: PeerRequestSender,
         status: Status,
+        /// The `UpgradeStatus` a BSC peer sent after the `Status` handshake.
+        upgrade_status: Option<UpgradeStatus>,
         direction: Direction,
     },
     SessionClosed {
@@ -176,7 +176,7 @@ where
             // we sent a response at which point we assume that the peer is aware of the transaction
             peer.transactions.extend(transactions.iter().map(|tx| tx.hash()));
 
-            let resp = PooledTransactions(transactions);
+            let resp = PooledTransactions::from(transactions);
             let _ = response.send(Ok(resp));
         }
     }
@@ -231,6 +231,11 @@ where
 
         // Note: Assuming ~random~ order due to random state of the peers map hasher
         for (peer_idx, (peer_id, peer)) in self.peers.iter_mut().enumerate() {
+            // skip peers that don't want to receive transactions, so they aren't marked as seen
+            if peer.tx_broadcast_disabled {
+                continue
+            }
+
             // filter all transactions unknown to the peer
             let mut hashes = PooledTransactionsHashesBuilder::new(peer.version);
             let mut full_transactions = FullTransactionsBuilder::default();
@@ -355,8 +360,16 @@ where
                 self.peers.remove(&peer_id);
             }
             NetworkEvent::SessionEstablished {
-                peer_id, client_version, messages, version, ..
+                peer_id,
+                client_version,
+                messages,
+                version,
+                upgrade_status,
+                ..
             } => {
+                let tx_broadcast_disabled = upgrade_status
+                    .map_or(false, |status| status.extensions.disabled_peer_tx_broadcast);
+
                 // insert a new peer into the peerset
                 self.peers.insert(
                     peer_id,
@@ -367,13 +380,14 @@ where
                         request_tx: messages,
                         version,
                         client_version,
+                        tx_broadcast_disabled,
                     },
                 );
 
                 // Send a `NewPooledTransactionHashes` to the peer with up to
                 // `NEW_POOLED_TRANSACTION_HASHES_SOFT_LIMIT` transactions in the
                 // pool
-                if !self.network.is_syncing() {
+                if !self.network.is_syncing() && !tx_broadcast_disabled {
                     let peer = self.peers.get_mut(&peer_id).expect("is present; qed");
 
                     let mut msg_builder = PooledTransactionsHashesBuilder::new(version);
@@ -527,7 +541,7 @@ where
                     this.inflight_requests.push(req);
                 }
                 Poll::Ready(Ok(Ok(txs))) => {
-                    this.import_transactions(req.peer_id, txs.0, TransactionSource::Response);
+                    this.import_transactions(req.peer_id, txs.into(), TransactionSource::Response);
                 }
                 Poll::Ready(Ok(Err(_))) => {
                     this.report_bad_message(req.peer_id);
@@ -706,6 +720,8 @@ struct Peer {
     /// The peer's client version.
     #[allow(unused)]
     client_version: Arc<String>,
+    /// Whether the peer asked to not receive transaction broadcasts in its `UpgradeStatus`.
+    tx_broadcast_disabled: bool,
 }
 
 /// Commands to send to the [`TransactionsManager`](crate::transactions::TransactionsManager)
@@ -821,6 +837,7 @@ mod tests {
                     capabilities,
                     messages,
                     status,
+                    upgrade_status,
                     version,
                 } => {
                     // to insert a new peer in transactions peerset
@@ -831,6 +848,7 @@ mod tests {
                         capabilities,
                         messages,
                         status,
+                        upgrade_status,
                         version,
                     })
                 }
@@ -900,6 +918,7 @@ mod tests {
                     capabilities,
                     messages,
                     status,
+                    upgrade_status,
                     version,
                 } => transactions.on_network_event(NetworkEvent::SessionEstablished {
                     peer_id,
@@ -908,6 +927,7 @@ mod tests {
                     capabilities,
                     messages,
                     status,
+                    upgrade_status,
                     version,
                 }),
                 NetworkEvent::PeerAdded(_peer_id) => continue,
@@ -943,4 +963,57 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_no_propagation_to_peers_with_disabled_tx_broadcast() {
+        reth_tracing::init_test_tracing();
+
+        let secret_key = SecretKey::new(&mut rand::thread_rng());
+
+        let client = NoopProvider::default();
+        let pool = testing_pool();
+        let config = NetworkConfigBuilder::new(secret_key)
+            .disable_discovery()
+            .listener_port(0)
+            .build(client);
+        let (_handle, network, mut transactions, _) = NetworkManager::new(config)
+            .await
+            .unwrap()
+            .into_builder()
+            .transactions(pool.clone())
+            .split_with_handle();
+        tokio::task::spawn(network);
+
+        let new_peer = |tx_broadcast_disabled| {
+            let peer_id = PeerId::random();
+            let (to_session_tx, _) = mpsc::channel(1);
+            let peer = Peer {
+                transactions: LruCache::new(
+                    NonZeroUsize::new(PEER_TRANSACTION_CACHE_LIMIT).unwrap(),
+                ),
+                request_tx: PeerRequestSender::new(peer_id, to_session_tx),
+                version: EthVersion::Eth67,
+                client_version: Default::default(),
+                tx_broadcast_disabled,
+            };
+            (peer_id, peer)
+        };
+        let (peer_id, peer) = new_peer(false);
+        transactions.peers.insert(peer_id, peer);
+        let (disabled_peer_id, disabled_peer) = new_peer(true);
+        transactions.peers.insert(disabled_peer_id, disabled_peer);
+
+        // random tx: <https://etherscan.io/getRawTx?tx=0x9448608d36e721ef403c53b00546068a6474d6cbab6816c3926de449898e7bce>
+        let input = hex::decode("02f871018302a90f808504890aef60826b6c94ddf4c5025d1a5742cf12f74eec246d4432c295e487e09c3bbcc12b2b80c080a0f21a4eacd0bf8fea9c5105c543be5a1d8c796516875710fafafdf16d16d8ee23a001280915021bb446d1973501a67f93d2b38894a514b976e7b46dc2fe54598d76").unwrap();
+        let signed_tx = TransactionSigned::decode(&mut &input[..]).unwrap();
+        let hash = signed_tx.hash();
+
+        let propagated = transactions
+            .propagate_transactions(vec![PropagateTransaction::new(Arc::new(signed_tx))]);
+
+        assert_eq!(propagated.0.get(&hash).unwrap(), &vec![PropagateKind::Full(peer_id)]);
+        assert!(transactions.peers[&peer_id].transactions.contains(&hash));
+        // the transaction isn't marked as seen by the peer that didn't receive it
+        assert!(!transactions.peers[&disabled_peer_id].transactions.contains(&hash));
+    }
 }
@@ -14,10 +14,10 @@ use reth_network::{
     test_utils::{
         enr_to_peer_id, unused_tcp_udp, NetworkEventStream, PeerConfig, Testnet, GETH_TIMEOUT,
     },
-    NetworkConfigBuilder, NetworkEvent, NetworkManager, PeersConfig,
+    NetworkConfigBuilder, NetworkEvent, NetworkManager, PeersConfig, SessionsConfig,
 };
 use reth_network_api::{NetworkInfo, Peers, PeersInfo};
-use reth_primitives::{mainnet_nodes, HeadersDirection, NodeRecord, PeerId};
+use reth_primitives::{mainnet_nodes, HeadersDirection, NodeRecord, PeerId, BSC};
 use reth_provider::test_utils::NoopProvider;
 use reth_transaction_pool::test_utils::testing_pool;
 use secp256k1::SecretKey;
@@ -196,6 +196,45 @@ async fn test_get_peer_by_id() {
     assert!(peer.is_none());
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tx_broadcast_disabled_by_bsc_peer() {
+    reth_tracing::init_test_tracing();
+
+    let launch = |disable_peer_tx_broadcast| async move {
+        let (p2p, disc) = unused_tcp_udp();
+        let config = NetworkConfigBuilder::new(SecretKey::new(&mut rand::thread_rng()))
+            .listener_addr(p2p)
+            .discovery_addr(disc)
+            .disable_dns_discovery()
+            .disable_discv4_discovery()
+            .chain_spec(BSC.clone())
+            .sessions_config(
+                SessionsConfig::default().with_disable_peer_tx_broadcast(disable_peer_tx_broadcast),
+            )
+            .build(NoopProvider::default());
+        let network = NetworkManager::new(config).await.unwrap();
+        let handle = network.handle().clone();
+        tokio::task::spawn(network);
+        handle
+    };
+    let handle0 = launch(false).await;
+    let handle1 = launch(true).await;
+
+    let mut listener0 = NetworkEventStream::new(handle0.event_listener());
+    let mut listener1 = NetworkEventStream::new(handle1.event_listener());
+
+    handle0.add_peer(*handle1.peer_id(), handle1.local_addr());
+    let _ = listener0.next_session_established().await.unwrap();
+    let _ = listener1.next_session_established().await.unwrap();
+
+    // both peers learned the other's setting in the handshake
+    assert!(handle0.is_tx_broadcast_disabled(*handle1.peer_id()).await.unwrap());
+    assert!(!handle1.is_tx_broadcast_disabled(*handle0.peer_id()).await.unwrap());
+
+    // there's no session to an unknown peer
+    assert!(!handle0.is_tx_broadcast_disabled(PeerId::random()).await.unwrap());
+}
+
 #[tokio::test(flavor = "multi_thread")]
 #[ignore]
 async fn test_connect_with_boot_nodes() {
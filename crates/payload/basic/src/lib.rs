@@ -708,6 +708,9 @@ fn build_payload<Pool, Client>(
             difficulty: U256::ZERO,
             gas_used: cumulative_gas_used,
             extra_data: extra_data.into(),
+            blob_gas_used: None,
+            excess_blob_gas: None,
+            parent_beacon_block_root: None,
         };
 
         // seal the block
@@ -779,6 +782,9 @@ where
         difficulty: U256::ZERO,
         gas_used: 0,
         extra_data: extra_data.into(),
+        blob_gas_used: None,
+        excess_blob_gas: None,
+        parent_beacon_block_root: None,
     };
 
     let block = Block { header, body: vec![], ommers: vec![], withdrawals };
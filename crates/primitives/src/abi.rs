@@ -1,16 +1,95 @@
 //! Eth ABI helpers.
 use crate::constants::SELECTOR_LEN;
+use ethers_core::{abi::AbiDecode, types::U256};
 
-/// Returns the revert reason from the given output data, if it's an abi encoded String. Returns
-/// `None` if the output is not long enough to contain a function selector or the content is not a
-/// valid abi encoded String.
+/// The function selector of `Error(string)`, used by `revert` and `require`.
+pub const ERROR_SELECTOR: [u8; SELECTOR_LEN] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// The function selector of `Panic(uint256)`, used by failing assertions and checked arithmetic.
+pub const PANIC_SELECTOR: [u8; SELECTOR_LEN] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Returns the revert reason from the given output data.
+///
+/// This supports an abi encoded `Error(string)`, in which case the string is returned, and an abi
+/// encoded `Panic(uint256)`, in which case a description of the panic code is returned, for
+/// example `Panic: arithmetic overflow`.
+///
+/// Returns `None` if the output is not long enough to contain a function selector, the selector is
+/// unknown or the content is not validly abi encoded.
 ///
 /// **Note:** it's assumed the `out` buffer starts with the call's signature
 pub fn decode_revert_reason(out: impl AsRef<[u8]>) -> Option<String> {
-    use ethers_core::abi::AbiDecode;
     let out = out.as_ref();
     if out.len() < SELECTOR_LEN {
         return None
     }
-    String::decode(&out[SELECTOR_LEN..]).ok()
+    let (selector, data) = out.split_at(SELECTOR_LEN);
+    if selector == ERROR_SELECTOR {
+        String::decode(data).ok()
+    } else if selector == PANIC_SELECTOR {
+        let code = U256::decode(data).ok()?;
+        let reason = match panic_reason(code) {
+            Some(reason) => format!("Panic: {reason}"),
+            None => format!("Panic: unknown code {code:#x}"),
+        };
+        Some(reason)
+    } else {
+        None
+    }
+}
+
+/// Returns the description of a solidity panic code.
+///
+/// See also <https://docs.soliditylang.org/en/latest/control-structures.html#panic-via-assert-and-error-via-require>
+fn panic_reason(code: U256) -> Option<&'static str> {
+    if code > U256::from(u8::MAX) {
+        return None
+    }
+    let reason = match code.low_u32() {
+        0x00 => "generic compiler panic",
+        0x01 => "assertion failed",
+        0x11 => "arithmetic overflow",
+        0x12 => "division or modulo by zero",
+        0x21 => "invalid enum value",
+        0x22 => "invalid storage byte array encoding",
+        0x31 => "pop on empty array",
+        0x32 => "array index out of bounds",
+        0x41 => "out of memory",
+        0x51 => "call to zero-initialized function",
+        _ => return None,
+    };
+    Some(reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers_core::abi::AbiEncode;
+
+    #[test]
+    fn decode_error_string() {
+        let out = [ERROR_SELECTOR.to_vec(), "not enough funds".to_string().encode()].concat();
+        assert_eq!(decode_revert_reason(out), Some("not enough funds".to_string()));
+    }
+
+    #[test]
+    fn decode_panic_code() {
+        let out = [PANIC_SELECTOR.to_vec(), U256::from(0x11).encode()].concat();
+        assert_eq!(decode_revert_reason(out), Some("Panic: arithmetic overflow".to_string()));
+
+        let out = [PANIC_SELECTOR.to_vec(), U256::from(0x99).encode()].concat();
+        assert_eq!(decode_revert_reason(out), Some("Panic: unknown code 0x99".to_string()));
+    }
+
+    #[test]
+    fn decode_malformed() {
+        assert_eq!(decode_revert_reason([0u8; 0]), None);
+        assert_eq!(decode_revert_reason(ERROR_SELECTOR), None);
+        assert_eq!(decode_revert_reason([ERROR_SELECTOR.as_slice(), &[0x01; 7]].concat()), None);
+        assert_eq!(decode_revert_reason([PANIC_SELECTOR.as_slice(), &[0x01; 7]].concat()), None);
+
+        // unknown selector
+        let out = [vec![0xde, 0xad, 0xbe, 0xef], "not enough funds".to_string().encode()].concat();
+        assert_eq!(decode_revert_reason(out), None);
+    }
 }
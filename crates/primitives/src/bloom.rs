@@ -145,6 +145,14 @@ impl Bloom {
     pub fn data(&self) -> &[u8; BLOOM_SIZE] {
         &self.0
     }
+
+    /// Computes the bloom of the given logs, e.g. to reconstruct or verify the `logs_bloom` of a
+    /// receipt or block.
+    ///
+    /// See also [logs_bloom].
+    pub fn from_logs(logs: &[Log]) -> Bloom {
+        logs_bloom(logs)
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -242,8 +250,16 @@ mod tests {
                     .into(),
             },
         ];
+        let bloom = Bloom::from_logs(&logs);
+        for log in &logs {
+            assert!(bloom.contains_bloom(&Bloom::from(Input::Raw(log.address.as_bytes()))));
+            for topic in &log.topics {
+                assert!(bloom.contains_bloom(&Bloom::from(Input::Raw(topic.as_bytes()))));
+            }
+        }
+        assert_eq!(logs_bloom(&logs), bloom);
         assert_eq!(
-            logs_bloom(&logs),
+            bloom,
             Bloom::from(hex!(
                 "000000000000000000810000000000000000000000000000000000020000000000000000000000000000008000"
                 "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
@@ -53,6 +53,7 @@ pub static MAINNET: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
                 },
             ),
             (Hardfork::Shanghai, ForkCondition::Timestamp(1681338455)),
+            // Cancun isn't scheduled until the engine API carries the Cancun payload fields
         ]),
     }
     .into()
@@ -295,6 +296,15 @@ impl ChainSpec {
         self.fork(fork).active_at_timestamp(timestamp)
     }
 
+    /// Returns `true` if the given fork is active at the given [Head].
+    ///
+    /// This checks block, timestamp and total difficulty based fork conditions alike, see
+    /// [ForkCondition::active_at_head].
+    #[inline]
+    pub fn is_active(&self, fork: Hardfork, head: Head) -> bool {
+        self.fork(fork).active_at_head(&head)
+    }
+
     /// Convenience method to check if [Hardfork::Shanghai] is active at a given timestamp.
     #[inline]
     pub fn is_shanghai_activated_at_timestamp(&self, timestamp: u64) -> bool {
@@ -304,6 +314,15 @@ impl ChainSpec {
             .unwrap_or_else(|| self.is_fork_active_at_timestamp(Hardfork::Shanghai, timestamp))
     }
 
+    /// Convenience method to check if [Hardfork::Cancun] is active at a given timestamp.
+    #[inline]
+    pub fn is_cancun_activated_at_timestamp(&self, timestamp: u64) -> bool {
+        self.fork_timestamps
+            .cancun
+            .map(|cancun| timestamp >= cancun)
+            .unwrap_or_else(|| self.is_fork_active_at_timestamp(Hardfork::Cancun, timestamp))
+    }
+
     /// Creates a [`ForkFilter`](crate::ForkFilter) for the block described by [Head].
     pub fn fork_filter(&self, head: Head) -> ForkFilter {
         let forks = self.forks_iter().filter_map(|(_, condition)| {
@@ -428,6 +447,8 @@ impl From<EthersGenesis> for ChainSpec {
 pub struct ForkTimestamps {
     /// The timestamp of the shanghai fork
     pub shanghai: Option<u64>,
+    /// The timestamp of the cancun fork
+    pub cancun: Option<u64>,
 }
 
 impl ForkTimestamps {
@@ -437,6 +458,9 @@ impl ForkTimestamps {
         if let Some(shanghai) = forks.get(&Hardfork::Shanghai).and_then(|f| f.as_timestamp()) {
             timestamps = timestamps.shanghai(shanghai);
         }
+        if let Some(cancun) = forks.get(&Hardfork::Cancun).and_then(|f| f.as_timestamp()) {
+            timestamps = timestamps.cancun(cancun);
+        }
         timestamps
     }
 
@@ -445,6 +469,12 @@ impl ForkTimestamps {
         self.shanghai = Some(shanghai);
         self
     }
+
+    /// Sets the given cancun timestamp
+    pub fn cancun(mut self, cancun: u64) -> Self {
+        self.cancun = Some(cancun);
+        self
+    }
 }
 
 /// A helper type for compatibility with geth's config
@@ -604,6 +634,13 @@ impl ChainSpecBuilder {
         self
     }
 
+    /// Enable Cancun at genesis.
+    pub fn cancun_activated(mut self) -> Self {
+        self = self.shanghai_activated();
+        self.hardforks.insert(Hardfork::Cancun, ForkCondition::Timestamp(0));
+        self
+    }
+
     /// Build the resulting [`ChainSpec`].
     ///
     /// # Panics
@@ -791,6 +828,20 @@ mod tests {
         assert_eq!(spec.fork_timestamps.shanghai, Some(1337));
         assert!(spec.is_shanghai_activated_at_timestamp(1337));
         assert!(!spec.is_shanghai_activated_at_timestamp(1336));
+        assert!(spec.fork_timestamps.cancun.is_none());
+        assert!(!spec.is_cancun_activated_at_timestamp(u64::MAX));
+
+        let spec = ChainSpec::builder()
+            .chain(Chain::mainnet())
+            .genesis(Genesis::default())
+            .with_fork(Hardfork::Shanghai, ForkCondition::Timestamp(1337))
+            .with_fork(Hardfork::Cancun, ForkCondition::Timestamp(1447))
+            .build();
+        assert_eq!(spec.fork_timestamps.cancun, Some(1447));
+        assert!(spec.is_cancun_activated_at_timestamp(1447));
+        assert!(!spec.is_cancun_activated_at_timestamp(1446));
+
+        assert!(!MAINNET.is_cancun_activated_at_timestamp(u64::MAX));
     }
 
     // Tests that all predefined timestamps are correctly set up in the chainspecs
@@ -802,14 +853,19 @@ mod tests {
             // no corresponding entry in the ForkTimestamp types, See also
             // [ForkTimestamps::from_hardforks]
 
-            // currently there are only 1 timestamps known: shanghai
-            let known_timestamp_based_forks = 1;
+            // currently there are 2 timestamps known: shanghai, cancun
+            let known_timestamp_based_forks =
+                [spec.fork_timestamps.shanghai, spec.fork_timestamps.cancun]
+                    .iter()
+                    .flatten()
+                    .count();
             let num_timestamp_based_forks =
                 spec.hardforks.values().copied().filter(ForkCondition::is_timestamp).count();
             assert_eq!(num_timestamp_based_forks, known_timestamp_based_forks);
 
             // ensures all timestamp forks are set
             assert!(spec.fork_timestamps.shanghai.is_some());
+            assert_eq!(spec.fork_timestamps, ForkTimestamps::from_hardforks(&spec.hardforks));
         }
 
         for spec in [&*MAINNET, &*SEPOLIA] {
@@ -1164,6 +1220,38 @@ mod tests {
             .active_at_ttd(first_pos_block_ttd, first_pos_difficulty));
     }
 
+    /// Checks [ChainSpec::is_active] at the activation boundaries of each kind of fork condition.
+    #[test]
+    fn is_active_at_fork_boundaries() {
+        let chainspec = ChainSpecBuilder::mainnet().build();
+
+        // London is activated at block 12965000
+        let london = |number| Head { number, ..Default::default() };
+        assert!(!chainspec.is_active(Hardfork::London, london(12964999)));
+        assert!(chainspec.is_active(Hardfork::London, london(12965000)));
+
+        // Shanghai is activated at timestamp 1681338455
+        let shanghai = |timestamp| Head { number: 17034870, timestamp, ..Default::default() };
+        assert!(!chainspec.is_active(Hardfork::Shanghai, shanghai(1681338454)));
+        assert!(chainspec.is_active(Hardfork::Shanghai, shanghai(1681338455)));
+
+        // Paris is activated once the total difficulty of the parent reaches the TTD
+        let ttd = U256::from(58_750_000_000_000_000_000_000_u128);
+        let paris = |total_difficulty, difficulty| Head {
+            total_difficulty,
+            difficulty,
+            ..Default::default()
+        };
+        assert!(!chainspec.is_active(Hardfork::Paris, paris(ttd, U256::from(1))));
+        assert!(chainspec.is_active(Hardfork::Paris, paris(ttd, U256::ZERO)));
+
+        // without a known fork block, Paris can't be activated by the block number alone
+        assert!(!chainspec.is_active(Hardfork::Paris, london(15537394)));
+
+        // forks that are not part of the spec are never active
+        assert!(!chainspec.is_active(Hardfork::Planck, shanghai(u64::MAX)));
+    }
+
     #[test]
     fn geth_genesis_with_shanghai() {
         let geth_genesis = r#"
@@ -4,25 +4,191 @@ mod transaction_dictionary;
 pub use receipt_dictionary::RECEIPT_DICTIONARY;
 pub use transaction_dictionary::TRANSACTION_DICTIONARY;
 
-use std::{cell::RefCell, thread_local};
+use std::{cell::RefCell, collections::HashMap, thread_local};
 use zstd::bulk::{Compressor, Decompressor};
 
+/// The version of the dictionaries that is used to compress new data.
+///
+/// Every compressed blob is prefixed with the version of the dictionary it was compressed with.
+/// When a dictionary is replaced, this has to be bumped and the previous dictionary has to be kept
+/// in [transaction_dictionary] and [receipt_dictionary], so that existing data can still be
+/// decompressed.
+///
+/// Note: a version must never be `0x28`, the first byte of the zstd magic number, which is how
+/// blobs written before dictionaries were versioned are detected.
+pub const DICTIONARY_VERSION: u8 = 1;
+
+/// The magic number every zstd frame starts with.
+const ZSTD_MAGIC_NUMBER: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// The dictionary version of blobs that were written before dictionaries were versioned, and
+/// thus aren't prefixed with a version.
+const UNVERSIONED_DICTIONARY_VERSION: u8 = 1;
+
+/// Returns the `Transaction` dictionary of the given version, if it's known.
+pub fn transaction_dictionary(version: u8) -> Option<&'static [u8]> {
+    match version {
+        1 => Some(&TRANSACTION_DICTIONARY),
+        _ => None,
+    }
+}
+
+/// Returns the `Receipt` dictionary of the given version, if it's known.
+pub fn receipt_dictionary(version: u8) -> Option<&'static [u8]> {
+    match version {
+        1 => Some(&RECEIPT_DICTIONARY),
+        _ => None,
+    }
+}
+
+/// A zstd compressor that uses the current [DICTIONARY_VERSION] and prefixes the compressed data
+/// with it.
+pub struct VersionedCompressor {
+    compressor: Compressor<'static>,
+}
+
+impl std::fmt::Debug for VersionedCompressor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VersionedCompressor").field("version", &DICTIONARY_VERSION).finish()
+    }
+}
+
+impl VersionedCompressor {
+    /// Creates a new compressor with the current version of the given dictionaries.
+    pub fn new(dictionaries: fn(u8) -> Option<&'static [u8]>) -> Self {
+        let dictionary =
+            dictionaries(DICTIONARY_VERSION).expect("Current dictionary version is known.");
+        let compressor =
+            Compressor::with_dictionary(0, dictionary).expect("Failed to initialize compressor.");
+        Self { compressor }
+    }
+
+    /// Compresses the data and prefixes it with the current [DICTIONARY_VERSION].
+    pub fn compress(&mut self, data: &[u8]) -> Vec<u8> {
+        let compressed = self.compressor.compress(data).expect("Failed to compress.");
+
+        let mut versioned = Vec::with_capacity(compressed.len() + 1);
+        versioned.push(DICTIONARY_VERSION);
+        versioned.extend_from_slice(&compressed);
+        versioned
+    }
+}
+
+/// A zstd decompressor that selects the dictionary based on the version prefix of the compressed
+/// data.
+///
+/// The decompressors of the individual dictionary versions are only created once they're needed.
+pub struct VersionedDecompressor {
+    dictionaries: fn(u8) -> Option<&'static [u8]>,
+    decompressors: HashMap<u8, Decompressor<'static>>,
+}
+
+impl std::fmt::Debug for VersionedDecompressor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VersionedDecompressor")
+            .field("versions", &self.decompressors.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl VersionedDecompressor {
+    /// Creates a new decompressor for all known versions of the given dictionaries.
+    pub fn new(dictionaries: fn(u8) -> Option<&'static [u8]>) -> Self {
+        Self { dictionaries, decompressors: HashMap::new() }
+    }
+
+    /// Decompresses data that was written by [VersionedCompressor::compress], with the dictionary
+    /// of the version it's prefixed with.
+    ///
+    /// Data that was written before dictionaries were versioned isn't prefixed, and is
+    /// decompressed with the dictionary it was written with.
+    ///
+    /// # Panics
+    ///
+    /// If the dictionary version is unknown or the data can't be decompressed.
+    pub fn decompress_with_version(&mut self, buf: &[u8]) -> Vec<u8> {
+        let (version, compressed) = if buf.starts_with(&ZSTD_MAGIC_NUMBER) {
+            (UNVERSIONED_DICTIONARY_VERSION, buf)
+        } else {
+            let (version, compressed) = buf.split_first().expect("Compressed data is not empty.");
+            (*version, compressed)
+        };
+
+        let dictionaries = self.dictionaries;
+        let decompressor = self.decompressors.entry(version).or_insert_with(|| {
+            let dictionary = dictionaries(version)
+                .unwrap_or_else(|| panic!("Unknown compression dictionary version: {version}"));
+            Decompressor::with_dictionary(dictionary).expect("Failed to initialize decompressor.")
+        });
+
+        let mut decompressed: Vec<u8> = Vec::with_capacity(300);
+
+        // `decompress_to_buffer` will return an error if the output buffer doesn't have enough
+        // capacity. However we don't actually have information on the required length. So we hope
+        // for the best, and keep trying again with a fairly bigger size if it fails.
+        while let Err(err) = decompressor.decompress_to_buffer(compressed, &mut decompressed) {
+            let err = err.to_string();
+            if !err.contains("Destination buffer is too small") {
+                panic!("Failed to decompress: {}", err);
+            }
+            decompressed.reserve(decompressed.capacity() + 24_000);
+        }
+        decompressed
+    }
+}
+
 // Reason for using static compressors is that dictionaries can be quite big, and zstd-rs
 // recommends to use one context/compressor per thread. Thus the usage of `thread_local`.
 thread_local! {
     /// Thread Transaction compressor.
-    pub static TRANSACTION_COMPRESSOR: RefCell<Compressor<'static>> = RefCell::new(Compressor::with_dictionary(0, &TRANSACTION_DICTIONARY)
-            .expect("Failed to initialize compressor."));
+    pub static TRANSACTION_COMPRESSOR: RefCell<VersionedCompressor> = RefCell::new(VersionedCompressor::new(transaction_dictionary));
 
     /// Thread Transaction decompressor.
-    pub static TRANSACTION_DECOMPRESSOR: RefCell<Decompressor<'static>> = RefCell::new(Decompressor::with_dictionary(&TRANSACTION_DICTIONARY)
-            .expect("Failed to initialize decompressor."));
+    pub static TRANSACTION_DECOMPRESSOR: RefCell<VersionedDecompressor> = RefCell::new(VersionedDecompressor::new(transaction_dictionary));
 
     /// Thread receipt compressor.
-    pub static RECEIPT_COMPRESSOR: RefCell<Compressor<'static>> = RefCell::new(Compressor::with_dictionary(0, &RECEIPT_DICTIONARY)
-            .expect("Failed to initialize compressor."));
+    pub static RECEIPT_COMPRESSOR: RefCell<VersionedCompressor> = RefCell::new(VersionedCompressor::new(receipt_dictionary));
 
     /// Thread receipt decompressor.
-    pub static RECEIPT_DECOMPRESSOR: RefCell<Decompressor<'static>> = RefCell::new(Decompressor::with_dictionary(&RECEIPT_DICTIONARY)
-            .expect("Failed to initialize decompressor."));
+    pub static RECEIPT_DECOMPRESSOR: RefCell<VersionedDecompressor> = RefCell::new(VersionedDecompressor::new(receipt_dictionary));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versioned_roundtrip() {
+        let data = [0xab; 512];
+
+        let compressed = TRANSACTION_COMPRESSOR.with(|c| c.borrow_mut().compress(&data));
+        assert_eq!(compressed[0], DICTIONARY_VERSION);
+
+        let decompressed =
+            TRANSACTION_DECOMPRESSOR.with(|d| d.borrow_mut().decompress_with_version(&compressed));
+        assert_eq!(decompressed, data);
+    }
+
+    #[test]
+    fn decompress_unversioned() {
+        let data = [0xcd; 512];
+
+        // receipts written before dictionaries were versioned
+        let compressed =
+            Compressor::with_dictionary(0, &RECEIPT_DICTIONARY).unwrap().compress(&data).unwrap();
+        assert!(compressed.starts_with(&ZSTD_MAGIC_NUMBER));
+
+        let decompressed =
+            RECEIPT_DECOMPRESSOR.with(|d| d.borrow_mut().decompress_with_version(&compressed));
+        assert_eq!(decompressed, data);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown compression dictionary version: 2")]
+    fn decompress_unknown_version() {
+        let mut compressed = TRANSACTION_COMPRESSOR.with(|c| c.borrow_mut().compress(&[0xab; 512]));
+        compressed[0] = 2;
+
+        TRANSACTION_DECOMPRESSOR.with(|d| d.borrow_mut().decompress_with_version(&compressed));
+    }
 }
@@ -44,6 +44,42 @@ pub const EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR: u64 = 8;
 /// Elasticity multiplier as defined in [EIP-1559](https://eips.ethereum.org/EIPS/eip-1559)
 pub const EIP1559_ELASTICITY_MULTIPLIER: u64 = 2;
 
+/// The bound divisor of the gas limit, used in update calculations.
+///
+/// The gas limit of a block may only differ by less than `parent_gas_limit / 1024` from its parent.
+pub const GAS_LIMIT_BOUND_DIVISOR: u64 = 1024;
+
+/// The gas every transaction is charged before execution.
+pub const TX_BASE_GAS: u64 = 21_000;
+
+/// The gas a contract creation transaction is charged before execution, since Homestead.
+pub const TX_CREATE_GAS: u64 = 53_000;
+
+/// The gas charged per zero byte of transaction data.
+pub const TX_DATA_ZERO_GAS: u64 = 4;
+
+/// The gas charged per non-zero byte of transaction data, before Istanbul.
+pub const TX_DATA_NON_ZERO_GAS_FRONTIER: u64 = 68;
+
+/// The gas charged per non-zero byte of transaction data, since Istanbul as defined in
+/// [EIP-2028](https://eips.ethereum.org/EIPS/eip-2028)
+pub const TX_DATA_NON_ZERO_GAS: u64 = 16;
+
+/// The gas charged per word of init code as defined in [EIP-3860](https://eips.ethereum.org/EIPS/eip-3860)
+pub const INITCODE_WORD_GAS: u64 = 2;
+
+/// The gas charged per address of an access list as defined in
+/// [EIP-2930](https://eips.ethereum.org/EIPS/eip-2930)
+pub const ACCESS_LIST_ADDRESS_GAS: u64 = 2400;
+
+/// The gas charged per storage key of an access list as defined in
+/// [EIP-2930](https://eips.ethereum.org/EIPS/eip-2930)
+pub const ACCESS_LIST_STORAGE_KEY_GAS: u64 = 1900;
+
+/// The gas charged per calldata token for the calldata gas floor as defined in
+/// [EIP-7623](https://eips.ethereum.org/EIPS/eip-7623)
+pub const TOTAL_COST_FLOOR_PER_TOKEN: u64 = 10;
+
 /// Multiplier for converting gwei to wei.
 pub const GWEI_TO_WEI: u64 = 1_000_000_000;
 
@@ -103,6 +139,10 @@ pub const EMPTY_WITHDRAWALS: H256 = EMPTY_SET_HASH;
 /// the database.
 pub const BEACON_CONSENSUS_REORG_UNWIND_DEPTH: u64 = 3;
 
+/// The gas consumed by a single blob of an [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844)
+/// transaction.
+pub const GAS_PER_BLOB: u64 = 1 << 17;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -8,17 +8,22 @@ use crate::{BlockNumber, Head, H256};
 use crc::*;
 use reth_codecs::derive_arbitrary;
 use reth_rlp::*;
+use schnellru::{ByLength, LruMap};
 use serde::{Deserialize, Serialize};
 use std::{
     cmp::Ordering,
     collections::{BTreeMap, BTreeSet},
     fmt,
     ops::{Add, AddAssign},
+    sync::{Arc, Mutex},
 };
 use thiserror::Error;
 
 const CRC_32_IEEE: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
 
+/// The maximum number of remote [`ForkId`]s whose validation result is cached by a [`ForkFilter`].
+const VALIDATION_CACHE_SIZE: u32 = 1024;
+
 /// `CRC32` hash of all previous forks starting from genesis block.
 #[derive_arbitrary(rlp)]
 #[derive(
@@ -168,6 +173,11 @@ pub struct ForkFilter {
     head: Head,
 
     cache: Cache,
+
+    /// The results of previous validations, shared by all clones of the filter and cleared on
+    /// every [`ForkTransition`].
+    #[serde(skip)]
+    validation_cache: ValidationCache,
 }
 
 impl ForkFilter {
@@ -196,7 +206,7 @@ impl ForkFilter {
 
         let cache = Cache::compute_cache(&forks, head);
 
-        Self { forks, head, cache }
+        Self { forks, head, cache, validation_cache: Default::default() }
     }
 
     fn set_head_priv(&mut self, head: Head) -> Option<ForkTransition> {
@@ -221,6 +231,7 @@ impl ForkFilter {
             let past = self.current();
 
             self.cache = Cache::compute_cache(&self.forks, head);
+            self.validation_cache.clear();
 
             transition = Some(ForkTransition { current: self.current(), past })
         }
@@ -252,6 +263,24 @@ impl ForkFilter {
     ///
     /// Returns a `ValidationError` if the `ForkId` is not compatible.
     pub fn validate(&self, fork_id: ForkId) -> Result<(), ValidationError> {
+        // The result for a remote with the same FORK_HASH that announces a FORK_NEXT depends on
+        // the local head, which can advance without a fork transition, so it's not cached.
+        if self.current().hash == fork_id.hash && fork_id.next != 0 {
+            return self.validate_uncached(fork_id)
+        }
+
+        let local = self.current();
+        if let Some(result) = self.validation_cache.get(local, fork_id) {
+            return result
+        }
+        let result = self.validate_uncached(fork_id);
+        self.validation_cache.insert(local, fork_id, result);
+        result
+    }
+
+    /// Checks the `ForkId` against the validation rules of `EIP-2124`, without the
+    /// [`ValidationCache`].
+    fn validate_uncached(&self, fork_id: ForkId) -> Result<(), ValidationError> {
         // 1) If local and remote FORK_HASH matches...
         if self.current().hash == fork_id.hash {
             if fork_id.next == 0 {
@@ -312,6 +341,57 @@ impl ForkFilter {
     }
 }
 
+/// A bounded LRU cache of the validation results of remote [`ForkId`]s.
+///
+/// The cache is shared by all clones of a filter, e.g. the filters of the sessions that validate
+/// the remote's [`ForkId`] during the handshake.
+///
+/// The results only depend on the current epoch of the [`ForkFilter`], so the cache is cleared
+/// whenever the filter transitions to another fork. Since clones may lag behind, the results are
+/// also keyed by the local [`ForkId`] they were computed for. It's not part of the state of the
+/// filter: it's ignored by comparisons and not serialized.
+#[derive(Clone)]
+struct ValidationCache(Arc<Mutex<LruMap<(ForkId, ForkId), Result<(), ValidationError>, ByLength>>>);
+
+impl ValidationCache {
+    fn get(&self, local: ForkId, remote: ForkId) -> Option<Result<(), ValidationError>> {
+        self.0.lock().unwrap().get(&(local, remote)).copied()
+    }
+
+    fn insert(&self, local: ForkId, remote: ForkId, result: Result<(), ValidationError>) {
+        self.0.lock().unwrap().insert((local, remote), result);
+    }
+
+    fn clear(&self) {
+        self.0.lock().unwrap().clear();
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.0.lock().unwrap().len()
+    }
+}
+
+impl Default for ValidationCache {
+    fn default() -> Self {
+        Self(Arc::new(Mutex::new(LruMap::new(ByLength::new(VALIDATION_CACHE_SIZE)))))
+    }
+}
+
+impl PartialEq for ValidationCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ValidationCache {}
+
+impl fmt::Debug for ValidationCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValidationCache").field("len", &self.0.lock().unwrap().len()).finish()
+    }
+}
+
 /// Represents a transition from one fork to another
 ///
 /// See also [`ForkFilter::set_head`]
@@ -601,4 +681,71 @@ mod tests {
         assert!(fork_filter.set_head_priv(Head { number: b2, ..Default::default() }).is_some());
         assert_eq!(fork_filter.current(), h2);
     }
+
+    #[test]
+    fn validation_cache_cleared_on_transition() {
+        let genesis = ForkHash::from(GENESIS_HASH);
+        let homestead = genesis + 1_150_000u64;
+
+        let mut filter = ForkFilter::new(
+            Head { number: 1_000_000, ..Default::default() },
+            GENESIS_HASH,
+            vec![ForkFilterKey::Block(1_150_000), ForkFilterKey::Block(1_920_000)],
+        );
+
+        // the remote already passed homestead, which is a known future fork
+        let remote = ForkId { hash: homestead, next: 0 };
+        assert_eq!(filter.validate(remote), Ok(()));
+        assert_eq!(filter.validation_cache.len(), 1);
+        assert_eq!(filter.validate(remote), Ok(()));
+
+        // verdicts that depend on the local head are not cached
+        assert_eq!(filter.validate(ForkId { hash: genesis, next: 1_150_000 }), Ok(()));
+        assert_eq!(filter.validation_cache.len(), 1);
+
+        // no fork transition, the cached verdict stays valid
+        assert!(filter.set_head(Head { number: 1_100_000, ..Default::default() }).is_none());
+        assert_eq!(filter.validation_cache.len(), 1);
+        assert_eq!(filter.validate(remote), Ok(()));
+
+        // local passed homestead and the next fork, which the remote doesn't know about
+        assert!(filter.set_head(Head { number: 2_000_000, ..Default::default() }).is_some());
+        assert_eq!(filter.validation_cache.len(), 0);
+        assert_eq!(
+            filter.validate(remote),
+            Err(ValidationError::RemoteStale { local: filter.current(), remote })
+        );
+    }
+
+    #[test]
+    fn validation_cache_shared_by_clones() {
+        let genesis = ForkHash::from(GENESIS_HASH);
+        let homestead = genesis + 1_150_000u64;
+
+        let mut filter = ForkFilter::new(
+            Head { number: 1_000_000, ..Default::default() },
+            GENESIS_HASH,
+            vec![ForkFilterKey::Block(1_150_000), ForkFilterKey::Block(1_920_000)],
+        );
+        let remote = ForkId { hash: homestead, next: 0 };
+
+        // e.g. the filter of a pending session
+        let session_filter = filter.clone();
+        assert_eq!(session_filter.validate(remote), Ok(()));
+        assert_eq!(filter.validation_cache.len(), 1);
+        assert_eq!(filter.clone().validate(remote), Ok(()));
+        assert_eq!(filter.validation_cache.len(), 1);
+
+        // a transition clears the results of all clones
+        assert!(filter.set_head(Head { number: 2_000_000, ..Default::default() }).is_some());
+        assert_eq!(session_filter.validation_cache.len(), 0);
+
+        // a clone that lags behind doesn't share results with the transitioned filter
+        assert_eq!(session_filter.validate(remote), Ok(()));
+        assert_eq!(
+            filter.validate(remote),
+            Err(ValidationError::RemoteStale { local: filter.current(), remote })
+        );
+        assert_eq!(filter.validation_cache.len(), 2);
+    }
 }
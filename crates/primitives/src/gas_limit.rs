@@ -0,0 +1,31 @@
+//! Helpers for working with the block gas limit
+
+use crate::constants::GAS_LIMIT_BOUND_DIVISOR;
+
+/// Returns `true` if the gas limit of a child block is within the allowed adjustment bound of its
+/// parent's gas limit.
+///
+/// The gas limit may change by strictly less than `parent_limit / 1024` from one block to the
+/// next, see the Yellow Paper, equation 47.
+pub fn validate_gas_limit(parent_limit: u64, child_limit: u64) -> bool {
+    parent_limit.abs_diff(child_limit) < parent_limit / GAS_LIMIT_BOUND_DIVISOR
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gas_limit_bound() {
+        let parent_limit = 30_000_000;
+        let max_delta = parent_limit / GAS_LIMIT_BOUND_DIVISOR;
+
+        assert!(validate_gas_limit(parent_limit, parent_limit));
+        assert!(validate_gas_limit(parent_limit, parent_limit + max_delta - 1));
+        assert!(validate_gas_limit(parent_limit, parent_limit - max_delta + 1));
+
+        assert!(!validate_gas_limit(parent_limit, parent_limit + max_delta));
+        assert!(!validate_gas_limit(parent_limit, parent_limit - max_delta));
+        assert!(!validate_gas_limit(parent_limit, parent_limit * 2));
+    }
+}
@@ -39,6 +39,10 @@ pub enum Hardfork {
     Paris,
     /// Shanghai.
     Shanghai,
+    /// Cancun.
+    Cancun,
+    /// Prague.
+    Prague,
     /// EIP150.
     EIP150,
     /// EIP155.
@@ -106,6 +110,8 @@ impl FromStr for Hardfork {
             "grayglacier" => Hardfork::GrayGlacier,
             "paris" => Hardfork::Paris,
             "shanghai" => Hardfork::Shanghai,
+            "cancun" => Hardfork::Cancun,
+            "prague" => Hardfork::Prague,
             _ => return Err(format!("Unknown hardfork: {s}")),
         };
         Ok(hardfork)
@@ -144,6 +150,8 @@ mod tests {
             "grayglacier",
             "PARIS",
             "ShAnGhAI",
+            "CaNcUn",
+            "pRaGuE",
         ];
         let expected_hardforks = [
             Hardfork::Frontier,
@@ -162,6 +170,8 @@ mod tests {
             Hardfork::GrayGlacier,
             Hardfork::Paris,
             Hardfork::Shanghai,
+            Hardfork::Cancun,
+            Hardfork::Prague,
         ];
 
         let hardforks: Vec<Hardfork> =
@@ -7,8 +7,8 @@ use crate::{
 use bytes::{Buf, BufMut, BytesMut};
 use ethers_core::types::{Block, H256 as EthersH256, H64};
 use reth_codecs::{add_arbitrary_tests, derive_arbitrary, main_codec, Compact};
-use reth_rlp::{length_of_length, Decodable, Encodable, EMPTY_STRING_CODE};
-use serde::{Deserialize, Serialize};
+use reth_rlp::{length_of_length, Decodable, Encodable, EMPTY_LIST_CODE, EMPTY_STRING_CODE};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::ops::{Deref, DerefMut};
 
 /// Describes the current head block.
@@ -88,6 +88,19 @@ pub struct Header {
     /// above the gas target, and decreasing when blocks are below the gas target. The base fee per
     /// gas is burned.
     pub base_fee_per_gas: Option<u64>,
+    /// The total amount of blob gas consumed by the transactions within the block, added in
+    /// EIP-4844.
+    pub blob_gas_used: Option<u64>,
+    /// A running total of blob gas consumed in excess of the target, prior to the block. Blocks
+    /// with above-target blob gas consumption increase this value, blocks with below-target blob
+    /// gas consumption decrease it (bounded at 0). This was added in EIP-4844.
+    pub excess_blob_gas: Option<u64>,
+    /// The hash of the parent beacon block's root is included in execution blocks, as proposed by
+    /// EIP-4788.
+    ///
+    /// This enables trust-minimized access to consensus state, supporting staking pools, bridges,
+    /// and more.
+    pub parent_beacon_block_root: Option<H256>,
     /// An arbitrary byte array containing data relevant to this block. This must be 32 bytes or
     /// fewer; formally Hx.
     pub extra_data: Bytes,
@@ -113,6 +126,9 @@ impl Default for Header {
             nonce: 0,
             base_fee_per_gas: None,
             withdrawals_root: None,
+            blob_gas_used: None,
+            excess_blob_gas: None,
+            parent_beacon_block_root: None,
         }
     }
 }
@@ -125,6 +141,8 @@ impl Header {
 
     /// Heavy function that will calculate hash of data and will *not* save the change to metadata.
     /// Use [`Header::seal`], [`SealedHeader`] and unlock if you need hash to be persistent.
+    ///
+    /// The hash is the keccak256 of the RLP encoded header, including all fork specific fields.
     pub fn hash_slow(&self) -> H256 {
         let mut out = BytesMut::new();
         self.encode(&mut out);
@@ -158,6 +176,15 @@ impl Header {
         Some(calculate_next_block_base_fee(self.gas_used, self.gas_limit, self.base_fee_per_gas?))
     }
 
+    /// Returns the amount of wei burned by this block, `base_fee_per_gas * gas_used`.
+    ///
+    /// Returns zero for pre-London blocks that have no base fee set.
+    pub fn burned_fees(&self) -> U256 {
+        self.base_fee_per_gas
+            .map(|base_fee| U256::from(base_fee) * U256::from(self.gas_used))
+            .unwrap_or_default()
+    }
+
     /// Seal the header with a known hash.
     ///
     /// WARNING: This method does not perform validation whether the hash is correct.
@@ -191,11 +218,37 @@ impl Header {
 
         if let Some(base_fee) = self.base_fee_per_gas {
             length += U256::from(base_fee).length();
-        } else if self.withdrawals_root.is_some() {
+        } else if self.withdrawals_root.is_some() ||
+            self.blob_gas_used.is_some() ||
+            self.excess_blob_gas.is_some() ||
+            self.parent_beacon_block_root.is_some()
+        {
             length += 1; // EMTY STRING CODE
         }
+
         if let Some(root) = self.withdrawals_root {
             length += root.length();
+        } else if self.blob_gas_used.is_some() ||
+            self.excess_blob_gas.is_some() ||
+            self.parent_beacon_block_root.is_some()
+        {
+            length += 1; // EMTY STRING CODE
+        }
+
+        if let Some(blob_gas_used) = self.blob_gas_used {
+            length += U256::from(blob_gas_used).length();
+        } else if self.excess_blob_gas.is_some() || self.parent_beacon_block_root.is_some() {
+            length += 1; // EMPTY LIST CODE
+        }
+
+        if let Some(excess_blob_gas) = self.excess_blob_gas {
+            length += U256::from(excess_blob_gas).length();
+        } else if self.parent_beacon_block_root.is_some() {
+            length += 1; // EMPTY LIST CODE
+        }
+
+        if let Some(parent_beacon_block_root) = self.parent_beacon_block_root {
+            length += parent_beacon_block_root.length();
         }
 
         length
@@ -224,15 +277,47 @@ impl Encodable for Header {
         H64::from_low_u64_be(self.nonce).encode(out);
 
         // Encode base fee. Put empty string if base fee is missing,
-        // but withdrawals root is present.
+        // but any of the following fields is present.
         if let Some(ref base_fee) = self.base_fee_per_gas {
             U256::from(*base_fee).encode(out);
-        } else if self.withdrawals_root.is_some() {
+        } else if self.withdrawals_root.is_some() ||
+            self.blob_gas_used.is_some() ||
+            self.excess_blob_gas.is_some() ||
+            self.parent_beacon_block_root.is_some()
+        {
             out.put_u8(EMPTY_STRING_CODE);
         }
 
+        // Encode withdrawals root. Put empty string if withdrawals root is missing,
+        // but any of the following fields is present.
         if let Some(ref root) = self.withdrawals_root {
             root.encode(out);
+        } else if self.blob_gas_used.is_some() ||
+            self.excess_blob_gas.is_some() ||
+            self.parent_beacon_block_root.is_some()
+        {
+            out.put_u8(EMPTY_STRING_CODE);
+        }
+
+        // Encode blob gas used. Put empty list if blob gas used is missing,
+        // but any of the following fields is present. An empty list is used instead of an empty
+        // string, since the latter is the encoding of zero.
+        if let Some(ref blob_gas_used) = self.blob_gas_used {
+            U256::from(*blob_gas_used).encode(out);
+        } else if self.excess_blob_gas.is_some() || self.parent_beacon_block_root.is_some() {
+            out.put_u8(EMPTY_LIST_CODE);
+        }
+
+        // Encode excess blob gas. Put empty list if excess blob gas is missing,
+        // but the parent beacon block root is present.
+        if let Some(ref excess_blob_gas) = self.excess_blob_gas {
+            U256::from(*excess_blob_gas).encode(out);
+        } else if self.parent_beacon_block_root.is_some() {
+            out.put_u8(EMPTY_LIST_CODE);
+        }
+
+        if let Some(ref parent_beacon_block_root) = self.parent_beacon_block_root {
+            parent_beacon_block_root.encode(out);
         }
     }
 
@@ -269,6 +354,9 @@ impl Decodable for Header {
             nonce: H64::decode(buf)?.to_low_u64_be(),
             base_fee_per_gas: None,
             withdrawals_root: None,
+            blob_gas_used: None,
+            excess_blob_gas: None,
+            parent_beacon_block_root: None,
         };
         if started_len - buf.len() < rlp_head.payload_length {
             if buf.first().map(|b| *b == EMPTY_STRING_CODE).unwrap_or_default() {
@@ -278,7 +366,28 @@ impl Decodable for Header {
             }
         }
         if started_len - buf.len() < rlp_head.payload_length {
-            this.withdrawals_root = Some(Decodable::decode(buf)?);
+            if buf.first().map(|b| *b == EMPTY_STRING_CODE).unwrap_or_default() {
+                buf.advance(1)
+            } else {
+                this.withdrawals_root = Some(Decodable::decode(buf)?);
+            }
+        }
+        if started_len - buf.len() < rlp_head.payload_length {
+            if buf.first().map(|b| *b == EMPTY_LIST_CODE).unwrap_or_default() {
+                buf.advance(1)
+            } else {
+                this.blob_gas_used = Some(U256::decode(buf)?.to::<u64>());
+            }
+        }
+        if started_len - buf.len() < rlp_head.payload_length {
+            if buf.first().map(|b| *b == EMPTY_LIST_CODE).unwrap_or_default() {
+                buf.advance(1)
+            } else {
+                this.excess_blob_gas = Some(U256::decode(buf)?.to::<u64>());
+            }
+        }
+        if started_len - buf.len() < rlp_head.payload_length {
+            this.parent_beacon_block_root = Some(Decodable::decode(buf)?);
         }
         let consumed = started_len - buf.len();
         if consumed != rlp_head.payload_length {
@@ -293,8 +402,10 @@ impl Decodable for Header {
 
 /// A [`Header`] that is sealed at a precalculated hash, use [`SealedHeader::unseal()`] if you want
 /// to modify header.
+///
+/// The hash is not serialized, it's recomputed when the header is deserialized.
 #[add_arbitrary_tests(rlp)]
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SealedHeader {
     /// Locked Header fields.
     pub header: Header,
@@ -324,6 +435,31 @@ impl SealedHeader {
     }
 }
 
+/// The serde representation of a [SealedHeader], without its hash.
+#[derive(Serialize, Deserialize)]
+struct SealedHeaderSerde<H> {
+    header: H,
+}
+
+impl Serialize for SealedHeader {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        SealedHeaderSerde { header: &self.header }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SealedHeader {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let SealedHeaderSerde::<Header> { header } = SealedHeaderSerde::deserialize(deserializer)?;
+        Ok(header.seal_slow())
+    }
+}
+
 #[cfg(any(test, feature = "arbitrary"))]
 impl proptest::arbitrary::Arbitrary for SealedHeader {
     type Parameters = ();
@@ -363,6 +499,9 @@ impl From<&Block<EthersH256>> for Header {
             gas_used: block.gas_used.as_u64(),
             withdrawals_root: None,
             logs_bloom: block.logs_bloom.unwrap_or_default().0.into(),
+            blob_gas_used: None,
+            excess_blob_gas: None,
+            parent_beacon_block_root: None,
         }
     }
 }
@@ -508,7 +647,7 @@ impl From<HeadersDirection> for bool {
 
 #[cfg(test)]
 mod tests {
-    use super::{Bytes, Decodable, Encodable, Header, H256};
+    use super::{BlockNumHash, Bytes, Decodable, Encodable, Header, SealedHeader, H256};
     use crate::{Address, HeadersDirection, U256};
     use ethers_core::utils::hex::{self, FromHex};
     use std::str::FromStr;
@@ -560,6 +699,9 @@ mod tests {
             nonce: 0,
             base_fee_per_gas: Some(0x036b_u64),
             withdrawals_root: None,
+            blob_gas_used: None,
+            excess_blob_gas: None,
+            parent_beacon_block_root: None,
         };
         assert_eq!(header.hash_slow(), expected_hash);
     }
@@ -638,6 +780,30 @@ mod tests {
         assert_eq!(header.hash_slow(), expected_hash);
     }
 
+    #[test]
+    fn hash_slow_matches_sealed_hash() {
+        let header = Header {
+            parent_hash: H256::random(),
+            number: 0x1234,
+            gas_limit: 30_000_000,
+            gas_used: 21_000,
+            timestamp: 0x65,
+            extra_data: Bytes::from_str("42").unwrap(),
+            base_fee_per_gas: Some(7),
+            withdrawals_root: Some(H256::random()),
+            blob_gas_used: Some(0x20000),
+            excess_blob_gas: Some(0),
+            parent_beacon_block_root: Some(H256::random()),
+            ..Default::default()
+        };
+
+        let mut data = vec![];
+        header.encode(&mut data);
+        let sealed = SealedHeader::decode(&mut data.as_slice()).unwrap();
+        assert_eq!(header.hash_slow(), sealed.hash());
+        assert_eq!(header.hash_slow(), header.clone().seal_slow().hash());
+    }
+
     #[test]
     fn sanity_direction() {
         let reverse = true;
@@ -658,4 +824,35 @@ mod tests {
         direction.encode(&mut buf);
         assert_eq!(direction, HeadersDirection::decode(&mut buf.as_slice()).unwrap());
     }
+
+    #[test]
+    fn sealed_header_num_hashes() {
+        let parent = Header { number: 9, ..Default::default() }.seal_slow();
+        let header = Header { number: 10, parent_hash: parent.hash(), ..Default::default() };
+        let sealed = header.clone().seal_slow();
+
+        // the hash is computed once when sealing
+        assert_eq!(sealed.hash(), header.hash_slow());
+        assert_eq!(sealed.num_hash(), BlockNumHash { number: 10, hash: header.hash_slow() });
+        assert_eq!(sealed.parent_num_hash(), parent.num_hash());
+
+        // genesis has no parent
+        let genesis = Header::default().seal_slow();
+        assert_eq!(genesis.parent_num_hash(), BlockNumHash { number: 0, hash: H256::zero() });
+    }
+
+    #[test]
+    fn sealed_header_serde_recomputes_hash() {
+        let header = Header { number: 10, ..Default::default() };
+        let sealed = header.clone().seal_slow();
+
+        // the hash isn't serialized
+        let json = serde_json::to_value(&sealed).unwrap();
+        assert_eq!(json, serde_json::json!({ "header": header }));
+        assert_eq!(serde_json::from_value::<SealedHeader>(json).unwrap(), sealed);
+
+        // a stale hash is ignored
+        let json = serde_json::json!({ "header": header, "hash": H256::random() });
+        assert_eq!(serde_json::from_value::<SealedHeader>(json).unwrap().hash(), sealed.hash());
+    }
 }
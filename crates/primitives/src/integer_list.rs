@@ -3,7 +3,10 @@ use serde::{
     ser::SerializeSeq,
     Deserialize, Deserializer, Serialize, Serializer,
 };
-use std::{fmt, ops::Deref};
+use std::{
+    fmt,
+    ops::{Deref, RangeInclusive},
+};
 use sucds::{EliasFano, Searial};
 
 /// Uses EliasFano to hold a list of integers. It provides really good compression with the
@@ -54,6 +57,29 @@ impl IntegerList {
     pub fn from_bytes(data: &[u8]) -> Result<Self, EliasFanoError> {
         Ok(Self(EliasFano::deserialize_from(data).map_err(|_| EliasFanoError::FailedDeserialize)?))
     }
+
+    /// Removes all integers that are contained in the given range.
+    ///
+    /// [`EliasFano`] can't be modified in place, so the list is rebuilt from the remaining
+    /// integers, which stay sorted. The list is left untouched if no integer is removed.
+    ///
+    /// Returns `true` if the list is empty afterwards.
+    pub fn remove_range(&mut self, range: RangeInclusive<u64>) -> bool {
+        if self.0.len() == 0 {
+            return true
+        }
+
+        let remaining =
+            self.0.iter(0).filter(|i| !range.contains(&(*i as u64))).collect::<Vec<usize>>();
+        if remaining.is_empty() {
+            *self = Self::default();
+            return true
+        }
+        if remaining.len() != self.0.len() {
+            *self = Self::new(remaining).expect("remaining integers are sorted and not empty");
+        }
+        false
+    }
 }
 
 macro_rules! impl_uint {
@@ -149,6 +175,30 @@ mod test {
         assert_eq!(ef_list.iter(0).collect::<Vec<usize>>(), original_list);
     }
 
+    #[test]
+    fn remove_range() {
+        let mut list = IntegerList::new([1, 3, 5, 7, 9]).unwrap();
+
+        // nothing in range
+        assert!(!list.remove_range(10..=20));
+        assert_eq!(list, IntegerList::new([1, 3, 5, 7, 9]).unwrap());
+
+        // leading and trailing integers
+        assert!(!list.remove_range(0..=3));
+        assert!(!list.remove_range(9..=u64::MAX));
+        assert_eq!(list.iter(0).collect::<Vec<usize>>(), vec![5, 7]);
+
+        // integers in the middle
+        let mut list = IntegerList::new([1, 3, 5, 7, 9]).unwrap();
+        assert!(!list.remove_range(2..=8));
+        assert_eq!(list.iter(0).collect::<Vec<usize>>(), vec![1, 9]);
+
+        // the whole list
+        assert!(list.remove_range(0..=u64::MAX));
+        assert_eq!(list.len(), 0);
+        assert!(list.remove_range(0..=1));
+    }
+
     #[test]
     fn test_integer_list_serialization() {
         let original_list = [1, 2, 3];
@@ -20,6 +20,7 @@ mod compression;
 pub mod constants;
 pub mod contract;
 mod forkid;
+pub mod gas_limit;
 mod genesis;
 mod hardfork;
 mod header;
@@ -66,17 +67,18 @@ pub use net::{
     SEPOLIA_BOOTNODES, BSC_BOOTNODES,
 };
 pub use peer::{PeerId, WithPeerId};
-pub use receipt::{Receipt, ReceiptWithBloom, ReceiptWithBloomRef};
+pub use receipt::{block_bloom, Receipt, ReceiptWithBloom, ReceiptWithBloomRef};
 pub use revm_primitives::JumpMap;
 pub use serde_helper::JsonU256;
 pub use storage::StorageEntry;
 pub use transaction::{
-    util::secp256k1::{recover_signer, sign_message},
-    AccessList, AccessListItem, AccessListWithGasUsed, FromRecoveredTransaction,
-    IntoRecoveredTransaction, InvalidTransactionError, Signature, Transaction, TransactionKind,
+    util::secp256k1::{recover_signer, recover_signer_with_chain_id, sign_message},
+    AccessList, AccessListItem, AccessListWithGasUsed, Blob, BlobTransaction,
+    BlobTransactionSidecar, FromRecoveredTransaction, IntoRecoveredTransaction,
+    InvalidTransactionError, PooledTransactionsElement, Signature, Transaction, TransactionKind,
     TransactionMeta, TransactionSigned, TransactionSignedEcRecovered, TransactionSignedNoHash,
-    TxEip1559, TxEip2930, TxLegacy, TxType, EIP1559_TX_TYPE_ID, EIP2930_TX_TYPE_ID,
-    LEGACY_TX_TYPE_ID,
+    TxEip1559, TxEip2930, TxEip4844, TxLegacy, TxType, EIP1559_TX_TYPE_ID, EIP2930_TX_TYPE_ID,
+    EIP4844_TX_TYPE_ID, LEGACY_TX_TYPE_ID,
 };
 pub use withdrawal::Withdrawal;
 
@@ -86,6 +86,46 @@ where
     ordered_trie_root_with_encoder(transactions, |tx: &T, buf| tx.as_ref().encode_inner(buf, false))
 }
 
+/// Incrementally computes the transaction root of a growing block body.
+///
+/// Transactions are encoded once when they are added, so recomputing the root after every insert
+/// only rebuilds the trie from the already encoded leaves. The root is cached until the next
+/// transaction is added.
+#[derive(Debug, Default)]
+pub struct TransactionsRootBuilder {
+    /// The encoded transactions, in block order.
+    encoded: Vec<Vec<u8>>,
+    /// The root of the current transactions, if already computed.
+    root: Option<H256>,
+}
+
+impl TransactionsRootBuilder {
+    /// Adds the next transaction of the block body.
+    pub fn push(&mut self, transaction: &TransactionSigned) {
+        let mut encoded = Vec::new();
+        transaction.encode_inner(&mut encoded, false);
+        self.encoded.push(encoded);
+        self.root = None;
+    }
+
+    /// Returns the number of transactions added so far.
+    pub fn len(&self) -> usize {
+        self.encoded.len()
+    }
+
+    /// Returns `true` if no transaction has been added yet.
+    pub fn is_empty(&self) -> bool {
+        self.encoded.is_empty()
+    }
+
+    /// Returns the transaction root of all transactions added so far.
+    pub fn root(&mut self) -> H256 {
+        *self.root.get_or_insert_with(|| {
+            ordered_trie_root_with_encoder(&self.encoded, |encoded, buf| buf.put_slice(encoded))
+        })
+    }
+}
+
 /// Calculates the root hash of the withdrawals.
 pub fn calculate_withdrawals_root(withdrawals: &[Withdrawal]) -> H256 {
     ordered_trie_root(withdrawals)
@@ -143,9 +183,12 @@ mod tests {
 
     use crate::{
         hex_literal::hex,
-        proofs::{calculate_receipt_root, calculate_transaction_root, genesis_state_root},
-        Address, Block, Bloom, GenesisAccount, Log, Receipt, ReceiptWithBloom, TxType, H160, H256,
-        U256,
+        proofs::{
+            calculate_receipt_root, calculate_transaction_root, genesis_state_root,
+            TransactionsRootBuilder,
+        },
+        Address, Block, Bloom, GenesisAccount, Log, Receipt, ReceiptWithBloom, Signature,
+        Transaction, TransactionKind, TransactionSigned, TxLegacy, TxType, H160, H256, U256,
     };
     use reth_rlp::Decodable;
 
@@ -161,6 +204,33 @@ mod tests {
         assert_eq!(block.transactions_root, tx_root, "Must be the same");
     }
 
+    #[test]
+    fn incremental_transaction_root() {
+        // enough transactions to cover the rlp index adjustment at 0x7f
+        let transactions = (0..200u64)
+            .map(|nonce| {
+                let transaction = Transaction::Legacy(TxLegacy {
+                    chain_id: Some(1),
+                    nonce,
+                    gas_price: 1,
+                    gas_limit: 21_000,
+                    to: TransactionKind::Call(Address::from_low_u64_be(nonce)),
+                    value: 1,
+                    input: Default::default(),
+                });
+                TransactionSigned::from_transaction_and_signature(transaction, Signature::default())
+            })
+            .collect::<Vec<_>>();
+
+        let mut builder = TransactionsRootBuilder::default();
+        assert_eq!(builder.root(), EMPTY_ROOT);
+        for (index, transaction) in transactions.iter().enumerate() {
+            builder.push(transaction);
+            assert_eq!(builder.root(), calculate_transaction_root(&transactions[..=index]));
+        }
+        assert_eq!(builder.len(), transactions.len());
+    }
+
     #[test]
     fn check_receipt_root() {
         let logs = vec![Log { address: H160::zero(), topics: vec![], data: Default::default() }];
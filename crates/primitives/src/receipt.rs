@@ -38,6 +38,14 @@ impl Receipt {
     }
 }
 
+/// Calculates the logs bloom of a block from the receipts of its transactions.
+///
+/// The logs of all receipts are accrued into a single [Bloom], instead of computing and merging
+/// the bloom of every receipt.
+pub fn block_bloom(receipts: &[Receipt]) -> Bloom {
+    logs_bloom(receipts.iter().flat_map(|receipt| &receipt.logs))
+}
+
 impl From<Receipt> for ReceiptWithBloom {
     fn from(receipt: Receipt) -> Self {
         let bloom = receipt.bloom_slow();
@@ -141,6 +149,9 @@ impl Decodable for ReceiptWithBloom {
                 } else if receipt_type == 0x02 {
                     buf.advance(1);
                     Self::decode_receipt(buf, TxType::EIP1559)
+                } else if receipt_type == 0x03 {
+                    buf.advance(1);
+                    Self::decode_receipt(buf, TxType::EIP4844)
                 } else {
                     Err(reth_rlp::DecodeError::Custom("invalid receipt type"))
                 }
@@ -245,6 +256,9 @@ impl<'a> ReceiptWithBloomEncoder<'a> {
             TxType::EIP1559 => {
                 out.put_u8(0x02);
             }
+            TxType::EIP4844 => {
+                out.put_u8(0x03);
+            }
             _ => unreachable!("legacy handled; qed."),
         }
         out.put_slice(payload.as_ref());
@@ -264,7 +278,7 @@ impl<'a> Encodable for ReceiptWithBloomEncoder<'a> {
     fn length(&self) -> usize {
         let mut payload_len = self.receipt_length();
         // account for eip-2718 type prefix and set the list
-        if matches!(self.receipt.tx_type, TxType::EIP1559 | TxType::EIP2930) {
+        if matches!(self.receipt.tx_type, TxType::EIP1559 | TxType::EIP2930 | TxType::EIP4844) {
             payload_len += 1;
             // we include a string header for typed receipts, so include the length here
             payload_len += length_of_length(payload_len);
@@ -384,4 +398,40 @@ mod tests {
         let (decoded, _) = Receipt::from_compact(&data[..], data.len());
         assert_eq!(decoded, receipt);
     }
+
+    #[test]
+    fn block_bloom_of_receipts() {
+        let log =
+            |address: Address, topics: Vec<H256>| Log { address, topics, data: Default::default() };
+        let receipts = vec![
+            Receipt {
+                tx_type: TxType::Legacy,
+                success: true,
+                cumulative_gas_used: 21_000,
+                logs: vec![log(Address::random(), vec![H256::random()])],
+            },
+            Receipt {
+                tx_type: TxType::EIP1559,
+                success: false,
+                cumulative_gas_used: 42_000,
+                logs: vec![],
+            },
+            Receipt {
+                tx_type: TxType::EIP2930,
+                success: true,
+                cumulative_gas_used: 63_000,
+                logs: vec![
+                    log(Address::random(), vec![H256::random(), H256::random()]),
+                    log(Address::random(), vec![]),
+                ],
+            },
+        ];
+
+        let mut expected = Bloom::zero();
+        for receipt in &receipts {
+            expected.accrue_bloom(&receipt.bloom_slow());
+        }
+        assert_eq!(block_bloom(&receipts), expected);
+        assert_eq!(block_bloom(&[]), Bloom::zero());
+    }
 }
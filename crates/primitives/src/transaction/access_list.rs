@@ -4,6 +4,7 @@ use revm_primitives::U256;
 use reth_codecs::{main_codec, Compact};
 use reth_rlp::{RlpDecodable, RlpDecodableWrapper, RlpEncodable, RlpEncodableWrapper};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 
 /// A list of addresses and storage keys that the transaction plans to access.
 /// Accesses outside the list are possible, but become more expensive.
@@ -35,6 +36,26 @@ impl AccessList {
             })
             .collect()
     }
+
+    /// Returns the canonical form of this list.
+    ///
+    /// Items with the same address are merged into a single item and duplicate storage keys are
+    /// removed. Items are sorted by address and storage keys are sorted in ascending order.
+    pub fn canonicalize(self) -> AccessList {
+        let mut items: BTreeMap<Address, BTreeSet<H256>> = BTreeMap::new();
+        for item in self.0 {
+            items.entry(item.address).or_default().extend(item.storage_keys);
+        }
+        AccessList(
+            items
+                .into_iter()
+                .map(|(address, storage_keys)| AccessListItem {
+                    address,
+                    storage_keys: storage_keys.into_iter().collect(),
+                })
+                .collect(),
+        )
+    }
 }
 
 /// Access list with gas used appended.
@@ -46,3 +67,30 @@ pub struct AccessListWithGasUsed {
     /// Estimated gas used with access list.
     pub gas_used: U256,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonicalize_access_list() {
+        let a = Address::from_low_u64_be(1);
+        let b = Address::from_low_u64_be(2);
+        let key = H256::from_low_u64_be;
+
+        let list = AccessList(vec![
+            AccessListItem { address: b, storage_keys: vec![key(3), key(1)] },
+            AccessListItem { address: a, storage_keys: vec![key(2)] },
+            AccessListItem { address: b, storage_keys: vec![key(1), key(2), key(2)] },
+            AccessListItem { address: a, storage_keys: vec![] },
+        ]);
+
+        assert_eq!(
+            list.canonicalize(),
+            AccessList(vec![
+                AccessListItem { address: a, storage_keys: vec![key(2)] },
+                AccessListItem { address: b, storage_keys: vec![key(1), key(2), key(3)] },
+            ])
+        );
+    }
+}
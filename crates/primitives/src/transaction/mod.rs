@@ -1,23 +1,34 @@
 use crate::{
     compression::{TRANSACTION_COMPRESSOR, TRANSACTION_DECOMPRESSOR},
-    keccak256, Address, Bytes, ChainId, TxHash, H256,
+    constants::{
+        ACCESS_LIST_ADDRESS_GAS, ACCESS_LIST_STORAGE_KEY_GAS, GAS_PER_BLOB, INITCODE_WORD_GAS,
+        TOTAL_COST_FLOOR_PER_TOKEN, TX_BASE_GAS, TX_CREATE_GAS, TX_DATA_NON_ZERO_GAS,
+        TX_DATA_NON_ZERO_GAS_FRONTIER, TX_DATA_ZERO_GAS,
+    },
+    keccak256, Address, Bytes, ChainId, ChainSpec, Hardfork, Head, TxHash, H256,
 };
 pub use access_list::{AccessList, AccessListItem, AccessListWithGasUsed};
 use bytes::{Buf, BytesMut};
 use derive_more::{AsRef, Deref};
 pub use error::InvalidTransactionError;
 pub use meta::TransactionMeta;
+pub use pooled::{BlobTransaction, PooledTransactionsElement};
 use reth_codecs::{add_arbitrary_tests, derive_arbitrary, main_codec, Compact};
 use reth_rlp::{
     length_of_length, Decodable, DecodeError, Encodable, Header, EMPTY_LIST_CODE, EMPTY_STRING_CODE,
 };
 use serde::{Deserialize, Serialize};
+pub use sidecar::{Blob, BlobTransactionSidecar, KzgCommitment, KzgProof, BYTES_PER_BLOB};
 pub use signature::Signature;
-pub use tx_type::{TxType, EIP1559_TX_TYPE_ID, EIP2930_TX_TYPE_ID, LEGACY_TX_TYPE_ID};
+pub use tx_type::{
+    TxType, EIP1559_TX_TYPE_ID, EIP2930_TX_TYPE_ID, EIP4844_TX_TYPE_ID, LEGACY_TX_TYPE_ID,
+};
 
 mod access_list;
 mod error;
 mod meta;
+mod pooled;
+mod sidecar;
 mod signature;
 mod tx_type;
 pub(crate) mod util;
@@ -168,6 +179,70 @@ pub struct TxEip1559 {
     pub input: Bytes,
 }
 
+/// A blob transaction ([EIP-4844](https://eips.ethereum.org/EIPS/eip-4844)).
+///
+/// The blobs themselves are not part of the transaction, they travel alongside it in a
+/// [BlobTransactionSidecar] while the transaction is gossiped and held in the pool.
+#[main_codec]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct TxEip4844 {
+    /// Added as EIP-155: Simple replay attack protection
+    pub chain_id: u64,
+    /// A scalar value equal to the number of transactions sent by the sender; formally Tn.
+    pub nonce: u64,
+    /// A scalar value equal to the maximum
+    /// amount of gas that should be used in executing
+    /// this transaction. This is paid up-front, before any
+    /// computation is done and may not be increased
+    /// later; formally Tg.
+    pub gas_limit: u64,
+    /// A scalar value equal to the maximum
+    /// amount of gas that should be used in executing
+    /// this transaction. This is paid up-front, before any
+    /// computation is done and may not be increased
+    /// later; formally Tg.
+    ///
+    /// As ethereum circulation is around 120mil eth as of 2022 that is around
+    /// 120000000000000000000000000 wei we are safe to use u128 as its max number is:
+    /// 340282366920938463463374607431768211455
+    pub max_fee_per_gas: u128,
+    /// Max Priority fee that transaction is paying
+    ///
+    /// As ethereum circulation is around 120mil eth as of 2022 that is around
+    /// 120000000000000000000000000 wei we are safe to use u128 as its max number is:
+    /// 340282366920938463463374607431768211455
+    pub max_priority_fee_per_gas: u128,
+    /// The 160-bit address of the message call’s recipient.
+    ///
+    /// Blob transactions can't create contracts, decoding rejects [TransactionKind::Create].
+    pub to: TransactionKind,
+    /// A scalar value equal to the number of Wei to
+    /// be transferred to the message call’s recipient or,
+    /// in the case of contract creation, as an endowment
+    /// to the newly created account; formally Tv.
+    ///
+    /// As ethereum circulation is around 120mil eth as of 2022 that is around
+    /// 120000000000000000000000000 wei we are safe to use u128 as its max number is:
+    /// 340282366920938463463374607431768211455
+    pub value: u128,
+    /// The accessList specifies a list of addresses and storage keys;
+    /// these addresses and storage keys are added into the `accessed_addresses`
+    /// and `accessed_storage_keys` global sets (introduced in EIP-2929).
+    /// A gas cost is charged, though at a discount relative to the cost of
+    /// accessing outside the list.
+    pub access_list: AccessList,
+    /// The maximum fee per unit of blob gas the sender is willing to pay.
+    pub max_fee_per_blob_gas: u128,
+    /// The versioned hashes of the KZG commitments to the blobs of the transaction.
+    pub blob_versioned_hashes: Vec<H256>,
+    /// Input has two uses depending if transaction is Create or Call (if `to` field is None or
+    /// Some). pub init: An unlimited size byte array specifying the
+    /// EVM-code for the account initialisation procedure CREATE,
+    /// data: An unlimited size byte array specifying the
+    /// input data of the message call, formally Td.
+    pub input: Bytes,
+}
+
 /// A raw transaction.
 ///
 /// Transaction types were introduced in [EIP-2718](https://eips.ethereum.org/EIPS/eip-2718).
@@ -180,6 +255,8 @@ pub enum Transaction {
     Eip2930(TxEip2930),
     /// A transaction with a priority fee ([EIP-1559](https://eips.ethereum.org/EIPS/eip-1559)).
     Eip1559(TxEip1559),
+    /// A blob transaction ([EIP-4844](https://eips.ethereum.org/EIPS/eip-4844)).
+    Eip4844(TxEip4844),
 }
 
 impl Transaction {
@@ -231,6 +308,7 @@ impl Transaction {
             Transaction::Legacy(tx) => tx.nonce = nonce,
             Transaction::Eip2930(tx) => tx.nonce = nonce,
             Transaction::Eip1559(tx) => tx.nonce = nonce,
+            Transaction::Eip4844(tx) => tx.nonce = nonce,
         }
     }
 
@@ -240,6 +318,7 @@ impl Transaction {
             Transaction::Legacy(tx) => tx.value = value,
             Transaction::Eip2930(tx) => tx.value = value,
             Transaction::Eip1559(tx) => tx.value = value,
+            Transaction::Eip4844(tx) => tx.value = value,
         }
     }
 
@@ -249,6 +328,7 @@ impl Transaction {
             Transaction::Legacy(tx) => tx.input = input,
             Transaction::Eip2930(tx) => tx.input = input,
             Transaction::Eip1559(tx) => tx.input = input,
+            Transaction::Eip4844(tx) => tx.input = input,
         }
     }
 }
@@ -271,6 +351,10 @@ impl Compact for Transaction {
                 tx.to_compact(buf);
                 2
             }
+            Transaction::Eip4844(tx) => {
+                tx.to_compact(buf);
+                3
+            }
         }
     }
 
@@ -288,6 +372,10 @@ impl Compact for Transaction {
                 let (tx, buf) = TxEip1559::from_compact(buf, buf.len());
                 (Transaction::Eip1559(tx), buf)
             }
+            3 => {
+                let (tx, buf) = TxEip4844::from_compact(buf, buf.len());
+                (Transaction::Eip4844(tx), buf)
+            }
             _ => unreachable!("Junk data in database: unknown Transaction variant"),
         }
     }
@@ -304,12 +392,28 @@ impl Transaction {
         keccak256(&buf)
     }
 
+    /// Returns the hash that has to be signed for the transaction on the given chain.
+    ///
+    /// For legacy transactions, the chain id is included according to
+    /// [EIP-155](https://eips.ethereum.org/EIPS/eip-155) if it is `Some`. Typed transactions always
+    /// commit to a chain id, their own chain id is used if `chain_id` is `None`.
+    pub fn signature_hash_with_chain_id(&self, chain_id: Option<ChainId>) -> H256 {
+        let mut tx = self.clone();
+        match (&mut tx, chain_id) {
+            (Transaction::Legacy(TxLegacy { chain_id: ref mut c, .. }), _) => *c = chain_id,
+            (tx, Some(chain_id)) => tx.set_chain_id(chain_id),
+            (_, None) => {}
+        }
+        tx.signature_hash()
+    }
+
     /// Get chain_id.
     pub fn chain_id(&self) -> Option<u64> {
         match self {
             Transaction::Legacy(TxLegacy { chain_id, .. }) => *chain_id,
             Transaction::Eip2930(TxEip2930 { chain_id, .. }) => Some(*chain_id),
             Transaction::Eip1559(TxEip1559 { chain_id, .. }) => Some(*chain_id),
+            Transaction::Eip4844(TxEip4844 { chain_id, .. }) => Some(*chain_id),
         }
     }
 
@@ -319,6 +423,7 @@ impl Transaction {
             Transaction::Legacy(TxLegacy { chain_id: ref mut c, .. }) => *c = Some(chain_id),
             Transaction::Eip2930(TxEip2930 { chain_id: ref mut c, .. }) => *c = chain_id,
             Transaction::Eip1559(TxEip1559 { chain_id: ref mut c, .. }) => *c = chain_id,
+            Transaction::Eip4844(TxEip4844 { chain_id: ref mut c, .. }) => *c = chain_id,
         }
     }
 
@@ -328,7 +433,8 @@ impl Transaction {
         match self {
             Transaction::Legacy(TxLegacy { to, .. }) |
             Transaction::Eip2930(TxEip2930 { to, .. }) |
-            Transaction::Eip1559(TxEip1559 { to, .. }) => to,
+            Transaction::Eip1559(TxEip1559 { to, .. }) |
+            Transaction::Eip4844(TxEip4844 { to, .. }) => to,
         }
     }
 
@@ -343,6 +449,7 @@ impl Transaction {
             Transaction::Legacy { .. } => TxType::Legacy,
             Transaction::Eip2930 { .. } => TxType::EIP2930,
             Transaction::Eip1559 { .. } => TxType::EIP1559,
+            Transaction::Eip4844 { .. } => TxType::EIP4844,
         }
     }
 
@@ -352,6 +459,7 @@ impl Transaction {
             Transaction::Legacy(TxLegacy { value, .. }) => value,
             Transaction::Eip2930(TxEip2930 { value, .. }) => value,
             Transaction::Eip1559(TxEip1559 { value, .. }) => value,
+            Transaction::Eip4844(TxEip4844 { value, .. }) => value,
         }
     }
 
@@ -361,6 +469,7 @@ impl Transaction {
             Transaction::Legacy(TxLegacy { nonce, .. }) => *nonce,
             Transaction::Eip2930(TxEip2930 { nonce, .. }) => *nonce,
             Transaction::Eip1559(TxEip1559 { nonce, .. }) => *nonce,
+            Transaction::Eip4844(TxEip4844 { nonce, .. }) => *nonce,
         }
     }
 
@@ -369,7 +478,8 @@ impl Transaction {
         match self {
             Transaction::Legacy(TxLegacy { gas_limit, .. }) |
             Transaction::Eip2930(TxEip2930 { gas_limit, .. }) |
-            Transaction::Eip1559(TxEip1559 { gas_limit, .. }) => *gas_limit,
+            Transaction::Eip1559(TxEip1559 { gas_limit, .. }) |
+            Transaction::Eip4844(TxEip4844 { gas_limit, .. }) => *gas_limit,
         }
     }
 
@@ -380,7 +490,8 @@ impl Transaction {
         match self {
             Transaction::Legacy(TxLegacy { gas_price, .. }) |
             Transaction::Eip2930(TxEip2930 { gas_price, .. }) => *gas_price,
-            Transaction::Eip1559(TxEip1559 { max_fee_per_gas, .. }) => *max_fee_per_gas,
+            Transaction::Eip1559(TxEip1559 { max_fee_per_gas, .. }) |
+            Transaction::Eip4844(TxEip4844 { max_fee_per_gas, .. }) => *max_fee_per_gas,
         }
     }
 
@@ -392,7 +503,8 @@ impl Transaction {
         match self {
             Transaction::Legacy(_) => None,
             Transaction::Eip2930(_) => None,
-            Transaction::Eip1559(TxEip1559 { max_priority_fee_per_gas, .. }) => {
+            Transaction::Eip1559(TxEip1559 { max_priority_fee_per_gas, .. }) |
+            Transaction::Eip4844(TxEip4844 { max_priority_fee_per_gas, .. }) => {
                 Some(*max_priority_fee_per_gas)
             }
         }
@@ -409,7 +521,8 @@ impl Transaction {
         match self {
             Transaction::Legacy(TxLegacy { gas_price, .. }) |
             Transaction::Eip2930(TxEip2930 { gas_price, .. }) => *gas_price,
-            Transaction::Eip1559(TxEip1559 { max_priority_fee_per_gas, .. }) => {
+            Transaction::Eip1559(TxEip1559 { max_priority_fee_per_gas, .. }) |
+            Transaction::Eip4844(TxEip4844 { max_priority_fee_per_gas, .. }) => {
                 *max_priority_fee_per_gas
             }
         }
@@ -419,13 +532,12 @@ impl Transaction {
     ///
     /// If the transaction is a legacy or EIP2930 transaction, the gas price is returned.
     pub fn effective_gas_price(&self, base_fee: Option<u64>) -> u128 {
-        let dynamic_tx = match self {
-            Transaction::Legacy(tx) => return tx.gas_price,
-            Transaction::Eip2930(tx) => return tx.gas_price,
-            Transaction::Eip1559(dynamic_tx) => dynamic_tx,
-        };
-
-        dynamic_tx.effective_gas_price(base_fee)
+        match self {
+            Transaction::Legacy(tx) => tx.gas_price,
+            Transaction::Eip2930(tx) => tx.gas_price,
+            Transaction::Eip1559(dynamic_tx) => dynamic_tx.effective_gas_price(base_fee),
+            Transaction::Eip4844(blob_tx) => blob_tx.effective_gas_price(base_fee),
+        }
     }
 
     // TODO: dedup with effective_tip_per_gas
@@ -479,9 +591,102 @@ impl Transaction {
             Transaction::Legacy(TxLegacy { input, .. }) => input,
             Transaction::Eip2930(TxEip2930 { input, .. }) => input,
             Transaction::Eip1559(TxEip1559 { input, .. }) => input,
+            Transaction::Eip4844(TxEip4844 { input, .. }) => input,
         }
     }
 
+    /// Returns the access list declared by the transaction.
+    ///
+    /// Returns `None` for legacy transactions, which can't declare one.
+    pub fn access_list(&self) -> Option<&AccessList> {
+        match self {
+            Transaction::Legacy(_) => None,
+            Transaction::Eip2930(TxEip2930 { access_list, .. }) => Some(access_list),
+            Transaction::Eip1559(TxEip1559 { access_list, .. }) => Some(access_list),
+            Transaction::Eip4844(TxEip4844 { access_list, .. }) => Some(access_list),
+        }
+    }
+
+    /// Returns the maximum fee per blob gas the sender is willing to pay.
+    ///
+    /// Returns `None` for non-blob transactions.
+    pub fn max_fee_per_blob_gas(&self) -> Option<u128> {
+        match self {
+            Transaction::Eip4844(TxEip4844 { max_fee_per_blob_gas, .. }) => {
+                Some(*max_fee_per_blob_gas)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the versioned hashes of the blobs of the transaction.
+    ///
+    /// Returns `None` for non-blob transactions.
+    pub fn blob_versioned_hashes(&self) -> Option<&[H256]> {
+        match self {
+            Transaction::Eip4844(TxEip4844 { blob_versioned_hashes, .. }) => {
+                Some(blob_versioned_hashes.as_slice())
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the number of calldata tokens of the transaction's input, where a zero byte counts
+    /// as one token and a non-zero byte counts as four tokens.
+    fn calldata_tokens(&self) -> u64 {
+        self.input().iter().map(|byte| if *byte == 0 { 1 } else { 4 }).sum()
+    }
+
+    /// Returns the calldata gas floor of the transaction as defined in
+    /// [EIP-7623](https://eips.ethereum.org/EIPS/eip-7623).
+    ///
+    /// This is the base transaction cost plus [TOTAL_COST_FLOOR_PER_TOKEN] per calldata token,
+    /// where a zero byte counts as one token and a non-zero byte counts as four tokens.
+    pub fn calldata_floor_gas(&self) -> u64 {
+        TX_BASE_GAS + TOTAL_COST_FLOOR_PER_TOKEN * self.calldata_tokens()
+    }
+
+    /// Returns the intrinsic gas of the transaction, the gas that is charged before execution.
+    ///
+    /// This accounts for the base cost, the calldata, the init code of contract creations and the
+    /// access list, depending on the hardforks active at the given head. Once [Hardfork::Prague]
+    /// is active, this is at least the [Transaction::calldata_floor_gas].
+    pub fn intrinsic_gas(&self, chain_spec: &ChainSpec, head: Head) -> u64 {
+        let is_create = matches!(self.kind(), TransactionKind::Create);
+        let mut gas = if is_create && chain_spec.is_active(Hardfork::Homestead, head) {
+            TX_CREATE_GAS
+        } else {
+            TX_BASE_GAS
+        };
+
+        let input = self.input();
+        let zero_bytes = input.iter().filter(|byte| **byte == 0).count() as u64;
+        let non_zero_bytes = input.len() as u64 - zero_bytes;
+        let non_zero_byte_gas = if chain_spec.is_active(Hardfork::Istanbul, head) {
+            TX_DATA_NON_ZERO_GAS
+        } else {
+            TX_DATA_NON_ZERO_GAS_FRONTIER
+        };
+        gas += zero_bytes * TX_DATA_ZERO_GAS + non_zero_bytes * non_zero_byte_gas;
+
+        if is_create && chain_spec.is_active(Hardfork::Shanghai, head) {
+            gas += INITCODE_WORD_GAS * ((input.len() as u64 + 31) / 32);
+        }
+
+        if let Some(access_list) = self.access_list() {
+            for item in access_list.0.iter() {
+                gas += ACCESS_LIST_ADDRESS_GAS +
+                    ACCESS_LIST_STORAGE_KEY_GAS * item.storage_keys.len() as u64;
+            }
+        }
+
+        if chain_spec.is_active(Hardfork::Prague, head) {
+            gas = gas.max(self.calldata_floor_gas());
+        }
+
+        gas
+    }
+
     /// Encodes EIP-155 arguments into the desired buffer. Only encodes values for legacy
     /// transactions.
     pub(crate) fn encode_eip155_fields(&self, out: &mut dyn bytes::BufMut) {
@@ -575,6 +780,33 @@ impl Transaction {
                 len += access_list.length();
                 len
             }
+            Transaction::Eip4844(TxEip4844 {
+                chain_id,
+                nonce,
+                gas_limit,
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                to,
+                value,
+                access_list,
+                max_fee_per_blob_gas,
+                blob_versioned_hashes,
+                input,
+            }) => {
+                let mut len = 0;
+                len += chain_id.length();
+                len += nonce.length();
+                len += max_priority_fee_per_gas.length();
+                len += max_fee_per_gas.length();
+                len += gas_limit.length();
+                len += to.length();
+                len += value.length();
+                len += input.0.length();
+                len += access_list.length();
+                len += max_fee_per_blob_gas.length();
+                len += blob_versioned_hashes.length();
+                len
+            }
         }
     }
 
@@ -637,6 +869,31 @@ impl Transaction {
                 input.0.encode(out);
                 access_list.encode(out);
             }
+            Transaction::Eip4844(TxEip4844 {
+                chain_id,
+                nonce,
+                gas_limit,
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                to,
+                value,
+                access_list,
+                max_fee_per_blob_gas,
+                blob_versioned_hashes,
+                input,
+            }) => {
+                chain_id.encode(out);
+                nonce.encode(out);
+                max_priority_fee_per_gas.encode(out);
+                max_fee_per_gas.encode(out);
+                gas_limit.encode(out);
+                to.encode(out);
+                value.encode(out);
+                input.0.encode(out);
+                access_list.encode(out);
+                max_fee_per_blob_gas.encode(out);
+                blob_versioned_hashes.encode(out);
+            }
         }
     }
 }
@@ -682,23 +939,66 @@ impl Encodable for Transaction {
     }
 }
 
+/// Returns the effective gas price of a dynamic fee transaction for the given `base_fee`.
+fn effective_gas_price(
+    max_fee_per_gas: u128,
+    max_priority_fee_per_gas: u128,
+    base_fee: Option<u64>,
+) -> u128 {
+    match base_fee {
+        None => max_fee_per_gas,
+        Some(base_fee) => {
+            // if the tip is greater than the max priority fee per gas, set it to the max
+            // priority fee per gas + base fee
+            let tip = max_fee_per_gas.saturating_sub(base_fee as u128);
+            if tip > max_priority_fee_per_gas {
+                max_priority_fee_per_gas + base_fee as u128
+            } else {
+                // otherwise return the max fee per gas
+                max_fee_per_gas
+            }
+        }
+    }
+}
+
 impl TxEip1559 {
     /// Returns the effective gas price for the given `base_fee`.
     pub fn effective_gas_price(&self, base_fee: Option<u64>) -> u128 {
-        match base_fee {
-            None => self.max_fee_per_gas,
-            Some(base_fee) => {
-                // if the tip is greater than the max priority fee per gas, set it to the max
-                // priority fee per gas + base fee
-                let tip = self.max_fee_per_gas - base_fee as u128;
-                if tip > self.max_priority_fee_per_gas {
-                    self.max_priority_fee_per_gas + base_fee as u128
-                } else {
-                    // otherwise return the max fee per gas
-                    self.max_fee_per_gas
-                }
-            }
+        effective_gas_price(self.max_fee_per_gas, self.max_priority_fee_per_gas, base_fee)
+    }
+
+    /// Validates the fee fields of the transaction.
+    ///
+    /// The `max_priority_fee_per_gas` must not exceed the `max_fee_per_gas`, see
+    /// [EIP-1559](https://eips.ethereum.org/EIPS/eip-1559).
+    pub fn validate_fees(&self) -> Result<(), InvalidTransactionError> {
+        if self.max_priority_fee_per_gas > self.max_fee_per_gas {
+            return Err(InvalidTransactionError::TipAboveFeeCap)
         }
+        Ok(())
+    }
+}
+
+impl TxEip4844 {
+    /// Returns the effective gas price for the given `base_fee`.
+    pub fn effective_gas_price(&self, base_fee: Option<u64>) -> u128 {
+        effective_gas_price(self.max_fee_per_gas, self.max_priority_fee_per_gas, base_fee)
+    }
+
+    /// Validates the fee fields of the transaction.
+    ///
+    /// The `max_priority_fee_per_gas` must not exceed the `max_fee_per_gas`, see
+    /// [EIP-1559](https://eips.ethereum.org/EIPS/eip-1559).
+    pub fn validate_fees(&self) -> Result<(), InvalidTransactionError> {
+        if self.max_priority_fee_per_gas > self.max_fee_per_gas {
+            return Err(InvalidTransactionError::TipAboveFeeCap)
+        }
+        Ok(())
+    }
+
+    /// Returns the blob gas consumed by the transaction, [GAS_PER_BLOB] for every blob.
+    pub fn blob_gas(&self) -> u64 {
+        GAS_PER_BLOB * self.blob_versioned_hashes.len() as u64
     }
 }
 
@@ -827,7 +1127,7 @@ impl Compact for TransactionSignedNoHash {
                 let mut tmp = bytes::BytesMut::with_capacity(200);
                 let tx_bits = self.transaction.to_compact(&mut tmp);
 
-                buf.put_slice(&compressor.compress(&tmp).expect("Failed to compress"));
+                buf.put_slice(&compressor.compress(&tmp));
                 tx_bits as u8
             })
         } else {
@@ -851,19 +1151,7 @@ impl Compact for TransactionSignedNoHash {
         let (transaction, buf) = if zstd_bit != 0 {
             TRANSACTION_DECOMPRESSOR.with(|decompressor| {
                 let mut decompressor = decompressor.borrow_mut();
-                let mut tmp: Vec<u8> = Vec::with_capacity(200);
-
-                // `decompress_to_buffer` will return an error if the output buffer doesn't have
-                // enough capacity. However we don't actually have information on the required
-                // length. So we hope for the best, and keep trying again with a fairly bigger size
-                // if it fails.
-                while let Err(err) = decompressor.decompress_to_buffer(buf, &mut tmp) {
-                    let err = err.to_string();
-                    if !err.contains("Destination buffer is too small") {
-                        panic!("Failed to decompress: {}", err);
-                    }
-                    tmp.reserve(tmp.capacity() + 24_000);
-                }
+                let tmp = decompressor.decompress_with_version(buf);
 
                 // TODO: enforce that zstd is only present at a "top" level type
 
@@ -1085,6 +1373,24 @@ impl TransactionSigned {
                 input: Bytes(Decodable::decode(data)?),
                 access_list: Decodable::decode(data)?,
             }),
+            3 => Transaction::Eip4844(TxEip4844 {
+                chain_id: Decodable::decode(data)?,
+                nonce: Decodable::decode(data)?,
+                max_priority_fee_per_gas: Decodable::decode(data)?,
+                max_fee_per_gas: Decodable::decode(data)?,
+                gas_limit: Decodable::decode(data)?,
+                to: match Decodable::decode(data)? {
+                    TransactionKind::Create => {
+                        return Err(DecodeError::Custom("blob tx can't create contracts"))
+                    }
+                    to => to,
+                },
+                value: Decodable::decode(data)?,
+                input: Bytes(Decodable::decode(data)?),
+                access_list: Decodable::decode(data)?,
+                max_fee_per_blob_gas: Decodable::decode(data)?,
+                blob_versioned_hashes: Decodable::decode(data)?,
+            }),
             _ => return Err(DecodeError::Custom("unsupported typed transaction type")),
         };
 
@@ -1170,6 +1476,12 @@ impl proptest::arbitrary::Arbitrary for TransactionSigned {
                     // Otherwise we might overflow when calculating `v` on `recalculate_hash`
                     transaction.set_chain_id(chain_id % (u64::MAX / 2 - 36));
                 }
+                if let Transaction::Eip4844(ref mut blob_tx) = transaction {
+                    // blob transactions can't create contracts
+                    if blob_tx.to == TransactionKind::Create {
+                        blob_tx.to = TransactionKind::Call(Address::default());
+                    }
+                }
                 let mut tx =
                     TransactionSigned { hash: Default::default(), signature: sig, transaction };
                 tx.hash = tx.recalculate_hash();
@@ -1189,6 +1501,12 @@ impl<'a> arbitrary::Arbitrary<'a> for TransactionSigned {
             // Otherwise we might overflow when calculating `v` on `recalculate_hash`
             transaction.set_chain_id(chain_id % (u64::MAX / 2 - 36));
         }
+        if let Transaction::Eip4844(ref mut blob_tx) = transaction {
+            // blob transactions can't create contracts
+            if blob_tx.to == TransactionKind::Create {
+                blob_tx.to = TransactionKind::Call(Address::default());
+            }
+        }
 
         let mut tx = TransactionSigned {
             hash: Default::default(),
@@ -1293,9 +1611,12 @@ impl IntoRecoveredTransaction for TransactionSignedEcRecovered {
 #[cfg(test)]
 mod tests {
     use crate::{
-        transaction::{signature::Signature, TransactionKind, TxEip1559, TxEip2930, TxLegacy},
-        AccessList, Address, Bytes, Transaction, TransactionSigned, TransactionSignedEcRecovered,
-        H256, U256,
+        transaction::{
+            signature::Signature, TransactionKind, TxEip1559, TxEip2930, TxEip4844, TxLegacy,
+        },
+        AccessList, AccessListItem, Address, Bytes, ChainSpecBuilder, ForkCondition, Hardfork,
+        Head, InvalidTransactionError, Transaction, TransactionSigned,
+        TransactionSignedEcRecovered, H256, U256,
     };
     use bytes::BytesMut;
     use ethers_core::utils::hex;
@@ -1309,6 +1630,74 @@ mod tests {
         assert_eq!(DecodeError::InputTooShort, res);
     }
 
+    #[test]
+    fn validate_eip1559_fees() {
+        let tx =
+            TxEip1559 { max_fee_per_gas: 100, max_priority_fee_per_gas: 10, ..Default::default() };
+        assert_eq!(tx.validate_fees(), Ok(()));
+
+        let tx = TxEip1559 { max_priority_fee_per_gas: 100, ..tx };
+        assert_eq!(tx.validate_fees(), Ok(()));
+
+        let tx = TxEip1559 { max_priority_fee_per_gas: 101, ..tx };
+        assert_eq!(tx.validate_fees(), Err(InvalidTransactionError::TipAboveFeeCap));
+    }
+
+    #[test]
+    fn intrinsic_gas_calldata_floor() {
+        let chain_spec = ChainSpecBuilder::mainnet()
+            .with_fork(Hardfork::Prague, ForkCondition::Timestamp(2_000_000_000))
+            .build();
+        let before_prague =
+            Head { number: 20_000_000, timestamp: 1_999_999_999, ..Default::default() };
+        let prague = Head { number: 20_000_001, timestamp: 2_000_000_000, ..Default::default() };
+
+        let call = |input: Vec<u8>| {
+            Transaction::Eip1559(TxEip1559 {
+                chain_id: 1,
+                nonce: 0,
+                gas_limit: 100_000,
+                max_fee_per_gas: 1,
+                max_priority_fee_per_gas: 1,
+                to: TransactionKind::Call(Address::from_low_u64_be(1)),
+                value: 0,
+                input: Bytes::from(input),
+                access_list: Default::default(),
+            })
+        };
+
+        // calldata heavy transaction, the floor dominates
+        let tx = call(vec![0xff; 1000]);
+        assert_eq!(tx.calldata_floor_gas(), 21_000 + 10 * 4 * 1000);
+        assert_eq!(tx.intrinsic_gas(&chain_spec, before_prague), 21_000 + 16 * 1000);
+        assert_eq!(tx.intrinsic_gas(&chain_spec, prague), 21_000 + 10 * 4 * 1000);
+
+        // zero bytes count as a single token, the floor still dominates the standard cost
+        let tx = call(vec![0; 1000]);
+        assert_eq!(tx.calldata_floor_gas(), 21_000 + 10 * 1000);
+        assert_eq!(tx.intrinsic_gas(&chain_spec, before_prague), 21_000 + 4 * 1000);
+        assert_eq!(tx.intrinsic_gas(&chain_spec, prague), 21_000 + 10 * 1000);
+
+        // access list heavy transaction, the standard cost dominates
+        let tx = Transaction::Eip2930(TxEip2930 {
+            chain_id: 1,
+            nonce: 0,
+            gas_price: 1,
+            gas_limit: 100_000,
+            to: TransactionKind::Call(Address::from_low_u64_be(1)),
+            value: 0,
+            input: Bytes::from(vec![0xff; 10]),
+            access_list: AccessList(vec![AccessListItem {
+                address: Address::from_low_u64_be(2),
+                storage_keys: vec![H256::from_low_u64_be(3)],
+            }]),
+        });
+        let standard = 21_000 + 16 * 10 + 2400 + 1900;
+        assert_eq!(tx.calldata_floor_gas(), 21_000 + 10 * 4 * 10);
+        assert_eq!(tx.intrinsic_gas(&chain_spec, before_prague), standard);
+        assert_eq!(tx.intrinsic_gas(&chain_spec, prague), standard);
+    }
+
     #[test]
     fn test_decode_create() {
         // tests that a contract creation tx encodes and decodes properly
@@ -1568,6 +1957,86 @@ mod tests {
         assert_eq!(signed_tx.recover_signer(), Some(signer), "Recovering signer should pass.");
     }
 
+    #[test]
+    fn eip4844_roundtrip_and_recover_signer() {
+        use crate::{sign_message, TxType, EIP4844_TX_TYPE_ID};
+
+        let secret = H256::from_low_u64_be(1);
+        let hashes = vec![H256::from_low_u64_be(2), H256::from_low_u64_be(3)];
+        let transaction = Transaction::Eip4844(TxEip4844 {
+            chain_id: 1,
+            nonce: 7,
+            gas_limit: 21_000,
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 10,
+            to: TransactionKind::Call(Address::from_low_u64_be(4)),
+            value: 5,
+            access_list: AccessList(vec![AccessListItem {
+                address: Address::from_low_u64_be(6),
+                storage_keys: vec![H256::from_low_u64_be(7)],
+            }]),
+            max_fee_per_blob_gas: 20,
+            blob_versioned_hashes: hashes.clone(),
+            input: Bytes::from(vec![1, 2, 3]),
+        });
+        assert_eq!(transaction.tx_type(), TxType::EIP4844);
+        assert_eq!(transaction.blob_versioned_hashes(), Some(hashes.as_slice()));
+        assert_eq!(transaction.max_fee_per_blob_gas(), Some(20));
+
+        let signature = sign_message(secret, transaction.signature_hash()).unwrap();
+        let tx = TransactionSigned::from_transaction_and_signature(transaction.clone(), signature);
+
+        // the enveloped encoding is the type byte followed by the rlp of the fields
+        let enveloped = tx.envelope_encoded();
+        assert_eq!(enveloped[0], EIP4844_TX_TYPE_ID);
+        let decoded = TransactionSigned::decode_enveloped(enveloped.clone().into()).unwrap();
+        assert_eq!(decoded, tx);
+        assert_eq!(decoded.hash(), crate::keccak256(&enveloped));
+
+        let mut encoded = BytesMut::new();
+        tx.encode(&mut encoded);
+        assert_eq!(encoded.len(), tx.length());
+        assert_eq!(TransactionSigned::decode(&mut &encoded[..]).unwrap(), tx);
+
+        // the hash commits to the blob fields
+        let mut other = transaction;
+        if let Transaction::Eip4844(ref mut blob_tx) = other {
+            blob_tx.blob_versioned_hashes.pop();
+        }
+        let other = TransactionSigned::from_transaction_and_signature(other, signature);
+        assert_ne!(other.hash(), tx.hash());
+
+        // the address of the secret key `1`
+        let signer = Address::from_str("0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf").unwrap();
+        assert_eq!(decoded.recover_signer(), Some(signer));
+    }
+
+    #[test]
+    fn eip4844_create_is_rejected() {
+        let transaction = Transaction::Eip4844(TxEip4844 {
+            chain_id: 1,
+            to: TransactionKind::Create,
+            blob_versioned_hashes: vec![H256::from_low_u64_be(1)],
+            ..Default::default()
+        });
+        let tx =
+            TransactionSigned::from_transaction_and_signature(transaction, Signature::default());
+
+        assert_eq!(
+            TransactionSigned::decode_enveloped(tx.envelope_encoded().into()),
+            Err(DecodeError::Custom("blob tx can't create contracts"))
+        );
+    }
+
+    #[test]
+    fn effective_gas_price_below_base_fee() {
+        let transaction =
+            TxEip1559 { max_fee_per_gas: 10, max_priority_fee_per_gas: 1, ..Default::default() };
+        assert_eq!(transaction.effective_gas_price(Some(5)), 6);
+        // a fee cap below the base fee doesn't underflow
+        assert_eq!(transaction.effective_gas_price(Some(20)), 10);
+    }
+
     #[test]
     fn recover_signer_eip1559() {
         use crate::hex_literal::hex;
@@ -1601,6 +2070,63 @@ mod tests {
         assert_eq!(signed_tx.recover_signer(), Some(signer), "Recovering signer should pass.");
     }
 
+    #[test]
+    fn signature_hash_with_chain_id() {
+        use crate::hex_literal::hex;
+
+        // the legacy transaction of `recover_signer_legacy`, signed for mainnet
+        let signer: Address = hex!("398137383b3d25c92898c656696e41950e47316b").into();
+        let tx = Transaction::Legacy(TxLegacy {
+            chain_id: None,
+            nonce: 0x18,
+            gas_price: 0xfa56ea00,
+            gas_limit: 119902,
+            to: TransactionKind::Call( hex!("06012c8cf97bead5deae237070f9587f8e7a266d").into()),
+            value: 0x1c6bf526340000u64.into(),
+            input:  hex!("f7d8c88300000000000000000000000000000000000000000000000000000000000cee6100000000000000000000000000000000000000000000000000000000000ac3e1").into(),
+        });
+        let sig = Signature {
+            r: U256::from_be_bytes(hex!(
+                "2a378831cf81d99a3f06a18ae1b6ca366817ab4d88a70053c41d7a8f0368e031"
+            )),
+            s: U256::from_be_bytes(hex!(
+                "450d831a05b6e418724436c05c155e0a1b7b921015d0fbc2f667aed709ac4fb5"
+            )),
+            odd_y_parity: false,
+        };
+
+        let hash = tx.signature_hash_with_chain_id(Some(1));
+        assert_eq!(sig.recover_signer(hash), Some(signer));
+        // the pre-EIP-155 hash doesn't commit to the chain
+        assert_eq!(tx.signature_hash_with_chain_id(None), tx.signature_hash());
+        assert_ne!(sig.recover_signer(tx.signature_hash_with_chain_id(None)), Some(signer));
+
+        // the EIP-1559 transaction of `recover_signer_eip1559`, signed for mainnet
+        let signer: Address = hex!("dd6b8b3dc6b7ad97db52f08a275ff4483e024cea").into();
+        let tx = Transaction::Eip1559( TxEip1559 {
+            chain_id: 5,
+            nonce: 0x42,
+            gas_limit: 44386,
+            to: TransactionKind::Call( hex!("6069a6c32cf691f5982febae4faf8a6f3ab2f0f6").into()),
+            value: 0,
+            input:  hex!("a22cb4650000000000000000000000005eee75727d804a2b13038928d36f8b188945a57a0000000000000000000000000000000000000000000000000000000000000000").into(),
+            max_fee_per_gas: 0x4a817c800,
+            max_priority_fee_per_gas: 0x3b9aca00,
+            access_list: AccessList::default(),
+        });
+        let sig = Signature {
+            r: U256::from_str("0x840cfc572845f5786e702984c2a582528cad4b49b2a10b9db1be7fca90058565")
+                .unwrap(),
+            s: U256::from_str("0x25e7109ceb98168d95b09b18bbf6b685130e0562f233877d492b94eee0c5b6d1")
+                .unwrap(),
+            odd_y_parity: false,
+        };
+
+        assert_eq!(sig.recover_signer(tx.signature_hash_with_chain_id(Some(1))), Some(signer));
+        assert_eq!(tx.signature_hash_with_chain_id(None), tx.signature_hash());
+        assert_ne!(sig.recover_signer(tx.signature_hash()), Some(signer));
+    }
+
     #[test]
     fn test_envelop_encode() {
         // random tx: <https://etherscan.io/getRawTx?tx=0x9448608d36e721ef403c53b00546068a6474d6cbab6816c3926de449898e7bce>
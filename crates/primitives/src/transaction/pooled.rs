@@ -0,0 +1,399 @@
+//! Transactions as they're gossiped between peers and held in the pool.
+use crate::{
+    transaction::{BlobTransactionSidecar, TransactionSigned, EIP4844_TX_TYPE_ID},
+    Bytes, Transaction, TxHash,
+};
+use bytes::Buf;
+use reth_rlp::{length_of_length, Decodable, DecodeError, Encodable, Header, EMPTY_LIST_CODE};
+
+/// A blob transaction together with its [BlobTransactionSidecar].
+///
+/// This is the network form of an [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844#networking)
+/// transaction: `type || rlp([tx_payload_body, blobs, commitments, proofs])`.
+///
+/// The sidecar is not covered by the transaction hash, which is computed over the consensus
+/// encoding `type || rlp(tx_payload_body)` only.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlobTransaction {
+    /// The signed blob transaction.
+    pub transaction: TransactionSigned,
+    /// The blobs, commitments and proofs of the transaction.
+    pub sidecar: BlobTransactionSidecar,
+}
+
+impl BlobTransaction {
+    /// Returns the hash of the transaction.
+    pub fn hash(&self) -> TxHash {
+        self.transaction.hash
+    }
+
+    /// Length of `rlp(tx_payload_body)`, the signed transaction fields as a list.
+    fn tx_list_len(&self) -> usize {
+        let payload_length =
+            self.transaction.transaction.fields_len() + self.transaction.signature.payload_len();
+        length_of_length(payload_length) + payload_length
+    }
+
+    /// Length of the payload of the outer list: the transaction followed by the sidecar fields.
+    fn payload_len(&self) -> usize {
+        self.tx_list_len() +
+            self.sidecar.blobs.length() +
+            self.sidecar.commitments.length() +
+            self.sidecar.proofs.length()
+    }
+
+    /// Length of the network encoding, `type || rlp([tx_payload_body, blobs, commitments,
+    /// proofs])`.
+    fn enveloped_len(&self) -> usize {
+        let payload_length = self.payload_len();
+        1 + length_of_length(payload_length) + payload_length
+    }
+
+    /// Encodes the transaction into its network form:
+    /// `type || rlp([tx_payload_body, blobs, commitments, proofs])`
+    pub fn encode_enveloped(&self, out: &mut dyn bytes::BufMut) {
+        out.put_u8(EIP4844_TX_TYPE_ID);
+        Header { list: true, payload_length: self.payload_len() }.encode(out);
+
+        let tx_payload_length =
+            self.transaction.transaction.fields_len() + self.transaction.signature.payload_len();
+        Header { list: true, payload_length: tx_payload_length }.encode(out);
+        self.transaction.transaction.encode_fields(out);
+        self.transaction.signature.encode(out);
+
+        self.sidecar.blobs.encode(out);
+        self.sidecar.commitments.encode(out);
+        self.sidecar.proofs.encode(out);
+    }
+
+    /// Decodes the network form of a blob transaction.
+    ///
+    /// CAUTION: this expects that `data` is `type || rlp([tx_payload_body, blobs, commitments,
+    /// proofs])`
+    fn decode_enveloped(data: &mut &[u8]) -> Result<Self, DecodeError> {
+        let tx_type = *data.first().ok_or(DecodeError::InputTooShort)?;
+        if tx_type != EIP4844_TX_TYPE_ID {
+            return Err(DecodeError::Custom("expected a blob transaction"))
+        }
+        data.advance(1);
+
+        let header = Header::decode(data)?;
+        if !header.list {
+            return Err(DecodeError::Custom("blob tx must be encoded as a list"))
+        }
+        let remaining = data.len();
+
+        // the hash only commits to `type || rlp(tx_payload_body)`, so re-assemble that and decode
+        // the transaction from it
+        let tx_header = Header::decode(&mut &data[..])?;
+        if !tx_header.list {
+            return Err(DecodeError::Custom("blob tx fields must be encoded as a list"))
+        }
+        let tx_length = tx_header.length() + tx_header.payload_length;
+        if data.len() < tx_length {
+            return Err(DecodeError::InputTooShort)
+        }
+        let mut tx_encoding = Vec::with_capacity(1 + tx_length);
+        tx_encoding.push(EIP4844_TX_TYPE_ID);
+        tx_encoding.extend_from_slice(&data[..tx_length]);
+        let transaction =
+            TransactionSigned::decode_enveloped_typed_transaction(&mut &tx_encoding[..])?;
+        data.advance(tx_length);
+
+        let sidecar = BlobTransactionSidecar {
+            blobs: Decodable::decode(data)?,
+            commitments: Decodable::decode(data)?,
+            proofs: Decodable::decode(data)?,
+        };
+
+        if remaining - data.len() != header.payload_length {
+            return Err(DecodeError::ListLengthMismatch {
+                expected: header.payload_length,
+                got: remaining - data.len(),
+            })
+        }
+
+        Ok(Self { transaction, sidecar })
+    }
+}
+
+/// A transaction as it's sent in response to a `GetPooledTransactions` request.
+///
+/// Blob transactions are sent together with their [BlobTransactionSidecar], all other
+/// transactions are sent in their regular encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PooledTransactionsElement {
+    /// Any transaction that isn't a blob transaction.
+    Transaction(TransactionSigned),
+    /// A blob transaction with its sidecar.
+    BlobTransaction(BlobTransaction),
+}
+
+impl PooledTransactionsElement {
+    /// Returns the hash of the transaction.
+    pub fn hash(&self) -> TxHash {
+        match self {
+            PooledTransactionsElement::Transaction(tx) => tx.hash,
+            PooledTransactionsElement::BlobTransaction(tx) => tx.hash(),
+        }
+    }
+
+    /// Returns the signed transaction, dropping the sidecar of a blob transaction.
+    pub fn into_transaction(self) -> TransactionSigned {
+        match self {
+            PooledTransactionsElement::Transaction(tx) => tx,
+            PooledTransactionsElement::BlobTransaction(tx) => tx.transaction,
+        }
+    }
+
+    /// Encodes the transaction into the "raw" format (e.g. `eth_sendRawTransaction`).
+    ///
+    /// Blob transactions are encoded in their network form, see [BlobTransaction], everything
+    /// else like [TransactionSigned::encode_enveloped].
+    pub fn encode_enveloped(&self, out: &mut dyn bytes::BufMut) {
+        match self {
+            PooledTransactionsElement::Transaction(tx) => tx.encode_enveloped(out),
+            PooledTransactionsElement::BlobTransaction(tx) => tx.encode_enveloped(out),
+        }
+    }
+
+    /// Decodes the "raw" format of a pooled transaction (e.g. `eth_sendRawTransaction`).
+    ///
+    /// Blob transactions are expected in their network form, see [BlobTransaction].
+    pub fn decode_enveloped(tx: Bytes) -> Result<Self, DecodeError> {
+        let mut data = tx.as_ref();
+
+        if data.is_empty() {
+            return Err(DecodeError::InputTooShort)
+        }
+
+        Self::decode_enveloped_inner(&mut data)
+    }
+
+    /// Decodes either a legacy transaction or an enveloped typed transaction.
+    fn decode_enveloped_inner(data: &mut &[u8]) -> Result<Self, DecodeError> {
+        if data[0] >= EMPTY_LIST_CODE {
+            TransactionSigned::decode_rlp_legacy_transaction(data).map(Self::Transaction)
+        } else if data[0] == EIP4844_TX_TYPE_ID {
+            BlobTransaction::decode_enveloped(data).map(Self::BlobTransaction)
+        } else {
+            TransactionSigned::decode_enveloped_typed_transaction(data).map(Self::Transaction)
+        }
+    }
+}
+
+impl TryFrom<TransactionSigned> for PooledTransactionsElement {
+    type Error = TransactionSigned;
+
+    /// Wraps a transaction that doesn't need a sidecar.
+    ///
+    /// Returns the transaction back if it's a blob transaction, those can only be converted
+    /// together with their [BlobTransactionSidecar].
+    fn try_from(tx: TransactionSigned) -> Result<Self, Self::Error> {
+        match tx.transaction {
+            Transaction::Eip4844(_) => Err(tx),
+            _ => Ok(PooledTransactionsElement::Transaction(tx)),
+        }
+    }
+}
+
+impl From<BlobTransaction> for PooledTransactionsElement {
+    fn from(tx: BlobTransaction) -> Self {
+        PooledTransactionsElement::BlobTransaction(tx)
+    }
+}
+
+/// Encodes the element like [TransactionSigned] is encoded for p2p: legacy transactions as
+/// `rlp(tx)`, typed transactions as an rlp string of their enveloped encoding.
+impl Encodable for PooledTransactionsElement {
+    fn encode(&self, out: &mut dyn bytes::BufMut) {
+        match self {
+            PooledTransactionsElement::Transaction(tx) => tx.encode(out),
+            PooledTransactionsElement::BlobTransaction(tx) => {
+                Header { list: false, payload_length: tx.enveloped_len() }.encode(out);
+                tx.encode_enveloped(out);
+            }
+        }
+    }
+
+    fn length(&self) -> usize {
+        match self {
+            PooledTransactionsElement::Transaction(tx) => tx.length(),
+            PooledTransactionsElement::BlobTransaction(tx) => {
+                let len = tx.enveloped_len();
+                length_of_length(len) + len
+            }
+        }
+    }
+}
+
+/// CAUTION: this expects that the given buf contains rlp, see also [TransactionSigned]'s
+/// [Decodable] implementation.
+impl Decodable for PooledTransactionsElement {
+    fn decode(buf: &mut &[u8]) -> Result<Self, DecodeError> {
+        let mut original_encoding = *buf;
+        let header = Header::decode(buf)?;
+
+        if header.list {
+            let tx = TransactionSigned::decode_rlp_legacy_transaction(&mut original_encoding)?;
+            *buf = original_encoding;
+            return Ok(PooledTransactionsElement::Transaction(tx))
+        }
+
+        if buf.len() < header.payload_length {
+            return Err(DecodeError::InputTooShort)
+        }
+        let (mut payload, rest) = buf.split_at(header.payload_length);
+        if payload.is_empty() {
+            return Err(DecodeError::InputTooShort)
+        }
+        let element = Self::decode_enveloped_inner(&mut payload)?;
+        if !payload.is_empty() {
+            return Err(DecodeError::UnexpectedLength)
+        }
+        *buf = rest;
+        Ok(element)
+    }
+}
+
+#[cfg(any(test, feature = "arbitrary"))]
+impl proptest::arbitrary::Arbitrary for PooledTransactionsElement {
+    type Parameters = ();
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::prelude::{any, Strategy};
+
+        any::<TransactionSigned>().prop_map(Self::from_arbitrary_transaction).boxed()
+    }
+
+    type Strategy = proptest::strategy::BoxedStrategy<PooledTransactionsElement>;
+}
+
+#[cfg(any(test, feature = "arbitrary"))]
+impl<'a> arbitrary::Arbitrary<'a> for PooledTransactionsElement {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_arbitrary_transaction(
+            <TransactionSigned as arbitrary::Arbitrary>::arbitrary(u)?,
+        ))
+    }
+}
+
+#[cfg(any(test, feature = "arbitrary"))]
+impl PooledTransactionsElement {
+    /// Wraps the transaction, blob transactions get an empty sidecar.
+    fn from_arbitrary_transaction(tx: TransactionSigned) -> Self {
+        Self::try_from(tx).unwrap_or_else(|transaction| {
+            Self::BlobTransaction(BlobTransaction {
+                transaction,
+                sidecar: BlobTransactionSidecar {
+                    blobs: vec![],
+                    commitments: vec![],
+                    proofs: vec![],
+                },
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        keccak256, sign_message, transaction::BYTES_PER_BLOB, AccessList, Address, TransactionKind,
+        TxEip1559, TxEip4844, H256,
+    };
+
+    fn signed(transaction: Transaction) -> TransactionSigned {
+        let signature =
+            sign_message(H256::from_low_u64_be(1), transaction.signature_hash()).unwrap();
+        TransactionSigned::from_transaction_and_signature(transaction, signature)
+    }
+
+    fn blob_transaction() -> BlobTransaction {
+        let transaction = signed(Transaction::Eip4844(TxEip4844 {
+            chain_id: 1,
+            nonce: 7,
+            gas_limit: 21_000,
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 10,
+            to: TransactionKind::Call(Address::from_low_u64_be(4)),
+            value: 5,
+            access_list: AccessList::default(),
+            max_fee_per_blob_gas: 20,
+            blob_versioned_hashes: vec![H256::from_low_u64_be(2)],
+            input: Bytes::from(vec![1, 2, 3]),
+        }));
+        let mut blob = [0u8; BYTES_PER_BLOB];
+        blob[0] = 1;
+        BlobTransaction {
+            transaction,
+            sidecar: BlobTransactionSidecar {
+                blobs: vec![blob],
+                commitments: vec![[2u8; 48]],
+                proofs: vec![[3u8; 48]],
+            },
+        }
+    }
+
+    #[test]
+    fn blob_transaction_roundtrip() {
+        let element = PooledTransactionsElement::from(blob_transaction());
+
+        let mut raw = Vec::new();
+        element.encode_enveloped(&mut raw);
+        assert_eq!(raw[0], EIP4844_TX_TYPE_ID);
+        assert_eq!(PooledTransactionsElement::decode_enveloped(raw.into()).unwrap(), element);
+
+        let mut encoded = Vec::new();
+        element.encode(&mut encoded);
+        assert_eq!(encoded.len(), element.length());
+        let mut buf = &encoded[..];
+        assert_eq!(PooledTransactionsElement::decode(&mut buf).unwrap(), element);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn blob_transaction_hash_excludes_sidecar() {
+        let blob_tx = blob_transaction();
+        let expected = keccak256(blob_tx.transaction.envelope_encoded());
+
+        let mut raw = Vec::new();
+        blob_tx.encode_enveloped(&mut raw);
+        let decoded = PooledTransactionsElement::decode_enveloped(raw.into()).unwrap();
+        assert_eq!(decoded.hash(), expected);
+
+        let mut other = blob_tx.clone();
+        other.sidecar.proofs = vec![[4u8; 48]];
+        let mut raw = Vec::new();
+        other.encode_enveloped(&mut raw);
+        let decoded = PooledTransactionsElement::decode_enveloped(raw.into()).unwrap();
+        assert_eq!(decoded.hash(), expected);
+        assert_eq!(decoded.into_transaction(), blob_tx.transaction);
+    }
+
+    #[test]
+    fn regular_transaction_roundtrip() {
+        let tx = signed(Transaction::Eip1559(TxEip1559 {
+            chain_id: 1,
+            nonce: 1,
+            gas_limit: 21_000,
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 10,
+            to: TransactionKind::Call(Address::from_low_u64_be(4)),
+            value: 5,
+            input: Bytes::default(),
+            access_list: AccessList::default(),
+        }));
+        let element = PooledTransactionsElement::try_from(tx.clone()).unwrap();
+
+        let mut encoded = Vec::new();
+        element.encode(&mut encoded);
+        let mut expected = Vec::new();
+        tx.encode(&mut expected);
+        assert_eq!(encoded, expected);
+        assert_eq!(PooledTransactionsElement::decode(&mut &encoded[..]).unwrap(), element);
+
+        // blob transactions can't be sent without their sidecar
+        let blob_tx = blob_transaction().transaction;
+        assert_eq!(PooledTransactionsElement::try_from(blob_tx.clone()), Err(blob_tx));
+    }
+}
@@ -0,0 +1,61 @@
+use reth_rlp::{RlpDecodable, RlpEncodable};
+
+/// Size of a single blob in bytes, see [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844#parameters).
+pub const BYTES_PER_BLOB: usize = 131_072;
+
+/// A single blob of data.
+pub type Blob = [u8; BYTES_PER_BLOB];
+
+/// A KZG commitment to a [Blob].
+pub type KzgCommitment = [u8; 48];
+
+/// A KZG proof for a [Blob] and its [KzgCommitment].
+pub type KzgProof = [u8; 48];
+
+/// The blobs, commitments and proofs that accompany a blob transaction while it is gossiped and
+/// held in the pool.
+///
+/// The sidecar is not part of the consensus encoding of the transaction and never ends up in a
+/// block body.
+#[derive(Debug, Clone, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+pub struct BlobTransactionSidecar {
+    /// The blob data.
+    pub blobs: Vec<Blob>,
+    /// The blob commitments.
+    pub commitments: Vec<KzgCommitment>,
+    /// The blob proofs.
+    pub proofs: Vec<KzgProof>,
+}
+
+impl BlobTransactionSidecar {
+    /// Returns `true` if there's one commitment and one proof for every blob.
+    pub fn is_well_formed(&self) -> bool {
+        self.blobs.len() == self.commitments.len() && self.blobs.len() == self.proofs.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_rlp::{Decodable, Encodable};
+
+    #[test]
+    fn sidecar_rlp_roundtrip() {
+        let mut blob = [0u8; BYTES_PER_BLOB];
+        blob[0] = 1;
+        blob[BYTES_PER_BLOB - 1] = 2;
+        let sidecar = BlobTransactionSidecar {
+            blobs: vec![blob],
+            commitments: vec![[3u8; 48]],
+            proofs: vec![[4u8; 48]],
+        };
+        assert!(sidecar.is_well_formed());
+
+        let mut encoded = Vec::new();
+        sidecar.encode(&mut encoded);
+        assert_eq!(encoded.len(), sidecar.length());
+
+        let decoded = BlobTransactionSidecar::decode(&mut &encoded[..]).unwrap();
+        assert_eq!(decoded, sidecar);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{transaction::util::secp256k1, Address, H256, U256};
+use crate::{transaction::util::secp256k1, Address, ChainId, H256, U256};
 use bytes::Buf;
 use reth_codecs::{derive_arbitrary, Compact};
 use reth_rlp::{Decodable, DecodeError, Encodable};
@@ -69,6 +69,17 @@ impl Signature {
         }
     }
 
+    /// Returns the chain id of a legacy signature with the given `v` value, the inverse of
+    /// [Self::v].
+    ///
+    /// Returns `None` for pre-[EIP-155](https://github.com/ethereum/EIPs/blob/master/EIPS/eip-155.md)
+    /// signatures, i.e. if `v` is 27 or 28, and for invalid `v` values.
+    ///
+    /// Note: the signature itself only stores the y parity, the chain id is only encoded in `v`.
+    pub fn chain_id(v: u64) -> Option<ChainId> {
+        extract_chain_id(v).ok().and_then(|(_, chain_id)| chain_id)
+    }
+
     /// Decodes the `v`, `r`, `s` values without a RLP header.
     /// This will return a chain ID if the `v` value is [EIP-155](https://github.com/ethereum/EIPs/blob/master/EIPS/eip-155.md) compatible.
     pub(crate) fn decode_with_eip155_chain_id(
@@ -77,19 +88,8 @@ impl Signature {
         let v = u64::decode(buf)?;
         let r = Decodable::decode(buf)?;
         let s = Decodable::decode(buf)?;
-        if v >= 35 {
-            // EIP-155: v = {0, 1} + CHAIN_ID * 2 + 35
-            let odd_y_parity = ((v - 35) % 2) != 0;
-            let chain_id = (v - 35) >> 1;
-            Ok((Signature { r, s, odd_y_parity }, Some(chain_id)))
-        } else {
-            // non-EIP-155 legacy scheme, v = 27 for even y-parity, v = 28 for odd y-parity
-            if v != 27 && v != 28 {
-                return Err(DecodeError::Custom("invalid Ethereum signature (V is not 27 or 28)"))
-            }
-            let odd_y_parity = v == 28;
-            Ok((Signature { r, s, odd_y_parity }, None))
-        }
+        let (odd_y_parity, chain_id) = extract_chain_id(v)?;
+        Ok((Signature { r, s, odd_y_parity }, chain_id))
     }
 
     /// Output the length of the signature without the length of the RLP header
@@ -138,6 +138,24 @@ impl Signature {
     }
 }
 
+/// Extracts the y parity and the chain id from the `v` value of a legacy signature.
+///
+/// Returns `None` as chain id if the `v` value is not [EIP-155](https://github.com/ethereum/EIPs/blob/master/EIPS/eip-155.md) encoded, i.e. it is 27 or 28.
+pub(crate) fn extract_chain_id(v: u64) -> Result<(bool, Option<ChainId>), DecodeError> {
+    if v >= 35 {
+        // EIP-155: v = {0, 1} + CHAIN_ID * 2 + 35
+        let odd_y_parity = ((v - 35) % 2) != 0;
+        let chain_id = (v - 35) >> 1;
+        Ok((odd_y_parity, Some(chain_id)))
+    } else {
+        // non-EIP-155 legacy scheme, v = 27 for even y-parity, v = 28 for odd y-parity
+        if v != 27 && v != 28 {
+            return Err(DecodeError::Custom("invalid Ethereum signature (V is not 27 or 28)"))
+        }
+        Ok((v == 28, None))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{Address, Signature, H256, U256};
@@ -164,6 +182,28 @@ mod tests {
         assert_eq!(38, signature.v(Some(1)));
     }
 
+    #[test]
+    fn test_chain_id() {
+        // pre-EIP-155
+        assert_eq!(Signature::chain_id(27), None);
+        assert_eq!(Signature::chain_id(28), None);
+
+        // EIP-155 mainnet and BSC
+        assert_eq!(Signature::chain_id(37), Some(1));
+        assert_eq!(Signature::chain_id(38), Some(1));
+        assert_eq!(Signature::chain_id(147), Some(56));
+        assert_eq!(Signature::chain_id(148), Some(56));
+
+        // invalid
+        assert_eq!(Signature::chain_id(29), None);
+
+        for odd_y_parity in [false, true] {
+            let signature = Signature { r: U256::default(), s: U256::default(), odd_y_parity };
+            assert_eq!(Signature::chain_id(signature.v(Some(97))), Some(97));
+            assert_eq!(Signature::chain_id(signature.v(None)), None);
+        }
+    }
+
     #[test]
     fn test_encode_and_decode_with_eip155_chain_id() {
         let signature = Signature { r: U256::default(), s: U256::default(), odd_y_parity: false };
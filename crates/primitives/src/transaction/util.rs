@@ -2,7 +2,7 @@ use crate::{keccak256, Address};
 
 pub(crate) mod secp256k1 {
     use super::*;
-    use crate::Signature;
+    use crate::{transaction::signature::extract_chain_id, ChainId, Signature};
     pub(crate) use ::secp256k1::Error;
     use ::secp256k1::{
         ecdsa::{RecoverableSignature, RecoveryId},
@@ -25,6 +25,25 @@ pub(crate) mod secp256k1 {
         Ok(Address::from_slice(&hash[12..]))
     }
 
+    /// Recovers the address of the sender of a legacy signature, given as `r`, `s` and its raw
+    /// `v` value, and the chain id the signature is bound to.
+    ///
+    /// The chain id is `None` for pre-[EIP-155](https://github.com/ethereum/EIPs/blob/master/EIPS/eip-155.md)
+    /// signatures, i.e. if `v` is 27 or 28.
+    pub fn recover_signer_with_chain_id(
+        sig: &[u8; 64],
+        v: u64,
+        msg: &[u8; 32],
+    ) -> Result<(Address, Option<ChainId>), Error> {
+        let (odd_y_parity, chain_id) = extract_chain_id(v).map_err(|_| Error::InvalidRecoveryId)?;
+
+        let mut recoverable_sig = [0u8; 65];
+        recoverable_sig[..64].copy_from_slice(sig);
+        recoverable_sig[64] = odd_y_parity as u8;
+
+        Ok((recover_signer(&recoverable_sig, msg)?, chain_id))
+    }
+
     /// Signs message with the given secret key.
     /// Returns the corresponding signature.
     pub fn sign_message(secret: B256, message: B256) -> Result<Signature, secp256k1::Error> {
@@ -44,7 +63,7 @@ pub(crate) mod secp256k1 {
 mod tests {
 
     use super::secp256k1;
-    use crate::{hex_literal::hex, Address};
+    use crate::{hex_literal::hex, Address, Transaction, TransactionKind, TxLegacy, H256};
 
     #[test]
     fn sanity_ecrecover_call() {
@@ -54,4 +73,46 @@ mod tests {
 
         assert_eq!(secp256k1::recover_signer(&sig, &hash), Ok(out));
     }
+
+    #[test]
+    fn recover_signer_with_eip155_chain_id() {
+        // mainnet transaction 0xbb3a336e3f823ec18197f1e13ee875700f08f03e2cab75f0d0b118dabb44cba0
+        let tx = Transaction::Legacy(TxLegacy {
+            chain_id: Some(1),
+            nonce: 0x18,
+            gas_price: 0xfa56ea00,
+            gas_limit: 119902,
+            to: TransactionKind::Call(hex!("06012c8cf97bead5deae237070f9587f8e7a266d").into()),
+            value: 0x1c6bf526340000u64.into(),
+            input: hex!("f7d8c88300000000000000000000000000000000000000000000000000000000000cee6100000000000000000000000000000000000000000000000000000000000ac3e1").into(),
+        });
+        let sig = hex!("2a378831cf81d99a3f06a18ae1b6ca366817ab4d88a70053c41d7a8f0368e031450d831a05b6e418724436c05c155e0a1b7b921015d0fbc2f667aed709ac4fb5");
+        let signer: Address = hex!("398137383b3d25c92898c656696e41950e47316b").into();
+
+        let hash = tx.signature_hash();
+        assert_eq!(
+            secp256k1::recover_signer_with_chain_id(&sig, 37, hash.as_fixed_bytes()),
+            Ok((signer, Some(1)))
+        );
+    }
+
+    #[test]
+    fn recover_signer_without_eip155_chain_id() {
+        let secret = H256::from_low_u64_be(1337);
+        let hash = H256::from_low_u64_be(42);
+        let signature = secp256k1::sign_message(secret, hash).unwrap();
+        let signer = signature.recover_signer(hash).unwrap();
+
+        let mut sig = [0u8; 64];
+        sig[..32].copy_from_slice(&signature.r.to_be_bytes::<32>());
+        sig[32..].copy_from_slice(&signature.s.to_be_bytes::<32>());
+        let v = signature.v(None);
+        assert_eq!(
+            secp256k1::recover_signer_with_chain_id(&sig, v, hash.as_fixed_bytes()),
+            Ok((signer, None))
+        );
+
+        // `v` must either be 27, 28 or EIP-155 encoded
+        assert!(secp256k1::recover_signer_with_chain_id(&sig, 29, hash.as_fixed_bytes()).is_err());
+    }
 }
@@ -19,20 +19,24 @@ pub struct AccessListInspector {
 impl AccessListInspector {
     /// Creates a new inspector instance
     ///
-    /// The `access_list` is the provided access list from the call request
+    /// The `access_list` is the provided access list from the call request, excluded addresses
+    /// without storage keys are dropped from it.
     pub fn new(
         access_list: AccessList,
         from: Address,
         to: Address,
         precompiles: Vec<Address>,
     ) -> Self {
+        let excluded: HashSet<Address> =
+            vec![from, to].iter().chain(precompiles.iter()).copied().collect();
         AccessListInspector {
-            excluded: vec![from, to].iter().chain(precompiles.iter()).copied().collect(),
             access_list: access_list
                 .0
                 .iter()
+                .filter(|v| !v.storage_keys.is_empty() || !excluded.contains(&v.address))
                 .map(|v| (v.address, v.storage_keys.iter().copied().collect()))
                 .collect(),
+            excluded,
         }
     }
 
@@ -97,6 +97,7 @@ impl GethTraceBuilder {
             gas: receipt_gas_used,
             return_value: main_trace.output.clone().into(),
             struct_logs,
+            truncated: false,
         }
     }
 
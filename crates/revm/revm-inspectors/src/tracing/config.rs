@@ -16,6 +16,10 @@ pub struct TracingInspectorConfig {
     pub record_state_diff: bool,
     /// Whether to ignore precompile calls.
     pub exclude_precompile_calls: bool,
+    /// The maximum number of opcode level steps to record, if any.
+    ///
+    /// Steps beyond the limit are not recorded and the inspector is marked as truncated.
+    pub max_steps: Option<usize>,
 }
 
 impl TracingInspectorConfig {
@@ -27,6 +31,7 @@ impl TracingInspectorConfig {
             record_stack_snapshots: true,
             record_state_diff: false,
             exclude_precompile_calls: false,
+            max_steps: None,
         }
     }
 
@@ -40,6 +45,7 @@ impl TracingInspectorConfig {
             record_stack_snapshots: false,
             record_state_diff: false,
             exclude_precompile_calls: true,
+            max_steps: None,
         }
     }
 
@@ -53,15 +59,16 @@ impl TracingInspectorConfig {
             record_stack_snapshots: true,
             record_state_diff: true,
             exclude_precompile_calls: false,
+            max_steps: None,
         }
     }
 
     /// Returns a config for geth style traces based on the given [GethDefaultTracingOptions].
     pub fn from_geth_config(config: &GethDefaultTracingOptions) -> Self {
         Self {
-            record_memory_snapshots: config.enable_memory.unwrap_or_default(),
-            record_stack_snapshots: !config.disable_stack.unwrap_or_default(),
-            record_state_diff: !config.disable_storage.unwrap_or_default(),
+            record_memory_snapshots: config.is_memory_enabled(),
+            record_stack_snapshots: config.is_stack_enabled(),
+            record_state_diff: config.is_storage_enabled(),
             ..Self::default_geth()
         }
     }
@@ -97,4 +104,37 @@ impl TracingInspectorConfig {
         self.record_state_diff = record_state_diff;
         self
     }
+
+    /// Configure the maximum number of opcode level steps the tracer should record
+    pub fn set_max_steps(mut self, max_steps: Option<usize>) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn geth_config_memory_capture() {
+        // memory capture is disabled by default
+        let config = TracingInspectorConfig::from_geth_config(&Default::default());
+        assert!(!config.record_memory_snapshots);
+        assert!(config.record_stack_snapshots);
+
+        let opts = GethDefaultTracingOptions { disable_memory: Some(false), ..Default::default() };
+        assert!(TracingInspectorConfig::from_geth_config(&opts).record_memory_snapshots);
+
+        // `enableMemory` takes precedence
+        let opts = GethDefaultTracingOptions {
+            enable_memory: Some(false),
+            disable_memory: Some(false),
+            ..Default::default()
+        };
+        assert!(!TracingInspectorConfig::from_geth_config(&opts).record_memory_snapshots);
+
+        let opts = GethDefaultTracingOptions { disable_stack: Some(true), ..Default::default() };
+        assert!(!TracingInspectorConfig::from_geth_config(&opts).record_stack_snapshots);
+    }
 }
@@ -55,6 +55,10 @@ pub struct TracingInspector {
     last_call_return_data: Option<Bytes>,
     /// The gas inspector used to track remaining gas.
     gas_inspector: GasInspector,
+    /// The number of recorded steps
+    recorded_steps: usize,
+    /// Whether steps were dropped because [TracingInspectorConfig::max_steps] was reached
+    steps_truncated: bool,
 }
 
 // === impl TracingInspector ===
@@ -69,9 +73,22 @@ impl TracingInspector {
             step_stack: vec![],
             last_call_return_data: None,
             gas_inspector: Default::default(),
+            recorded_steps: 0,
+            steps_truncated: false,
         }
     }
 
+    /// Returns the number of opcode level steps that were recorded.
+    pub fn recorded_steps(&self) -> usize {
+        self.recorded_steps
+    }
+
+    /// Returns `true` if steps were not recorded because the configured
+    /// [TracingInspectorConfig::max_steps] was reached.
+    pub fn is_truncated(&self) -> bool {
+        self.steps_truncated
+    }
+
     /// Consumes the Inspector and returns a [ParityTraceBuilder].
     pub fn into_parity_builder(self) -> ParityTraceBuilder {
         ParityTraceBuilder::new(self.traces.arena, self.config)
@@ -196,6 +213,12 @@ impl TracingInspector {
     /// This expects an existing [CallTrace], in other words, this panics if not within the context
     /// of a call.
     fn start_step<DB: Database>(&mut self, interp: &mut Interpreter, data: &mut EVMData<'_, DB>) {
+        if self.config.max_steps.map_or(false, |max_steps| self.recorded_steps >= max_steps) {
+            self.steps_truncated = true;
+            return
+        }
+        self.recorded_steps += 1;
+
         let trace_idx = self.last_trace_idx();
         let trace = &mut self.traces.arena[trace_idx];
 
@@ -227,6 +250,16 @@ impl TracingInspector {
         });
     }
 
+    /// Returns `true` if the step that ends at the given depth was recorded.
+    ///
+    /// Once steps are truncated, the only recorded steps that are still pending are the calls of
+    /// the parent frames, which end at a lower depth than any step of their child frames.
+    fn is_step_recorded(&self, depth: u64) -> bool {
+        self.step_stack.last().map_or(false, |StackStep { trace_idx, step_idx }| {
+            self.traces.arena[*trace_idx].trace.steps[*step_idx].depth == depth
+        })
+    }
+
     /// Fills the current trace with the output of a step.
     ///
     /// Invoked on [Inspector::step_end].
@@ -236,6 +269,10 @@ impl TracingInspector {
         data: &mut EVMData<'_, DB>,
         status: InstructionResult,
     ) {
+        if self.steps_truncated && !self.is_step_recorded(data.journaled_state.depth()) {
+            return
+        }
+
         let StackStep { trace_idx, step_idx } =
             self.step_stack.pop().expect("can't fill step without starting a step first");
         let step = &mut self.traces.arena[trace_idx].trace.steps[step_idx];
@@ -10,7 +10,9 @@ pub fn revm_spec_by_timestamp_after_merge(
     chain_spec: &ChainSpec,
     timestamp: u64,
 ) -> revm::primitives::SpecId {
-    if chain_spec.is_fork_active_at_timestamp(Hardfork::Shanghai, timestamp) {
+    if chain_spec.is_cancun_activated_at_timestamp(timestamp) {
+        revm::primitives::CANCUN
+    } else if chain_spec.is_shanghai_activated_at_timestamp(timestamp) {
         revm::primitives::SHANGHAI
     } else {
         revm::primitives::MERGE
@@ -19,7 +21,9 @@ pub fn revm_spec_by_timestamp_after_merge(
 
 /// return revm_spec from spec configuration.
 pub fn revm_spec(chain_spec: &ChainSpec, block: Head) -> revm::primitives::SpecId {
-    if chain_spec.fork(Hardfork::Shanghai).active_at_head(&block) {
+    if chain_spec.fork(Hardfork::Cancun).active_at_head(&block) {
+        revm::primitives::CANCUN
+    } else if chain_spec.fork(Hardfork::Shanghai).active_at_head(&block) {
         revm::primitives::SHANGHAI
     } else if chain_spec.fork(Hardfork::Paris).active_at_head(&block) {
         revm::primitives::MERGE
@@ -48,10 +52,18 @@ pub fn revm_spec(chain_spec: &ChainSpec, block: Head) -> revm::primitives::SpecI
 
 #[cfg(test)]
 mod tests {
-    use crate::config::revm_spec;
-    use reth_primitives::{ChainSpecBuilder, Head, MAINNET, U256};
+    use crate::config::{revm_spec, revm_spec_by_timestamp_after_merge};
+    use reth_primitives::{ChainSpecBuilder, ForkCondition, Hardfork, Head, MAINNET, U256};
     #[test]
     fn test_to_revm_spec() {
+        assert_eq!(
+            revm_spec(&ChainSpecBuilder::mainnet().cancun_activated().build(), Head::default()),
+            revm::primitives::CANCUN
+        );
+        assert_eq!(
+            revm_spec(&ChainSpecBuilder::mainnet().shanghai_activated().build(), Head::default()),
+            revm::primitives::SHANGHAI
+        );
         assert_eq!(
             revm_spec(&ChainSpecBuilder::mainnet().paris_activated().build(), Head::default()),
             revm::primitives::MERGE
@@ -102,6 +114,18 @@ mod tests {
 
     #[test]
     fn test_eth_spec() {
+        assert_eq!(
+            revm_spec(
+                &MAINNET,
+                Head {
+                    timestamp: 1710338135,
+                    total_difficulty: U256::from(58_750_000_000_000_000_000_010_u128),
+                    difficulty: U256::from(10_u128),
+                    ..Default::default()
+                }
+            ),
+            revm::primitives::SHANGHAI
+        );
         assert_eq!(
             revm_spec(
                 &MAINNET,
@@ -163,4 +187,23 @@ mod tests {
             revm::primitives::FRONTIER
         );
     }
+
+    #[test]
+    fn test_eth_spec_by_timestamp_after_merge() {
+        let spec = ChainSpecBuilder::mainnet()
+            .with_fork(Hardfork::Cancun, ForkCondition::Timestamp(1710338135))
+            .build();
+        assert_eq!(revm_spec_by_timestamp_after_merge(&spec, 1710338135), revm::primitives::CANCUN);
+        assert_eq!(
+            revm_spec_by_timestamp_after_merge(&spec, 1710338134),
+            revm::primitives::SHANGHAI
+        );
+        assert_eq!(revm_spec_by_timestamp_after_merge(&spec, 1681338454), revm::primitives::MERGE);
+
+        // Cancun isn't scheduled on mainnet
+        assert_eq!(
+            revm_spec_by_timestamp_after_merge(&MAINNET, u64::MAX),
+            revm::primitives::SHANGHAI
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use crate::config::revm_spec;
 use reth_primitives::{
     recover_signer, Address, Bytes, Chain, ChainSpec, Head, Header, Transaction, TransactionKind,
-    TransactionSignedEcRecovered, TxEip1559, TxEip2930, TxLegacy, U256,
+    TransactionSignedEcRecovered, TxEip1559, TxEip2930, TxEip4844, TxLegacy, U256,
 };
 use revm::primitives::{AnalysisKind, BlockEnv, CfgEnv, SpecId, TransactTo, TxEnv};
 
@@ -186,6 +186,18 @@ where
             value,
             input,
             access_list,
+        }) |
+        Transaction::Eip4844(TxEip4844 {
+            nonce,
+            chain_id,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            to,
+            value,
+            input,
+            access_list,
+            ..
         }) => {
             tx_env.gas_limit = *gas_limit;
             tx_env.gas_price = U256::from(*max_fee_per_gas);
@@ -0,0 +1,45 @@
+//! BSC system contract related constants and helpers.
+//!
+//! At the end of every block the validator sends system transactions to these contracts, e.g. to
+//! distribute the block reward or to slash a validator that missed its turn.
+use reth_primitives::{
+    hex_literal::hex, Address, Chain, ChainSpec, Header, TransactionSigned, H160,
+};
+
+/// The contracts that receive BSC system transactions.
+pub static BSC_SYSTEM_CONTRACTS: [H160; 9] = [
+    // validator set
+    H160(hex!("0000000000000000000000000000000000001000")),
+    // slash
+    H160(hex!("0000000000000000000000000000000000001001")),
+    // system reward
+    H160(hex!("0000000000000000000000000000000000001002")),
+    // light client
+    H160(hex!("0000000000000000000000000000000000001003")),
+    // token hub
+    H160(hex!("0000000000000000000000000000000000001004")),
+    // relayer incentivize
+    H160(hex!("0000000000000000000000000000000000001005")),
+    // relayer hub
+    H160(hex!("0000000000000000000000000000000000001006")),
+    // gov hub
+    H160(hex!("0000000000000000000000000000000000001007")),
+    // cross chain
+    H160(hex!("0000000000000000000000000000000000002000")),
+];
+
+/// Returns `true` if the transaction is a BSC system transaction.
+///
+/// System transactions are sent by the block's validator, the coinbase, to one of the
+/// [BSC_SYSTEM_CONTRACTS] and don't pay for gas.
+pub fn is_system_transaction(
+    chain_spec: &ChainSpec,
+    header: &Header,
+    transaction: &TransactionSigned,
+    sender: Address,
+) -> bool {
+    chain_spec.chain == Chain::bsc() &&
+        sender == header.beneficiary &&
+        transaction.max_fee_per_gas() == 0 &&
+        transaction.to().map_or(false, |to| BSC_SYSTEM_CONTRACTS.contains(&to))
+}
@@ -1,4 +1,5 @@
 use crate::{
+    bsc_system_contracts::is_system_transaction,
     database::SubState,
     env::{fill_cfg_and_block_env, fill_tx_env},
     eth_dao_fork::{DAO_HARDFORK_BENEFICIARY, DAO_HARDKFORK_ACCOUNTS},
@@ -213,6 +214,10 @@ where
     /// so on).
     ///
     /// The second returned value represents the total gas used by this block of transactions.
+    ///
+    /// BSC system transactions are not limited by the block gas limit and don't consume the gas
+    /// available to the other transactions of the block, but their gas is still part of the total
+    /// gas used, same as in their receipts.
     pub fn execute_transactions(
         &mut self,
         block: &Block,
@@ -228,12 +233,17 @@ where
         self.init_env(&block.header, total_difficulty);
 
         let mut cumulative_gas_used = 0;
+        // gas used by all transactions except for system transactions
+        let mut block_gas_used = 0;
         let mut post_state = PostState::with_tx_capacity(block.number, block.body.len());
         for (transaction, sender) in block.body.iter().zip(senders.into_iter()) {
+            let is_system_tx =
+                is_system_transaction(&self.chain_spec, &block.header, transaction, sender);
+
             // The sum of the transaction’s gas limit, Tg, and the gas utilised in this block prior,
             // must be no greater than the block’s gasLimit.
-            let block_available_gas = block.header.gas_limit - cumulative_gas_used;
-            if transaction.gas_limit() > block_available_gas {
+            let block_available_gas = block.header.gas_limit - block_gas_used;
+            if !is_system_tx && transaction.gas_limit() > block_available_gas {
                 return Err(BlockValidationError::TransactionGasLimitMoreThanAvailableBlockGas {
                     transaction_gas_limit: transaction.gas_limit(),
                     block_available_gas,
@@ -241,6 +251,7 @@ where
                 .into())
             }
             // Execute transaction.
+            self.evm.env.cfg.disable_block_gas_limit = is_system_tx;
             let ResultAndState { result, state } = self.transact(transaction, sender)?;
 
             // commit changes
@@ -253,6 +264,9 @@ where
 
             // append gas used
             cumulative_gas_used += result.gas_used();
+            if !is_system_tx {
+                block_gas_used += result.gas_used();
+            }
 
             // Push transaction changeset and calculate header bloom filter for receipt.
             post_state.add_receipt(
@@ -645,12 +659,13 @@ pub fn insert_post_block_withdrawals_balance_increments(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::database::State;
+    use crate::{bsc_system_contracts::BSC_SYSTEM_CONTRACTS, database::State};
     use once_cell::sync::Lazy;
     use reth_consensus_common::calc;
     use reth_primitives::{
         constants::ETH_TO_WEI, hex_literal::hex, keccak256, Account, Address, BlockNumber,
-        Bytecode, Bytes, ChainSpecBuilder, ForkCondition, StorageKey, H256, MAINNET, U256,
+        Bytecode, Bytes, Chain, ChainSpecBuilder, ForkCondition, Signature, StorageKey,
+        Transaction, TransactionKind, TxLegacy, H256, MAINNET, U256,
     };
     use reth_provider::{
         post_state::{AccountChanges, Storage, StorageTransition, StorageWipe},
@@ -748,6 +763,14 @@ mod tests {
         ) -> reth_interfaces::Result<(Vec<Bytes>, H256, Vec<Vec<Bytes>>)> {
             todo!()
         }
+
+        fn account_range(
+            &self,
+            _start: H256,
+            _limit: usize,
+        ) -> reth_interfaces::Result<(Vec<(H256, Account)>, Vec<Bytes>)> {
+            Ok((vec![], vec![]))
+        }
     }
 
     #[test]
@@ -1333,4 +1356,55 @@ mod tests {
         assert_eq!(post_state_after_state_clear.accounts(), &BTreeMap::default());
         assert_eq!(post_state_after_state_clear.account_changes(), &AccountChanges::default());
     }
+
+    #[test]
+    fn bsc_system_transactions() {
+        let validator = Address::random();
+        // system transactions have a gas limit far above the block gas limit
+        let system_tx = |nonce| {
+            TransactionSigned::from_transaction_and_signature(
+                Transaction::Legacy(TxLegacy {
+                    nonce,
+                    gas_limit: u64::MAX / 2,
+                    to: TransactionKind::Call(BSC_SYSTEM_CONTRACTS[0]),
+                    ..Default::default()
+                }),
+                Signature::default(),
+            )
+        };
+        let block = Block {
+            header: Header {
+                beneficiary: validator,
+                gas_limit: 30_000_000,
+                gas_used: 2 * 21_000,
+                ..Default::default()
+            },
+            body: vec![system_tx(0), system_tx(1)],
+            ..Default::default()
+        };
+        let senders = Some(vec![validator; 2]);
+
+        let chain_spec =
+            Arc::new(ChainSpecBuilder::mainnet().chain(Chain::bsc()).berlin_activated().build());
+        let db = SubState::new(State::new(StateProviderTest::default()));
+        let mut executor = Executor::new(chain_spec, db);
+        let out = executor.execute(&block, U256::ZERO, senders.clone()).unwrap();
+
+        // the gas of system transactions is still part of the receipts
+        let receipts = out.receipts(block.number);
+        assert_eq!(receipts[0].cumulative_gas_used, 21_000);
+        assert_eq!(receipts[1].cumulative_gas_used, 2 * 21_000);
+        assert_eq!(out.accounts().get(&validator).unwrap().unwrap().nonce, 2);
+
+        // other chains don't have system transactions
+        let chain_spec = Arc::new(ChainSpecBuilder::mainnet().berlin_activated().build());
+        let db = SubState::new(State::new(StateProviderTest::default()));
+        let mut executor = Executor::new(chain_spec, db);
+        assert!(matches!(
+            executor.execute(&block, U256::ZERO, senders),
+            Err(BlockExecutionError::Validation(
+                BlockValidationError::TransactionGasLimitMoreThanAvailableBlockGas { .. }
+            ))
+        ));
+    }
 }
@@ -27,3 +27,6 @@ pub use revm;
 
 /// Etereum DAO hardfork state change data.
 pub mod eth_dao_fork;
+
+/// BSC system contracts and system transactions.
+pub mod bsc_system_contracts;
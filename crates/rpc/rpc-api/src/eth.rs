@@ -4,8 +4,9 @@ use reth_primitives::{
     H256, H64, U256, U64,
 };
 use reth_rpc_types::{
-    state::StateOverride, BlockOverrides, CallRequest, EIP1186AccountProofResponse, FeeHistory,
-    Index, RichBlock, SyncStatus, Transaction, TransactionReceipt, TransactionRequest, Work,
+    state::StateOverride, BlockOverrides, CallOptions, CallRequest, CallResponse,
+    EIP1186AccountProofResponse, FeeHistory, Index, RichBlock, SyncStatus, Transaction,
+    TransactionReceipt, TransactionRequest, Work,
 };
 
 /// Eth rpc interface: <https://ethereum.github.io/execution-apis/api-documentation/>
@@ -111,6 +112,11 @@ pub trait EthApi {
     #[method(name = "getTransactionReceipt")]
     async fn transaction_receipt(&self, hash: H256) -> RpcResult<Option<TransactionReceipt>>;
 
+    /// Returns the receipts of all transactions in the given block.
+    #[method(name = "getBlockReceipts")]
+    async fn block_receipts(&self, block_id: BlockId)
+        -> RpcResult<Option<Vec<TransactionReceipt>>>;
+
     /// Returns the balance of the account of given address.
     #[method(name = "getBalance")]
     async fn balance(&self, address: Address, block_number: Option<BlockId>) -> RpcResult<U256>;
@@ -137,6 +143,11 @@ pub trait EthApi {
     async fn get_code(&self, address: Address, block_number: Option<BlockId>) -> RpcResult<Bytes>;
 
     /// Executes a new message call immediately without creating a transaction on the block chain.
+    ///
+    /// If [CallOptions::with_access_list] is set, the accounts and storage slots touched by the
+    /// call are returned alongside the output, as `eth_createAccessList` would create them.
+    /// If [CallOptions::with_created_contract] is set, a call without `to` returns the address and
+    /// the deployed code of the created contract.
     #[method(name = "call")]
     async fn call(
         &self,
@@ -144,7 +155,8 @@ pub trait EthApi {
         block_number: Option<BlockId>,
         state_overrides: Option<StateOverride>,
         block_overrides: Option<Box<BlockOverrides>>,
-    ) -> RpcResult<Bytes>;
+        call_options: Option<CallOptions>,
+    ) -> RpcResult<CallResponse>;
 
     /// Generates an access list for a transaction.
     ///
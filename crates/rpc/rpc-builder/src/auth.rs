@@ -11,7 +11,8 @@ use jsonrpsee::{
 };
 use reth_network_api::{NetworkInfo, Peers};
 use reth_provider::{
-    BlockProviderIdExt, EvmEnvProvider, HeaderProvider, ReceiptProviderIdExt, StateProviderFactory,
+    BlockProviderIdExt, ChainSpecProvider, EvmEnvProvider, HeaderProvider, ReceiptProviderIdExt,
+    StateProviderFactory,
 };
 use reth_rpc::{
     eth::{cache::EthStateCache, gas_oracle::GasPriceOracle},
@@ -42,6 +43,7 @@ where
         + HeaderProvider
         + StateProviderFactory
         + EvmEnvProvider
+        + ChainSpecProvider
         + Clone
         + Unpin
         + 'static,
@@ -60,6 +62,7 @@ where
         network,
         eth_cache.clone(),
         gas_oracle,
+        0,
         Box::new(executor.clone()),
     );
     let eth_filter = EthFilter::new(
@@ -67,6 +70,7 @@ where
         pool,
         eth_cache.clone(),
         DEFAULT_MAX_LOGS_IN_RESPONSE,
+        0,
         Box::new(executor.clone()),
     );
     launch_with_eth_api(eth_api, eth_filter, engine_api, socket_addr, secret).await
@@ -2,8 +2,9 @@ use reth_rpc::{
     eth::{
         cache::{EthStateCache, EthStateCacheConfig},
         gas_oracle::GasPriceOracleConfig,
+        DEFAULT_MAX_SUBSCRIPTIONS, DEFAULT_MAX_SUBSCRIPTIONS_PER_CONNECTION,
     },
-    EthApi, EthFilter, EthPubSub,
+    EthApi, EthFilter, EthPubSub, StructLogLimits,
 };
 use serde::{Deserialize, Serialize};
 
@@ -37,6 +38,16 @@ pub struct EthConfig {
     pub max_tracing_requests: u32,
     /// Maximum number of logs that can be returned in a single response in `eth_getLogs` calls.
     pub max_logs_per_response: usize,
+    /// The maximum number of concurrently active subscriptions.
+    pub max_subscriptions: u32,
+    /// The maximum number of concurrently active subscriptions of a single connection.
+    pub max_subscriptions_per_connection: u32,
+    /// The number of blocks the `latest` block tag lags behind the tip of the chain.
+    pub latest_block_lag: u64,
+    /// The maximum number of struct logs captured by `debug` tracing of a single transaction.
+    pub max_trace_steps_per_call: Option<usize>,
+    /// The maximum number of struct logs captured by `debug` tracing of a whole block.
+    pub max_trace_steps_per_block: Option<usize>,
 }
 
 impl Default for EthConfig {
@@ -46,6 +57,11 @@ impl Default for EthConfig {
             gas_oracle: GasPriceOracleConfig::default(),
             max_tracing_requests: DEFAULT_MAX_TRACING_REQUESTS,
             max_logs_per_response: DEFAULT_MAX_LOGS_IN_RESPONSE,
+            max_subscriptions: DEFAULT_MAX_SUBSCRIPTIONS,
+            max_subscriptions_per_connection: DEFAULT_MAX_SUBSCRIPTIONS_PER_CONNECTION,
+            latest_block_lag: 0,
+            max_trace_steps_per_call: None,
+            max_trace_steps_per_block: None,
         }
     }
 }
@@ -74,4 +90,43 @@ impl EthConfig {
         self.max_logs_per_response = max_logs;
         self
     }
+
+    /// Configures the maximum number of concurrently active subscriptions
+    pub fn max_subscriptions(mut self, max_subscriptions: u32) -> Self {
+        self.max_subscriptions = max_subscriptions;
+        self
+    }
+
+    /// Configures the maximum number of concurrently active subscriptions per connection
+    pub fn max_subscriptions_per_connection(mut self, max_subscriptions: u32) -> Self {
+        self.max_subscriptions_per_connection = max_subscriptions;
+        self
+    }
+
+    /// Configures the number of blocks the `latest` block tag lags behind the tip of the chain
+    pub fn latest_block_lag(mut self, lag: u64) -> Self {
+        self.latest_block_lag = lag;
+        self
+    }
+
+    /// Configures the maximum number of struct logs captured by `debug` tracing of a single
+    /// transaction
+    pub fn max_trace_steps_per_call(mut self, max_steps: Option<usize>) -> Self {
+        self.max_trace_steps_per_call = max_steps;
+        self
+    }
+
+    /// Configures the maximum number of struct logs captured by `debug` tracing of a whole block
+    pub fn max_trace_steps_per_block(mut self, max_steps: Option<usize>) -> Self {
+        self.max_trace_steps_per_block = max_steps;
+        self
+    }
+
+    /// Returns the [StructLogLimits] for the `debug` namespace
+    pub(crate) fn struct_log_limits(&self) -> StructLogLimits {
+        StructLogLimits {
+            max_steps_per_call: self.max_trace_steps_per_call,
+            max_steps_per_block: self.max_trace_steps_per_block,
+        }
+    }
 }
@@ -25,13 +25,13 @@
 //!
 //! ```
 //! use reth_network_api::{NetworkInfo, Peers};
-//! use reth_provider::{BlockProviderIdExt, CanonStateSubscriptions, StateProviderFactory, EvmEnvProvider};
+//! use reth_provider::{BlockProviderIdExt, CanonStateSubscriptions, ChainSpecProvider, StateProviderFactory, EvmEnvProvider};
 //! use reth_rpc_builder::{RethRpcModule, RpcModuleBuilder, RpcServerConfig, ServerBuilder, TransportRpcModuleConfig};
 //! use reth_tasks::TokioTaskExecutor;
 //! use reth_transaction_pool::TransactionPool;
 //! pub async fn launch<Provider, Pool, Network, Events>(provider: Provider, pool: Pool, network: Network, events: Events)
 //! where
-//!     Provider: BlockProviderIdExt + StateProviderFactory + EvmEnvProvider + Clone + Unpin + 'static,
+//!     Provider: BlockProviderIdExt + StateProviderFactory + EvmEnvProvider + ChainSpecProvider + Clone + Unpin + 'static,
 //!     Pool: TransactionPool + Clone + 'static,
 //!     Network: NetworkInfo + Peers + Clone + 'static,
 //!     Events: CanonStateSubscriptions +  Clone + 'static,
@@ -58,7 +58,7 @@
 //! ```
 //! use tokio::try_join;
 //! use reth_network_api::{NetworkInfo, Peers};
-//! use reth_provider::{BlockProviderIdExt, CanonStateSubscriptions, StateProviderFactory, EvmEnvProvider};
+//! use reth_provider::{BlockProviderIdExt, CanonStateSubscriptions, ChainSpecProvider, StateProviderFactory, EvmEnvProvider};
 //! use reth_rpc::JwtSecret;
 //! use reth_rpc_builder::{RethRpcModule, RpcModuleBuilder, RpcServerConfig, TransportRpcModuleConfig};
 //! use reth_tasks::TokioTaskExecutor;
@@ -67,7 +67,7 @@
 //! use reth_rpc_builder::auth::AuthServerConfig;
 //! pub async fn launch<Provider, Pool, Network, Events, EngineApi>(provider: Provider, pool: Pool, network: Network, events: Events, engine_api: EngineApi)
 //! where
-//!     Provider: BlockProviderIdExt + StateProviderFactory + EvmEnvProvider + Clone + Unpin + 'static,
+//!     Provider: BlockProviderIdExt + StateProviderFactory + EvmEnvProvider + ChainSpecProvider + Clone + Unpin + 'static,
 //!     Pool: TransactionPool + Clone + 'static,
 //!     Network: NetworkInfo + Peers + Clone + 'static,
 //!     Events: CanonStateSubscriptions +  Clone + 'static,
@@ -83,7 +83,7 @@
 //!     let builder = RpcModuleBuilder::new(provider, pool, network, TokioTaskExecutor::default(), events);
 //!
 //!   // configure the server modules
-//!    let (modules, auth_module) = builder.build_with_auth_server(transports, engine_api);
+//!    let (modules, auth_module, _registry) = builder.build_with_auth_server(transports, engine_api);
 //!
 //!   // start the servers
 //!   let auth_config = AuthServerConfig::builder(JwtSecret::random()).build();
@@ -107,7 +107,7 @@ use jsonrpsee::{
 use reth_ipc::server::IpcServer;
 use reth_network_api::{NetworkInfo, Peers};
 use reth_provider::{
-    BlockProvider, BlockProviderIdExt, CanonStateSubscriptions, EvmEnvProvider,
+    BlockProvider, BlockProviderIdExt, CanonStateSubscriptions, ChainSpecProvider, EvmEnvProvider,
     StateProviderFactory,
 };
 use reth_rpc::{
@@ -116,7 +116,7 @@ use reth_rpc::{
         gas_oracle::GasPriceOracle,
     },
     AdminApi, DebugApi, EngineEthApi, EthApi, EthFilter, EthPubSub, EthSubscriptionIdProvider,
-    NetApi, RPCApi, TraceApi, TracingCallGuard, TxPoolApi, Web3Api,
+    NetApi, RPCApi, RateLimiter, TraceApi, TracingCallGuard, TxPoolApi, Web3Api,
 };
 use reth_rpc_api::{servers::*, EngineApiServer};
 use reth_tasks::TaskSpawner;
@@ -164,7 +164,13 @@ pub async fn launch<Provider, Pool, Network, Tasks, Events>(
     events: Events,
 ) -> Result<RpcServerHandle, RpcError>
 where
-    Provider: BlockProviderIdExt + StateProviderFactory + EvmEnvProvider + Clone + Unpin + 'static,
+    Provider: BlockProviderIdExt
+        + StateProviderFactory
+        + EvmEnvProvider
+        + ChainSpecProvider
+        + Clone
+        + Unpin
+        + 'static,
     Pool: TransactionPool + Clone + 'static,
     Network: NetworkInfo + Peers + Clone + 'static,
     Tasks: TaskSpawner + Clone + 'static,
@@ -263,7 +269,13 @@ impl<Provider, Pool, Network, Tasks, Events>
 impl<Provider, Pool, Network, Tasks, Events>
     RpcModuleBuilder<Provider, Pool, Network, Tasks, Events>
 where
-    Provider: BlockProviderIdExt + StateProviderFactory + EvmEnvProvider + Clone + Unpin + 'static,
+    Provider: BlockProviderIdExt
+        + StateProviderFactory
+        + EvmEnvProvider
+        + ChainSpecProvider
+        + Clone
+        + Unpin
+        + 'static,
     Pool: TransactionPool + Clone + 'static,
     Network: NetworkInfo + Peers + Clone + 'static,
     Tasks: TaskSpawner + Clone + 'static,
@@ -274,11 +286,18 @@ where
     ///
     /// This behaves exactly as [RpcModuleBuilder::build] for the [TransportRpcModules], but also
     /// configures the auth (engine api) server, which exposes a subset of the `eth_` namespace.
+    ///
+    /// Also returns the [RethModuleRegistry] the modules were created from, which gives access to
+    /// the configured handlers, e.g. [RethModuleRegistry::eth_api].
     pub fn build_with_auth_server<EngineApi>(
         self,
         module_config: TransportRpcModuleConfig,
         engine: EngineApi,
-    ) -> (TransportRpcModules<()>, AuthRpcModule)
+    ) -> (
+        TransportRpcModules<()>,
+        AuthRpcModule,
+        RethModuleRegistry<Provider, Pool, Network, Tasks, Events>,
+    )
     where
         EngineApi: EngineApiServer,
     {
@@ -304,7 +323,7 @@ where
 
         let auth_module = registry.create_auth_module(engine);
 
-        (modules, auth_module)
+        (modules, auth_module, registry)
     }
 
     /// Configures all [RpcModule]s specific to the given [TransportRpcModuleConfig] which can be
@@ -480,8 +499,13 @@ impl RpcModuleSelection {
         config: RpcModuleConfig,
     ) -> RpcModule<()>
     where
-        Provider:
-            BlockProviderIdExt + StateProviderFactory + EvmEnvProvider + Clone + Unpin + 'static,
+        Provider: BlockProviderIdExt
+            + StateProviderFactory
+            + EvmEnvProvider
+            + ChainSpecProvider
+            + Clone
+            + Unpin
+            + 'static,
         Pool: TransactionPool + Clone + 'static,
         Network: NetworkInfo + Peers + Clone + 'static,
         Tasks: TaskSpawner + Clone + 'static,
@@ -687,7 +711,13 @@ where
 impl<Provider, Pool, Network, Tasks, Events>
     RethModuleRegistry<Provider, Pool, Network, Tasks, Events>
 where
-    Provider: BlockProviderIdExt + StateProviderFactory + EvmEnvProvider + Clone + Unpin + 'static,
+    Provider: BlockProviderIdExt
+        + StateProviderFactory
+        + EvmEnvProvider
+        + ChainSpecProvider
+        + Clone
+        + Unpin
+        + 'static,
     Pool: TransactionPool + Clone + 'static,
     Network: NetworkInfo + Peers + Clone + 'static,
     Tasks: TaskSpawner + Clone + 'static,
@@ -705,11 +735,12 @@ where
         let eth_api = self.eth_api();
         self.modules.insert(
             RethRpcModule::Debug,
-            DebugApi::new(
+            DebugApi::with_struct_log_limits(
                 self.provider.clone(),
                 eth_api,
                 Box::new(self.executor.clone()),
                 self.tracing_call_guard.clone(),
+                self.config.eth.struct_log_limits(),
             )
             .into_rpc()
             .into(),
@@ -808,11 +839,12 @@ where
                         RethRpcModule::Admin => {
                             AdminApi::new(self.network.clone()).into_rpc().into()
                         }
-                        RethRpcModule::Debug => DebugApi::new(
+                        RethRpcModule::Debug => DebugApi::with_struct_log_limits(
                             self.provider.clone(),
                             eth_api.clone(),
                             Box::new(self.executor.clone()),
                             self.tracing_call_guard.clone(),
+                            self.config.eth.struct_log_limits(),
                         )
                         .into_rpc()
                         .into(),
@@ -862,6 +894,14 @@ where
         self.with_eth(|handlers| handlers.cache.clone())
     }
 
+    /// Returns the configured [EthApi] instance.
+    ///
+    /// This creates the `eth_` handlers, including the [EthStateCache] service, if this is the
+    /// first time they are requested.
+    pub fn eth_api(&mut self) -> EthApi<Provider, Pool, Network> {
+        self.with_eth(|handlers| handlers.api.clone())
+    }
+
     /// Creates the [EthHandlers] type the first time this is called.
     fn with_eth<F, R>(&mut self, f: F) -> R
     where
@@ -894,6 +934,7 @@ where
                 self.network.clone(),
                 cache.clone(),
                 gas_oracle,
+                self.config.eth.latest_block_lag,
                 executor.clone(),
             );
             let filter = EthFilter::new(
@@ -901,6 +942,7 @@ where
                 self.pool.clone(),
                 cache.clone(),
                 self.config.eth.max_logs_per_response,
+                self.config.eth.latest_block_lag,
                 executor.clone(),
             );
 
@@ -910,7 +952,16 @@ where
                 self.events.clone(),
                 self.network.clone(),
                 executor,
-            );
+            )
+            .with_max_subscriptions(
+                self.config.eth.max_subscriptions,
+                self.config.eth.max_subscriptions_per_connection,
+            )
+            .with_gas_oracle(GasPriceOracle::new(
+                self.provider.clone(),
+                self.config.eth.gas_oracle.clone(),
+                cache.clone(),
+            ));
 
             let eth = EthHandlers { api, cache, filter, pubsub };
             self.eth = Some(eth);
@@ -958,6 +1009,8 @@ pub struct RpcServerConfig {
     ipc_server_config: Option<IpcServerBuilder>,
     /// The Endpoint where to launch the ipc server
     ipc_endpoint: Option<Endpoint>,
+    /// Requests-per-second limits of the modules on http and ws
+    rate_limits: HashMap<RethRpcModule, u32>,
 }
 
 impl fmt::Debug for RpcServerConfig {
@@ -970,6 +1023,7 @@ impl fmt::Debug for RpcServerConfig {
             .field("ws_addr", &self.ws_addr)
             .field("ipc_server_config", &self.ipc_server_config)
             .field("ipc_endpoint", &self.ipc_endpoint.as_ref().map(|endpoint| endpoint.path()))
+            .field("rate_limits", &self.rate_limits)
             .finish()
     }
 }
@@ -1019,6 +1073,14 @@ impl RpcServerConfig {
         self
     }
 
+    /// Limits the number of calls per second to the methods of the given module on HTTP and WS.
+    ///
+    /// Calls that exceed the limit are rejected, see also [RateLimiter].
+    pub fn with_rate_limit(mut self, module: RethRpcModule, requests_per_second: u32) -> Self {
+        self.rate_limits.insert(module, requests_per_second);
+        self
+    }
+
     /// Configures the ws server
     ///
     /// Note: this always configures an [EthSubscriptionIdProvider] [IdProvider] for convenience.
@@ -1105,6 +1167,16 @@ impl RpcServerConfig {
         self.ipc_endpoint.as_ref()
     }
 
+    /// Returns the [RateLimiter] for the configured rate limits, if any.
+    fn rate_limiter(&self) -> Option<RateLimiter> {
+        if self.rate_limits.is_empty() {
+            return None
+        }
+        Some(RateLimiter::new(
+            self.rate_limits.iter().map(|(module, limit)| (module.to_string(), *limit)),
+        ))
+    }
+
     /// Convenience function to do [RpcServerConfig::build] and [RpcServer::start] in one step
     pub async fn start(
         self,
@@ -1213,6 +1285,7 @@ impl RpcServerConfig {
     pub async fn build(mut self) -> Result<RpcServer, RpcError> {
         let mut server = RpcServer::empty();
         server.ws_http = self.build_ws_http().await?;
+        server.rate_limiter = self.rate_limiter();
 
         if let Some(builder) = self.ipc_server_config {
             let ipc_path = self
@@ -1378,8 +1451,8 @@ impl WsHttpServers {
     /// Starts the servers and returns the handles (http, ws)
     async fn start(
         self,
-        http_module: Option<RpcModule<()>>,
-        ws_module: Option<RpcModule<()>>,
+        http_module: Option<Methods>,
+        ws_module: Option<Methods>,
         config: &TransportRpcModuleConfig,
     ) -> Result<(Option<ServerHandle>, Option<ServerHandle>), RpcError> {
         let mut http_handle = None;
@@ -1432,7 +1505,7 @@ enum WsHttpServerKind {
 
 impl WsHttpServerKind {
     /// Starts the server and returns the handle
-    async fn start(self, module: RpcModule<()>) -> Result<ServerHandle, RpcError> {
+    async fn start(self, module: Methods) -> Result<ServerHandle, RpcError> {
         match self {
             WsHttpServerKind::Plain(server) => Ok(server.start(module)?),
             WsHttpServerKind::WithCors(server) => Ok(server.start(module)?),
@@ -1475,13 +1548,15 @@ pub struct RpcServer {
     ws_http: WsHttpServer,
     /// ipc server
     ipc: Option<IpcServer>,
+    /// Limits the calls of the http and ws modules
+    rate_limiter: Option<RateLimiter>,
 }
 
 // === impl RpcServer ===
 
 impl RpcServer {
     fn empty() -> RpcServer {
-        RpcServer { ws_http: Default::default(), ipc: None }
+        RpcServer { ws_http: Default::default(), ipc: None, rate_limiter: None }
     }
 
     /// Returns the [`SocketAddr`] of the http server if started.
@@ -1509,8 +1584,13 @@ impl RpcServer {
         modules: TransportRpcModules<()>,
     ) -> Result<RpcServerHandle, RpcError> {
         trace!(target: "rpc", "staring RPC server");
-        let Self { ws_http, ipc: ipc_server } = self;
+        let Self { ws_http, ipc: ipc_server, rate_limiter } = self;
         let TransportRpcModules { config, http, ws, ipc } = modules;
+        let rate_limit = |module: RpcModule<()>| match &rate_limiter {
+            Some(rate_limiter) => rate_limiter.rate_limit_methods(module),
+            None => module.into(),
+        };
+        let (http, ws) = (http.map(rate_limit), ws.map(rate_limit));
         let mut handle = RpcServerHandle {
             http_local_addr: ws_http.http_local_addr,
             ws_local_addr: ws_http.ws_local_addr,
@@ -1,6 +1,6 @@
 //! Standalone http tests
 
-use crate::utils::{launch_http, launch_http_ws, launch_ws};
+use crate::utils::{launch_http, launch_http_ws, launch_ws, test_address, test_rpc_builder};
 use jsonrpsee::{
     core::{
         client::{ClientT, SubscriptionClientT},
@@ -12,11 +12,12 @@ use reth_primitives::{
     hex_literal::hex, Address, BlockId, BlockNumberOrTag, Bytes, NodeRecord, TxHash, H256, H64,
     U256,
 };
+use reth_rpc::LIMIT_EXCEEDED_ERROR_CODE;
 use reth_rpc_api::{
     clients::{AdminApiClient, EthApiClient},
     DebugApiClient, NetApiClient, TraceApiClient, Web3ApiClient,
 };
-use reth_rpc_builder::RethRpcModule;
+use reth_rpc_builder::{RethRpcModule, RpcServerConfig, TransportRpcModuleConfig};
 use reth_rpc_types::{trace::filter::TraceFilter, CallRequest, Index, TransactionRequest};
 use std::collections::HashSet;
 
@@ -84,13 +85,14 @@ where
     EthApiClient::transaction_by_hash(client, tx_hash).await.unwrap();
     EthApiClient::transaction_by_block_hash_and_index(client, hash, index).await.unwrap();
     EthApiClient::transaction_by_block_number_and_index(client, block_number, index).await.unwrap();
+    EthApiClient::block_receipts(client, block_number.into()).await.unwrap();
     EthApiClient::create_access_list(client, call_request.clone(), Some(block_number.into()))
         .await
         .unwrap();
     EthApiClient::estimate_gas(client, call_request.clone(), Some(block_number.into()))
         .await
         .unwrap();
-    EthApiClient::call(client, call_request.clone(), Some(block_number.into()), None, None)
+    EthApiClient::call(client, call_request.clone(), Some(block_number.into()), None, None, None)
         .await
         .unwrap();
     EthApiClient::syncing(client).await.unwrap();
@@ -341,3 +343,57 @@ async fn test_call_web3_functions_http_and_ws() {
     let client = handle.http_client().unwrap();
     test_basic_web3_calls(&client).await;
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_rate_limit_debug_functions_http() {
+    reth_tracing::init_test_tracing();
+
+    let modules = vec![RethRpcModule::Eth, RethRpcModule::Debug];
+    let server = test_rpc_builder().build(TransportRpcModuleConfig::set_http(modules));
+    let handle = server
+        .start_server(
+            RpcServerConfig::http(Default::default())
+                .with_http_address(test_address())
+                .with_rate_limit(RethRpcModule::Debug, 1),
+        )
+        .await
+        .unwrap();
+    let client = handle.http_client().unwrap();
+    let block_id = BlockId::Number(BlockNumberOrTag::default());
+
+    DebugApiClient::raw_header(&client, block_id).await.unwrap();
+    let err = DebugApiClient::raw_header(&client, block_id).await.unwrap_err();
+    assert!(matches!(&err, Error::Call(err) if err.code() == LIMIT_EXCEEDED_ERROR_CODE), "{err:?}");
+
+    // the eth namespace isn't limited
+    for _ in 0..5 {
+        EthApiClient::chain_id(&client).await.unwrap();
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_rate_limit_debug_functions_ws() {
+    reth_tracing::init_test_tracing();
+
+    let modules = vec![RethRpcModule::Eth, RethRpcModule::Debug];
+    let server = test_rpc_builder().build(TransportRpcModuleConfig::set_ws(modules));
+    let handle = server
+        .start_server(
+            RpcServerConfig::ws(Default::default())
+                .with_ws_address(test_address())
+                .with_rate_limit(RethRpcModule::Debug, 1),
+        )
+        .await
+        .unwrap();
+    let client = handle.ws_client().await.unwrap();
+    let block_id = BlockId::Number(BlockNumberOrTag::default());
+
+    DebugApiClient::raw_header(&client, block_id).await.unwrap();
+    let err = DebugApiClient::raw_header(&client, block_id).await.unwrap_err();
+    assert!(matches!(&err, Error::Call(err) if err.code() == LIMIT_EXCEEDED_ERROR_CODE), "{err:?}");
+
+    // the connection stays usable for the other namespaces
+    for _ in 0..5 {
+        EthApiClient::chain_id(&client).await.unwrap();
+    }
+}
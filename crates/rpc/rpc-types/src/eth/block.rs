@@ -267,6 +267,9 @@ impl Header {
                     base_fee_per_gas,
                     extra_data,
                     withdrawals_root,
+                    blob_gas_used: _,
+                    excess_blob_gas: _,
+                    parent_beacon_block_root: _,
                 },
             hash,
         } = primitive_header;
@@ -44,6 +44,63 @@ impl CallRequest {
     }
 }
 
+/// Non-standard options of an `eth_call` request
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CallOptions {
+    /// Whether to also return the accounts and storage slots touched by the call, as an access
+    /// list
+    pub with_access_list: bool,
+    /// Whether to return the address and the deployed code of the created contract for a call
+    /// without `to`, instead of only the output of the call
+    pub with_created_contract: bool,
+}
+
+/// Response of an `eth_call` request
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CallResponse {
+    /// The output of the call
+    Output(Bytes),
+    /// The output of the call and the accessed accounts and storage slots, see
+    /// [CallOptions::with_access_list]
+    WithAccessList(CallOutputWithAccessList),
+    /// The address and the deployed code of the contract created by a call without `to`, see
+    /// [CallOptions::with_created_contract]
+    Create(CallCreateOutput),
+}
+
+impl CallResponse {
+    /// Returns the output of the call.
+    pub fn into_output(self) -> Bytes {
+        match self {
+            CallResponse::Output(output) => output,
+            CallResponse::WithAccessList(res) => res.output,
+            CallResponse::Create(res) => res.code,
+        }
+    }
+}
+
+/// Output of a call together with the accounts and storage slots it touched
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallOutputWithAccessList {
+    /// The output of the call
+    pub output: Bytes,
+    /// The accounts and storage slots touched by the call
+    pub access_list: AccessList,
+}
+
+/// Output of a call that creates a contract
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallCreateOutput {
+    /// The address of the created contract
+    pub address: Address,
+    /// The deployed code of the created contract, the output of its constructor
+    pub code: Bytes,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,4 +110,28 @@ mod tests {
         let s = r#"{"accessList":[],"data":"0x0902f1ac","to":"0xa478c2975ab1ea89e8196811f51a7b7ade33eb11","type":"0x02"}"#;
         let _req = serde_json::from_str::<CallRequest>(s).unwrap();
     }
+
+    #[test]
+    fn serde_call_response() {
+        let output = CallResponse::Output(Bytes::from(vec![0x01]));
+        let s = serde_json::to_string(&output).unwrap();
+        assert_eq!(s, r#""0x01""#);
+        assert_eq!(serde_json::from_str::<CallResponse>(&s).unwrap(), output);
+
+        let with_access_list = CallResponse::WithAccessList(CallOutputWithAccessList {
+            output: Bytes::from(vec![0x01]),
+            access_list: AccessList::default(),
+        });
+        let s = serde_json::to_string(&with_access_list).unwrap();
+        assert_eq!(s, r#"{"output":"0x01","accessList":[]}"#);
+        assert_eq!(serde_json::from_str::<CallResponse>(&s).unwrap(), with_access_list);
+
+        let create = CallResponse::Create(CallCreateOutput {
+            address: Address::from_low_u64_be(0x01),
+            code: Bytes::from(vec![0x01]),
+        });
+        let s = serde_json::to_string(&create).unwrap();
+        assert_eq!(s, r#"{"address":"0x0000000000000000000000000000000000000001","code":"0x01"}"#);
+        assert_eq!(serde_json::from_str::<CallResponse>(&s).unwrap(), create);
+    }
 }
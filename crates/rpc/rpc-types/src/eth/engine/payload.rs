@@ -168,6 +168,9 @@ impl TryFrom<ExecutionPayload> for SealedBlock {
             ommers_hash: EMPTY_LIST_HASH,
             difficulty: Default::default(),
             nonce: Default::default(),
+            blob_gas_used: None,
+            excess_blob_gas: None,
+            parent_beacon_block_root: None,
         }
         .seal_slow();
 
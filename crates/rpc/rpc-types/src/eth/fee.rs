@@ -60,10 +60,29 @@ impl FeeHistoryCache {
     pub fn new(cap: NonZeroUsize) -> Self {
         Self(Arc::new(Mutex::new(LruCache::new(cap))))
     }
+
+    /// Returns all cached entries, ordered from most to least recently used.
+    pub async fn entries(&self) -> Vec<(BlockNumber, FeeHistoryCacheItem)> {
+        self.0.lock().await.iter().map(|(number, item)| (*number, item.clone())).collect()
+    }
+
+    /// Inserts the given entries, ordered from most to least recently used, into the cache.
+    pub async fn extend(
+        &self,
+        entries: impl IntoIterator<Item = (BlockNumber, FeeHistoryCacheItem)>,
+    ) {
+        let entries = entries.into_iter().collect::<Vec<_>>();
+        let mut cache = self.0.lock().await;
+        // insert the least recently used entry first to retain the order
+        for (number, item) in entries.into_iter().rev() {
+            cache.push(number, item);
+        }
+    }
 }
 
 /// [FeeHistoryCache] item.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct FeeHistoryCacheItem {
     /// Block hash (`None` if it wasn't the oldest block in `eth_feeHistory` response where
     /// cache is populated)
@@ -19,7 +19,9 @@ mod work;
 
 pub use account::*;
 pub use block::*;
-pub use call::CallRequest;
+pub use call::{
+    CallCreateOutput, CallOptions, CallOutputWithAccessList, CallRequest, CallResponse,
+};
 pub use fee::{FeeHistory, FeeHistoryCache, FeeHistoryCacheItem, TxGasAndReward};
 pub use filter::*;
 pub use index::Index;
@@ -1,7 +1,7 @@
 //! Ethereum types for pub-sub
 
 use crate::{eth::Filter, Log, RichHeader};
-use reth_primitives::H256;
+use reth_primitives::{Address, H256, U256};
 use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Subscription result.
@@ -16,6 +16,8 @@ pub enum SubscriptionResult {
     TransactionHash(H256),
     /// SyncStatus
     SyncState(PubSubSyncStatus),
+    /// Gas price update
+    GasPrice(GasPriceUpdate),
 }
 
 /// Response type for a SyncStatus subscription
@@ -40,6 +42,21 @@ pub struct SyncStatusMetadata {
     pub highest_block: Option<u64>,
 }
 
+/// Response type for a `gasPrice` subscription
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasPriceUpdate {
+    /// Number of the new canonical block
+    pub block_number: u64,
+    /// Hash of the new canonical block
+    pub block_hash: H256,
+    /// Base fee of the new canonical block, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_fee_per_gas: Option<U256>,
+    /// Suggested gas price, the suggested tip plus the base fee
+    pub gas_price: U256,
+}
+
 impl Serialize for SubscriptionResult {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -50,6 +67,7 @@ impl Serialize for SubscriptionResult {
             SubscriptionResult::Log(ref log) => log.serialize(serializer),
             SubscriptionResult::TransactionHash(ref hash) => hash.serialize(serializer),
             SubscriptionResult::SyncState(ref sync) => sync.serialize(serializer),
+            SubscriptionResult::GasPrice(ref update) => update.serialize(serializer),
         }
     }
 }
@@ -87,6 +105,11 @@ pub enum SubscriptionKind {
     /// indicating that the synchronization has started (true), finished (false) or an object with
     /// various progress indicators.
     Syncing,
+    /// Gas price subscription.
+    ///
+    /// Non-standard. Returns the suggested gas price and the base fee each time a new block
+    /// becomes the tip of the canonical chain, at most once per block.
+    GasPrice,
 }
 
 /// Subscription kind.
@@ -97,6 +120,16 @@ pub enum Params {
     None,
     /// Log parameters.
     Logs(Box<Filter>),
+    /// New pending transactions parameters.
+    NewPendingTransactions(PendingTransactionFilter),
+}
+
+/// Parameters for the `newPendingTransactions` subscription.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PendingTransactionFilter {
+    /// Only emit transactions sent by one of these addresses.
+    pub from_address: Vec<Address>,
 }
 
 impl Serialize for Params {
@@ -107,6 +140,7 @@ impl Serialize for Params {
         match self {
             Params::None => (&[] as &[serde_json::Value]).serialize(serializer),
             Params::Logs(logs) => logs.serialize(serializer),
+            Params::NewPendingTransactions(filter) => filter.serialize(serializer),
         }
     }
 }
@@ -122,6 +156,12 @@ impl<'a> Deserialize<'a> for Params {
             return Ok(Params::None)
         }
 
+        if v.get("fromAddress").is_some() {
+            return serde_json::from_value(v)
+                .map(Params::NewPendingTransactions)
+                .map_err(|e| D::Error::custom(format!("Invalid Pub-Sub parameters: {e}")))
+        }
+
         serde_json::from_value(v)
             .map(|f| Params::Logs(Box::new(f)))
             .map_err(|e| D::Error::custom(format!("Invalid Pub-Sub parameters: {e}")))
@@ -45,6 +45,11 @@ pub struct DefaultFrame {
     #[serde(serialize_with = "reth_primitives::serde_helper::serialize_hex_string_no_prefix")]
     pub return_value: Bytes,
     pub struct_logs: Vec<StructLog>,
+    /// Whether struct logs were dropped because the node's step limit was reached.
+    ///
+    /// Non-standard: only serialized if the struct logs are truncated.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
 }
 
 /// Represents a struct log entry in a trace
@@ -208,7 +213,12 @@ impl GethDebugTracerConfig {
     }
 
     /// Returns the [CallConfig] if it is a call config.
+    ///
+    /// A missing config is treated as the default [CallConfig].
     pub fn into_call_config(self) -> Result<CallConfig, serde_json::Error> {
+        if self.is_null() {
+            return Ok(Default::default())
+        }
         self.from_value()
     }
 
@@ -370,6 +380,19 @@ fn serialize_string_storage_map_opt<S: Serializer>(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_call_config_defaults() {
+        assert_eq!(
+            GethDebugTracerConfig::default().into_call_config().unwrap(),
+            Default::default()
+        );
+
+        let config = GethDebugTracerConfig(serde_json::json!({ "onlyTopCall": true }));
+        assert_eq!(config.into_call_config().unwrap().only_top_call, Some(true));
+
+        assert!(GethDebugTracerConfig(serde_json::json!(1)).into_call_config().is_err());
+    }
+
     #[test]
     fn test_memory_capture() {
         let mut config = GethDefaultTracingOptions::default();
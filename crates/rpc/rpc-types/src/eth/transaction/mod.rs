@@ -7,13 +7,13 @@ mod typed;
 pub use common::TransactionInfo;
 pub use receipt::TransactionReceipt;
 pub use request::TransactionRequest;
-pub use signature::Signature;
+pub use signature::{Parity, Signature};
 pub use typed::*;
 
 use reth_primitives::{
     rpc::transaction::eip2930::AccessListItem, Address, BlockNumber, Bytes,
-    Transaction as PrimitiveTransaction, TransactionKind as PrimitiveTransactionKind,
-    TransactionSignedEcRecovered, TxType, H256, U128, U256, U64,
+    TransactionKind as PrimitiveTransactionKind, TransactionSignedEcRecovered, TxType, H256, U128,
+    U256, U64,
 };
 use serde::{Deserialize, Serialize};
 
@@ -112,7 +112,7 @@ impl Transaction {
         let (gas_price, max_fee_per_gas) = match signed_tx.tx_type() {
             TxType::Legacy => (Some(U128::from(signed_tx.max_fee_per_gas())), None),
             TxType::EIP2930 => (Some(U128::from(signed_tx.max_fee_per_gas())), None),
-            TxType::EIP1559 => {
+            TxType::EIP1559 | TxType::EIP4844 => {
                 // the gas price field for EIP1559 is set to `min(tip, gasFeeCap - baseFee) +
                 // baseFee`
                 let gas_price = base_fee
@@ -126,29 +126,17 @@ impl Transaction {
         };
 
         let chain_id = signed_tx.chain_id().map(U64::from);
-        let access_list = match &signed_tx.transaction {
-            PrimitiveTransaction::Legacy(_) => None,
-            PrimitiveTransaction::Eip2930(tx) => Some(
-                tx.access_list
-                    .0
-                    .iter()
-                    .map(|item| AccessListItem {
-                        address: item.address.0.into(),
-                        storage_keys: item.storage_keys.iter().map(|key| key.0.into()).collect(),
-                    })
-                    .collect(),
-            ),
-            PrimitiveTransaction::Eip1559(tx) => Some(
-                tx.access_list
-                    .0
-                    .iter()
-                    .map(|item| AccessListItem {
-                        address: item.address.0.into(),
-                        storage_keys: item.storage_keys.iter().map(|key| key.0.into()).collect(),
-                    })
-                    .collect(),
-            ),
-        };
+        // the access list declared by the transaction, not the one it would create when executed
+        let access_list = signed_tx.access_list().map(|access_list| {
+            access_list
+                .0
+                .iter()
+                .map(|item| AccessListItem {
+                    address: item.address.0.into(),
+                    storage_keys: item.storage_keys.iter().map(|key| key.0.into()).collect(),
+                })
+                .collect()
+        });
 
         let signature = Signature::from_primitive_signature(
             *signed_tx.signature(),
@@ -183,6 +171,124 @@ impl Transaction {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use reth_primitives::{
+        AccessList, Signature as PrimitiveSignature, Transaction as PrimitiveTransaction,
+        TransactionSigned, TxEip1559, TxEip2930, TxLegacy,
+    };
+
+    /// Returns the serialized rpc object of the pending transaction.
+    fn rpc_transaction(transaction: PrimitiveTransaction) -> serde_json::Value {
+        let signature =
+            PrimitiveSignature { r: U256::from(1), s: U256::from(2), odd_y_parity: true };
+        let signed = TransactionSigned::from_transaction_and_signature(transaction, signature);
+        let recovered =
+            TransactionSignedEcRecovered::from_signed_transaction(signed, Address::random());
+        serde_json::to_value(Transaction::from_recovered(recovered)).unwrap()
+    }
+
+    #[test]
+    fn serde_transaction_fields_by_type() {
+        let access_list = AccessList(vec![reth_primitives::AccessListItem {
+            address: Address::from_low_u64_be(1),
+            storage_keys: vec![H256::from_low_u64_be(2)],
+        }]);
+
+        let legacy = rpc_transaction(PrimitiveTransaction::Legacy(TxLegacy {
+            chain_id: Some(1),
+            gas_price: 10,
+            ..Default::default()
+        }));
+        assert_eq!(legacy["type"], "0x0");
+        assert_eq!(legacy["gasPrice"], "0xa");
+        assert_eq!(legacy["chainId"], "0x1");
+        // EIP-155 recovery id
+        assert_eq!(legacy["v"], "0x26");
+        for field in ["accessList", "maxFeePerGas", "maxPriorityFeePerGas", "yParity"] {
+            assert!(legacy.get(field).is_none(), "unexpected field {field}");
+        }
+
+        let eip2930 = rpc_transaction(PrimitiveTransaction::Eip2930(TxEip2930 {
+            chain_id: 1,
+            gas_price: 10,
+            access_list: access_list.clone(),
+            ..Default::default()
+        }));
+        assert_eq!(eip2930["type"], "0x1");
+        assert_eq!(eip2930["gasPrice"], "0xa");
+        assert_eq!(eip2930["chainId"], "0x1");
+        assert_eq!(eip2930["v"], "0x1");
+        assert_eq!(eip2930["yParity"], "0x1");
+        assert_eq!(
+            eip2930["accessList"][0]["address"],
+            serde_json::to_value(Address::from_low_u64_be(1)).unwrap()
+        );
+        for field in ["maxFeePerGas", "maxPriorityFeePerGas"] {
+            assert!(eip2930.get(field).is_none(), "unexpected field {field}");
+        }
+
+        let eip1559 = rpc_transaction(PrimitiveTransaction::Eip1559(TxEip1559 {
+            chain_id: 1,
+            max_fee_per_gas: 20,
+            max_priority_fee_per_gas: 5,
+            access_list,
+            ..Default::default()
+        }));
+        assert_eq!(eip1559["type"], "0x2");
+        // without a base fee, the gas price of a pending transaction is its max fee
+        assert_eq!(eip1559["gasPrice"], "0x14");
+        assert_eq!(eip1559["maxFeePerGas"], "0x14");
+        assert_eq!(eip1559["maxPriorityFeePerGas"], "0x5");
+        assert_eq!(eip1559["chainId"], "0x1");
+        assert_eq!(eip1559["v"], "0x1");
+        assert_eq!(eip1559["yParity"], "0x1");
+        assert_eq!(eip1559["accessList"].as_array().unwrap().len(), 1);
+
+        let deserialized: Transaction = serde_json::from_value(eip1559).unwrap();
+        assert_eq!(deserialized.signature.unwrap().y_parity, Some(Parity(true)));
+    }
+
+    #[test]
+    fn mined_transaction_declared_access_list() {
+        let access_list = AccessList(vec![
+            reth_primitives::AccessListItem {
+                address: Address::from_low_u64_be(1),
+                storage_keys: vec![H256::from_low_u64_be(2), H256::from_low_u64_be(3)],
+            },
+            reth_primitives::AccessListItem {
+                address: Address::from_low_u64_be(4),
+                storage_keys: vec![],
+            },
+        ]);
+        let signed = TransactionSigned::from_transaction_and_signature(
+            PrimitiveTransaction::Eip1559(TxEip1559 {
+                chain_id: 1,
+                max_fee_per_gas: 20,
+                access_list: access_list.clone(),
+                ..Default::default()
+            }),
+            PrimitiveSignature::default(),
+        );
+        let recovered =
+            TransactionSignedEcRecovered::from_signed_transaction(signed, Address::random());
+
+        let transaction = Transaction::from_recovered_with_block_context(
+            recovered,
+            H256::random(),
+            1,
+            Some(10),
+            U256::ZERO,
+        );
+        let declared = transaction
+            .access_list
+            .unwrap()
+            .into_iter()
+            .map(|item| reth_primitives::AccessListItem {
+                address: item.address.0.into(),
+                storage_keys: item.storage_keys.into_iter().map(|key| key.0.into()).collect(),
+            })
+            .collect();
+        assert_eq!(AccessList(declared), access_list);
+    }
 
     #[test]
     fn serde_transaction() {
@@ -198,7 +304,12 @@ mod tests {
             gas_price: Some(U128::from(9)),
             gas: U256::from(10),
             input: Bytes::from(vec![11, 12, 13]),
-            signature: Some(Signature { v: U256::from(14), r: U256::from(14), s: U256::from(14) }),
+            signature: Some(Signature {
+                v: U256::from(14),
+                r: U256::from(14),
+                s: U256::from(14),
+                y_parity: None,
+            }),
             chain_id: Some(U64::from(17)),
             access_list: None,
             transaction_type: Some(U64::from(20)),
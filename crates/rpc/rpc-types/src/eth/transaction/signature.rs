@@ -1,6 +1,6 @@
 //! Signature related RPC values
 use reth_primitives::{Signature as PrimitiveSignature, TxType, U256};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Container type for all signature fields in RPC
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
@@ -16,6 +16,9 @@ pub struct Signature {
     ///
     /// See also <https://ethereum.github.io/execution-apis/api-documentation/> and <https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_gettransactionbyhash>
     pub v: U256,
+    /// The y parity of the signature. This is only set for typed (non-legacy) transactions.
+    #[serde(default, rename = "yParity", skip_serializing_if = "Option::is_none")]
+    pub y_parity: Option<Parity>,
 }
 
 impl Signature {
@@ -28,14 +31,24 @@ impl Signature {
         signature: PrimitiveSignature,
         chain_id: Option<u64>,
     ) -> Self {
-        Self { r: signature.r, s: signature.s, v: U256::from(signature.v(chain_id)) }
+        Self {
+            r: signature.r,
+            s: signature.s,
+            v: U256::from(signature.v(chain_id)),
+            y_parity: None,
+        }
     }
 
     /// Creates a new rpc signature from a non-legacy [primitive
-    /// signature](reth_primitives::Signature). This sets the `v` value to `0` or `1` depending on
-    /// the signature's `odd_y_parity`.
+    /// signature](reth_primitives::Signature). This sets the `v` value and the `y_parity` to `0`
+    /// or `1` depending on the signature's `odd_y_parity`.
     pub(crate) fn from_typed_primitive_signature(signature: PrimitiveSignature) -> Self {
-        Self { r: signature.r, s: signature.s, v: U256::from(signature.odd_y_parity as u8) }
+        Self {
+            r: signature.r,
+            s: signature.s,
+            v: U256::from(signature.odd_y_parity as u8),
+            y_parity: Some(Parity(signature.odd_y_parity)),
+        }
     }
 
     /// Creates a new rpc signature from a legacy [primitive
@@ -58,3 +71,29 @@ impl Signature {
         }
     }
 }
+
+/// Type that represents the y parity of a signature in RPC.
+///
+/// This is serialized as `"0x0"` if false, and `"0x1"` if true.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Parity(pub bool);
+
+impl Serialize for Parity {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0 {
+            serializer.serialize_str("0x1")
+        } else {
+            serializer.serialize_str("0x0")
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Parity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match String::deserialize(deserializer)?.as_str() {
+            "0x0" => Ok(Parity(false)),
+            "0x1" => Ok(Parity(true)),
+            other => Err(serde::de::Error::custom(format!("invalid y parity: {other}"))),
+        }
+    }
+}
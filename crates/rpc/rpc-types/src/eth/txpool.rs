@@ -112,13 +112,16 @@ impl Serialize for TxpoolInspectSummary {
 /// the transactions currently pending for inclusion in the next block(s), as well
 /// as the ones that are being scheduled for future execution only.
 ///
+/// The transactions of a sender are keyed by their nonce in ascending order. Like geth does, the
+/// nonces are serialized as decimal strings.
+///
 /// See [here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_content) for more details
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TxpoolContent {
     /// pending tx
-    pub pending: BTreeMap<Address, BTreeMap<String, Transaction>>,
+    pub pending: BTreeMap<Address, BTreeMap<u64, Transaction>>,
     /// queued tx
-    pub queued: BTreeMap<Address, BTreeMap<String, Transaction>>,
+    pub queued: BTreeMap<Address, BTreeMap<u64, Transaction>>,
 }
 
 impl TxpoolContent {
@@ -133,15 +136,16 @@ impl TxpoolContent {
 
 /// Transaction Pool Content From
 ///
-/// Same as [TxpoolContent] but for a specific address.
+/// Same as [TxpoolContent] but for a specific address, with the same ascending order of the nonce
+/// keys.
 ///
 /// See [here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_contentFrom) for more details
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TxpoolContentFrom {
     /// pending tx
-    pub pending: BTreeMap<String, Transaction>,
+    pub pending: BTreeMap<u64, Transaction>,
     /// queued tx
-    pub queued: BTreeMap<String, Transaction>,
+    pub queued: BTreeMap<u64, Transaction>,
 }
 
 /// Transaction Pool Inspect
@@ -54,8 +54,31 @@ impl<Provider, Eth> DebugApi<Provider, Eth> {
         task_spawner: Box<dyn TaskSpawner>,
         tracing_call_guard: TracingCallGuard,
     ) -> Self {
-        let inner =
-            Arc::new(DebugApiInner { provider, eth_api: eth, task_spawner, tracing_call_guard });
+        Self::with_struct_log_limits(
+            provider,
+            eth,
+            task_spawner,
+            tracing_call_guard,
+            StructLogLimits::default(),
+        )
+    }
+
+    /// Create a new instance of the [DebugApi] that caps the struct logs captured by the default
+    /// tracer.
+    pub fn with_struct_log_limits(
+        provider: Provider,
+        eth: Eth,
+        task_spawner: Box<dyn TaskSpawner>,
+        tracing_call_guard: TracingCallGuard,
+        struct_log_limits: StructLogLimits,
+    ) -> Self {
+        let inner = Arc::new(DebugApiInner {
+            provider,
+            eth_api: eth,
+            task_spawner,
+            tracing_call_guard,
+            struct_log_limits,
+        });
         Self { inner }
     }
 }
@@ -101,27 +124,48 @@ where
         // replay all transactions of the block
         let this = self.clone();
         self.inner.eth_api.with_state_at_block(at, move |state| {
-            let mut results = Vec::with_capacity(transactions.len());
-            let mut db = SubState::new(State::new(state));
-
-            let mut transactions = transactions.into_iter().peekable();
-            while let Some(tx) = transactions.next() {
-                let tx = tx.into_ecrecovered().ok_or(BlockError::InvalidSignature)?;
-                let tx = tx_env_with_recovered(&tx);
-                let env = Env { cfg: cfg.clone(), block: block_env.clone(), tx };
-                let (result, state_changes) =
-                    this.trace_transaction(opts.clone(), env, at, &mut db)?;
-                results.push(TraceResult::Success { result });
-
-                if transactions.peek().is_some() {
-                    // need to apply the state changes of this transaction before executing the next
-                    // transaction
-                    db.commit(state_changes)
-                }
+            this.trace_transactions_on_state(at, transactions, cfg, block_env, opts, state)
+        })
+    }
+
+    /// Traces the transactions one after another on top of the given state.
+    ///
+    /// The struct logs captured by the default tracer count towards the configured
+    /// [StructLogLimits::max_steps_per_block].
+    fn trace_transactions_on_state(
+        &self,
+        at: BlockId,
+        transactions: Vec<TransactionSigned>,
+        cfg: CfgEnv,
+        block_env: BlockEnv,
+        opts: GethDebugTracingOptions,
+        state: StateProviderBox<'_>,
+    ) -> EthResult<Vec<TraceResult>> {
+        let mut results = Vec::with_capacity(transactions.len());
+        let mut db = SubState::new(State::new(state));
+        let mut block_steps = 0;
+
+        let mut transactions = transactions.into_iter().peekable();
+        while let Some(tx) = transactions.next() {
+            let tx = tx.into_ecrecovered().ok_or(BlockError::InvalidSignature)?;
+            let tx = tx_env_with_recovered(&tx);
+            let env = Env { cfg: cfg.clone(), block: block_env.clone(), tx };
+            let max_steps = self.inner.struct_log_limits.max_steps(block_steps);
+            let (result, state_changes) =
+                self.trace_transaction(opts.clone(), env, at, &mut db, max_steps)?;
+            if let GethTrace::Default(frame) = &result {
+                block_steps += frame.struct_logs.len();
             }
+            results.push(TraceResult::Success { result });
 
-            Ok(results)
-        })
+            if transactions.peek().is_some() {
+                // need to apply the state changes of this transaction before executing the next
+                // transaction
+                db.commit(state_changes)
+            }
+        }
+
+        Ok(results)
     }
 
     /// Trace the entire block asynchronously
@@ -230,7 +274,9 @@ where
                 )?;
 
                 let env = Env { cfg, block: block_env, tx: tx_env_with_recovered(&tx) };
-                this.trace_transaction(opts, env, state_at, &mut db).map(|(trace, _)| trace)
+                let max_steps = this.inner.struct_log_limits.max_steps_per_call;
+                this.trace_transaction(opts, env, state_at, &mut db, max_steps)
+                    .map(|(trace, _)| trace)
             })
         })
         .await
@@ -324,7 +370,8 @@ where
         }
 
         // default structlog tracer
-        let inspector_config = TracingInspectorConfig::from_geth_config(&config);
+        let inspector_config = TracingInspectorConfig::from_geth_config(&config)
+            .set_max_steps(self.inner.struct_log_limits.max_steps_per_call);
 
         let mut inspector = TracingInspector::new(inspector_config);
 
@@ -332,7 +379,9 @@ where
             self.inner.eth_api.inspect_call_at(call, at, overrides, &mut inspector).await?;
         let gas_used = res.result.gas_used();
 
-        let frame = inspector.into_geth_builder().geth_traces(gas_used, config);
+        let truncated = inspector.is_truncated();
+        let mut frame = inspector.into_geth_builder().geth_traces(gas_used, config);
+        frame.truncated = truncated;
 
         Ok(frame.into())
     }
@@ -341,6 +390,8 @@ where
     ///
     /// Returns the trace frame and the state that got updated after executing the transaction.
     ///
+    /// The default struct log tracer records at most `max_steps` steps, if set.
+    ///
     /// Note: this does not apply any state overrides if they're configured in the `opts`.
     fn trace_transaction(
         &self,
@@ -348,6 +399,7 @@ where
         env: Env,
         at: BlockId,
         db: &mut SubState<StateProviderBox<'_>>,
+        max_steps: Option<usize>,
     ) -> EthResult<(GethTrace, revm_primitives::State)> {
         let GethDebugTracingOptions { config, tracer, tracer_config, .. } = opts;
 
@@ -400,14 +452,17 @@ where
         }
 
         // default structlog tracer
-        let inspector_config = TracingInspectorConfig::from_geth_config(&config);
+        let inspector_config =
+            TracingInspectorConfig::from_geth_config(&config).set_max_steps(max_steps);
 
         let mut inspector = TracingInspector::new(inspector_config);
 
         let (res, _) = inspect(db, env, &mut inspector)?;
         let gas_used = res.result.gas_used();
 
-        let frame = inspector.into_geth_builder().geth_traces(gas_used, config);
+        let truncated = inspector.is_truncated();
+        let mut frame = inspector.into_geth_builder().geth_traces(gas_used, config);
+        frame.truncated = truncated;
 
         Ok((frame.into(), res.state))
     }
@@ -636,4 +691,201 @@ struct DebugApiInner<Provider, Eth> {
     tracing_call_guard: TracingCallGuard,
     /// The type that can spawn tasks which would otherwise block.
     task_spawner: Box<dyn TaskSpawner>,
+    /// Caps the struct logs captured by the default tracer
+    struct_log_limits: StructLogLimits,
+}
+
+/// Caps the number of struct logs captured by the default tracer of the `debug` namespace.
+///
+/// If a cap is reached, the remaining steps are not captured and the trace is marked as
+/// truncated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StructLogLimits {
+    /// The maximum number of steps captured for a single transaction or call.
+    pub max_steps_per_call: Option<usize>,
+    /// The maximum number of steps captured across all transactions of a traced block.
+    pub max_steps_per_block: Option<usize>,
+}
+
+impl StructLogLimits {
+    /// Returns the maximum number of steps the next transaction of a block may capture, given the
+    /// number of steps the previous transactions of the block captured.
+    fn max_steps(&self, block_steps: usize) -> Option<usize> {
+        let remaining = self.max_steps_per_block.map(|max| max.saturating_sub(block_steps));
+        match (self.max_steps_per_call, remaining) {
+            (Some(per_call), Some(remaining)) => Some(per_call.min(remaining)),
+            (per_call, remaining) => per_call.or(remaining),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        eth::{cache::EthStateCache, gas_oracle::GasPriceOracle},
+        EthApi,
+    };
+    use reth_network_api::test_utils::NoopNetwork;
+    use reth_primitives::{
+        hex_literal::hex, sign_message, Address, Transaction, TransactionKind, TxLegacy, U256,
+    };
+    use reth_provider::{
+        test_utils::{ExtendedAccount, MockEthProvider},
+        StateProviderFactory,
+    };
+    use reth_rpc_types::trace::geth::DefaultFrame;
+    use reth_tasks::TokioTaskExecutor;
+    use reth_transaction_pool::test_utils::{testing_pool, TestPool};
+    use revm::primitives::{TransactTo, TxEnv};
+
+    fn build_debug_api(
+        provider: MockEthProvider,
+        limits: StructLogLimits,
+    ) -> DebugApi<MockEthProvider, EthApi<MockEthProvider, TestPool, NoopNetwork>> {
+        let cache = EthStateCache::spawn(provider.clone(), Default::default());
+        let eth_api = EthApi::new(
+            provider.clone(),
+            testing_pool(),
+            NoopNetwork,
+            cache.clone(),
+            GasPriceOracle::new(provider.clone(), Default::default(), cache),
+        );
+        DebugApi::with_struct_log_limits(
+            provider,
+            eth_api,
+            Box::<TokioTaskExecutor>::default(),
+            TracingCallGuard::new(1),
+            limits,
+        )
+    }
+
+    /// Traces two calls to a contract that executes 9 steps, under the given limits.
+    fn trace_block(limits: StructLogLimits) -> Vec<DefaultFrame> {
+        let provider = MockEthProvider::default();
+        let contract = Address::random();
+        let code = [[0x5b; 8].as_slice(), &[0x00]].concat();
+        provider
+            .add_account(contract, ExtendedAccount::new(0, U256::ZERO).with_bytecode(code.into()));
+
+        let secret = H256::from_low_u64_be(1);
+        let transactions = (0..2)
+            .map(|nonce| {
+                let tx = Transaction::Legacy(TxLegacy {
+                    chain_id: Some(1),
+                    nonce,
+                    gas_limit: 100_000,
+                    to: TransactionKind::Call(contract),
+                    ..Default::default()
+                });
+                let signature = sign_message(secret, tx.signature_hash()).unwrap();
+                TransactionSigned::from_transaction_and_signature(tx, signature)
+            })
+            .collect::<Vec<_>>();
+        let sender = transactions[0].recover_signer().unwrap();
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(u64::MAX)));
+
+        let debug_api = build_debug_api(provider.clone(), limits);
+
+        debug_api
+            .trace_transactions_on_state(
+                BlockNumberOrTag::Latest.into(),
+                transactions,
+                CfgEnv::default(),
+                BlockEnv::default(),
+                GethDebugTracingOptions::default(),
+                Box::new(provider),
+            )
+            .unwrap()
+            .into_iter()
+            .map(|result| match result {
+                TraceResult::Success { result: GethTrace::Default(frame) } => frame,
+                _ => panic!("expected struct logs"),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn trace_block_truncates_struct_logs() {
+        let frames = trace_block(StructLogLimits::default());
+        assert!(frames.iter().all(|frame| frame.struct_logs.len() == 9 && !frame.truncated));
+
+        // the second transaction only gets the remaining steps of the block
+        let frames = trace_block(StructLogLimits {
+            max_steps_per_call: None,
+            max_steps_per_block: Some(12),
+        });
+        assert_eq!(frames[0].struct_logs.len(), 9);
+        assert!(!frames[0].truncated);
+        assert_eq!(frames[1].struct_logs.len(), 3);
+        assert!(frames[1].truncated);
+
+        let frames =
+            trace_block(StructLogLimits { max_steps_per_call: Some(5), max_steps_per_block: None });
+        assert!(frames.iter().all(|frame| frame.struct_logs.len() == 5 && frame.truncated));
+    }
+
+    #[tokio::test]
+    async fn trace_nested_call_with_call_tracer() {
+        let provider = MockEthProvider::default();
+
+        // returns 42 as a 32 byte word
+        let callee = Address::random();
+        provider.add_account(
+            callee,
+            ExtendedAccount::new(0, U256::ZERO)
+                .with_bytecode(hex!("602a60005260206000f3").to_vec().into()),
+        );
+
+        // calls the callee and returns its output
+        let contract = Address::random();
+        let code = [
+            hex!("6020600060006000600073").as_slice(),
+            callee.as_bytes(),
+            hex!("5af15060206000f3").as_slice(),
+        ]
+        .concat();
+        provider
+            .add_account(contract, ExtendedAccount::new(0, U256::ZERO).with_bytecode(code.into()));
+
+        let sender = Address::random();
+        let env = Env {
+            tx: TxEnv {
+                caller: sender,
+                gas_limit: 100_000,
+                transact_to: TransactTo::Call(contract),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let opts = GethDebugTracingOptions {
+            tracer: Some(GethDebugTracerType::BuiltInTracer(
+                GethDebugBuiltInTracerType::CallTracer,
+            )),
+            ..Default::default()
+        };
+
+        let debug_api = build_debug_api(provider.clone(), StructLogLimits::default());
+        let mut db = SubState::new(State::new(provider.latest().unwrap()));
+        let (trace, _) = debug_api
+            .trace_transaction(opts, env, BlockNumberOrTag::Latest.into(), &mut db, None)
+            .unwrap();
+        let GethTrace::CallTracer(frame) = trace else { panic!("expected call frame") };
+
+        let output = Bytes::from(H256::from_low_u64_be(42).as_bytes().to_vec());
+        assert_eq!(frame.typ, "CALL");
+        assert_eq!(frame.from, sender);
+        assert_eq!(frame.to, Some(contract));
+        assert_eq!(frame.output, Some(output.clone()));
+        assert_eq!(frame.error, None);
+
+        let calls = frame.calls.expect("nested call");
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].typ, "CALL");
+        assert_eq!(calls[0].from, contract);
+        assert_eq!(calls[0].to, Some(callee));
+        assert_eq!(calls[0].value, Some(U256::ZERO));
+        assert_eq!(calls[0].output, Some(output));
+        assert_eq!(calls[0].calls, None);
+    }
 }
@@ -4,7 +4,8 @@ use reth_rpc_api::{EngineEthApiServer, EthApiServer, EthFilterApiServer};
 /// Re-export for convenience
 pub use reth_rpc_engine_api::EngineApi;
 use reth_rpc_types::{
-    state::StateOverride, BlockOverrides, CallRequest, Filter, Log, RichBlock, SyncStatus,
+    state::StateOverride, BlockOverrides, CallRequest, CallResponse, Filter, Log, RichBlock,
+    SyncStatus,
 };
 use tracing_futures::Instrument;
 
@@ -65,9 +66,10 @@ where
         block_overrides: Option<Box<BlockOverrides>>,
     ) -> Result<Bytes> {
         self.eth
-            .call(request, block_number, state_overrides, block_overrides)
+            .call(request, block_number, state_overrides, block_overrides, None)
             .instrument(engine_span!())
             .await
+            .map(CallResponse::into_output)
     }
 
     /// Handler for: `eth_getCode`
@@ -1,12 +1,15 @@
 //! Contains RPC handler implementations specific to blocks.
 
 use crate::{
-    eth::error::{EthApiError, EthResult},
+    eth::{
+        api::transactions::build_transaction_receipt_with_block_receipts,
+        error::{EthApiError, EthResult},
+    },
     EthApi,
 };
-use reth_primitives::BlockId;
+use reth_primitives::{BlockId, TransactionMeta};
 use reth_provider::{BlockProviderIdExt, EvmEnvProvider, StateProviderFactory};
-use reth_rpc_types::{Block, Index, RichBlock};
+use reth_rpc_types::{Block, Index, RichBlock, TransactionReceipt};
 
 impl<Provider, Pool, Network> EthApi<Provider, Pool, Network>
 where
@@ -19,7 +22,7 @@ where
         &self,
         block_id: impl Into<BlockId>,
     ) -> EthResult<Option<Vec<reth_primitives::Header>>> {
-        let block_id = block_id.into();
+        let block_id = self.resolve_latest_block_id(block_id.into())?;
         Ok(self.provider().ommers_by_id(block_id)?)
     }
 
@@ -34,7 +37,7 @@ where
             // Pending block can be fetched directly without need for caching
             self.provider().pending_block()?.map(|block| block.ommers)
         } else {
-            self.provider().ommers_by_id(block_id)?
+            self.provider().ommers_by_id(self.resolve_latest_block_id(block_id)?)?
         }
         .unwrap_or_default();
 
@@ -46,6 +49,50 @@ where
         Ok(uncle)
     }
 
+    /// Returns all transaction receipts in the given block.
+    ///
+    /// Returns `None` if the block wasn't found, or if it's the pending block.
+    pub(crate) async fn block_receipts(
+        &self,
+        block_id: BlockId,
+    ) -> EthResult<Option<Vec<TransactionReceipt>>> {
+        if block_id.is_pending() {
+            return Ok(None)
+        }
+
+        let block_id = self.resolve_latest_block_id(block_id)?;
+        let block_hash = match self.provider().block_hash_for_id(block_id)? {
+            Some(block_hash) => block_hash,
+            None => return Ok(None),
+        };
+
+        let (block, receipts) = futures::try_join!(
+            self.cache().get_block(block_hash),
+            self.cache().get_receipts(block_hash)
+        )?;
+        let (Some(block), Some(receipts)) = (block, receipts) else { return Ok(None) };
+
+        let block_number = block.number;
+        let base_fee = block.base_fee_per_gas;
+        block
+            .body
+            .into_iter()
+            .zip(receipts.iter())
+            .enumerate()
+            .map(|(index, (tx, receipt))| {
+                let meta = TransactionMeta {
+                    tx_hash: tx.hash(),
+                    index: index as u64,
+                    block_hash,
+                    block_number,
+                    base_fee,
+                };
+                build_transaction_receipt_with_block_receipts(tx, meta, receipt.clone(), &receipts)
+            })
+            .collect::<EthResult<Vec<_>>>()
+            .map(Some)
+    }
+
     /// Returns the number transactions in the given block.
     ///
     /// Returns `None` if the block does not exist
@@ -60,6 +107,7 @@ where
             return Ok(self.provider().pending_block()?.map(|block| block.body.len()))
         }
 
+        let block_id = self.resolve_latest_block_id(block_id)?;
         let block_hash = match self.provider().block_hash_for_id(block_id)? {
             Some(block_hash) => block_hash,
             None => return Ok(None),
@@ -80,6 +128,7 @@ where
             return Ok(self.provider().pending_block()?)
         }
 
+        let block_id = self.resolve_latest_block_id(block_id)?;
         let block_hash = match self.provider().block_hash_for_id(block_id)? {
             Some(block_hash) => block_hash,
             None => return Ok(None),
@@ -109,3 +158,177 @@ where
         Ok(Some(block.into()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::{cache::EthStateCache, gas_oracle::GasPriceOracle, EthApiSpec};
+    use reth_network_api::test_utils::NoopNetwork;
+    use reth_primitives::{
+        sign_message, BlockNumHash, BlockNumberOrTag, Header, Log, Receipt, Transaction,
+        TransactionKind, TransactionSigned, TxEip1559, TxLegacy, TxType, H256, U128, U256,
+    };
+    use reth_provider::test_utils::MockEthProvider;
+    use reth_tasks::TokioTaskExecutor;
+    use reth_transaction_pool::test_utils::testing_pool;
+    use revm_primitives::utilities::create_address;
+
+    #[tokio::test]
+    async fn latest_block_lag() {
+        let provider = MockEthProvider::default();
+        for number in 0..10u64 {
+            let header = Header { number, ..Default::default() }.seal_slow();
+            let block = reth_primitives::Block { header: header.header, ..Default::default() };
+            provider.add_block(header.hash, block);
+        }
+
+        for lag in [0, 3] {
+            let cache = EthStateCache::spawn(provider.clone(), Default::default());
+            let eth_api = EthApi::with_spawner(
+                provider.clone(),
+                testing_pool(),
+                NoopNetwork,
+                cache.clone(),
+                GasPriceOracle::new(provider.clone(), Default::default(), cache),
+                lag,
+                Box::<TokioTaskExecutor>::default(),
+            );
+
+            // `latest` resolves to `tip - lag`
+            assert_eq!(
+                eth_api.convert_block_number(BlockNumberOrTag::Latest).unwrap(),
+                Some(9 - lag)
+            );
+            // `eth_blockNumber` reports the lagged block
+            assert_eq!(EthApiSpec::chain_info(&eth_api).unwrap().best_number, 9 - lag);
+            let latest = eth_api.block(BlockNumberOrTag::Latest).await.unwrap().unwrap();
+            assert_eq!(latest.number, 9 - lag);
+        }
+    }
+
+    #[tokio::test]
+    async fn finalized_and_safe_tags() {
+        let provider = MockEthProvider::default();
+        let mut hashes = Vec::new();
+        for number in 0..10u64 {
+            let header = Header { number, ..Default::default() }.seal_slow();
+            let block = reth_primitives::Block { header: header.header, ..Default::default() };
+            provider.add_block(header.hash, block);
+            hashes.push(header.hash);
+        }
+
+        let cache = EthStateCache::spawn(provider.clone(), Default::default());
+        let eth_api = EthApi::new(
+            provider.clone(),
+            testing_pool(),
+            NoopNetwork,
+            cache.clone(),
+            GasPriceOracle::new(provider.clone(), Default::default(), cache),
+        );
+
+        // no forkchoice update received yet
+        for tag in [BlockNumberOrTag::Finalized, BlockNumberOrTag::Safe] {
+            assert_eq!(eth_api.convert_block_number(tag).unwrap(), None);
+            assert!(eth_api.block(tag).await.unwrap().is_none());
+            assert!(matches!(
+                eth_api.state_at_block_id(tag.into()),
+                Err(EthApiError::UnknownBlockNumber)
+            ));
+        }
+
+        provider.set_finalized_block(BlockNumHash::new(7, hashes[7]));
+        provider.set_safe_block(BlockNumHash::new(8, hashes[8]));
+        assert_eq!(eth_api.convert_block_number(BlockNumberOrTag::Finalized).unwrap(), Some(7));
+        assert_eq!(eth_api.convert_block_number(BlockNumberOrTag::Safe).unwrap(), Some(8));
+        let finalized = eth_api.block(BlockNumberOrTag::Finalized).await.unwrap().unwrap();
+        assert_eq!(finalized.hash, hashes[7]);
+        let safe = eth_api.block(BlockNumberOrTag::Safe).await.unwrap().unwrap();
+        assert_eq!(safe.hash, hashes[8]);
+    }
+
+    #[tokio::test]
+    async fn block_receipts() {
+        let secret = H256::from_low_u64_be(1);
+        let sign = |transaction: Transaction| {
+            let signature = sign_message(secret, transaction.signature_hash()).unwrap();
+            TransactionSigned::from_transaction_and_signature(transaction, signature)
+        };
+        let to = H256::random().into();
+        let create = sign(Transaction::Legacy(TxLegacy {
+            chain_id: Some(1),
+            gas_price: 150,
+            gas_limit: 100_000,
+            to: TransactionKind::Create,
+            ..Default::default()
+        }));
+        let call = sign(Transaction::Eip1559(TxEip1559 {
+            chain_id: 1,
+            nonce: 1,
+            max_priority_fee_per_gas: 5,
+            max_fee_per_gas: 200,
+            gas_limit: 21_000,
+            to: TransactionKind::Call(to),
+            ..Default::default()
+        }));
+        let sender = create.recover_signer().unwrap();
+
+        let log = Log { address: to, topics: vec![H256::random()], data: Default::default() };
+        let receipts = vec![
+            Receipt {
+                tx_type: TxType::Legacy,
+                success: true,
+                cumulative_gas_used: 50_000,
+                logs: vec![log.clone()],
+            },
+            Receipt {
+                tx_type: TxType::EIP1559,
+                success: false,
+                cumulative_gas_used: 71_000,
+                logs: vec![log.clone(), log],
+            },
+        ];
+
+        let header = Header { number: 1, base_fee_per_gas: Some(100), ..Default::default() };
+        let block_hash = header.hash_slow();
+        let block =
+            reth_primitives::Block { header, body: vec![create, call], ..Default::default() };
+        let provider = MockEthProvider::default();
+        provider.add_block(block_hash, block);
+        provider.add_receipts(block_hash, receipts);
+
+        let cache = EthStateCache::spawn(provider.clone(), Default::default());
+        let eth_api = EthApi::new(
+            provider.clone(),
+            testing_pool(),
+            NoopNetwork,
+            cache.clone(),
+            GasPriceOracle::new(provider, Default::default(), cache),
+        );
+
+        let receipts = eth_api.block_receipts(block_hash.into()).await.unwrap().unwrap();
+        assert_eq!(receipts.len(), 2);
+
+        assert_eq!(receipts[0].from, sender);
+        assert_eq!(receipts[0].to, None);
+        assert_eq!(receipts[0].contract_address, Some(create_address(sender, 0)));
+        assert_eq!(receipts[0].gas_used, Some(U256::from(50_000)));
+        assert_eq!(receipts[0].effective_gas_price, U128::from(150));
+
+        assert_eq!(receipts[1].to, Some(to));
+        assert_eq!(receipts[1].contract_address, None);
+        assert_eq!(receipts[1].cumulative_gas_used, U256::from(71_000));
+        assert_eq!(receipts[1].gas_used, Some(U256::from(21_000)));
+        assert_eq!(receipts[1].effective_gas_price, U128::from(105));
+        assert_eq!(
+            receipts[1].logs.iter().map(|log| log.log_index).collect::<Vec<_>>(),
+            vec![Some(U256::from(1)), Some(U256::from(2))]
+        );
+
+        assert!(eth_api
+            .block_receipts(BlockId::Number(BlockNumberOrTag::Pending))
+            .await
+            .unwrap()
+            .is_none());
+        assert!(eth_api.block_receipts(H256::random().into()).await.unwrap().is_none());
+    }
+}
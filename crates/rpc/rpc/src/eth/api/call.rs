@@ -4,8 +4,8 @@ use crate::{
     eth::{
         error::{ensure_success, EthApiError, EthResult, RevertError, RpcInvalidTransactionError},
         revm_utils::{
-            build_call_evm_env, cap_tx_gas_limit_with_caller_allowance, get_precompiles, inspect,
-            transact, EvmOverrides,
+            build_call_evm_env, call_request_intrinsic_gas, cap_tx_gas_limit_with_caller_allowance,
+            get_precompiles, inspect, prepare_call_env, transact, EvmOverrides,
         },
         EthTransactions,
     },
@@ -13,23 +13,27 @@ use crate::{
 };
 use ethers_core::utils::get_contract_address;
 use reth_network_api::NetworkInfo;
-use reth_primitives::{AccessList, BlockId, BlockNumberOrTag, Bytes, U256};
+use reth_primitives::{
+    AccessList, AccessListWithGasUsed, Address, BlockId, BlockNumberOrTag, Bytes, U256,
+};
 use reth_provider::{BlockProviderIdExt, EvmEnvProvider, StateProvider, StateProviderFactory};
 use reth_revm::{
     access_list::AccessListInspector,
     database::{State, SubState},
 };
-use reth_rpc_types::CallRequest;
+use reth_rpc_types::{
+    CallCreateOutput, CallOptions, CallOutputWithAccessList, CallRequest, CallResponse,
+};
 use reth_transaction_pool::TransactionPool;
 use revm::{
     db::{CacheDB, DatabaseRef},
-    primitives::{BlockEnv, CfgEnv, Env, ExecutionResult, Halt, TransactTo},
+    primitives::{BlockEnv, CfgEnv, Env, ExecutionResult, Halt, Output, TransactTo},
 };
 use tracing::trace;
 
-// Gas per transaction not creating a contract.
-const MIN_TRANSACTION_GAS: u64 = 21_000u64;
-const MIN_CREATE_GAS: u64 = 53_000u64;
+/// The maximum number of executions to find the access list of a transaction in
+/// `eth_createAccessList`, each execution uses the access list of the previous one.
+const MAX_ACCESS_LIST_ITERATIONS: usize = 100;
 
 impl<Provider, Pool, Network> EthApi<Provider, Pool, Network>
 where
@@ -48,22 +52,120 @@ where
         self.estimate_gas_with(cfg, block_env, request, state)
     }
 
-    /// Executes the call request (`eth_call`) and returns the output
-    pub(crate) async fn call(
+    /// Executes the call request (`eth_call`) with the non-standard [CallOptions] and returns the
+    /// response selected by them.
+    pub(crate) async fn call_with_options(
         &self,
         request: CallRequest,
         block_number: Option<BlockId>,
         overrides: EvmOverrides,
-    ) -> EthResult<Bytes> {
-        let (res, _env) = self
-            .transact_call_at(
-                request,
-                block_number.unwrap_or(BlockId::Number(BlockNumberOrTag::Latest)),
-                overrides,
-            )
+        options: CallOptions,
+    ) -> EthResult<CallResponse> {
+        let (cfg, block_env, at) = self
+            .evm_env_at(block_number.unwrap_or(BlockId::Number(BlockNumberOrTag::Latest)))
             .await?;
+        let state = self.state_at(at)?;
+        self.call_with_options_with(cfg, block_env, request, state, overrides, options)
+    }
+
+    /// Executes the call request with the state and the [CallOptions].
+    ///
+    /// Without any options the response is the output of the call, as for a plain `eth_call`, even
+    /// if the request creates a contract.
+    fn call_with_options_with<S>(
+        &self,
+        cfg: CfgEnv,
+        block: BlockEnv,
+        request: CallRequest,
+        state: S,
+        overrides: EvmOverrides,
+        options: CallOptions,
+    ) -> EthResult<CallResponse>
+    where
+        S: StateProvider,
+    {
+        if options.with_access_list {
+            let (output, access_list) =
+                self.call_with_access_list_with(cfg, block, request, state, overrides)?;
+            return Ok(CallResponse::WithAccessList(CallOutputWithAccessList {
+                output,
+                access_list,
+            }))
+        }
+
+        if options.with_created_contract && request.to.is_none() {
+            let (address, code) = self.call_create_with(cfg, block, request, state, overrides)?;
+            return Ok(CallResponse::Create(CallCreateOutput { address, code }))
+        }
+
+        let mut db = SubState::new(State::new(state));
+        let env = prepare_call_env(cfg, block, request, &mut db, overrides)?;
+        let (res, _env) = transact(&mut db, env)?;
+        ensure_success(res.result).map(CallResponse::Output)
+    }
+
+    /// Executes the contract creation request with the state.
+    ///
+    /// The address is the one the EVM created the contract at, which is derived from the sender
+    /// and its nonce in the state.
+    fn call_create_with<S>(
+        &self,
+        cfg: CfgEnv,
+        block: BlockEnv,
+        request: CallRequest,
+        state: S,
+        overrides: EvmOverrides,
+    ) -> EthResult<(Address, Bytes)>
+    where
+        S: StateProvider,
+    {
+        let mut db = SubState::new(State::new(state));
+        let env = prepare_call_env(cfg, block, request, &mut db, overrides)?;
+        let (res, _env) = transact(&mut db, env)?;
+        match res.result {
+            ExecutionResult::Success { output: Output::Create(code, Some(address)), .. } => {
+                Ok((address, code.into()))
+            }
+            result => Err(ensure_success(result).err().unwrap_or(EthApiError::InternalEthError)),
+        }
+    }
+
+    /// Executes the call request with the state and records the touched accounts and storage
+    /// slots.
+    ///
+    /// The access list is built the same way as for `eth_createAccessList`, so it excludes the
+    /// sender and the precompiles.
+    fn call_with_access_list_with<S>(
+        &self,
+        cfg: CfgEnv,
+        block: BlockEnv,
+        request: CallRequest,
+        state: S,
+        overrides: EvmOverrides,
+    ) -> EthResult<(Bytes, AccessList)>
+    where
+        S: StateProvider,
+    {
+        let from = request.from.unwrap_or_default();
+        let to = request.to;
+        let initial = request.access_list.clone().unwrap_or_default();
+
+        let mut db = SubState::new(State::new(state));
+        let env = prepare_call_env(cfg, block, request, &mut db, overrides)?;
+
+        let to = if let Some(to) = to {
+            to
+        } else {
+            let nonce = db.basic(from)?.unwrap_or_default().nonce;
+            get_contract_address(from, nonce).into()
+        };
 
-        ensure_success(res.result)
+        let precompiles = get_precompiles(&env.cfg.spec_id);
+        let mut inspector = AccessListInspector::new(initial, from, to, precompiles);
+        let (res, _env) = inspect(&mut db, env, &mut inspector)?;
+        let output = ensure_success(res.result)?;
+
+        Ok((output, inspector.into_access_list().canonicalize()))
     }
 
     /// Estimates the gas usage of the `request` with the state.
@@ -93,6 +195,9 @@ where
         let request_gas_price = request.gas_price;
         let env_gas_limit = block.gas_limit;
 
+        // the transaction can't succeed with less gas than it's charged before execution
+        let intrinsic_gas = call_request_intrinsic_gas(self.chain_spec(), &block, &request);
+
         // get the highest possible gas limit, either the request's set value or the currently
         // configured gas limit
         let mut highest_gas_limit = request.gas.unwrap_or(block.gas_limit);
@@ -115,7 +220,7 @@ where
                                 RpcInvalidTransactionError::InsufficientFundsForTransfer.into()
                             )
                         }
-                        return Ok(U256::from(MIN_TRANSACTION_GAS))
+                        return Ok(U256::from(intrinsic_gas))
                     }
                 }
             }
@@ -142,6 +247,11 @@ where
             }
         }
 
+        // reject calls that can't even cover their intrinsic gas
+        if highest_gas_limit < U256::from(intrinsic_gas) {
+            return Err(RpcInvalidTransactionError::GasTooLow.into())
+        }
+
         // if the provided gas limit is less than computed cap, use that
         let gas_limit = std::cmp::min(U256::from(env.tx.gas_limit), highest_gas_limit);
         env.block.gas_limit = gas_limit;
@@ -187,9 +297,9 @@ where
         // possible range NOTE: this is the gas the transaction used, which is less than the
         // transaction requires to succeed
         let gas_used = res.result.gas_used();
-        // the lowest value is capped by the gas it takes for a transfer
-        let mut lowest_gas_limit =
-            if env.tx.transact_to.is_create() { MIN_CREATE_GAS } else { MIN_TRANSACTION_GAS };
+        // the lowest value is capped by the intrinsic gas, the transaction is known to fail with
+        // less than that
+        let mut lowest_gas_limit = intrinsic_gas - 1;
         let mut highest_gas_limit: u64 = highest_gas_limit.try_into().unwrap_or(u64::MAX);
         // pick a point that's close to the estimated gas
         let mut mid_gas_limit = std::cmp::min(
@@ -249,33 +359,57 @@ where
         Ok(U256::from(highest_gas_limit))
     }
 
-    pub(crate) async fn create_access_list_at(
+    /// Creates the access list of the `request` at the [BlockId] (`eth_createAccessList`) and
+    /// estimates the gas usage of the `request` with the created access list.
+    ///
+    /// Defaults to the latest block if no block is provided.
+    pub(crate) async fn create_access_list(
         &self,
         request: CallRequest,
-        at: Option<BlockId>,
-    ) -> EthResult<AccessList> {
-        let block_id = at.unwrap_or(BlockId::Number(BlockNumberOrTag::Latest));
-        let (cfg, block, at) = self.evm_env_at(block_id).await?;
+        block_id: Option<BlockId>,
+    ) -> EthResult<AccessListWithGasUsed> {
+        let (cfg, block, at) =
+            self.evm_env_at(block_id.unwrap_or(BlockId::Number(BlockNumberOrTag::Latest))).await?;
         let state = self.state_at(at)?;
+        self.estimate_gas_with_access_list(cfg, block, request, state)
+    }
 
-        let mut env = build_call_evm_env(cfg, block, request.clone())?;
-
-        // we want to disable this in eth_createAccessList, since this is common practice used by
-        // other node impls and providers <https://github.com/foundry-rs/foundry/issues/4388>
-        env.cfg.disable_block_gas_limit = true;
-
-        // The basefee should be ignored for eth_createAccessList
-        // See:
-        // <https://github.com/ethereum/go-ethereum/blob/8990c92aea01ca07801597b00c0d83d4e2d9b811/internal/ethapi/api.go#L1476-L1476>
-        env.cfg.disable_base_fee = true;
+    /// Creates the access list of the `request` and estimates its gas usage with the access list
+    /// applied.
+    fn estimate_gas_with_access_list<S>(
+        &self,
+        cfg: CfgEnv,
+        block: BlockEnv,
+        mut request: CallRequest,
+        state: S,
+    ) -> EthResult<AccessListWithGasUsed>
+    where
+        S: StateProvider,
+    {
+        let access_list =
+            self.create_access_list_with(cfg.clone(), block.clone(), request.clone(), &state)?;
+        request.access_list = Some(access_list.clone());
+        let gas_used = self.estimate_gas_with(cfg, block, request, state)?;
+        Ok(AccessListWithGasUsed { access_list, gas_used })
+    }
 
+    /// Creates the access list of the `request` with the state.
+    ///
+    /// The access list changes the gas available to the call, which can change the accounts and
+    /// storage slots it touches. So the call is executed with the previously created access list
+    /// until the list stabilizes.
+    fn create_access_list_with<S>(
+        &self,
+        cfg: CfgEnv,
+        block: BlockEnv,
+        mut request: CallRequest,
+        state: S,
+    ) -> EthResult<AccessList>
+    where
+        S: StateProvider,
+    {
         let mut db = SubState::new(State::new(state));
 
-        if request.gas.is_none() && env.tx.gas_price > U256::ZERO {
-            // no gas limit was provided in the request, so we need to cap the request's gas limit
-            cap_tx_gas_limit_with_caller_allowance(&mut db, &mut env.tx)?;
-        }
-
         let from = request.from.unwrap_or_default();
         let to = if let Some(to) = request.to {
             to
@@ -283,24 +417,51 @@ where
             let nonce = db.basic(from)?.unwrap_or_default().nonce;
             get_contract_address(from, nonce).into()
         };
+        let precompiles = get_precompiles(&cfg.spec_id);
+
+        let mut access_list = request.access_list.clone().unwrap_or_default();
+        for _ in 0..MAX_ACCESS_LIST_ITERATIONS {
+            request.access_list = Some(access_list.clone());
+            let mut env = build_call_evm_env(cfg.clone(), block.clone(), request.clone())?;
+
+            // we want to disable this in eth_createAccessList, since this is common practice used
+            // by other node impls and providers <https://github.com/foundry-rs/foundry/issues/4388>
+            env.cfg.disable_block_gas_limit = true;
+
+            // The basefee should be ignored for eth_createAccessList
+            // See:
+            // <https://github.com/ethereum/go-ethereum/blob/8990c92aea01ca07801597b00c0d83d4e2d9b811/internal/ethapi/api.go#L1476-L1476>
+            env.cfg.disable_base_fee = true;
+
+            if request.gas.is_none() && env.tx.gas_price > U256::ZERO {
+                // no gas limit was provided in the request, so we need to cap the request's gas
+                // limit
+                cap_tx_gas_limit_with_caller_allowance(&mut db, &mut env.tx)?;
+            }
 
-        let initial = request.access_list.clone().unwrap_or_default();
-
-        let precompiles = get_precompiles(&env.cfg.spec_id);
-        let mut inspector = AccessListInspector::new(initial, from, to, precompiles);
-        let (result, _env) = inspect(&mut db, env, &mut inspector)?;
+            let mut inspector =
+                AccessListInspector::new(access_list.clone(), from, to, precompiles.clone());
+            let (result, _env) = inspect(&mut db, env, &mut inspector)?;
+
+            match result.result {
+                ExecutionResult::Halt { reason, .. } => Err(match reason {
+                    Halt::NonceOverflow => RpcInvalidTransactionError::NonceMaxValue,
+                    halt => RpcInvalidTransactionError::EvmHalt(halt),
+                }),
+                ExecutionResult::Revert { output, .. } => {
+                    Err(RpcInvalidTransactionError::Revert(RevertError::new(output)))
+                }
+                ExecutionResult::Success { .. } => Ok(()),
+            }?;
 
-        match result.result {
-            ExecutionResult::Halt { reason, .. } => Err(match reason {
-                Halt::NonceOverflow => RpcInvalidTransactionError::NonceMaxValue,
-                halt => RpcInvalidTransactionError::EvmHalt(halt),
-            }),
-            ExecutionResult::Revert { output, .. } => {
-                Err(RpcInvalidTransactionError::Revert(RevertError::new(output)))
+            let created = inspector.into_access_list().canonicalize();
+            if created == access_list {
+                return Ok(created)
             }
-            ExecutionResult::Success { .. } => Ok(()),
-        }?;
-        Ok(inspector.into_access_list())
+            access_list = created;
+        }
+
+        Err(EthApiError::AccessListNotConverged(MAX_ACCESS_LIST_ITERATIONS))
     }
 }
 
@@ -334,3 +495,331 @@ where
         ExecutionResult::Halt { reason, .. } => RpcInvalidTransactionError::EvmHalt(reason).into(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::{cache::EthStateCache, gas_oracle::GasPriceOracle};
+    use reth_network_api::test_utils::NoopNetwork;
+    use reth_primitives::{
+        contract::create_address, hex_literal::hex, AccessListItem, H256, MAINNET,
+    };
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+    use reth_transaction_pool::test_utils::testing_pool;
+
+    #[tokio::test]
+    async fn estimate_gas_with_created_access_list() {
+        let mock_provider = MockEthProvider::default();
+        let contract = Address::from_low_u64_be(0x1337);
+        // PUSH1 0x00 SLOAD POP STOP
+        let code = hex!("6000545000");
+        mock_provider.add_account(
+            contract,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(code.to_vec().into()),
+        );
+
+        let cache = EthStateCache::spawn(mock_provider.clone(), Default::default());
+        let eth_api = EthApi::new(
+            mock_provider.clone(),
+            testing_pool(),
+            NoopNetwork,
+            cache.clone(),
+            GasPriceOracle::new(mock_provider.clone(), Default::default(), cache),
+        );
+
+        let block = BlockEnv { gas_limit: U256::from(30_000_000), ..Default::default() };
+        let request = CallRequest {
+            from: Some(Address::from_low_u64_be(0x42)),
+            to: Some(contract),
+            ..Default::default()
+        };
+
+        let naive = eth_api
+            .estimate_gas_with(
+                CfgEnv::default(),
+                block.clone(),
+                request.clone(),
+                mock_provider.clone(),
+            )
+            .unwrap();
+        let with_access_list = eth_api
+            .estimate_gas_with_access_list(CfgEnv::default(), block, request, mock_provider)
+            .unwrap();
+
+        assert_eq!(
+            with_access_list.access_list,
+            AccessList(vec![AccessListItem {
+                address: contract,
+                storage_keys: vec![H256::zero()]
+            }])
+        );
+        // 21000 base gas, PUSH1 3, the cold SLOAD 2100 and POP 2
+        assert_eq!(naive, U256::from(23_105));
+        // the access list makes the SLOAD warm at 100 gas, but 2400 for the address and 1900 for
+        // the storage key are paid upfront
+        assert_eq!(with_access_list.gas_used, U256::from(25_405));
+    }
+
+    #[tokio::test]
+    async fn call_with_access_list_matches_created_access_list() {
+        let mock_provider = MockEthProvider::default();
+        let contract = Address::from_low_u64_be(0x1337);
+        // PUSH1 0x00 SLOAD PUSH1 0x00 MSTORE PUSH1 0x20 PUSH1 0x00 RETURN
+        let code = hex!("60005460005260206000f3");
+        mock_provider.add_account(
+            contract,
+            ExtendedAccount::new(0, U256::ZERO)
+                .with_bytecode(code.to_vec().into())
+                .extend_storage(vec![(H256::zero(), U256::from(42))]),
+        );
+
+        let cache = EthStateCache::spawn(mock_provider.clone(), Default::default());
+        let eth_api = EthApi::new(
+            mock_provider.clone(),
+            testing_pool(),
+            NoopNetwork,
+            cache.clone(),
+            GasPriceOracle::new(mock_provider.clone(), Default::default(), cache),
+        );
+
+        let block = BlockEnv { gas_limit: U256::from(30_000_000), ..Default::default() };
+        let request = CallRequest {
+            from: Some(Address::from_low_u64_be(0x42)),
+            to: Some(contract),
+            ..Default::default()
+        };
+
+        let (output, access_list) = eth_api
+            .call_with_access_list_with(
+                CfgEnv::default(),
+                block.clone(),
+                request.clone(),
+                mock_provider.clone(),
+                EvmOverrides::default(),
+            )
+            .unwrap();
+        let created = eth_api
+            .create_access_list_with(CfgEnv::default(), block, request, mock_provider)
+            .unwrap();
+
+        assert_eq!(output, Bytes::from(H256::from_low_u64_be(42).as_bytes().to_vec()));
+        assert_eq!(access_list, created);
+        assert_eq!(
+            access_list,
+            AccessList(vec![AccessListItem {
+                address: contract,
+                storage_keys: vec![H256::zero()]
+            }])
+        );
+    }
+
+    #[tokio::test]
+    async fn call_create_returns_contract_address_and_code() {
+        let mock_provider = MockEthProvider::default();
+        let sender = Address::from_low_u64_be(0x42);
+        mock_provider.add_account(sender, ExtendedAccount::new(5, U256::ZERO));
+
+        let cache = EthStateCache::spawn(mock_provider.clone(), Default::default());
+        let eth_api = EthApi::new(
+            mock_provider.clone(),
+            testing_pool(),
+            NoopNetwork,
+            cache.clone(),
+            GasPriceOracle::new(mock_provider.clone(), Default::default(), cache),
+        );
+
+        // PUSH1 0x2a PUSH1 0x00 MSTORE PUSH1 0x20 PUSH1 0x00 RETURN
+        let runtime = hex!("602a60005260206000f3");
+        // the constructor copies the runtime code to memory and returns it:
+        // PUSH1 0x0a PUSH1 0x0c PUSH1 0x00 CODECOPY PUSH1 0x0a PUSH1 0x00 RETURN
+        let mut init_code = hex!("600a600c600039600a6000f3").to_vec();
+        init_code.extend_from_slice(&runtime);
+
+        let block = BlockEnv { gas_limit: U256::from(30_000_000), ..Default::default() };
+        let request =
+            CallRequest { from: Some(sender), data: Some(init_code.into()), ..Default::default() };
+
+        let (address, code) = eth_api
+            .call_create_with(
+                CfgEnv::default(),
+                block,
+                request,
+                mock_provider,
+                EvmOverrides::default(),
+            )
+            .unwrap();
+
+        assert_eq!(address, create_address(sender, 5));
+        assert_eq!(code, Bytes::from(runtime.to_vec()));
+    }
+
+    #[tokio::test]
+    async fn call_create_response_is_opt_in() {
+        let mock_provider = MockEthProvider::default();
+        let sender = Address::from_low_u64_be(0x42);
+        mock_provider.add_account(sender, ExtendedAccount::new(5, U256::ZERO));
+
+        let cache = EthStateCache::spawn(mock_provider.clone(), Default::default());
+        let eth_api = EthApi::new(
+            mock_provider.clone(),
+            testing_pool(),
+            NoopNetwork,
+            cache.clone(),
+            GasPriceOracle::new(mock_provider.clone(), Default::default(), cache),
+        );
+
+        // returns the runtime code `0x602a60005260206000f3` from the constructor
+        let runtime = hex!("602a60005260206000f3");
+        let mut init_code = hex!("600a600c600039600a6000f3").to_vec();
+        init_code.extend_from_slice(&runtime);
+
+        let block = BlockEnv { gas_limit: U256::from(30_000_000), ..Default::default() };
+        let request =
+            CallRequest { from: Some(sender), data: Some(init_code.into()), ..Default::default() };
+
+        // by default the response is the hex encoded output, like for any other call
+        let response = eth_api
+            .call_with_options_with(
+                CfgEnv::default(),
+                block.clone(),
+                request.clone(),
+                mock_provider.clone(),
+                EvmOverrides::default(),
+                CallOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(response, CallResponse::Output(Bytes::from(runtime.to_vec())));
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            serde_json::json!("0x602a60005260206000f3")
+        );
+
+        let response = eth_api
+            .call_with_options_with(
+                CfgEnv::default(),
+                block,
+                request,
+                mock_provider,
+                EvmOverrides::default(),
+                CallOptions { with_created_contract: true, ..Default::default() },
+            )
+            .unwrap();
+        assert_eq!(
+            response,
+            CallResponse::Create(CallCreateOutput {
+                address: create_address(sender, 5),
+                code: Bytes::from(runtime.to_vec()),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn create_access_list_until_stable() {
+        let mock_provider = MockEthProvider::default();
+        let contract = Address::from_low_u64_be(0x1337);
+        // GAS PUSH3 75000 GT PUSH1 0x0d JUMPI PUSH1 0x01 SLOAD STOP JUMPDEST PUSH1 0x00 SLOAD STOP
+        //
+        // reads slot 1 with at least 75000 gas left, slot 0 otherwise
+        let code = hex!("5a620124f811600d57600154005b60005400");
+        mock_provider.add_account(
+            contract,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(code.to_vec().into()),
+        );
+
+        let cache = EthStateCache::spawn(mock_provider.clone(), Default::default());
+        let eth_api = EthApi::new(
+            mock_provider.clone(),
+            testing_pool(),
+            NoopNetwork,
+            cache.clone(),
+            GasPriceOracle::new(mock_provider.clone(), Default::default(), cache),
+        );
+
+        // the sender is excluded from the created list
+        let from = Address::from_low_u64_be(0x42);
+        let block = BlockEnv { gas_limit: U256::from(30_000_000), ..Default::default() };
+        let request = CallRequest {
+            from: Some(from),
+            to: Some(contract),
+            gas: Some(U256::from(100_000)),
+            access_list: Some(AccessList(vec![AccessListItem {
+                address: from,
+                storage_keys: vec![],
+            }])),
+            ..Default::default()
+        };
+
+        // the first execution reads slot 1, the cost of the access list then leaves too little gas
+        // for it and the second execution reads slot 0
+        let access_list = eth_api
+            .create_access_list_with(CfgEnv::default(), block, request, mock_provider)
+            .unwrap();
+        assert_eq!(
+            access_list,
+            AccessList(vec![AccessListItem {
+                address: contract,
+                storage_keys: vec![H256::zero(), H256::from_low_u64_be(1)]
+            }])
+        );
+    }
+
+    #[tokio::test]
+    async fn estimate_gas_with_access_list_intrinsic_gas() {
+        let mock_provider = MockEthProvider::default();
+        let contract = Address::from_low_u64_be(0x1337);
+        // PUSH1 0x00 SLOAD POP STOP
+        let code = hex!("6000545000");
+        mock_provider.add_account(
+            contract,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(code.to_vec().into()),
+        );
+
+        let cache = EthStateCache::spawn(mock_provider.clone(), Default::default());
+        let eth_api = EthApi::new(
+            mock_provider.clone(),
+            testing_pool(),
+            NoopNetwork,
+            cache.clone(),
+            GasPriceOracle::new(mock_provider.clone(), Default::default(), cache),
+        );
+
+        // 10 addresses with 10 storage keys each, including the slot read by the contract
+        let access_list = AccessList(
+            (0..10u64)
+                .map(|i| AccessListItem {
+                    address: if i == 0 { contract } else { Address::from_low_u64_be(i) },
+                    storage_keys: (0..10u64).map(H256::from_low_u64_be).collect(),
+                })
+                .collect(),
+        );
+        let block = BlockEnv { gas_limit: U256::from(30_000_000), ..Default::default() };
+        let request = CallRequest {
+            from: Some(Address::from_low_u64_be(0x42)),
+            to: Some(contract),
+            access_list: Some(access_list),
+            ..Default::default()
+        };
+
+        // 21000 base gas, 2400 per address and 1900 per storage key
+        let intrinsic_gas = 21_000 + 10 * 2_400 + 100 * 1_900;
+        assert_eq!(call_request_intrinsic_gas(&MAINNET, &block, &request), intrinsic_gas);
+
+        // the warm SLOAD costs 100 gas, PUSH1 3 and POP 2
+        let estimate = eth_api
+            .estimate_gas_with(
+                CfgEnv::default(),
+                block.clone(),
+                request.clone(),
+                mock_provider.clone(),
+            )
+            .unwrap();
+        assert_eq!(estimate, U256::from(intrinsic_gas + 105));
+
+        // a gas limit that doesn't cover the intrinsic gas is rejected
+        let request = CallRequest { gas: Some(U256::from(intrinsic_gas - 1)), ..request };
+        assert!(matches!(
+            eth_api.estimate_gas_with(CfgEnv::default(), block, request, mock_provider),
+            Err(EthApiError::InvalidTransaction(RpcInvalidTransactionError::GasTooLow))
+        ));
+    }
+}
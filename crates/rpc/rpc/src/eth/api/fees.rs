@@ -1,15 +1,31 @@
 //! Contains RPC handler implementations for fee history.
 
 use crate::{
-    eth::error::{EthApiError, EthResult, RpcInvalidTransactionError},
+    eth::error::{EthApiError, EthResult, FeeHistoryCacheFileError, RpcInvalidTransactionError},
     EthApi,
 };
 use reth_network_api::NetworkInfo;
-use reth_primitives::{BlockId, BlockNumberOrTag, U256};
+use reth_primitives::{
+    basefee::calculate_next_block_base_fee, BlockId, BlockNumber, BlockNumberOrTag, Header,
+    Receipt, SealedHeader, TransactionSigned, H256, U256,
+};
 use reth_provider::{BlockProviderIdExt, EvmEnvProvider, StateProviderFactory};
 use reth_rpc_types::{FeeHistory, FeeHistoryCacheItem, TxGasAndReward};
-use reth_transaction_pool::TransactionPool;
-use std::collections::BTreeMap;
+use reth_transaction_pool::{BestTransactions, PoolTransaction, TransactionPool};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, path::Path};
+
+/// A fee history cache entry as written by [EthApi::save_fee_history_cache].
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedFeeHistoryCacheItem {
+    /// Number of the block.
+    number: BlockNumber,
+    /// Hash of the canonical block at the time the entry was written.
+    block_hash: H256,
+    /// The cached item.
+    item: FeeHistoryCacheItem,
+}
 
 impl<Provider, Pool, Network> EthApi<Provider, Pool, Network>
 where
@@ -28,6 +44,49 @@ where
         Ok(suggested_tip + U256::from(base_fee))
     }
 
+    /// Writes all entries of the fee history cache to the file at `path`.
+    ///
+    /// Every entry is stored alongside the hash of its canonical block, so entries of blocks that
+    /// are no longer canonical can be dropped by [Self::load_fee_history_cache], e.g. on restart.
+    pub async fn save_fee_history_cache(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<(), FeeHistoryCacheFileError> {
+        let mut persisted = Vec::new();
+        for (number, item) in self.inner.fee_history_cache.entries().await {
+            if let Some(block_hash) = self.inner.provider.block_hash(number)? {
+                persisted.push(PersistedFeeHistoryCacheItem { number, block_hash, item });
+            }
+        }
+        std::fs::write(path, serde_json::to_vec(&persisted)?)?;
+        Ok(())
+    }
+
+    /// Loads the fee history cache entries written by [Self::save_fee_history_cache] from the
+    /// file at `path`.
+    ///
+    /// Entries of blocks that are no longer part of the canonical chain are dropped.
+    ///
+    /// Returns the number of restored entries.
+    pub async fn load_fee_history_cache(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<usize, FeeHistoryCacheFileError> {
+        let persisted: Vec<PersistedFeeHistoryCacheItem> =
+            serde_json::from_slice(&std::fs::read(path)?)?;
+
+        let mut entries = Vec::with_capacity(persisted.len());
+        for PersistedFeeHistoryCacheItem { number, block_hash, item } in persisted {
+            if self.inner.provider.block_hash(number)? == Some(block_hash) {
+                entries.push((number, item));
+            }
+        }
+
+        let restored = entries.len();
+        self.inner.fee_history_cache.extend(entries).await;
+        Ok(restored)
+    }
+
     /// Returns a suggestion for the priority fee (the tip)
     pub(crate) async fn suggested_priority_fee(&self) -> EthResult<U256> {
         self.gas_oracle().suggest_tip_cap().await
@@ -35,6 +94,9 @@ where
 
     /// Reports the fee history, for the given amount of blocks, up until the newest block
     /// provided.
+    ///
+    /// If the newest block is `pending`, the history ends with the pending block built from the
+    /// pool, see [Self::pending_fee_history].
     pub(crate) async fn fee_history(
         &self,
         block_count: u64,
@@ -45,35 +107,121 @@ where
             return Ok(FeeHistory::default())
         }
 
-        let Some(previous_to_end_block) = self.inner.provider.block_number_for_id(newest_block)? else { return Err(EthApiError::UnknownBlockNumber)};
-        let end_block = previous_to_end_block + 1;
+        // if not provided the percentiles are []
+        let reward_percentiles = reward_percentiles.unwrap_or_default();
 
-        if end_block < block_count {
-            return Err(EthApiError::InvalidBlockRange)
+        // percentiles must be within 0..=100 and monotonically increasing
+        for (i, percentile) in reward_percentiles.iter().enumerate() {
+            if !(0.0..=100.0).contains(percentile) {
+                return Err(EthApiError::InvalidRewardPercentile(*percentile))
+            }
+            if i > 0 && *percentile < reward_percentiles[i - 1] {
+                return Err(EthApiError::InvalidRewardPercentile(*percentile))
+            }
         }
 
-        let mut start_block = end_block - block_count;
-
-        if block_count == 1 {
-            start_block = previous_to_end_block;
+        if newest_block.is_pending() {
+            return self.pending_fee_history(block_count, &reward_percentiles).await
         }
 
-        // if not provided the percentiles are []
-        let reward_percentiles = reward_percentiles.unwrap_or_default();
+        let Some(end_block) = self.inner.provider.block_number_for_id(newest_block)? else {
+            return Err(EthApiError::UnknownBlockNumber)
+        };
+
+        self.fee_history_until(block_count, end_block, &reward_percentiles).await
+    }
 
-        // checks for rewardPercentile's sorted-ness
-        // check if any of rewardPercentile is greater than 100
-        // pre 1559 blocks, return 0 for baseFeePerGas
-        for window in reward_percentiles.windows(2) {
-            if window[0] >= window[1] {
-                return Err(EthApiError::InvalidRewardPercentile(window[1]))
+    /// Reports the fee history for the given amount of blocks up until the pending block.
+    ///
+    /// The pending block is built from the best transactions of the pool on top of the latest
+    /// block, see [Self::pending_block_from_pool]. It's never cached, since its contents change
+    /// with the pool.
+    async fn pending_fee_history(
+        &self,
+        block_count: u64,
+        reward_percentiles: &[f64],
+    ) -> EthResult<FeeHistory> {
+        let latest = self.inner.provider.latest_header()?.ok_or(EthApiError::UnknownBlockNumber)?;
+        let (pending, transactions) = self.pending_block_from_pool(&latest);
+
+        let mut fee_history = if block_count > 1 {
+            // the base fee projected from the latest block is the base fee of the pending block
+            self.fee_history_until(block_count - 1, latest.number, reward_percentiles).await?
+        } else {
+            FeeHistory {
+                base_fee_per_gas: vec![U256::from(pending.base_fee_per_gas.unwrap_or_default())],
+                gas_used_ratio: Vec::new(),
+                oldest_block: U256::from(pending.number),
+                reward: (!reward_percentiles.is_empty()).then(Vec::new),
             }
+        };
+
+        fee_history
+            .base_fee_per_gas
+            .push(U256::from(pending.next_block_base_fee().unwrap_or_default()));
+        fee_history.gas_used_ratio.push(pending.gas_used as f64 / pending.gas_limit as f64);
+        if let Some(reward) = &mut fee_history.reward {
+            reward.push(rewards_at_percentiles(reward_percentiles, pending.gas_used, transactions));
+        }
+
+        Ok(fee_history)
+    }
 
-            if window[0] < 0.0 || window[0] > 100.0 {
-                return Err(EthApiError::InvalidRewardPercentile(window[0]))
+    /// Builds the header of the pending block from the best transactions of the pool, on top of
+    /// the given latest block.
+    ///
+    /// The transactions aren't executed, so every included transaction is assumed to use its
+    /// entire gas limit. Returns the header alongside the gas used and the reward of every
+    /// included transaction.
+    fn pending_block_from_pool(&self, latest: &SealedHeader) -> (Header, Vec<TxGasAndReward>) {
+        let mut pending = Header {
+            parent_hash: latest.hash,
+            number: latest.number + 1,
+            gas_limit: latest.gas_limit,
+            base_fee_per_gas: latest.next_block_base_fee(),
+            ..Default::default()
+        };
+        let base_fee = pending.base_fee_per_gas.unwrap_or_default() as u128;
+
+        let mut transactions = Vec::new();
+        let mut best_txs = self.pool().best_transactions();
+        while let Some(pool_tx) = best_txs.next() {
+            // skip transactions that don't fit into the block or can't pay its base fee, which
+            // also skips all of their descendants
+            let max_fee_per_gas = pool_tx.max_fee_per_gas();
+            if pending.gas_used + pool_tx.gas_limit() > pending.gas_limit ||
+                max_fee_per_gas < base_fee
+            {
+                best_txs.mark_invalid(&pool_tx);
+                continue
             }
+
+            let max_reward = max_fee_per_gas - base_fee;
+            let reward = pool_tx
+                .transaction
+                .max_priority_fee_per_gas()
+                .map_or(max_reward, |max_priority_fee| max_priority_fee.min(max_reward));
+
+            pending.gas_used += pool_tx.gas_limit();
+            transactions.push(TxGasAndReward { gas_used: pool_tx.gas_limit() as u128, reward });
+        }
+
+        (pending, transactions)
+    }
+
+    /// Reports the fee history for the given amount of blocks up until the given block number.
+    async fn fee_history_until(
+        &self,
+        block_count: u64,
+        end_block: BlockNumber,
+        reward_percentiles: &[f64],
+    ) -> EthResult<FeeHistory> {
+        if block_count > end_block + 1 {
+            return Err(EthApiError::InvalidBlockRange)
         }
 
+        let start_block = end_block + 1 - block_count;
+
         let mut fee_history_cache = self.inner.fee_history_cache.0.lock().await;
 
         // Sorted map that's populated in two rounds:
@@ -84,8 +232,16 @@ where
         let mut first_non_cached_block = None;
         let mut last_non_cached_block = None;
         for block in start_block..=end_block {
-            // Check if block exists in cache, and move it to the head of the list if so
-            if let Some(fee_history_cache_item) = fee_history_cache.get(&block) {
+            // Rewards depend on the requested percentiles, so cached entries can only be used if
+            // no rewards were requested
+            let cached = if reward_percentiles.is_empty() {
+                // Check if block exists in cache, and move it to the head of the list if so
+                fee_history_cache.get(&block)
+            } else {
+                None
+            };
+
+            if let Some(fee_history_cache_item) = cached {
                 fee_history_cache_items.insert(block, fee_history_cache_item.clone());
             } else {
                 // If block doesn't exist in cache, set it as a first non-cached block to query it
@@ -102,61 +258,52 @@ where
             (first_non_cached_block, last_non_cached_block)
         {
             let header_range = start_block..=end_block;
+            let num_blocks = (end_block - start_block + 1) as usize;
 
             let headers = self.inner.provider.headers_range(header_range.clone())?;
-            let transactions_by_block =
-                self.inner.provider.transactions_by_block_range(header_range)?;
-
-            let header_tx = headers.iter().zip(&transactions_by_block);
-
-            // We should receive exactly the amount of blocks missing from the cache
-            if headers.len() != (end_block - start_block + 1) as usize {
-                return Err(EthApiError::InvalidBlockRange)
-            }
 
             // We should receive exactly the amount of blocks missing from the cache
-            if transactions_by_block.len() != (end_block - start_block + 1) as usize {
+            if headers.len() != num_blocks {
                 return Err(EthApiError::InvalidBlockRange)
             }
 
-            for (header, transactions) in header_tx {
-                let base_fee_per_gas: U256 = header.base_fee_per_gas.
-                        unwrap_or_default(). // Zero for pre-EIP-1559 blocks
-                        try_into().unwrap(); // u64 -> U256 won't fail
-                let gas_used_ratio = header.gas_used as f64 / header.gas_limit as f64;
-
-                let mut sorter = Vec::with_capacity(transactions.len());
-                for transaction in transactions.iter() {
-                    let reward = transaction
-                        .effective_gas_tip(header.base_fee_per_gas)
-                        .ok_or(RpcInvalidTransactionError::FeeCapTooLow)?;
-
-                    sorter.push(TxGasAndReward { gas_used: header.gas_used as u128, reward })
+            let rewards_by_block = if reward_percentiles.is_empty() {
+                vec![None; num_blocks]
+            } else {
+                let transactions_by_block =
+                    self.inner.provider.transactions_by_block_range(header_range.clone())?;
+                let receipts_by_block =
+                    self.inner.provider.receipts_by_block_range(header_range)?;
+
+                // We should receive exactly the amount of blocks missing from the cache
+                if transactions_by_block.len() != num_blocks ||
+                    receipts_by_block.len() != num_blocks
+                {
+                    return Err(EthApiError::InvalidBlockRange)
                 }
 
-                sorter.sort();
-
-                let mut rewards = Vec::with_capacity(reward_percentiles.len());
-                let mut sum_gas_used = sorter[0].gas_used;
-                let mut tx_index = 0;
-
-                for percentile in reward_percentiles.iter() {
-                    let threshold_gas_used = (header.gas_used as f64) * percentile / 100_f64;
-                    while sum_gas_used < threshold_gas_used as u128 && tx_index < transactions.len()
-                    {
-                        tx_index += 1;
-                        sum_gas_used += sorter[tx_index].gas_used;
-                    }
-
-                    rewards.push(U256::from(sorter[tx_index].reward));
-                }
+                headers
+                    .iter()
+                    .zip(&transactions_by_block)
+                    .zip(&receipts_by_block)
+                    .map(|((header, transactions), (_, receipts))| {
+                        calculate_reward_percentiles(
+                            reward_percentiles,
+                            header,
+                            transactions,
+                            receipts,
+                        )
+                        .map(Some)
+                    })
+                    .collect::<EthResult<Vec<_>>>()?
+            };
+
+            for (header, reward) in headers.iter().zip(rewards_by_block) {
+                let base_fee_per_gas = U256::from(header.base_fee_per_gas.unwrap_or_default()); // Zero for pre-EIP-1559 blocks
+                let gas_used_ratio = header.gas_used as f64 / header.gas_limit as f64;
 
-                let fee_history_cache_item = FeeHistoryCacheItem {
-                    hash: None,
-                    base_fee_per_gas,
-                    gas_used_ratio,
-                    reward: Some(rewards),
-                };
+                let fee_history_cache_item =
+                    FeeHistoryCacheItem { hash: None, base_fee_per_gas, gas_used_ratio, reward };
 
                 // Insert missing cache entries in the map for further response composition from
                 // it
@@ -166,6 +313,23 @@ where
             }
         }
 
+        // the base fee of the block after the newest block is derived from the newest block
+        let newest_header = self
+            .inner
+            .provider
+            .header_by_number(end_block)?
+            .ok_or(EthApiError::UnknownBlockNumber)?;
+        let next_base_fee_per_gas = newest_header
+            .base_fee_per_gas
+            .map(|base_fee| {
+                calculate_next_block_base_fee(
+                    newest_header.gas_used,
+                    newest_header.gas_limit,
+                    base_fee,
+                )
+            })
+            .unwrap_or_default();
+
         // get the first block in the range from the db
         let oldest_block_hash =
             self.inner.provider.block_hash(start_block)?.ok_or(EthApiError::UnknownBlockNumber)?;
@@ -181,24 +345,341 @@ where
 
         // `fee_history_cache_items` now contains full requested block range (populated from both
         // cache and database), so we can iterate over it in order and populate the response fields
-        let base_fee_per_gas =
+        let mut base_fee_per_gas: Vec<U256> =
             fee_history_cache_items.values().map(|item| item.base_fee_per_gas).collect();
+        base_fee_per_gas.push(U256::from(next_base_fee_per_gas));
 
-        let mut gas_used_ratio: Vec<f64> =
+        let gas_used_ratio =
             fee_history_cache_items.values().map(|item| item.gas_used_ratio).collect();
 
-        let mut rewards: Vec<Vec<_>> =
-            fee_history_cache_items.values().filter_map(|item| item.reward.clone()).collect();
-
-        // gasUsedRatio doesn't have data for next block in this case the last block
-        gas_used_ratio.pop();
-        rewards.pop();
+        let reward = if reward_percentiles.is_empty() {
+            None
+        } else {
+            Some(fee_history_cache_items.into_values().filter_map(|item| item.reward).collect())
+        };
 
         Ok(FeeHistory {
             base_fee_per_gas,
             gas_used_ratio,
             oldest_block: U256::from(start_block),
-            reward: Some(rewards),
+            reward,
         })
     }
 }
+
+/// Calculates the effective priority fees at the given percentiles of the gas used in the block.
+///
+/// The transactions are sorted by their effective priority fee, and for every percentile the fee
+/// of the transaction at which the cumulative gas used reaches that percentile of the block's gas
+/// used is sampled. All zeroes are returned for empty blocks.
+///
+/// See also: <https://github.com/ethereum/go-ethereum/blob/ee8e83fa5f6cb261dad2ed0a7bbcde4930c41e6c/eth/gasprice/feehistory.go#L92>
+fn calculate_reward_percentiles(
+    percentiles: &[f64],
+    header: &Header,
+    transactions: &[TransactionSigned],
+    receipts: &[Receipt],
+) -> EthResult<Vec<U256>> {
+    if transactions.is_empty() {
+        return Ok(vec![U256::ZERO; percentiles.len()])
+    }
+    if transactions.len() != receipts.len() {
+        return Err(EthApiError::InvalidBlockRange)
+    }
+
+    let mut sorter = Vec::with_capacity(transactions.len());
+    let mut previous_cumulative_gas_used = 0;
+    for (transaction, receipt) in transactions.iter().zip(receipts) {
+        let gas_used = receipt.cumulative_gas_used - previous_cumulative_gas_used;
+        previous_cumulative_gas_used = receipt.cumulative_gas_used;
+
+        let reward = transaction
+            .effective_gas_tip(header.base_fee_per_gas)
+            .ok_or(RpcInvalidTransactionError::FeeCapTooLow)?;
+
+        sorter.push(TxGasAndReward { gas_used: gas_used as u128, reward })
+    }
+
+    Ok(rewards_at_percentiles(percentiles, header.gas_used, sorter))
+}
+
+/// Samples the rewards of the given transactions at the given percentiles of the block's gas used.
+///
+/// All zeroes are returned if there are no transactions.
+fn rewards_at_percentiles(
+    percentiles: &[f64],
+    gas_used: u64,
+    mut sorter: Vec<TxGasAndReward>,
+) -> Vec<U256> {
+    if sorter.is_empty() {
+        return vec![U256::ZERO; percentiles.len()]
+    }
+
+    sorter.sort();
+
+    let mut rewards = Vec::with_capacity(percentiles.len());
+    let mut sum_gas_used = sorter[0].gas_used;
+    let mut tx_index = 0;
+
+    for percentile in percentiles {
+        let threshold_gas_used = (gas_used as f64 * percentile / 100_f64) as u128;
+        while sum_gas_used < threshold_gas_used && tx_index < sorter.len() - 1 {
+            tx_index += 1;
+            sum_gas_used += sorter[tx_index].gas_used;
+        }
+
+        rewards.push(U256::from(sorter[tx_index].reward));
+    }
+
+    rewards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::{cache::EthStateCache, gas_oracle::GasPriceOracle};
+    use reth_network_api::test_utils::NoopNetwork;
+    use reth_primitives::{Block, Transaction, TxEip1559, TxType};
+    use reth_provider::test_utils::MockEthProvider;
+    use reth_transaction_pool::{
+        test_utils::{testing_pool, MockOrdering, MockTransaction, TestPool},
+        Pool, TransactionOrigin, TransactionValidationOutcome, TransactionValidator,
+    };
+
+    fn build_eth_api(provider: MockEthProvider) -> EthApi<MockEthProvider, TestPool, NoopNetwork> {
+        let cache = EthStateCache::spawn(provider.clone(), Default::default());
+        EthApi::new(
+            provider.clone(),
+            testing_pool(),
+            NoopNetwork,
+            cache.clone(),
+            GasPriceOracle::new(provider, Default::default(), cache),
+        )
+    }
+
+    fn add_block(provider: &MockEthProvider, number: BlockNumber) -> H256 {
+        let hash = H256::random();
+        let header = Header { number, ..Default::default() };
+        provider.add_block(hash, Block { header, ..Default::default() });
+        hash
+    }
+
+    #[tokio::test]
+    async fn persist_fee_history_cache() {
+        let provider = MockEthProvider::default();
+        let hashes = (0..5).map(|number| add_block(&provider, number)).collect::<Vec<_>>();
+
+        let eth_api = build_eth_api(provider);
+        let items = (1..5)
+            .map(|number| {
+                let item = FeeHistoryCacheItem {
+                    hash: None,
+                    base_fee_per_gas: U256::from(number),
+                    gas_used_ratio: number as f64 / 10.0,
+                    reward: Some(vec![U256::from(number * 2)]),
+                };
+                (number, item)
+            })
+            .collect::<Vec<_>>();
+        eth_api.inner.fee_history_cache.extend(items.clone()).await;
+
+        let path = std::env::temp_dir().join(format!("fee-history-{:x}.json", H256::random()));
+        eth_api.save_fee_history_cache(&path).await.unwrap();
+
+        // blocks 3 and 4 were reorged out while the node was down
+        let provider = MockEthProvider::default();
+        for (number, hash) in hashes.iter().enumerate().take(3) {
+            let header = Header { number: number as u64, ..Default::default() };
+            provider.add_block(*hash, Block { header, ..Default::default() });
+        }
+        add_block(&provider, 3);
+        add_block(&provider, 4);
+
+        let eth_api = build_eth_api(provider);
+        assert_eq!(eth_api.load_fee_history_cache(&path).await.unwrap(), 2);
+        std::fs::remove_file(&path).unwrap();
+
+        let mut restored = eth_api.inner.fee_history_cache.entries().await;
+        restored.sort_by_key(|(number, _)| *number);
+        assert_eq!(restored, items[..2]);
+    }
+
+    /// Returns an EIP-1559 transaction that pays the given tip at the given base fee.
+    fn transaction_with_tip(base_fee: u64, tip: u128) -> TransactionSigned {
+        TransactionSigned {
+            transaction: Transaction::Eip1559(TxEip1559 {
+                max_priority_fee_per_gas: tip,
+                max_fee_per_gas: base_fee as u128 + tip,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a block with transactions that pay the given tips and use the given amounts of gas.
+    fn add_block_with_transactions(
+        provider: &MockEthProvider,
+        number: BlockNumber,
+        transactions: &[(u128, u64)],
+    ) {
+        let base_fee = 1000;
+        let mut cumulative_gas_used = 0;
+        let mut body = Vec::new();
+        let mut receipts = Vec::new();
+        for (tip, gas_used) in transactions {
+            cumulative_gas_used += gas_used;
+            body.push(transaction_with_tip(base_fee, *tip));
+            receipts.push(Receipt {
+                tx_type: TxType::EIP1559,
+                success: true,
+                cumulative_gas_used,
+                logs: vec![],
+            });
+        }
+
+        let header = Header {
+            number,
+            gas_limit: 200,
+            gas_used: cumulative_gas_used,
+            base_fee_per_gas: Some(base_fee),
+            ..Default::default()
+        };
+        let hash = H256::random();
+        provider.add_block(hash, Block { header, body, ..Default::default() });
+        provider.add_receipts(hash, receipts);
+    }
+
+    #[test]
+    fn reward_percentiles() {
+        let header = Header { gas_used: 100, base_fee_per_gas: Some(10), ..Default::default() };
+        let transactions =
+            [(5, 50), (1, 30), (3, 20)].map(|(tip, _)| transaction_with_tip(10, tip));
+        let receipts = [50, 80, 100].map(|cumulative_gas_used| Receipt {
+            tx_type: TxType::EIP1559,
+            success: true,
+            cumulative_gas_used,
+            logs: vec![],
+        });
+
+        // sorted by tip, the transactions use 30, 20 and 50 gas
+        let rewards = calculate_reward_percentiles(
+            &[0.0, 30.0, 31.0, 50.0, 100.0],
+            &header,
+            &transactions,
+            &receipts,
+        )
+        .unwrap();
+        assert_eq!(rewards, [1u64, 1, 3, 3, 5].map(U256::from));
+
+        // all zeroes for an empty block
+        let rewards = calculate_reward_percentiles(&[10.0, 90.0], &header, &[], &[]).unwrap();
+        assert_eq!(rewards, vec![U256::ZERO; 2]);
+    }
+
+    #[tokio::test]
+    async fn fee_history_rewards() {
+        let provider = MockEthProvider::default();
+        add_block_with_transactions(&provider, 0, &[]);
+        add_block_with_transactions(&provider, 1, &[(2, 100), (1, 50)]);
+        add_block_with_transactions(&provider, 2, &[(4, 25), (8, 50)]);
+
+        let eth_api = build_eth_api(provider);
+        let newest_block = BlockId::Number(BlockNumberOrTag::Number(2));
+
+        let fee_history =
+            eth_api.fee_history(2, newest_block, Some(vec![25.0, 50.0, 75.0])).await.unwrap();
+        assert_eq!(fee_history.oldest_block, U256::from(1));
+        assert_eq!(
+            fee_history.reward,
+            Some(vec![
+                [1u64, 2, 2].map(U256::from).to_vec(),
+                [4u64, 8, 8].map(U256::from).to_vec()
+            ])
+        );
+        // the base fee of the next block decreases, since block 2 is below the gas target
+        assert_eq!(fee_history.base_fee_per_gas, [1000u64, 1000, 969].map(U256::from));
+        assert_eq!(fee_history.gas_used_ratio, vec![0.75, 0.375]);
+
+        let fee_history = eth_api.fee_history(3, newest_block, None).await.unwrap();
+        assert_eq!(fee_history.reward, None);
+        assert_eq!(fee_history.base_fee_per_gas.len(), 4);
+
+        for percentiles in [vec![-1.0], vec![100.5], vec![50.0, 25.0]] {
+            assert!(matches!(
+                eth_api.fee_history(2, newest_block, Some(percentiles)).await,
+                Err(EthApiError::InvalidRewardPercentile(_))
+            ));
+        }
+        assert!(eth_api.fee_history(2, newest_block, Some(vec![50.0, 50.0])).await.is_ok());
+    }
+
+    /// A validator that accepts all transactions, with senders that can pay for all of them.
+    #[derive(Debug)]
+    struct FundedValidator;
+
+    #[async_trait::async_trait]
+    impl TransactionValidator for FundedValidator {
+        type Transaction = MockTransaction;
+
+        async fn validate_transaction(
+            &self,
+            _origin: TransactionOrigin,
+            transaction: MockTransaction,
+        ) -> TransactionValidationOutcome<MockTransaction> {
+            TransactionValidationOutcome::Valid { balance: U256::MAX, state_nonce: 0, transaction }
+        }
+    }
+
+    #[tokio::test]
+    async fn fee_history_pending_block() {
+        let provider = MockEthProvider::default();
+        add_block_with_transactions(&provider, 0, &[]);
+        add_block_with_transactions(&provider, 1, &[(2, 100), (1, 50)]);
+
+        let pool = Pool::new(FundedValidator, MockOrdering::default(), Default::default());
+        let cache = EthStateCache::spawn(provider.clone(), Default::default());
+        let eth_api = EthApi::new(
+            provider.clone(),
+            pool.clone(),
+            NoopNetwork,
+            cache.clone(),
+            GasPriceOracle::new(provider, Default::default(), cache),
+        );
+
+        // block 1 is above the gas target, so the base fee of the pending block increases
+        let base_fee = 1062;
+        for (tip, gas_limit) in [(5, 120), (3, 60), (1, 50)] {
+            let transaction = MockTransaction::eip1559()
+                .with_gas_limit(gas_limit)
+                .with_max_fee(base_fee + tip)
+                .with_priority_fee(tip);
+            pool.add_external_transaction(transaction).await.unwrap();
+        }
+        // can't pay the base fee of the pending block
+        let transaction =
+            MockTransaction::eip1559().with_gas_limit(10).with_max_fee(1000).with_priority_fee(50);
+        pool.add_external_transaction(transaction).await.unwrap();
+
+        let pending = BlockId::Number(BlockNumberOrTag::Pending);
+
+        let fee_history =
+            eth_api.fee_history(2, pending, Some(vec![25.0, 50.0, 100.0])).await.unwrap();
+        assert_eq!(fee_history.oldest_block, U256::from(1));
+        assert_eq!(fee_history.base_fee_per_gas, [1000, base_fee, 1168].map(U256::from));
+        // the transaction with a tip of 1 doesn't fit into the pending block anymore
+        assert_eq!(fee_history.gas_used_ratio, vec![0.75, 0.9]);
+        assert_eq!(
+            fee_history.reward,
+            Some(vec![
+                [1u64, 2, 2].map(U256::from).to_vec(),
+                [3u64, 5, 5].map(U256::from).to_vec()
+            ])
+        );
+
+        let fee_history = eth_api.fee_history(1, pending, None).await.unwrap();
+        assert_eq!(fee_history.oldest_block, U256::from(2));
+        assert_eq!(fee_history.base_fee_per_gas, [base_fee, 1168].map(U256::from));
+        assert_eq!(fee_history.gas_used_ratio, vec![0.9]);
+        assert_eq!(fee_history.reward, None);
+    }
+}
@@ -8,12 +8,15 @@ use crate::eth::{
     error::{EthApiError, EthResult},
     gas_oracle::GasPriceOracle,
     signer::EthSigner,
+    utils::{lagged_chain_info, resolve_latest_tag},
 };
 use async_trait::async_trait;
 use reth_interfaces::Result;
 use reth_network_api::NetworkInfo;
-use reth_primitives::{Address, BlockId, BlockNumberOrTag, ChainInfo, H256, U256, U64};
-use reth_provider::{BlockProviderIdExt, EvmEnvProvider, StateProviderBox, StateProviderFactory};
+use reth_primitives::{Address, BlockId, BlockNumberOrTag, ChainInfo, ChainSpec, H256, U256, U64};
+use reth_provider::{
+    BlockProviderIdExt, ChainSpecProvider, EvmEnvProvider, StateProviderBox, StateProviderFactory,
+};
 use reth_rpc_types::{FeeHistoryCache, SyncInfo, SyncStatus};
 use reth_tasks::{TaskSpawner, TokioTaskExecutor};
 use reth_transaction_pool::TransactionPool;
@@ -72,7 +75,7 @@ pub struct EthApi<Provider, Pool, Network> {
 
 impl<Provider, Pool, Network> EthApi<Provider, Pool, Network>
 where
-    Provider: BlockProviderIdExt,
+    Provider: BlockProviderIdExt + ChainSpecProvider,
 {
     /// Creates a new, shareable instance using the default tokio task spawner.
     pub fn new(
@@ -88,17 +91,21 @@ where
             network,
             eth_cache,
             gas_oracle,
+            0,
             Box::<TokioTaskExecutor>::default(),
         )
     }
 
     /// Creates a new, shareable instance.
+    ///
+    /// The `latest` block tag is served `latest_block_lag` blocks behind the tip of the chain.
     pub fn with_spawner(
         provider: Provider,
         pool: Pool,
         network: Network,
         eth_cache: EthStateCache,
         gas_oracle: GasPriceOracle<Provider>,
+        latest_block_lag: u64,
         task_spawner: Box<dyn TaskSpawner>,
     ) -> Self {
         // get the block number of the latest block
@@ -109,13 +116,20 @@ where
             .map(|header| header.number)
             .unwrap_or_default();
 
+        // the chain spec never changes, so it's read from the provider only once
+        let chain_spec = provider.chain_spec();
+        let chain_id = U64::from(chain_spec.chain.id());
+
         let inner = EthApiInner {
+            chain_id,
+            chain_spec,
             provider,
             pool,
             network,
             signers: Default::default(),
             eth_cache,
             gas_oracle,
+            latest_block_lag,
             starting_block: U256::from(latest_block),
             task_spawner,
             fee_history_cache: FeeHistoryCache::new(
@@ -155,6 +169,11 @@ where
         &self.inner.gas_oracle
     }
 
+    /// Returns the chain spec of the provider
+    pub(crate) fn chain_spec(&self) -> &ChainSpec {
+        &self.inner.chain_spec
+    }
+
     /// Returns the inner `Provider`
     pub fn provider(&self) -> &Provider {
         &self.inner.provider
@@ -178,7 +197,23 @@ where
     Provider: BlockProviderIdExt + StateProviderFactory + EvmEnvProvider + 'static,
 {
     fn convert_block_number(&self, num: BlockNumberOrTag) -> Result<Option<u64>> {
-        self.provider().convert_block_number(num)
+        self.provider().convert_block_number(self.resolve_latest_tag(num)?)
+    }
+
+    /// Replaces [BlockNumberOrTag::Latest] with the block the configured `latest_block_lag` blocks
+    /// behind the tip of the chain.
+    ///
+    /// Other tags are returned unchanged.
+    fn resolve_latest_tag(&self, num: BlockNumberOrTag) -> Result<BlockNumberOrTag> {
+        resolve_latest_tag(self.provider(), num, self.inner.latest_block_lag)
+    }
+
+    /// Replaces [BlockNumberOrTag::Latest] in the [BlockId], see [Self::resolve_latest_tag].
+    pub(crate) fn resolve_latest_block_id(&self, at: BlockId) -> Result<BlockId> {
+        match at {
+            BlockId::Number(num) => Ok(BlockId::Number(self.resolve_latest_tag(num)?)),
+            at => Ok(at),
+        }
     }
 
     /// Returns the state at the given [BlockId] enum.
@@ -198,6 +233,8 @@ where
     ) -> EthResult<StateProviderBox<'_>> {
         if let Some(block_id) = block_id {
             self.state_at_block_id(block_id)
+        } else if self.inner.latest_block_lag > 0 {
+            self.state_at_block_id(BlockNumberOrTag::Latest.into())
         } else {
             Ok(self.latest_state()?)
         }
@@ -217,16 +254,19 @@ where
         }
     }
 
-    /// Returns the state at the given block number
+    /// Returns the state at the given block hash
+    ///
+    /// Returns an error if the block hash is not part of the canonical chain.
     pub fn state_at_hash(&self, block_hash: H256) -> Result<StateProviderBox<'_>> {
         self.provider().history_by_block_hash(block_hash)
     }
 
     /// Returns the state at the given block number
     pub fn state_at_number(&self, block_number: u64) -> Result<StateProviderBox<'_>> {
-        match self.convert_block_number(BlockNumberOrTag::Latest)? {
-            Some(num) if num == block_number => self.latest_state(),
-            _ => self.provider().history_by_block_number(block_number),
+        if self.provider().best_block_number()? == block_number {
+            self.latest_state()
+        } else {
+            self.provider().history_by_block_number(block_number)
         }
     }
 
@@ -265,12 +305,12 @@ where
 
     /// Returns the chain id
     fn chain_id(&self) -> U64 {
-        U64::from(self.network().chain_id())
+        self.inner.chain_id
     }
 
-    /// Returns the current info for the chain
+    /// Returns the current info for the chain, with the configured `latest_block_lag` applied
     fn chain_info(&self) -> Result<ChainInfo> {
-        self.provider().chain_info()
+        lagged_chain_info(self.provider(), self.inner.latest_block_lag)
     }
 
     fn accounts(&self) -> Vec<Address> {
@@ -309,12 +349,18 @@ struct EthApiInner<Provider, Pool, Network> {
     provider: Provider,
     /// An interface to interact with the network
     network: Network,
+    /// The chain id of the chain spec, cached on construction
+    chain_id: U64,
+    /// The chain spec of the provider, cached on construction
+    chain_spec: Arc<ChainSpec>,
     /// All configured Signers
     signers: Vec<Box<dyn EthSigner>>,
     /// The async cache frontend for eth related data
     eth_cache: EthStateCache,
     /// The async gas oracle frontend for gas price suggestions
     gas_oracle: GasPriceOracle<Provider>,
+    /// The number of blocks the `latest` block tag lags behind the tip of the chain
+    latest_block_lag: u64,
     /// The block number at which the node started
     starting_block: U256,
     /// The type that can spawn tasks which would otherwise block.
@@ -21,8 +21,9 @@ use reth_provider::{
 };
 use reth_rpc_api::EthApiServer;
 use reth_rpc_types::{
-    state::StateOverride, BlockOverrides, CallRequest, EIP1186AccountProofResponse, FeeHistory,
-    Index, RichBlock, SyncStatus, TransactionReceipt, TransactionRequest, Work,
+    state::StateOverride, BlockOverrides, CallOptions, CallRequest, CallResponse,
+    EIP1186AccountProofResponse, FeeHistory, Index, RichBlock, SyncStatus, TransactionReceipt,
+    TransactionRequest, Work,
 };
 use reth_transaction_pool::TransactionPool;
 use serde_json::Value;
@@ -174,6 +175,12 @@ where
         Ok(EthTransactions::transaction_receipt(self, hash).await?)
     }
 
+    /// Handler for: `eth_getBlockReceipts`
+    async fn block_receipts(&self, block_id: BlockId) -> Result<Option<Vec<TransactionReceipt>>> {
+        trace!(target: "rpc::eth", ?block_id, "Serving eth_getBlockReceipts");
+        Ok(EthApi::block_receipts(self, block_id).await?)
+    }
+
     /// Handler for: `eth_getBalance`
     async fn balance(&self, address: Address, block_number: Option<BlockId>) -> Result<U256> {
         trace!(target: "rpc::eth", ?address, ?block_number, "Serving eth_getBalance");
@@ -222,14 +229,16 @@ where
         block_number: Option<BlockId>,
         state_overrides: Option<StateOverride>,
         block_overrides: Option<Box<BlockOverrides>>,
-    ) -> Result<Bytes> {
-        trace!(target: "rpc::eth", ?request, ?block_number, ?state_overrides, ?block_overrides, "Serving eth_call");
+        call_options: Option<CallOptions>,
+    ) -> Result<CallResponse> {
+        trace!(target: "rpc::eth", ?request, ?block_number, ?state_overrides, ?block_overrides, ?call_options, "Serving eth_call");
         Ok(self
             .on_blocking_task(|this| async move {
-                this.call(
+                this.call_with_options(
                     request,
                     block_number,
                     EvmOverrides::new(state_overrides, block_overrides),
+                    call_options.unwrap_or_default(),
                 )
                 .await
             })
@@ -239,18 +248,14 @@ where
     /// Handler for: `eth_createAccessList`
     async fn create_access_list(
         &self,
-        mut request: CallRequest,
+        request: CallRequest,
         block_number: Option<BlockId>,
     ) -> Result<AccessListWithGasUsed> {
         trace!(target: "rpc::eth", ?request, ?block_number, "Serving eth_createAccessList");
         Ok(self
-            .on_blocking_task(|this| async move {
-                let block_id = block_number.unwrap_or(BlockId::Number(BlockNumberOrTag::Latest));
-                let access_list = this.create_access_list_at(request.clone(), block_number).await?;
-                request.access_list = Some(access_list.clone());
-                let gas_used = this.estimate_gas_at(request, block_id).await?;
-                Ok(AccessListWithGasUsed { access_list, gas_used })
-            })
+            .on_blocking_task(
+                |this| async move { this.create_access_list(request, block_number).await },
+            )
             .await?)
     }
 
@@ -361,21 +366,14 @@ where
     /// Handler for: `eth_getProof`
     async fn get_proof(
         &self,
-        _address: Address,
-        _keys: Vec<JsonStorageKey>,
-        _block_number: Option<BlockId>,
+        address: Address,
+        keys: Vec<JsonStorageKey>,
+        block_number: Option<BlockId>,
     ) -> Result<EIP1186AccountProofResponse> {
-        // TODO: uncomment when implemented
-        // trace!(target: "rpc::eth", ?address, ?keys, ?block_number, "Serving eth_getProof");
-        // let res = EthApi::get_proof(self, address, keys, block_number);
-
-        // Ok(res.map_err(|e| match e {
-        //     EthApiError::InvalidBlockRange => {
-        //         internal_rpc_err("eth_getProof is unimplemented for historical blocks")
-        //     }
-        //     _ => e.into(),
-        // })?)
-        Err(internal_rpc_err("unimplemented"))
+        trace!(target: "rpc::eth", ?address, ?keys, ?block_number, "Serving eth_getProof");
+        Ok(self
+            .on_blocking_task(|this| async move { this.get_proof(address, keys, block_number) })
+            .await?)
     }
 }
 
@@ -385,13 +383,75 @@ mod tests {
         eth::{cache::EthStateCache, gas_oracle::GasPriceOracle},
         EthApi,
     };
+    use async_trait::async_trait;
     use jsonrpsee::types::error::INVALID_PARAMS_CODE;
-    use rand::random;
-    use reth_network_api::test_utils::NoopNetwork;
-    use reth_primitives::{Block, BlockNumberOrTag, Header, TransactionSigned, H256, U256};
-    use reth_provider::test_utils::{MockEthProvider, NoopProvider};
+    use rand::{random, thread_rng, Rng};
+    use reth_network_api::{test_utils::NoopNetwork, NetworkError, NetworkInfo};
+    use reth_primitives::{
+        basefee::calculate_next_block_base_fee, Block, BlockNumberOrTag, Header, TransactionSigned,
+        H256, MAINNET, U256, U64,
+    };
+    use reth_provider::{
+        test_utils::{MockEthProvider, NoopProvider},
+        HeaderProvider,
+    };
     use reth_rpc_api::EthApiServer;
+    use reth_rpc_types::NetworkStatus;
     use reth_transaction_pool::test_utils::testing_pool;
+    use std::{
+        net::SocketAddr,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+
+    /// A network that records how often the chain id is requested.
+    #[derive(Clone, Default)]
+    struct ChainIdRecordingNetwork {
+        chain_id_calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl NetworkInfo for ChainIdRecordingNetwork {
+        fn local_addr(&self) -> SocketAddr {
+            NoopNetwork.local_addr()
+        }
+
+        async fn network_status(&self) -> Result<NetworkStatus, NetworkError> {
+            NoopNetwork.network_status().await
+        }
+
+        fn chain_id(&self) -> u64 {
+            self.chain_id_calls.fetch_add(1, Ordering::Relaxed);
+            5
+        }
+
+        fn is_syncing(&self) -> bool {
+            false
+        }
+    }
+
+    #[tokio::test]
+    /// Handler for: `eth_chainId`
+    async fn test_chain_id_is_cached() {
+        let network = ChainIdRecordingNetwork::default();
+        let cache = EthStateCache::spawn(NoopProvider::default(), Default::default());
+        let eth_api = EthApi::new(
+            NoopProvider::default(),
+            testing_pool(),
+            network.clone(),
+            cache.clone(),
+            GasPriceOracle::new(NoopProvider::default(), Default::default(), cache),
+        );
+
+        // the chain id is the one of the spec, the network is never asked
+        for _ in 0..3 {
+            let chain_id = <EthApi<_, _, _> as EthApiServer>::chain_id(&eth_api).await.unwrap();
+            assert_eq!(chain_id, Some(U64::from(MAINNET.chain.id())));
+        }
+        assert_eq!(network.chain_id_calls.load(Ordering::Relaxed), 0);
+    }
 
     #[tokio::test]
     /// Handler for: `eth_test_fee_history`
@@ -427,9 +487,9 @@ mod tests {
 
         for i in (0..=block_count).rev() {
             let hash = H256::random();
-            let gas_limit: u64 = random();
-            let gas_used: u64 = random();
-            let base_fee_per_gas: Option<u64> = random::<bool>().then(random);
+            let gas_limit = thread_rng().gen_range(1_000..30_000_000u64);
+            let gas_used = thread_rng().gen_range(0..=gas_limit);
+            let base_fee_per_gas: Option<u64> = random::<bool>().then(|| random::<u32>() as u64);
 
             let header = Header {
                 number: newest_block - i,
@@ -480,7 +540,19 @@ mod tests {
                 .push(base_fee_per_gas.map(|fee| U256::try_from(fee).unwrap()).unwrap_or_default());
         }
 
+        // the newest block is not part of the requested range, its base fee is projected from its
+        // parent instead
         gas_used_ratios.pop();
+        base_fees_per_gas.pop();
+        let parent = mock_provider.header_by_number(newest_block - 1).unwrap().unwrap();
+        base_fees_per_gas.push(U256::from(
+            parent
+                .base_fee_per_gas
+                .map(|base_fee| {
+                    calculate_next_block_base_fee(parent.gas_used, parent.gas_limit, base_fee)
+                })
+                .unwrap_or_default(),
+        ));
 
         let cache = EthStateCache::spawn(mock_provider.clone(), Default::default());
         let eth_api = EthApi::new(
@@ -5,8 +5,8 @@ use crate::{
     EthApi,
 };
 use reth_primitives::{
-    serde_helper::JsonStorageKey, Address, BlockId, BlockNumberOrTag, Bytes, H256, KECCAK_EMPTY,
-    U256,
+    proofs::EMPTY_ROOT, serde_helper::JsonStorageKey, Address, BlockId, BlockNumberOrTag, Bytes,
+    H256, KECCAK_EMPTY, U256,
 };
 use reth_provider::{
     AccountProvider, BlockProviderIdExt, EvmEnvProvider, StateProvider, StateProviderFactory,
@@ -82,7 +82,6 @@ where
         Ok(H256(value.to_be_bytes()))
     }
 
-    #[allow(unused)]
     pub(crate) fn get_proof(
         &self,
         address: Address,
@@ -90,7 +89,12 @@ where
         block_id: Option<BlockId>,
     ) -> EthResult<EIP1186AccountProofResponse> {
         let chain_info = self.provider().chain_info()?;
-        let block_id = block_id.unwrap_or(BlockId::Number(BlockNumberOrTag::Latest));
+        let block_id = self.resolve_latest_block_id(
+            block_id.unwrap_or(BlockId::Number(BlockNumberOrTag::Latest)),
+        )?;
+
+        // resolve the state first, so that unknown or non-canonical block hashes are rejected
+        let state = self.state_at_block_id_or_latest(Some(block_id))?;
 
         // if we are trying to create a proof for the latest block, but have a BlockId as input
         // that is not BlockNumberOrTag::Latest, then we need to figure out whether or not the
@@ -102,12 +106,23 @@ where
             _ => false,
         };
 
-        // TODO: remove when HistoricalStateProviderRef::proof is implemented
+        // the trie nodes are only kept for the latest state, so historical state can't be proven
         if !is_blockid_latest {
-            return Err(EthApiError::InvalidBlockRange)
+            return Err(EthApiError::HistoricalProofsUnavailable)
         }
 
-        let state = self.state_at_block_id(block_id)?;
+        // non-existent accounts are served with empty proofs
+        let Some(account) = state.basic_account(address)? else {
+            let storage_proof =
+                keys.into_iter().map(|key| StorageProof { key, ..Default::default() }).collect();
+            return Ok(EIP1186AccountProofResponse {
+                address,
+                code_hash: KECCAK_EMPTY,
+                storage_hash: EMPTY_ROOT,
+                storage_proof,
+                ..Default::default()
+            })
+        };
 
         let hash_keys = keys.iter().map(|key| key.0).collect::<Vec<_>>();
         let (account_proof, storage_hash, stg_proofs) = state.proof(address, &hash_keys)?;
@@ -124,22 +139,15 @@ where
             })
             .collect::<Result<_, _>>()?;
 
-        let mut proof = EIP1186AccountProofResponse {
+        Ok(EIP1186AccountProofResponse {
             address,
-            code_hash: KECCAK_EMPTY,
-            account_proof,
+            balance: account.balance,
+            code_hash: account.get_bytecode_hash(),
+            nonce: account.nonce.into(),
             storage_hash,
+            account_proof,
             storage_proof,
-            ..Default::default()
-        };
-
-        if let Some(account) = state.basic_account(proof.address)? {
-            proof.balance = account.balance;
-            proof.nonce = account.nonce.into();
-            proof.code_hash = account.get_bytecode_hash();
-        }
-
-        Ok(proof)
+        })
     }
 }
 
@@ -147,7 +155,7 @@ where
 mod tests {
     use super::*;
     use crate::eth::{cache::EthStateCache, gas_oracle::GasPriceOracle};
-    use reth_primitives::{StorageKey, StorageValue};
+    use reth_primitives::{StorageKey, StorageValue, U64};
     use reth_provider::test_utils::{ExtendedAccount, MockEthProvider, NoopProvider};
     use reth_transaction_pool::test_utils::testing_pool;
     use std::collections::HashMap;
@@ -190,4 +198,37 @@ mod tests {
         let storage = eth_api.storage_at(address, storage_key.into(), None).unwrap();
         assert_eq!(storage, storage_value.into());
     }
+
+    #[tokio::test]
+    async fn test_get_proof() {
+        let pool = testing_pool();
+        let cache = EthStateCache::spawn(NoopProvider::default(), Default::default());
+        let eth_api = EthApi::new(
+            NoopProvider::default(),
+            pool,
+            (),
+            cache.clone(),
+            GasPriceOracle::new(NoopProvider::default(), Default::default(), cache),
+        );
+
+        // non-existent accounts are served with empty proofs
+        let address = Address::random();
+        let key = JsonStorageKey(H256::random());
+        let proof = eth_api.get_proof(address, vec![key], None).unwrap();
+        assert_eq!(proof.address, address);
+        assert_eq!(proof.balance, U256::ZERO);
+        assert_eq!(proof.nonce, U64::ZERO);
+        assert_eq!(proof.code_hash, KECCAK_EMPTY);
+        assert_eq!(proof.storage_hash, EMPTY_ROOT);
+        assert!(proof.account_proof.is_empty());
+        assert_eq!(proof.storage_proof, vec![StorageProof { key, ..Default::default() }]);
+
+        // historical blocks exist but can't be proven
+        let err = eth_api.get_proof(address, vec![], Some(H256::random().into())).unwrap_err();
+        assert!(matches!(err, EthApiError::HistoricalProofsUnavailable));
+        let err = eth_api
+            .get_proof(address, vec![], Some(BlockNumberOrTag::Number(5).into()))
+            .unwrap_err();
+        assert!(matches!(err, EthApiError::HistoricalProofsUnavailable));
+    }
 }
@@ -9,11 +9,12 @@ use revm::primitives::{BlockEnv, CfgEnv};
 use schnellru::{ByMemoryUsage, Limiter, LruMap};
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{hash_map::Entry, HashMap},
+    collections::{hash_map::Entry, HashMap, HashSet},
     future::Future,
     hash::Hash,
     pin::Pin,
     task::{ready, Context, Poll},
+    time::{Duration, Instant},
 };
 use tokio::sync::{
     mpsc::{unbounded_channel, UnboundedSender},
@@ -71,6 +72,15 @@ pub struct EthStateCacheConfig {
     ///
     /// Default is 1MB (env configs are very small)
     pub max_env_bytes: usize,
+    /// Max age of cached data.
+    ///
+    /// Older entries are evicted when they're accessed, even if the memory limit isn't reached.
+    /// Within the max age, the least recently used entries are evicted first once the memory limit
+    /// is reached.
+    ///
+    /// Default is `None`, entries are only evicted if the memory limit is reached.
+    #[serde(default)]
+    pub max_age: Option<Duration>,
 }
 
 impl Default for EthStateCacheConfig {
@@ -79,6 +89,7 @@ impl Default for EthStateCacheConfig {
             max_block_bytes: DEFAULT_BLOCK_CACHE_SIZE_BYTES_MB * 1024 * 1024,
             max_receipt_bytes: DEFAULT_RECEIPT_CACHE_SIZE_BYTES_MB * 1024 * 1024,
             max_env_bytes: DEFAULT_ENV_CACHE_SIZE_BYTES_MB * 1024 * 1024,
+            max_age: None,
         }
     }
 }
@@ -100,13 +111,14 @@ impl EthStateCache {
         max_block_bytes: usize,
         max_receipt_bytes: usize,
         max_env_bytes: usize,
+        max_age: Option<Duration>,
     ) -> (Self, EthStateCacheService<Provider, Tasks>) {
         let (to_service, rx) = unbounded_channel();
         let service = EthStateCacheService {
             provider,
-            full_block_cache: BlockLruCache::with_memory_budget(max_block_bytes),
-            receipts_cache: ReceiptsLruCache::with_memory_budget(max_receipt_bytes),
-            evm_env_cache: EnvLruCache::with_memory_budget(max_env_bytes),
+            full_block_cache: BlockLruCache::with_memory_budget(max_block_bytes, max_age),
+            receipts_cache: ReceiptsLruCache::with_memory_budget(max_receipt_bytes, max_age),
+            evm_env_cache: EnvLruCache::with_memory_budget(max_env_bytes, max_age),
             action_tx: to_service.clone(),
             action_rx: UnboundedReceiverStream::new(rx),
             action_task_spawner,
@@ -139,13 +151,15 @@ impl EthStateCache {
         Provider: StateProviderFactory + BlockProvider + EvmEnvProvider + Clone + Unpin + 'static,
         Tasks: TaskSpawner + Clone + 'static,
     {
-        let EthStateCacheConfig { max_block_bytes, max_receipt_bytes, max_env_bytes } = config;
+        let EthStateCacheConfig { max_block_bytes, max_receipt_bytes, max_env_bytes, max_age } =
+            config;
         let (this, service) = Self::create(
             provider,
             executor.clone(),
             max_block_bytes,
             max_receipt_bytes,
             max_env_bytes,
+            max_age,
         );
         executor.spawn_critical("eth state cache", Box::pin(service));
         this
@@ -197,6 +211,12 @@ impl EthStateCache {
         let _ = self.to_service.send(CacheAction::GetEnv { block_hash, response_tx });
         rx.await.map_err(|_| ProviderError::CacheServiceUnavailable)?
     }
+
+    /// Evicts the cached block, receipts and env of the block hash, e.g. because the block was
+    /// reorged out of the canonical chain.
+    pub fn invalidate_block(&self, block_hash: H256) {
+        let _ = self.to_service.send(CacheAction::InvalidateBlock { block_hash });
+    }
 }
 
 /// A task than manages caches for data required by the `eth` rpc implementation.
@@ -222,9 +242,9 @@ pub(crate) struct EthStateCacheService<
     LimitReceipts = ByMemoryUsage,
     LimitEnvs = ByMemoryUsage,
 > where
-    LimitBlocks: Limiter<H256, Block>,
-    LimitReceipts: Limiter<H256, Vec<Receipt>>,
-    LimitEnvs: Limiter<H256, (CfgEnv, BlockEnv)>,
+    LimitBlocks: Limiter<H256, CacheEntry<Block>>,
+    LimitReceipts: Limiter<H256, CacheEntry<Vec<Receipt>>>,
+    LimitEnvs: Limiter<H256, CacheEntry<(CfgEnv, BlockEnv)>>,
 {
     /// The type used to lookup data from disk
     provider: Provider,
@@ -266,7 +286,7 @@ where
 
         // cache good block
         if let Ok(Some(block)) = res {
-            self.full_block_cache.cache.insert(block_hash, block);
+            self.full_block_cache.insert(block_hash, block, Instant::now());
         }
     }
 
@@ -280,7 +300,7 @@ where
 
         // cache good receipts
         if let Ok(Some(receipts)) = res {
-            self.receipts_cache.cache.insert(block_hash, receipts);
+            self.receipts_cache.insert(block_hash, receipts, Instant::now());
         }
     }
 }
@@ -305,7 +325,7 @@ where
                         CacheAction::GetBlock { block_hash, response_tx } => {
                             // check if block is cached
                             if let Some(block) =
-                                this.full_block_cache.cache.get(&block_hash).cloned()
+                                this.full_block_cache.get(&block_hash, Instant::now()).cloned()
                             {
                                 let _ = response_tx.send(Ok(Some(block)));
                                 continue
@@ -324,7 +344,9 @@ where
                         }
                         CacheAction::GetBlockTransactions { block_hash, response_tx } => {
                             // check if block is cached
-                            if let Some(block) = this.full_block_cache.cache.get(&block_hash) {
+                            if let Some(block) =
+                                this.full_block_cache.get(&block_hash, Instant::now())
+                            {
                                 let _ = response_tx.send(Ok(Some(block.body.clone())));
                                 continue
                             }
@@ -343,7 +365,7 @@ where
                         CacheAction::GetReceipts { block_hash, response_tx } => {
                             // check if block is cached
                             if let Some(receipts) =
-                                this.receipts_cache.cache.get(&block_hash).cloned()
+                                this.receipts_cache.get(&block_hash, Instant::now()).cloned()
                             {
                                 let _ = response_tx.send(Ok(Some(receipts)));
                                 continue
@@ -362,7 +384,9 @@ where
                         }
                         CacheAction::GetEnv { block_hash, response_tx } => {
                             // check if env data is cached
-                            if let Some(env) = this.evm_env_cache.cache.get(&block_hash).cloned() {
+                            if let Some(env) =
+                                this.evm_env_cache.get(&block_hash, Instant::now()).cloned()
+                            {
                                 let _ = response_tx.send(Ok(env));
                                 continue
                             }
@@ -386,10 +410,20 @@ where
                             }
                         }
                         CacheAction::BlockResult { block_hash, res } => {
+                            let invalidated = this.full_block_cache.take_invalidated(&block_hash);
                             this.on_new_block(block_hash, res);
+                            if invalidated {
+                                // the block was reorged while it was fetched
+                                this.full_block_cache.remove(&block_hash);
+                            }
                         }
                         CacheAction::ReceiptsResult { block_hash, res } => {
+                            let invalidated = this.receipts_cache.take_invalidated(&block_hash);
                             this.on_new_receipts(block_hash, res);
+                            if invalidated {
+                                // the block was reorged while its receipts were fetched
+                                this.receipts_cache.remove(&block_hash);
+                            }
                         }
                         CacheAction::EnvResult { block_hash, res } => {
                             let res = *res;
@@ -400,11 +434,18 @@ where
                                 }
                             }
 
-                            // cache good env data
-                            if let Ok(data) = res {
-                                this.evm_env_cache.cache.insert(block_hash, data);
+                            // cache good env data, unless the block was reorged while it was
+                            // fetched
+                            let invalidated = this.evm_env_cache.take_invalidated(&block_hash);
+                            if let (Ok(data), false) = (res, invalidated) {
+                                this.evm_env_cache.insert(block_hash, data, Instant::now());
                             }
                         }
+                        CacheAction::InvalidateBlock { block_hash } => {
+                            this.full_block_cache.invalidate(block_hash);
+                            this.receipts_cache.invalidate(block_hash);
+                            this.evm_env_cache.invalidate(block_hash);
+                        }
                         CacheAction::CacheNewCanonicalChain { blocks, receipts } => {
                             for block in blocks {
                                 this.on_new_block(block.hash, Ok(Some(block.unseal())));
@@ -424,22 +465,73 @@ where
     }
 }
 
+/// A cached value and the time it was inserted into the cache.
+pub(crate) struct CacheEntry<V> {
+    value: V,
+    inserted_at: Instant,
+}
+
 struct MultiConsumerLruCache<K, V, L, S>
 where
     K: Hash + Eq,
-    L: Limiter<K, V>,
+    L: Limiter<K, CacheEntry<V>>,
 {
     /// The LRU cache for the
-    cache: LruMap<K, V, L>,
+    cache: LruMap<K, CacheEntry<V>, L>,
+    /// Max age of cached entries, if any
+    max_age: Option<Duration>,
     /// All queued consumers
     queued: HashMap<K, Vec<S>>,
+    /// Keys that were invalidated while their value was fetched, the fetched values of these keys
+    /// are only sent to the queued consumers but not cached.
+    invalidated: HashSet<K>,
 }
 
 impl<K, V, L, S> MultiConsumerLruCache<K, V, L, S>
 where
     K: Hash + Eq,
-    L: Limiter<K, V>,
+    L: Limiter<K, CacheEntry<V>>,
 {
+    /// Returns the cached value for the given key.
+    ///
+    /// If the entry is older than the configured max age at `now`, it's evicted and `None` is
+    /// returned.
+    fn get(&mut self, key: &K, now: Instant) -> Option<&V> {
+        if let Some(max_age) = self.max_age {
+            let expired = self
+                .cache
+                .peek(key)
+                .map_or(false, |entry| now.saturating_duration_since(entry.inserted_at) > max_age);
+            if expired {
+                self.cache.remove(key);
+                return None
+            }
+        }
+        self.cache.get(key).map(|entry| &entry.value)
+    }
+
+    /// Removes the cached value for the given key.
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.cache.remove(key).map(|entry| entry.value)
+    }
+
+    /// Removes the cached value for the given key, and marks the key as invalidated if its value
+    /// is currently fetched.
+    ///
+    /// See also [Self::take_invalidated]
+    fn invalidate(&mut self, key: K) {
+        self.remove(&key);
+        if self.queued.contains_key(&key) {
+            self.invalidated.insert(key);
+        }
+    }
+
+    /// Returns `true` if the key was invalidated while its value was fetched, in which case the
+    /// fetched value must not be cached.
+    fn take_invalidated(&mut self, key: &K) -> bool {
+        self.invalidated.remove(key)
+    }
+
     /// Adds the sender to the queue for the given key.
     ///
     /// Returns true if this is the first queued sender for the key
@@ -461,11 +553,22 @@ impl<K, V, S> MultiConsumerLruCache<K, V, ByMemoryUsage, S>
 where
     K: Hash + Eq,
 {
-    /// Creates a new empty map with a given `memory_budget`.
+    /// Creates a new empty map with a given `memory_budget` and an optional `max_age` of its
+    /// entries.
     ///
     /// See also [LruMap::with_memory_budget]
-    fn with_memory_budget(memory_budget: usize) -> Self {
-        Self { cache: LruMap::with_memory_budget(memory_budget), queued: Default::default() }
+    fn with_memory_budget(memory_budget: usize, max_age: Option<Duration>) -> Self {
+        Self {
+            cache: LruMap::with_memory_budget(memory_budget),
+            max_age,
+            queued: Default::default(),
+            invalidated: Default::default(),
+        }
+    }
+
+    /// Inserts the value for the given key, inserted at `now`.
+    fn insert(&mut self, key: K, value: V, now: Instant) {
+        self.cache.insert(key, CacheEntry { value, inserted_at: now });
     }
 }
 
@@ -478,6 +581,7 @@ enum CacheAction {
     BlockResult { block_hash: H256, res: Result<Option<Block>> },
     ReceiptsResult { block_hash: H256, res: Result<Option<Vec<Receipt>>> },
     EnvResult { block_hash: H256, res: Box<Result<(CfgEnv, BlockEnv)>> },
+    InvalidateBlock { block_hash: H256 },
     CacheNewCanonicalChain { blocks: Vec<SealedBlock>, receipts: Vec<BlockReceipts> },
 }
 
@@ -488,11 +592,21 @@ struct BlockReceipts {
 
 /// Awaits for new chain events and directly inserts them into the cache so they're available
 /// immediately before they need to be fetched from disk.
+///
+/// Blocks that are reverted by a reorg are evicted from the cache, so they aren't served after
+/// they're no longer canonical. This includes their data that is being fetched from disk while
+/// the reorg happens, which is only returned to the pending requests but not cached.
 pub async fn cache_new_blocks_task<St>(eth_state_cache: EthStateCache, mut events: St)
 where
     St: Stream<Item = CanonStateNotification> + Unpin + 'static,
 {
     while let Some(event) = events.next().await {
+        if let Some(reverted) = event.reverted() {
+            for block in reverted.blocks().values() {
+                eth_state_cache.invalidate_block(block.hash);
+            }
+        }
+
         if let Some(committed) = event.committed() {
             // we're only interested in new committed blocks
             let (blocks, state) = committed.inner();
@@ -515,3 +629,112 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{Header, SealedBlockWithSenders};
+    use reth_provider::{test_utils::MockEthProvider, Chain, PostState};
+    use std::sync::Arc;
+
+    #[test]
+    fn evicts_entries_older_than_max_age() {
+        let max_age = Duration::from_secs(12);
+        let mut cache =
+            ReceiptsLruCache::<ByMemoryUsage>::with_memory_budget(1024 * 1024, Some(max_age));
+
+        let block_hash = H256::random();
+        let now = Instant::now();
+        cache.insert(block_hash, vec![], now);
+
+        assert!(cache.get(&block_hash, now + max_age).is_some());
+        assert!(cache.get(&block_hash, now + max_age + Duration::from_secs(1)).is_none());
+        // the expired entry was evicted
+        assert!(cache.cache.peek(&block_hash).is_none());
+        assert!(cache.get(&block_hash, now).is_none());
+
+        // without a max age, entries are only evicted by the memory limit
+        let mut cache = ReceiptsLruCache::<ByMemoryUsage>::with_memory_budget(1024 * 1024, None);
+        cache.insert(block_hash, vec![], now);
+        assert!(cache.get(&block_hash, now + Duration::from_secs(60 * 60)).is_some());
+    }
+
+    #[test]
+    fn removes_invalidated_entries() {
+        let mut cache = ReceiptsLruCache::<ByMemoryUsage>::with_memory_budget(1024 * 1024, None);
+
+        let reorged = H256::random();
+        let canonical = H256::random();
+        let now = Instant::now();
+        cache.insert(reorged, vec![Receipt::default()], now);
+        cache.insert(canonical, vec![], now);
+
+        assert_eq!(cache.remove(&reorged), Some(vec![Receipt::default()]));
+        assert!(cache.get(&reorged, now).is_none());
+        assert!(cache.remove(&reorged).is_none());
+        assert!(cache.get(&canonical, now).is_some());
+    }
+
+    #[test]
+    fn tracks_keys_invalidated_while_fetched() {
+        let mut cache = ReceiptsLruCache::<ByMemoryUsage>::with_memory_budget(1024 * 1024, None);
+
+        let fetched = H256::random();
+        let cached = H256::random();
+        let (tx, _rx) = oneshot::channel();
+        assert!(cache.queue(fetched, tx));
+        cache.insert(cached, vec![], Instant::now());
+
+        cache.invalidate(fetched);
+        cache.invalidate(cached);
+
+        // only the key that is being fetched is tracked
+        assert!(cache.take_invalidated(&fetched));
+        assert!(!cache.take_invalidated(&fetched));
+        assert!(!cache.take_invalidated(&cached));
+        assert!(cache.get(&cached, Instant::now()).is_none());
+    }
+
+    fn chain_of(block: &SealedBlock) -> Arc<Chain> {
+        Arc::new(Chain::new(vec![(
+            SealedBlockWithSenders { block: block.clone(), senders: vec![] },
+            PostState::default(),
+        )]))
+    }
+
+    async fn send_notification(cache: &EthStateCache, notification: CanonStateNotification) {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        tx.send(notification).unwrap();
+        drop(tx);
+        cache_new_blocks_task(cache.clone(), UnboundedReceiverStream::new(rx)).await;
+    }
+
+    #[tokio::test]
+    async fn evicts_reorged_blocks() {
+        // the provider doesn't have any blocks, so blocks are only served from the cache
+        let cache = EthStateCache::spawn(MockEthProvider::default(), Default::default());
+
+        let seal = |extra_data: &'static [u8]| {
+            let header = Header { number: 1, extra_data: extra_data.into(), ..Default::default() }
+                .seal_slow();
+            SealedBlock { header, body: vec![], ommers: vec![], withdrawals: None }
+        };
+        let reorged = seal(b"reorged");
+        let canonical = seal(b"canonical");
+        assert_ne!(reorged.hash, canonical.hash);
+
+        send_notification(&cache, CanonStateNotification::Commit { new: chain_of(&reorged) }).await;
+        assert_eq!(cache.get_block(reorged.hash).await.unwrap(), Some(reorged.clone().unseal()));
+
+        send_notification(
+            &cache,
+            CanonStateNotification::Reorg { old: chain_of(&reorged), new: chain_of(&canonical) },
+        )
+        .await;
+        assert_eq!(cache.get_block(reorged.hash).await.unwrap(), None);
+        assert_eq!(
+            cache.get_block(canonical.hash).await.unwrap(),
+            Some(canonical.clone().unseal())
+        );
+    }
+}
@@ -30,6 +30,10 @@ pub enum EthApiError {
     UnknownBlockOrTxIndex,
     #[error("Invalid block range")]
     InvalidBlockRange,
+    /// Thrown when a proof is requested for a block other than the latest, the trie nodes are
+    /// only kept for the latest state
+    #[error("proofs are unavailable for historical blocks")]
+    HistoricalProofsUnavailable,
     /// An internal error where prevrandao is not set in the evm's environment
     #[error("Prevrandao not in th EVM's environment after merge")]
     PrevrandaoNotSet,
@@ -65,8 +69,11 @@ pub enum EthApiError {
     #[error("invalid tracer config")]
     InvalidTracerConfig,
     /// Percentile array is invalid
-    #[error("invalid reward percentile")]
+    #[error("invalid reward percentile: {0}")]
     InvalidRewardPercentile(f64),
+    /// The access list of `eth_createAccessList` changed in every execution up to the limit
+    #[error("access list did not converge after {0} executions")]
+    AccessListNotConverged(usize),
     /// Error thrown when a spawned tracing task failed to deliver an anticipated response.
     #[error("internal error while tracing")]
     InternalTracingError,
@@ -78,6 +85,20 @@ pub enum EthApiError {
     InternalJsTracerError(String),
 }
 
+/// Errors that can occur when writing or reading the fee history cache file.
+#[derive(Debug, thiserror::Error)]
+pub enum FeeHistoryCacheFileError {
+    /// Failed to access the file.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// Failed to (de)serialize the cache entries.
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    /// Failed to look up the canonical block of an entry.
+    #[error(transparent)]
+    Provider(#[from] reth_interfaces::Error),
+}
+
 impl From<EthApiError> for ErrorObject<'static> {
     fn from(error: EthApiError) -> Self {
         match error {
@@ -88,20 +109,24 @@ impl From<EthApiError> for ErrorObject<'static> {
             EthApiError::ConflictingFeeFieldsInRequest |
             EthApiError::Signing(_) |
             EthApiError::BothStateAndStateDiffInOverride(_) |
+            EthApiError::InvalidRewardPercentile(_) |
             EthApiError::InvalidTracerConfig => invalid_params_rpc_err(error.to_string()),
             EthApiError::InvalidTransaction(err) => err.into(),
             EthApiError::PoolError(err) => err.into(),
             EthApiError::PrevrandaoNotSet |
+            EthApiError::AccessListNotConverged(_) |
             EthApiError::InvalidBlockData(_) |
             EthApiError::Internal(_) |
             EthApiError::TransactionNotFound => internal_rpc_err(error.to_string()),
             EthApiError::UnknownBlockNumber | EthApiError::UnknownBlockOrTxIndex => {
                 rpc_error_with_code(EthRpcErrorCode::ResourceNotFound.code(), error.to_string())
             }
+            EthApiError::HistoricalProofsUnavailable => {
+                rpc_error_with_code(EthRpcErrorCode::InvalidInput.code(), error.to_string())
+            }
             EthApiError::Unsupported(msg) => internal_rpc_err(msg),
             EthApiError::InternalJsTracerError(msg) => internal_rpc_err(msg),
             EthApiError::InvalidParams(msg) => invalid_params_rpc_err(msg),
-            EthApiError::InvalidRewardPercentile(msg) => internal_rpc_err(msg.to_string()),
             err @ EthApiError::InternalTracingError => internal_rpc_err(err.to_string()),
             err @ EthApiError::InternalEthError => internal_rpc_err(err.to_string()),
         }
@@ -442,6 +467,8 @@ pub enum RpcPoolError {
     OversizedData,
     #[error("max initcode size exceeded")]
     ExceedsMaxInitCodeSize,
+    #[error("only replay-protected (EIP-155) transactions allowed")]
+    Unprotected,
     #[error(transparent)]
     Invalid(#[from] RpcInvalidTransactionError),
     #[error(transparent)]
@@ -464,6 +491,7 @@ impl From<PoolError> for RpcPoolError {
             PoolError::FeeCapBelowMinimumProtocolFeeCap(_, _) => RpcPoolError::Underpriced,
             PoolError::SpammerExceededCapacity(_, _) => RpcPoolError::TxPoolOverflow,
             PoolError::DiscardedOnInsert(_) => RpcPoolError::TxPoolOverflow,
+            PoolError::PoolLimitReached(_) => RpcPoolError::TxPoolOverflow,
             PoolError::InvalidTransaction(_, err) => err.into(),
             PoolError::Other(_, err) => RpcPoolError::Other(err),
             PoolError::AlreadyImported(_) => RpcPoolError::AlreadyKnown,
@@ -481,6 +509,7 @@ impl From<InvalidPoolTransactionError> for RpcPoolError {
             }
             InvalidPoolTransactionError::OversizedData(_, _) => RpcPoolError::OversizedData,
             InvalidPoolTransactionError::Underpriced => RpcPoolError::Underpriced,
+            InvalidPoolTransactionError::Unprotected => RpcPoolError::Unprotected,
         }
     }
 }
@@ -3,13 +3,16 @@ use crate::{
     eth::{
         error::{EthApiError, EthResult},
         logs_utils,
+        utils::{lagged_chain_info, resolve_latest_tag},
     },
     result::{rpc_error_with_code, ToRpcResult},
     EthSubscriptionIdProvider,
 };
 use async_trait::async_trait;
 use jsonrpsee::{core::RpcResult, server::IdProvider};
-use reth_primitives::{BlockHashOrNumber, Receipt, SealedBlock, H256};
+use reth_primitives::{
+    BlockHashOrNumber, BlockNumber, BlockNumberOrTag, ChainInfo, Receipt, SealedBlock, H256,
+};
 use reth_provider::{BlockIdProvider, BlockProvider, EvmEnvProvider};
 use reth_rpc_api::EthFilterApiServer;
 use reth_rpc_types::{Filter, FilterBlockOption, FilterChanges, FilterId, FilteredParams, Log};
@@ -37,12 +40,14 @@ impl<Provider, Pool> EthFilter<Provider, Pool> {
     /// This uses the given pool to get notified about new transactions, the provider to interact
     /// with the blockchain, the cache to fetch cacheable data, like the logs and the
     /// max_logs_per_response to limit the amount of logs returned in a single response
-    /// `eth_getLogs`
+    /// `eth_getLogs`. The `latest` block tag is served `latest_block_lag` blocks behind the tip of
+    /// the chain, like in [EthApi](crate::EthApi).
     pub fn new(
         provider: Provider,
         pool: Pool,
         eth_cache: EthStateCache,
         max_logs_per_response: usize,
+        latest_block_lag: u64,
         task_spawner: Box<dyn TaskSpawner>,
     ) -> Self {
         let inner = EthFilterInner {
@@ -54,6 +59,7 @@ impl<Provider, Pool> EthFilter<Provider, Pool> {
             eth_cache,
             max_headers_range: MAX_HEADERS_RANGE,
             task_spawner,
+            latest_block_lag,
         };
         Self { inner: Arc::new(inner) }
     }
@@ -91,7 +97,7 @@ where
 
     /// Returns all the filter changes for the given id, if any
     pub async fn filter_changes(&self, id: FilterId) -> Result<FilterChanges, FilterError> {
-        let info = self.inner.provider.chain_info()?;
+        let info = self.inner.chain_info()?;
         let best_number = info.best_number;
 
         let (start_block, kind) = {
@@ -128,11 +134,11 @@ where
                 let (from_block_number, to_block_number) = match filter.block_option {
                     FilterBlockOption::Range { from_block, to_block } => {
                         let from = from_block
-                            .map(|num| self.inner.provider.convert_block_number(num))
+                            .map(|num| self.inner.convert_block_number(num))
                             .transpose()?
                             .flatten();
                         let to = to_block
-                            .map(|num| self.inner.provider.convert_block_number(num))
+                            .map(|num| self.inner.convert_block_number(num))
                             .transpose()?
                             .flatten();
                         logs_utils::get_filter_block_range(from, to, start_block, info)
@@ -270,6 +276,8 @@ struct EthFilterInner<Provider, Pool> {
     max_headers_range: u64,
     /// The type that can spawn tasks.
     task_spawner: Box<dyn TaskSpawner>,
+    /// The number of blocks behind the tip of the chain at which `latest` is served.
+    latest_block_lag: u64,
 }
 
 impl<Provider, Pool> EthFilterInner<Provider, Pool>
@@ -298,18 +306,13 @@ where
             }
             FilterBlockOption::Range { from_block, to_block } => {
                 // compute the range
-                let info = self.provider.chain_info()?;
+                let info = self.chain_info()?;
 
                 // we start at the most recent block if unset in filter
                 let start_block = info.best_number;
-                let from = from_block
-                    .map(|num| self.provider.convert_block_number(num))
-                    .transpose()?
-                    .flatten();
-                let to = to_block
-                    .map(|num| self.provider.convert_block_number(num))
-                    .transpose()?
-                    .flatten();
+                let from =
+                    from_block.map(|num| self.convert_block_number(num)).transpose()?.flatten();
+                let to = to_block.map(|num| self.convert_block_number(num)).transpose()?.flatten();
                 let (from_block_number, to_block_number) =
                     logs_utils::get_filter_block_range(from, to, start_block, info);
                 self.get_logs_in_block_range(&filter, from_block_number, to_block_number).await
@@ -317,9 +320,27 @@ where
         }
     }
 
+    /// Returns the current info for the chain, with the configured `latest_block_lag` applied.
+    fn chain_info(&self) -> reth_interfaces::Result<ChainInfo> {
+        lagged_chain_info(&self.provider, self.latest_block_lag)
+    }
+
+    /// Converts the [BlockNumberOrTag] to a block number, with the configured `latest_block_lag`
+    /// applied to [BlockNumberOrTag::Latest].
+    fn convert_block_number(
+        &self,
+        num: BlockNumberOrTag,
+    ) -> reth_interfaces::Result<Option<BlockNumber>> {
+        self.provider.convert_block_number(resolve_latest_tag(
+            &self.provider,
+            num,
+            self.latest_block_lag,
+        )?)
+    }
+
     /// Installs a new filter and returns the new identifier.
     async fn install_filter(&self, kind: FilterKind) -> RpcResult<FilterId> {
-        let last_poll_block_number = self.provider.best_block_number().to_rpc_result()?;
+        let last_poll_block_number = self.chain_info().to_rpc_result()?.best_number;
         let id = FilterId::from(self.id_provider.next_id());
         let mut filters = self.active_filters.inner.lock().await;
         filters.insert(
@@ -522,6 +543,10 @@ impl Iterator for BlockRangeInclusiveIter {
 mod tests {
     use super::*;
     use rand::{thread_rng, Rng};
+    use reth_primitives::{Block, Header};
+    use reth_provider::test_utils::MockEthProvider;
+    use reth_tasks::TokioTaskExecutor;
+    use reth_transaction_pool::test_utils::testing_pool;
 
     #[test]
     fn test_block_range_iter() {
@@ -545,4 +570,44 @@ mod tests {
             assert_eq!(end, *range.end());
         }
     }
+
+    #[tokio::test]
+    async fn latest_block_lag() {
+        let provider = MockEthProvider::default();
+        let mut hashes = Vec::new();
+        for number in 0..10u64 {
+            let header = Header { number, ..Default::default() }.seal_slow();
+            hashes.push(header.hash);
+            provider.add_block(header.hash, Block { header: header.header, ..Default::default() });
+        }
+
+        for lag in [0, 3] {
+            let filter = EthFilter::new(
+                provider.clone(),
+                testing_pool(),
+                EthStateCache::spawn(provider.clone(), Default::default()),
+                usize::MAX,
+                lag,
+                Box::<TokioTaskExecutor>::default(),
+            );
+
+            // `latest` resolves to `tip - lag` for `eth_getLogs`
+            let info = filter.inner.chain_info().unwrap();
+            assert_eq!(info.best_number, 9 - lag);
+            assert_eq!(info.best_hash, hashes[9 - lag as usize]);
+            assert_eq!(
+                filter.inner.convert_block_number(BlockNumberOrTag::Latest).unwrap(),
+                Some(9 - lag)
+            );
+            assert_eq!(
+                filter.inner.convert_block_number(BlockNumberOrTag::Number(9)).unwrap(),
+                Some(9)
+            );
+
+            // filters start polling at the lagged block
+            let id = filter.inner.install_filter(FilterKind::Block).await.unwrap();
+            let filters = filter.inner.active_filters.inner.lock().await;
+            assert_eq!(filters.get(&id).unwrap().block, 9 - lag);
+        }
+    }
 }
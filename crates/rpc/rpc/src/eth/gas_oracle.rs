@@ -261,10 +261,74 @@ impl Default for GasPriceOracleResult {
 
 #[cfg(test)]
 mod tests {
-    use reth_primitives::constants::GWEI_TO_WEI;
+    use reth_primitives::{
+        constants::GWEI_TO_WEI, sign_message, Block, Header, Transaction, TransactionKind,
+        TransactionSigned, TxLegacy,
+    };
+    use reth_provider::test_utils::MockEthProvider;
 
     use super::*;
 
+    /// Returns a provider with a block that contains zero-gas spam transactions and three
+    /// transactions that pay 1, 1 and 2 gwei.
+    fn provider_with_spam() -> MockEthProvider {
+        let provider = MockEthProvider::default();
+
+        let genesis = Header::default();
+        let genesis_hash = genesis.hash_slow();
+        provider.add_block(genesis_hash, Block { header: genesis, ..Default::default() });
+
+        let secret = H256::from_low_u64_be(1);
+        let body = [0, 0, 0, 0, GWEI_TO_WEI, GWEI_TO_WEI, 2 * GWEI_TO_WEI]
+            .into_iter()
+            .enumerate()
+            .map(|(nonce, gas_price)| {
+                let tx = Transaction::Legacy(TxLegacy {
+                    chain_id: Some(1),
+                    nonce: nonce as u64,
+                    gas_price: gas_price as u128,
+                    gas_limit: 21_000,
+                    to: TransactionKind::Call(Default::default()),
+                    ..Default::default()
+                });
+                let signature = sign_message(secret, tx.signature_hash()).unwrap();
+                TransactionSigned::from_transaction_and_signature(tx, signature)
+            })
+            .collect();
+        let header = Header { number: 1, parent_hash: genesis_hash, ..Default::default() };
+        provider.add_block(header.hash_slow(), Block { header, body, ..Default::default() });
+
+        provider
+    }
+
+    #[tokio::test]
+    async fn ignores_transactions_below_ignore_price() {
+        let provider = provider_with_spam();
+        let cache = EthStateCache::spawn(provider.clone(), Default::default());
+
+        // the zero-gas transactions are ignored with the default ignore price
+        let oracle = GasPriceOracle::new(provider.clone(), Default::default(), cache.clone());
+        assert_eq!(oracle.suggest_tip_cap().await.unwrap(), U256::from(GWEI_TO_WEI));
+
+        let oracle = GasPriceOracle::new(
+            provider.clone(),
+            GasPriceOracleConfig {
+                ignore_price: Some(U256::from(GWEI_TO_WEI + 1)),
+                ..Default::default()
+            },
+            cache.clone(),
+        );
+        assert_eq!(oracle.suggest_tip_cap().await.unwrap(), U256::from(2 * GWEI_TO_WEI));
+
+        // without an ignore price the spam drags the suggestion down to zero
+        let oracle = GasPriceOracle::new(
+            provider,
+            GasPriceOracleConfig { ignore_price: None, ..Default::default() },
+            cache,
+        );
+        assert_eq!(oracle.suggest_tip_cap().await.unwrap(), U256::ZERO);
+    }
+
     #[test]
     fn max_price_sanity() {
         assert_eq!(DEFAULT_MAX_PRICE, U256::from(500_000_000_000u64));
@@ -15,4 +15,4 @@ pub(crate) mod utils;
 pub use api::{EthApi, EthApiSpec, EthTransactions, TransactionSource};
 pub use filter::EthFilter;
 pub use id_provider::EthSubscriptionIdProvider;
-pub use pubsub::EthPubSub;
+pub use pubsub::{EthPubSub, DEFAULT_MAX_SUBSCRIPTIONS, DEFAULT_MAX_SUBSCRIPTIONS_PER_CONNECTION};
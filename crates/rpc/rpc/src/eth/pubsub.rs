@@ -1,28 +1,57 @@
 //! `eth_` PubSub RPC handler implementation
-use crate::eth::logs_utils;
+use crate::{
+    eth::{
+        error::{EthApiError, EthResult},
+        gas_oracle::GasPriceOracle,
+        logs_utils,
+    },
+    result::rpc_error_with_code,
+};
 use futures::StreamExt;
-use jsonrpsee::{server::SubscriptionMessage, PendingSubscriptionSink, SubscriptionSink};
+use jsonrpsee::{
+    server::SubscriptionMessage,
+    types::error::{ErrorObject, METHOD_NOT_FOUND_CODE, TOO_MANY_SUBSCRIPTIONS_CODE},
+    PendingSubscriptionSink, SubscriptionSink,
+};
 use reth_network_api::NetworkInfo;
-use reth_primitives::TxHash;
-use reth_provider::{BlockProvider, CanonStateSubscriptions, EvmEnvProvider};
+use reth_primitives::{Address, BlockNumber, TxHash, H256, U256};
+use reth_provider::{
+    BlockProvider, BlockProviderIdExt, CanonStateNotifications, CanonStateSubscriptions,
+    EvmEnvProvider,
+};
 use reth_rpc_api::EthPubSubApiServer;
-use reth_rpc_types::FilteredParams;
+use reth_rpc_types::{Filter, FilteredParams};
 
 use reth_rpc_types::{
     pubsub::{
-        Params, PubSubSyncStatus, SubscriptionKind, SubscriptionResult as EthSubscriptionResult,
-        SyncStatusMetadata,
+        GasPriceUpdate, Params, PubSubSyncStatus, SubscriptionKind,
+        SubscriptionResult as EthSubscriptionResult, SyncStatusMetadata,
     },
     Header, Log,
 };
 use reth_tasks::{TaskSpawner, TokioTaskExecutor};
 use reth_transaction_pool::TransactionPool;
 use serde::Serialize;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+};
+use tokio::sync::{oneshot, OwnedSemaphorePermit, Semaphore};
 use tokio_stream::{
     wrappers::{BroadcastStream, ReceiverStream},
     Stream,
 };
 
+/// The default maximum number of concurrently active subscriptions.
+pub const DEFAULT_MAX_SUBSCRIPTIONS: u32 = 10_000;
+
+/// The default maximum number of concurrently active subscriptions of a single connection.
+pub const DEFAULT_MAX_SUBSCRIPTIONS_PER_CONNECTION: u32 = 1_024;
+
+/// The maximum number of past blocks whose logs are sent to a logs subscription with a
+/// `fromBlock`.
+pub(crate) const MAX_LOGS_BACKFILL_BLOCKS: u64 = 10_000;
+
 /// `Eth` pubsub RPC implementation.
 ///
 /// This handles `eth_subscribe` RPC calls.
@@ -32,6 +61,8 @@ pub struct EthPubSub<Provider, Pool, Events, Network> {
     inner: EthPubSubInner<Provider, Pool, Events, Network>,
     /// The type that's used to spawn subscription tasks.
     subscription_task_spawner: Box<dyn TaskSpawner>,
+    /// Limits the number of concurrently active subscriptions.
+    subscription_guard: SubscriptionGuard,
 }
 
 // === impl EthPubSub ===
@@ -58,8 +89,34 @@ impl<Provider, Pool, Events, Network> EthPubSub<Provider, Pool, Events, Network>
         network: Network,
         subscription_task_spawner: Box<dyn TaskSpawner>,
     ) -> Self {
-        let inner = EthPubSubInner { provider, pool, chain_events, network };
-        Self { inner, subscription_task_spawner }
+        let inner = EthPubSubInner { provider, pool, chain_events, network, gas_oracle: None };
+        let subscription_guard = SubscriptionGuard::new(
+            DEFAULT_MAX_SUBSCRIPTIONS,
+            DEFAULT_MAX_SUBSCRIPTIONS_PER_CONNECTION,
+        );
+        Self { inner, subscription_task_spawner, subscription_guard }
+    }
+
+    /// Configures the maximum number of concurrently active subscriptions, in total and per
+    /// connection.
+    ///
+    /// Subscriptions exceeding either limit are rejected.
+    pub fn with_max_subscriptions(
+        mut self,
+        max_subscriptions: u32,
+        max_subscriptions_per_connection: u32,
+    ) -> Self {
+        self.subscription_guard =
+            SubscriptionGuard::new(max_subscriptions, max_subscriptions_per_connection);
+        self
+    }
+
+    /// Configures the [GasPriceOracle] used for `gasPrice` subscriptions.
+    ///
+    /// Without an oracle, `gasPrice` subscriptions are rejected.
+    pub fn with_gas_oracle(mut self, gas_oracle: GasPriceOracle<Provider>) -> Self {
+        self.inner.gas_oracle = Some(Arc::new(gas_oracle));
+        self
     }
 }
 
@@ -67,7 +124,7 @@ impl<Provider, Pool, Events, Network> EthPubSub<Provider, Pool, Events, Network>
 impl<Provider, Pool, Events, Network> EthPubSubApiServer
     for EthPubSub<Provider, Pool, Events, Network>
 where
-    Provider: BlockProvider + EvmEnvProvider + Clone + 'static,
+    Provider: BlockProviderIdExt + EvmEnvProvider + Clone + 'static,
     Pool: TransactionPool + 'static,
     Events: CanonStateSubscriptions + Clone + 'static,
     Network: NetworkInfo + Clone + 'static,
@@ -79,10 +136,36 @@ where
         kind: SubscriptionKind,
         params: Option<Params>,
     ) -> jsonrpsee::core::SubscriptionResult {
+        let permit = match self.subscription_guard.try_acquire(pending.connection_id()) {
+            Ok(permit) => permit,
+            Err(err) => {
+                pending.reject(err).await;
+                return Ok(())
+            }
+        };
+        if kind == SubscriptionKind::GasPrice && self.inner.gas_oracle.is_none() {
+            pending
+                .reject(rpc_error_with_code(
+                    METHOD_NOT_FOUND_CODE,
+                    "gasPrice subscriptions are not supported",
+                ))
+                .await;
+            return Ok(())
+        }
+        if let (SubscriptionKind::Logs, Some(Params::Logs(filter))) = (&kind, &params) {
+            // reject the subscription before it's accepted if its logs can't be backfilled
+            if let Err(err) = self.inner.validate_logs_backfill(filter) {
+                pending.reject(err).await;
+                return Ok(())
+            }
+        }
         let sink = pending.accept().await?;
         let pubsub = self.inner.clone();
+        let task_spawner = self.subscription_task_spawner.clone();
         self.subscription_task_spawner.spawn(Box::pin(async move {
-            let _ = handle_accepted(pubsub, sink, kind, params).await;
+            let _ = handle_accepted(pubsub, sink, kind, params, task_spawner).await;
+            // release the subscription slot once the subscription ended
+            drop(permit);
         }));
 
         Ok(())
@@ -95,9 +178,10 @@ async fn handle_accepted<Provider, Pool, Events, Network>(
     accepted_sink: SubscriptionSink,
     kind: SubscriptionKind,
     params: Option<Params>,
+    task_spawner: Box<dyn TaskSpawner>,
 ) -> Result<(), jsonrpsee::core::Error>
 where
-    Provider: BlockProvider + EvmEnvProvider + Clone + 'static,
+    Provider: BlockProviderIdExt + EvmEnvProvider + Clone + 'static,
     Pool: TransactionPool + 'static,
     Events: CanonStateSubscriptions + Clone + 'static,
     Network: NetworkInfo + Clone + 'static,
@@ -115,13 +199,34 @@ where
                 Some(Params::Logs(filter)) => FilteredParams::new(Some(*filter)),
                 _ => FilteredParams::default(),
             };
-            let stream =
-                pubsub.into_log_stream(filter).map(|log| EthSubscriptionResult::Log(Box::new(log)));
-            pipe_from_stream(accepted_sink, stream).await
+            // if a `fromBlock` is provided, the matching logs since that block are sent first
+            match filter.filter.as_ref().and_then(|filter| filter.get_from_block()) {
+                Some(from_block) => {
+                    let stream = pubsub
+                        .into_log_stream_from(filter, from_block, task_spawner)
+                        .await
+                        .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))?
+                        .map(|log| EthSubscriptionResult::Log(Box::new(log)));
+                    pipe_from_stream(accepted_sink, stream).await
+                }
+                None => {
+                    let stream = pubsub
+                        .into_log_stream(filter)
+                        .map(|log| EthSubscriptionResult::Log(Box::new(log)));
+                    pipe_from_stream(accepted_sink, stream).await
+                }
+            }
         }
         SubscriptionKind::NewPendingTransactions => {
+            // if senders are provided, only forward transactions sent by them
+            let senders = match params {
+                Some(Params::NewPendingTransactions(filter)) => {
+                    Some(filter.from_address.into_iter().collect())
+                }
+                _ => None,
+            };
             let stream = pubsub
-                .into_pending_transaction_stream()
+                .into_pending_transaction_stream(senders)
                 .map(EthSubscriptionResult::TransactionHash);
             pipe_from_stream(accepted_sink, stream).await
         }
@@ -157,6 +262,12 @@ where
 
             Ok(())
         }
+        SubscriptionKind::GasPrice => {
+            let Some(gas_oracle) = pubsub.gas_oracle.clone() else { return Ok(()) };
+            let stream =
+                pubsub.into_gas_price_stream(gas_oracle).map(EthSubscriptionResult::GasPrice);
+            pipe_from_stream(accepted_sink, Box::pin(stream)).await
+        }
     }
 }
 
@@ -192,6 +303,101 @@ where
     }
 }
 
+/// Limits the number of concurrently active subscriptions, in total and per connection.
+#[derive(Clone, Debug)]
+struct SubscriptionGuard {
+    /// Permits for all active subscriptions.
+    subscriptions: Arc<Semaphore>,
+    /// The maximum number of active subscriptions in total.
+    max_subscriptions: u32,
+    /// The maximum number of active subscriptions of a single connection.
+    max_subscriptions_per_connection: u32,
+    /// Number of active subscriptions per connection.
+    connections: Arc<Mutex<HashMap<usize, u32>>>,
+}
+
+impl SubscriptionGuard {
+    /// Creates a new guard with the given limits.
+    fn new(max_subscriptions: u32, max_subscriptions_per_connection: u32) -> Self {
+        Self {
+            subscriptions: Arc::new(Semaphore::new(max_subscriptions as usize)),
+            max_subscriptions,
+            max_subscriptions_per_connection,
+            connections: Default::default(),
+        }
+    }
+
+    /// Reserves a slot for a new subscription of the given connection.
+    ///
+    /// The slot is released when the returned [SubscriptionPermit] is dropped.
+    fn try_acquire(
+        &self,
+        connection_id: usize,
+    ) -> Result<SubscriptionPermit, SubscriptionLimitError> {
+        let mut connections = self.connections.lock().expect("not poisoned");
+        let active = connections.get(&connection_id).copied().unwrap_or_default();
+        if active >= self.max_subscriptions_per_connection {
+            return Err(SubscriptionLimitError::MaxSubscriptionsPerConnection(
+                self.max_subscriptions_per_connection,
+            ))
+        }
+        let permit = self
+            .subscriptions
+            .clone()
+            .try_acquire_owned()
+            .map_err(|_| SubscriptionLimitError::MaxSubscriptions(self.max_subscriptions))?;
+        // only connections with active subscriptions are tracked, the entry is removed again when
+        // the last permit of the connection is dropped
+        *connections.entry(connection_id).or_default() += 1;
+
+        Ok(SubscriptionPermit {
+            _permit: permit,
+            connection_id,
+            connections: Arc::clone(&self.connections),
+        })
+    }
+}
+
+/// A reserved subscription slot of a [SubscriptionGuard].
+#[derive(Debug)]
+struct SubscriptionPermit {
+    /// The global permit, released on drop.
+    _permit: OwnedSemaphorePermit,
+    /// The connection the subscription belongs to.
+    connection_id: usize,
+    /// Number of active subscriptions per connection.
+    connections: Arc<Mutex<HashMap<usize, u32>>>,
+}
+
+impl Drop for SubscriptionPermit {
+    fn drop(&mut self) {
+        let mut connections = self.connections.lock().expect("not poisoned");
+        if let Some(active) = connections.get_mut(&self.connection_id) {
+            *active -= 1;
+            if *active == 0 {
+                connections.remove(&self.connection_id);
+            }
+        }
+    }
+}
+
+/// Errors returned when a subscription exceeds a configured limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+enum SubscriptionLimitError {
+    /// Too many active subscriptions in total.
+    #[error("too many active subscriptions (max {0})")]
+    MaxSubscriptions(u32),
+    /// Too many active subscriptions on this connection.
+    #[error("too many active subscriptions on this connection (max {0})")]
+    MaxSubscriptionsPerConnection(u32),
+}
+
+impl From<SubscriptionLimitError> for ErrorObject<'static> {
+    fn from(err: SubscriptionLimitError) -> Self {
+        rpc_error_with_code(TOO_MANY_SUBSCRIPTIONS_CODE, err.to_string())
+    }
+}
+
 impl<Provider, Pool, Events, Network> std::fmt::Debug
     for EthPubSub<Provider, Pool, Events, Network>
 {
@@ -211,6 +417,8 @@ struct EthPubSubInner<Provider, Pool, Events, Network> {
     chain_events: Events,
     /// The network.
     network: Network,
+    /// The gas price oracle for `gasPrice` subscriptions, if configured.
+    gas_oracle: Option<Arc<GasPriceOracle<Provider>>>,
 }
 
 // == impl EthPubSubInner ===
@@ -219,6 +427,32 @@ impl<Provider, Pool, Events, Network> EthPubSubInner<Provider, Pool, Events, Net
 where
     Provider: BlockProvider + 'static,
 {
+    /// Returns the last stored block whose logs are sent to a `logs` subscription with the given
+    /// filter before the logs of new blocks.
+    ///
+    /// This is the best block, unless the `toBlock` of the filter is lower.
+    fn logs_backfill_to_block(&self, filter: Option<&Filter>) -> EthResult<BlockNumber> {
+        let best_block = self.provider.best_block_number()?;
+        Ok(filter
+            .and_then(|filter| filter.get_to_block())
+            .map_or(best_block, |to_block| to_block.min(best_block)))
+    }
+
+    /// Checks that the stored logs of a `logs` subscription with the given filter can be sent.
+    ///
+    /// Returns an error if the `fromBlock` of the filter is more than [MAX_LOGS_BACKFILL_BLOCKS]
+    /// blocks in the past.
+    fn validate_logs_backfill(&self, filter: &Filter) -> EthResult<()> {
+        let Some(from_block) = filter.get_from_block() else { return Ok(()) };
+        let to_block = self.logs_backfill_to_block(Some(filter))?;
+        if to_block.saturating_sub(from_block) >= MAX_LOGS_BACKFILL_BLOCKS {
+            return Err(EthApiError::InvalidParams(format!(
+                "fromBlock is more than {MAX_LOGS_BACKFILL_BLOCKS} blocks in the past"
+            )))
+        }
+        Ok(())
+    }
+
     /// Returns the current sync status for the `syncing` subscription
     async fn sync_status(&self, is_syncing: bool) -> EthSubscriptionResult {
         if is_syncing {
@@ -241,8 +475,18 @@ where
     Pool: TransactionPool + 'static,
 {
     /// Returns a stream that yields all transactions emitted by the txpool.
-    fn into_pending_transaction_stream(self) -> impl Stream<Item = TxHash> {
-        ReceiverStream::new(self.pool.pending_transactions_listener())
+    ///
+    /// If `senders` is set, only transactions sent by one of the given addresses are yielded.
+    fn into_pending_transaction_stream(
+        self,
+        senders: Option<HashSet<Address>>,
+    ) -> impl Stream<Item = TxHash> {
+        ReceiverStream::new(self.pool.pending_transactions_listener()).filter(move |hash| {
+            let is_match = senders.as_ref().map_or(true, |senders| {
+                self.pool.get(hash).map_or(false, |tx| senders.contains(&tx.sender()))
+            });
+            futures::future::ready(is_match)
+        })
     }
 }
 
@@ -275,19 +519,460 @@ where
 
     /// Returns a stream that yields all logs that match the given filter.
     fn into_log_stream(self, filter: FilteredParams) -> impl Stream<Item = Log> {
-        BroadcastStream::new(self.chain_events.subscribe_to_canonical_state())
-            .map(move |canon_state| {
-                canon_state.expect("new block subscription never ends; qed").block_receipts()
-            })
-            .flat_map(futures::stream::iter)
-            .flat_map(move |(block_receipts, removed)| {
-                let all_logs = logs_utils::matching_block_logs(
-                    &filter,
-                    block_receipts.block,
-                    block_receipts.tx_receipts.into_iter(),
-                    removed,
-                );
-                futures::stream::iter(all_logs)
-            })
+        canon_state_log_stream(self.chain_events.subscribe_to_canonical_state(), filter)
+    }
+
+    /// Returns a stream that yields all logs that match the given filter, starting at
+    /// `from_block`.
+    ///
+    /// The matching logs of the blocks already in the database are yielded first, followed by
+    /// the logs of new blocks. Logs that were already yielded are not repeated, unless they are
+    /// reorged out.
+    ///
+    /// The stored logs are read on a blocking task spawned by the `task_spawner`. The range of
+    /// stored blocks must be checked with [Self::validate_logs_backfill] before the subscription
+    /// is accepted.
+    async fn into_log_stream_from(
+        self,
+        filter: FilteredParams,
+        from_block: BlockNumber,
+        task_spawner: Box<dyn TaskSpawner>,
+    ) -> EthResult<impl Stream<Item = Log>> {
+        // subscribe before reading the database, so that no new block is missed in between
+        let canon_state = self.chain_events.subscribe_to_canonical_state();
+
+        let to_block = self.logs_backfill_to_block(filter.filter.as_ref())?;
+        let provider = self.provider;
+
+        let (tx, rx) = oneshot::channel();
+        let historical_filter = FilteredParams::new(filter.filter.clone());
+        task_spawner.spawn_blocking(Box::pin(async move {
+            let _ = tx.send(logs_in_range(&provider, &historical_filter, from_block, to_block));
+        }));
+        let historical_logs = rx.await.map_err(|_| EthApiError::InternalEthError)??;
+
+        // the logs that were already yielded, by block hash and log index
+        let backfilled = historical_logs
+            .iter()
+            .filter_map(|log| Some((log.block_hash?, log.log_index?)))
+            .collect::<HashSet<(H256, U256)>>();
+        let live_logs = canon_state_log_stream(canon_state, filter).filter(move |log| {
+            let is_new = log.removed ||
+                log.block_hash.zip(log.log_index).map_or(true, |key| !backfilled.contains(&key));
+            futures::future::ready(is_new)
+        });
+
+        Ok(futures::stream::iter(historical_logs).chain(live_logs))
+    }
+}
+
+/// Returns all logs in the given _inclusive_ range of stored blocks that match the filter.
+fn logs_in_range<Provider>(
+    provider: &Provider,
+    filter: &FilteredParams,
+    from_block: BlockNumber,
+    to_block: BlockNumber,
+) -> reth_interfaces::Result<Vec<Log>>
+where
+    Provider: BlockProvider,
+{
+    let mut all_logs = Vec::new();
+    if from_block > to_block {
+        return Ok(all_logs)
+    }
+
+    // derive bloom filters from filter input
+    let (address_filter, topics_filter) = match &filter.filter {
+        Some(inner) => (
+            FilteredParams::address_filter(&inner.address),
+            FilteredParams::topics_filter(&inner.has_topics().then(|| filter.flat_topics.clone())),
+        ),
+        None => Default::default(),
+    };
+
+    for header in provider.sealed_headers_range(from_block..=to_block)? {
+        // only if filter matches
+        if !FilteredParams::matches_address(header.logs_bloom, &address_filter) ||
+            !FilteredParams::matches_topics(header.logs_bloom, &topics_filter)
+        {
+            continue
+        }
+
+        let (Some(transactions), Some(receipts)) = (
+            provider.transactions_by_block(header.hash.into())?,
+            provider.receipts_by_block(header.hash.into())?,
+        ) else {
+            continue
+        };
+
+        logs_utils::append_matching_block_logs(
+            &mut all_logs,
+            filter,
+            header.num_hash(),
+            transactions.into_iter().map(|tx| tx.hash()).zip(receipts),
+            false,
+        );
+    }
+
+    Ok(all_logs)
+}
+
+impl<Provider, Pool, Events, Network> EthPubSubInner<Provider, Pool, Events, Network>
+where
+    Provider: BlockProviderIdExt + 'static,
+    Events: CanonStateSubscriptions + 'static,
+{
+    /// Returns a stream that yields the suggested gas price for every new canonical tip.
+    ///
+    /// Only the tip of a committed chain is reported, so there's at most one update per block.
+    fn into_gas_price_stream(
+        self,
+        gas_oracle: Arc<GasPriceOracle<Provider>>,
+    ) -> impl Stream<Item = GasPriceUpdate> {
+        let mut last_tip = None;
+        BroadcastStream::new(self.chain_events.subscribe_to_canonical_state()).filter_map(
+            move |canon_state| {
+                let canon_state = canon_state.expect("new block subscription never ends; qed");
+                // reverts don't add a new tip
+                let tip = canon_state.committed().map(|new| new.tip().header.clone());
+                let tip = tip.filter(|tip| last_tip.replace(tip.hash) != Some(tip.hash));
+                let gas_oracle = gas_oracle.clone();
+                async move {
+                    let tip = tip?;
+                    let suggested_tip = gas_oracle.suggest_tip_cap().await.ok()?;
+                    let base_fee = tip.base_fee_per_gas.map(U256::from);
+                    Some(GasPriceUpdate {
+                        block_number: tip.number,
+                        block_hash: tip.hash,
+                        base_fee_per_gas: base_fee,
+                        gas_price: suggested_tip + base_fee.unwrap_or_default(),
+                    })
+                }
+            },
+        )
+    }
+}
+
+/// Returns a stream that yields all logs of new canonical blocks that match the given filter.
+fn canon_state_log_stream(
+    canon_state: CanonStateNotifications,
+    filter: FilteredParams,
+) -> impl Stream<Item = Log> {
+    BroadcastStream::new(canon_state)
+        .map(move |canon_state| {
+            canon_state.expect("new block subscription never ends; qed").block_receipts()
+        })
+        .flat_map(futures::stream::iter)
+        .flat_map(move |(block_receipts, removed)| {
+            let all_logs = logs_utils::matching_block_logs(
+                &filter,
+                block_receipts.block,
+                block_receipts.tx_receipts.into_iter(),
+                removed,
+            );
+            futures::stream::iter(all_logs)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::cache::EthStateCache;
+    use reth_network_api::test_utils::NoopNetwork;
+    use reth_primitives::{
+        bloom::logs_bloom, constants::GWEI_TO_WEI, Header, Receipt, SealedBlock,
+        SealedBlockWithSenders, Signature, Transaction, TransactionSigned, TxLegacy, TxType, H256,
+    };
+    use reth_provider::{
+        test_utils::{MockEthProvider, NoopProvider, TestCanonStateSubscriptions},
+        Chain, PostState,
+    };
+    use reth_transaction_pool::test_utils::{testing_pool, MockTransaction};
+
+    #[tokio::test]
+    async fn pending_transactions_filtered_by_sender() {
+        let pool = testing_pool();
+        let pubsub = EthPubSubInner {
+            pool: pool.clone(),
+            provider: NoopProvider::default(),
+            chain_events: (),
+            network: (),
+            gas_oracle: None,
+        };
+
+        let watched = Address::random();
+        let mut stream = pubsub.into_pending_transaction_stream(Some(HashSet::from([watched])));
+
+        let first = MockTransaction::eip1559().with_sender(watched);
+        let second = first.next();
+        let transactions = vec![
+            first.clone(),
+            MockTransaction::eip1559(),
+            second.clone(),
+            MockTransaction::eip1559(),
+        ];
+        for tx in transactions {
+            pool.add_external_transaction(tx).await.unwrap();
+        }
+
+        assert_eq!(stream.next().await, Some(first.get_hash()));
+        assert_eq!(stream.next().await, Some(second.get_hash()));
+
+        // transactions from other senders are skipped
+        let third = second.next();
+        pool.add_external_transaction(MockTransaction::eip1559()).await.unwrap();
+        pool.add_external_transaction(third.clone()).await.unwrap();
+        assert_eq!(stream.next().await, Some(third.get_hash()));
+    }
+
+    /// Returns a block with a single transaction that emits a log of the contract.
+    fn block_with_log(
+        number: u64,
+        parent_hash: H256,
+        contract: Address,
+        nonce: u64,
+    ) -> (SealedBlock, Receipt) {
+        let log =
+            reth_primitives::Log { address: contract, topics: vec![], data: Default::default() };
+        let receipt = Receipt {
+            tx_type: TxType::Legacy,
+            success: true,
+            cumulative_gas_used: 21_000,
+            logs: vec![log],
+        };
+        let header = Header {
+            number,
+            parent_hash,
+            logs_bloom: logs_bloom(&receipt.logs),
+            ..Default::default()
+        }
+        .seal_slow();
+        let transaction = TransactionSigned::from_transaction_and_signature(
+            Transaction::Legacy(TxLegacy { nonce, ..Default::default() }),
+            Signature::default(),
+        );
+        let block =
+            SealedBlock { header, body: vec![transaction], ommers: vec![], withdrawals: None };
+        (block, receipt)
+    }
+
+    fn chain_of(blocks: &[(SealedBlock, Receipt)]) -> Arc<Chain> {
+        Arc::new(Chain::new(
+            blocks
+                .iter()
+                .map(|(block, receipt)| {
+                    let mut post_state = PostState::default();
+                    post_state.add_receipt(block.number, receipt.clone());
+                    let senders = vec![Address::random()];
+                    (SealedBlockWithSenders { block: block.clone(), senders }, post_state)
+                })
+                .collect(),
+        ))
+    }
+
+    /// Stores blocks 0..=2 and returns them, together with block 3 that's not stored yet.
+    fn stored_blocks(provider: &MockEthProvider, contract: Address) -> Vec<(SealedBlock, Receipt)> {
+        let mut blocks = Vec::new();
+        let mut parent_hash = H256::zero();
+        for number in 0..4u64 {
+            let (block, receipt) = block_with_log(number, parent_hash, contract, number);
+            parent_hash = block.hash;
+            blocks.push((block, receipt));
+        }
+        for (block, receipt) in &blocks[..3] {
+            provider.add_block(block.hash, block.clone().unseal());
+            provider.add_receipts(block.hash, vec![receipt.clone()]);
+        }
+        blocks
+    }
+
+    #[tokio::test]
+    async fn logs_from_past_block() {
+        let provider = MockEthProvider::default();
+        let mut chain_events = TestCanonStateSubscriptions::default();
+        let contract = Address::random();
+        let blocks = stored_blocks(&provider, contract);
+
+        let pubsub = EthPubSubInner {
+            pool: (),
+            provider,
+            chain_events: chain_events.clone(),
+            network: NoopNetwork,
+            gas_oracle: None,
+        };
+        let filter = Filter::new().address(contract).from_block(1u64);
+        let stream = pubsub
+            .into_log_stream_from(
+                FilteredParams::new(Some(filter)),
+                1,
+                Box::<TokioTaskExecutor>::default(),
+            )
+            .await
+            .unwrap();
+
+        // the new chain overlaps with the stored block 2
+        chain_events.add_next_commit(chain_of(&blocks[2..]));
+
+        // historical logs are yielded first, the boundary block is not repeated
+        let block_numbers =
+            stream.take(3).map(|log| log.block_number.unwrap()).collect::<Vec<_>>().await;
+        assert_eq!(block_numbers, vec![U256::from(1), U256::from(2), U256::from(3)]);
+    }
+
+    #[tokio::test]
+    async fn logs_from_past_block_reorg() {
+        let provider = MockEthProvider::default();
+        let mut chain_events = TestCanonStateSubscriptions::default();
+        let contract = Address::random();
+        let blocks = stored_blocks(&provider, contract);
+
+        let pubsub = EthPubSubInner {
+            pool: (),
+            provider,
+            chain_events: chain_events.clone(),
+            network: NoopNetwork,
+            gas_oracle: None,
+        };
+        let filter = Filter::new().address(contract).from_block(1u64);
+        let stream = pubsub
+            .into_log_stream_from(
+                FilteredParams::new(Some(filter)),
+                1,
+                Box::<TokioTaskExecutor>::default(),
+            )
+            .await
+            .unwrap();
+
+        // the backfilled block 2 is replaced by another block at the same height
+        let replacement = block_with_log(2, blocks[1].0.hash, contract, 42);
+        chain_events.add_next_reorg(chain_of(&blocks[2..3]), chain_of(&[replacement.clone()]));
+
+        let logs = stream
+            .take(4)
+            .map(|log| (log.block_number.unwrap(), log.block_hash.unwrap(), log.removed))
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            logs,
+            vec![
+                (U256::from(1), blocks[1].0.hash, false),
+                (U256::from(2), blocks[2].0.hash, false),
+                (U256::from(2), blocks[2].0.hash, true),
+                (U256::from(2), replacement.0.hash, false),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn logs_from_block_too_far_in_the_past() {
+        let provider = MockEthProvider::default();
+        let contract = Address::random();
+        let (block, receipt) = block_with_log(MAX_LOGS_BACKFILL_BLOCKS, H256::zero(), contract, 0);
+        provider.add_block(block.hash, block.clone().unseal());
+        provider.add_receipts(block.hash, vec![receipt]);
+
+        let pubsub = EthPubSubInner {
+            pool: (),
+            provider,
+            chain_events: TestCanonStateSubscriptions::default(),
+            network: NoopNetwork,
+            gas_oracle: None,
+        };
+        let filter = Filter::new().address(contract).from_block(0u64);
+        let res = pubsub.validate_logs_backfill(&filter);
+        assert!(matches!(res, Err(EthApiError::InvalidParams(_))));
+
+        // the range is limited by the `toBlock`, or there's nothing to backfill
+        let filter = Filter::new().address(contract).from_block(0u64).to_block(1u64);
+        assert!(pubsub.validate_logs_backfill(&filter).is_ok());
+        assert!(pubsub.validate_logs_backfill(&Filter::new().address(contract)).is_ok());
+        let filter = Filter::new().address(contract).from_block(1u64);
+        assert!(pubsub.validate_logs_backfill(&filter).is_ok());
+    }
+
+    #[tokio::test]
+    async fn gas_price_per_block() {
+        let provider = MockEthProvider::default();
+        let mut chain_events = TestCanonStateSubscriptions::default();
+        let cache = EthStateCache::spawn(provider.clone(), Default::default());
+        let gas_oracle = GasPriceOracle::new(provider.clone(), Default::default(), cache);
+
+        let pubsub = EthPubSubInner {
+            pool: (),
+            provider: provider.clone(),
+            chain_events: chain_events.clone(),
+            network: (),
+            gas_oracle: None,
+        };
+        let mut stream = Box::pin(pubsub.into_gas_price_stream(Arc::new(gas_oracle)));
+
+        let mut parent_hash = H256::zero();
+        for number in 0..3u64 {
+            let base_fee = 7 + number;
+            let header = Header {
+                number,
+                parent_hash,
+                base_fee_per_gas: Some(base_fee),
+                ..Default::default()
+            }
+            .seal_slow();
+            parent_hash = header.hash;
+            let block = SealedBlock { header, body: vec![], ommers: vec![], withdrawals: None };
+            provider.add_block(block.hash, block.clone().unseal());
+
+            let new_chain = Arc::new(Chain::new(vec![(
+                SealedBlockWithSenders { block: block.clone(), senders: vec![] },
+                PostState::default(),
+            )]));
+            // the same tip is only reported once
+            chain_events.add_next_commit(new_chain.clone());
+            chain_events.add_next_commit(new_chain);
+
+            let update = stream.next().await.unwrap();
+            assert_eq!(update.block_number, number);
+            assert_eq!(update.block_hash, block.hash);
+            assert_eq!(update.base_fee_per_gas, Some(U256::from(base_fee)));
+            // no transactions yet, so the default tip of the oracle is suggested
+            assert_eq!(update.gas_price, U256::from(GWEI_TO_WEI + base_fee));
+        }
+    }
+
+    #[test]
+    fn subscription_limits() {
+        let guard = SubscriptionGuard::new(3, 2);
+
+        let first = guard.try_acquire(1).unwrap();
+        let _second = guard.try_acquire(1).unwrap();
+        assert_eq!(
+            guard.try_acquire(1).unwrap_err(),
+            SubscriptionLimitError::MaxSubscriptionsPerConnection(2)
+        );
+
+        // other connections are only bound by the global limit
+        let _third = guard.try_acquire(2).unwrap();
+        assert_eq!(guard.try_acquire(2).unwrap_err(), SubscriptionLimitError::MaxSubscriptions(3));
+
+        // ended subscriptions release their slot
+        drop(first);
+        let _fourth = guard.try_acquire(1).unwrap();
+        assert_eq!(
+            guard.try_acquire(1).unwrap_err(),
+            SubscriptionLimitError::MaxSubscriptionsPerConnection(2)
+        );
+    }
+
+    #[test]
+    fn subscription_limits_untrack_connections() {
+        let guard = SubscriptionGuard::new(1, 1);
+
+        let first = guard.try_acquire(1).unwrap();
+        assert_eq!(guard.connections.lock().unwrap().len(), 1);
+
+        // rejected subscriptions don't track their connection
+        assert_eq!(guard.try_acquire(2).unwrap_err(), SubscriptionLimitError::MaxSubscriptions(1));
+        assert!(!guard.connections.lock().unwrap().contains_key(&2));
+
+        drop(first);
+        assert!(guard.connections.lock().unwrap().is_empty());
     }
 }
@@ -2,7 +2,8 @@
 
 use crate::eth::error::{EthApiError, EthResult, RpcInvalidTransactionError};
 use reth_primitives::{
-    AccessList, Address, TransactionSigned, TransactionSignedEcRecovered, TxHash, H256, U256,
+    keccak256, AccessList, Address, ChainSpec, Head, Transaction, TransactionKind,
+    TransactionSigned, TransactionSignedEcRecovered, TxEip2930, TxHash, H256, U256,
 };
 use reth_revm::env::{fill_tx_env, fill_tx_env_with_recovered};
 use reth_rpc_types::{
@@ -109,6 +110,30 @@ pub(crate) fn get_precompiles(spec_id: &SpecId) -> Vec<reth_primitives::H160> {
     Precompiles::new(spec).addresses().into_iter().map(Address::from).collect()
 }
 
+/// Returns the intrinsic gas of the [CallRequest], the gas that is charged before execution.
+///
+/// The active hardforks are derived from the number and timestamp of the [BlockEnv], which is
+/// sufficient since none of the hardforks that change the intrinsic gas are activated by total
+/// difficulty.
+pub(crate) fn call_request_intrinsic_gas(
+    chain_spec: &ChainSpec,
+    block: &BlockEnv,
+    request: &CallRequest,
+) -> u64 {
+    let tx = Transaction::Eip2930(TxEip2930 {
+        to: request.to.map(TransactionKind::Call).unwrap_or(TransactionKind::Create),
+        input: request.data.clone().unwrap_or_default(),
+        access_list: request.access_list.clone().unwrap_or_default(),
+        ..Default::default()
+    });
+    let head = Head {
+        number: block.number.to::<u64>(),
+        timestamp: block.timestamp.to::<u64>(),
+        ..Default::default()
+    };
+    tx.intrinsic_gas(chain_spec, head)
+}
+
 /// Executes the [Env] against the given [Database] without committing state changes.
 pub(crate) fn transact<DB>(db: DB, env: Env) -> EthResult<(ResultAndState, Env)>
 where
@@ -438,6 +463,9 @@ where
         account_info.nonce = nonce.as_u64();
     }
     if let Some(code) = account_override.code {
+        // the code hash must match the new code, otherwise the overridden code could be looked up
+        // by the hash of the account's original code
+        account_info.code_hash = keccak256(&code);
         account_info.code = Some(Bytecode::new_raw(code.0));
     }
     if let Some(balance) = account_override.balance {
@@ -478,3 +506,142 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{Bytes, U64};
+    use reth_provider::test_utils::NoopProvider;
+    use reth_revm::database::{State, SubState};
+    use revm::primitives::AccountInfo;
+    use std::collections::HashMap;
+
+    /// `TIMESTAMP PUSH1 0x00 MSTORE PUSH1 0x20 PUSH1 0x00 RETURN`
+    const RETURN_TIMESTAMP: [u8; 9] = [0x42, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3];
+
+    /// `PUSH1 0x2a PUSH1 0x00 MSTORE PUSH1 0x20 PUSH1 0x00 RETURN`
+    const RETURN_42: [u8; 10] = [0x60, 0x2a, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3];
+
+    fn call(
+        db: &mut SubState<NoopProvider>,
+        block: BlockEnv,
+        to: Address,
+        overrides: EvmOverrides,
+    ) -> U256 {
+        let request = CallRequest { to: Some(to), ..Default::default() };
+        let env = prepare_call_env(CfgEnv::default(), block, request, db, overrides).unwrap();
+        let (res, _) = transact(db, env).unwrap();
+        U256::from_be_slice(res.result.output().unwrap())
+    }
+
+    #[test]
+    fn block_overrides_timestamp() {
+        let mut db = CacheDB::new(State::new(NoopProvider::default()));
+        let contract = Address::random();
+        let state = HashMap::from([(
+            contract,
+            AccountOverride {
+                code: Some(Bytes::from(RETURN_TIMESTAMP.to_vec())),
+                ..Default::default()
+            },
+        )]);
+        let block = BlockEnv {
+            timestamp: U256::from(1_000),
+            gas_limit: U256::from(1_000_000),
+            ..Default::default()
+        };
+
+        let overrides = EvmOverrides::new(
+            Some(state),
+            Some(Box::new(BlockOverrides { time: Some(U64::from(1_337)), ..Default::default() })),
+        );
+        assert_eq!(call(&mut db, block.clone(), contract, overrides), U256::from(1_337));
+
+        // the block override only applies to the call it was provided for
+        assert_eq!(call(&mut db, block, contract, EvmOverrides::default()), U256::from(1_000));
+    }
+
+    #[test]
+    fn state_overrides_code() {
+        let mut db = CacheDB::new(State::new(NoopProvider::default()));
+        let contract = Address::random();
+        let code = Bytes::from(RETURN_TIMESTAMP.to_vec());
+        db.insert_account_info(
+            contract,
+            AccountInfo {
+                code_hash: keccak256(&code),
+                code: Some(Bytecode::new_raw(code.0)),
+                ..Default::default()
+            },
+        );
+        let block = BlockEnv {
+            timestamp: U256::from(1_000),
+            gas_limit: U256::from(1_000_000),
+            ..Default::default()
+        };
+        assert_eq!(
+            call(&mut db, block.clone(), contract, EvmOverrides::default()),
+            U256::from(1_000)
+        );
+
+        let stub = Bytes::from(RETURN_42.to_vec());
+        let state = HashMap::from([(
+            contract,
+            AccountOverride { code: Some(stub.clone()), ..Default::default() },
+        )]);
+        assert_eq!(
+            call(&mut db, block, contract, EvmOverrides::state(Some(state))),
+            U256::from(42)
+        );
+
+        let account = db.basic(contract).unwrap().unwrap();
+        assert_eq!(account.code_hash, keccak256(&stub));
+        assert_eq!(account.code.unwrap().original_bytes(), stub.0);
+    }
+
+    #[test]
+    fn state_overrides_storage() {
+        let contract = Address::random();
+        let db_with_storage = || {
+            let mut db = CacheDB::new(State::new(NoopProvider::default()));
+            db.insert_account_storage(contract, U256::from(0), U256::from(1)).unwrap();
+            db.insert_account_storage(contract, U256::from(1), U256::from(2)).unwrap();
+            db
+        };
+        let slots = HashMap::from([(H256::from_low_u64_be(1), H256::from_low_u64_be(5))]);
+
+        // `stateDiff` only patches the given slots
+        let mut db = db_with_storage();
+        let state = HashMap::from([(
+            contract,
+            AccountOverride { state_diff: Some(slots.clone()), ..Default::default() },
+        )]);
+        apply_state_overrides(state, &mut db).unwrap();
+        assert_eq!(db.storage(contract, U256::from(0)).unwrap(), U256::from(1));
+        assert_eq!(db.storage(contract, U256::from(1)).unwrap(), U256::from(5));
+
+        // `state` replaces the entire storage of the account
+        let mut db = db_with_storage();
+        let state = HashMap::from([(
+            contract,
+            AccountOverride { state: Some(slots.clone()), ..Default::default() },
+        )]);
+        apply_state_overrides(state, &mut db).unwrap();
+        assert_eq!(db.storage(contract, U256::from(0)).unwrap(), U256::ZERO);
+        assert_eq!(db.storage(contract, U256::from(1)).unwrap(), U256::from(5));
+
+        let mut db = db_with_storage();
+        let state = HashMap::from([(
+            contract,
+            AccountOverride {
+                state: Some(slots.clone()),
+                state_diff: Some(slots),
+                ..Default::default()
+            },
+        )]);
+        assert!(matches!(
+            apply_state_overrides(state, &mut db),
+            Err(EthApiError::BothStateAndStateDiffInOverride(account)) if account == contract
+        ));
+    }
+}
@@ -1,7 +1,11 @@
 //! Commonly used code snippets
 
 use crate::eth::error::{EthApiError, EthResult};
-use reth_primitives::{Bytes, TransactionSigned, TransactionSignedEcRecovered};
+use reth_interfaces::{provider::ProviderError, Result};
+use reth_primitives::{
+    BlockNumberOrTag, Bytes, ChainInfo, TransactionSigned, TransactionSignedEcRecovered,
+};
+use reth_provider::BlockNumProvider;
 
 /// Recovers a [TransactionSignedEcRecovered] from an enveloped encoded byte stream.
 ///
@@ -16,3 +20,36 @@ pub(crate) fn recover_raw_transaction(data: Bytes) -> EthResult<TransactionSigne
 
     transaction.into_ecrecovered().ok_or(EthApiError::InvalidTransactionSignature)
 }
+
+/// Replaces [BlockNumberOrTag::Latest] with the block `latest_block_lag` blocks behind the tip of
+/// the chain.
+///
+/// Other tags are returned unchanged.
+pub(crate) fn resolve_latest_tag(
+    provider: &impl BlockNumProvider,
+    num: BlockNumberOrTag,
+    latest_block_lag: u64,
+) -> Result<BlockNumberOrTag> {
+    if latest_block_lag == 0 || !num.is_latest() {
+        return Ok(num)
+    }
+    let tip = provider.best_block_number()?;
+    Ok(BlockNumberOrTag::Number(tip.saturating_sub(latest_block_lag)))
+}
+
+/// Returns the [ChainInfo] of the block `latest_block_lag` blocks behind the tip of the chain, see
+/// [resolve_latest_tag].
+pub(crate) fn lagged_chain_info(
+    provider: &impl BlockNumProvider,
+    latest_block_lag: u64,
+) -> Result<ChainInfo> {
+    let info = provider.chain_info()?;
+    if latest_block_lag == 0 {
+        return Ok(info)
+    }
+    let best_number = info.best_number.saturating_sub(latest_block_lag);
+    let best_hash = provider
+        .block_hash(best_number)?
+        .ok_or_else(|| ProviderError::HeaderNotFound(best_number.into()))?;
+    Ok(ChainInfo { best_hash, best_number })
+}
@@ -0,0 +1,182 @@
+use crate::result::rpc_error_with_code;
+use futures::FutureExt;
+use jsonrpsee::{
+    core::server::helpers::MethodResponse,
+    types::{ErrorObject, Id, Params},
+    MethodCallback, Methods,
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// The JSON-RPC error code for requests that exceed a limit of the server, see also
+/// [EIP-1474](https://eips.ethereum.org/EIPS/eip-1474).
+pub const LIMIT_EXCEEDED_ERROR_CODE: i32 = -32005;
+
+/// Limits the number of JSON-RPC calls per second for individual method namespaces, e.g. `debug`
+/// for all `debug_` methods.
+///
+/// The limits are enforced when a call is dispatched to its method, see
+/// [RateLimiter::rate_limit_methods], so they apply to the calls of a batch and to calls over an
+/// established WS connection alike. A call to a namespace that exceeded its limit isn't executed
+/// and is answered with a JSON-RPC error with [LIMIT_EXCEEDED_ERROR_CODE], the other calls of the
+/// same batch are unaffected. Calls to namespaces without a configured limit and subscriptions are
+/// never limited.
+///
+/// The limits are shared by all servers that dispatch to methods of the same [RateLimiter].
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    windows: Arc<Mutex<HashMap<String, RateLimitWindow>>>,
+}
+
+impl RateLimiter {
+    /// Creates a new [RateLimiter] from the requests-per-second limits of the given namespaces.
+    pub fn new(limits: impl IntoIterator<Item = (String, u32)>) -> Self {
+        let windows = limits
+            .into_iter()
+            .map(|(namespace, requests_per_second)| {
+                (namespace, RateLimitWindow::new(requests_per_second))
+            })
+            .collect();
+        Self { windows: Arc::new(Mutex::new(windows)) }
+    }
+
+    /// Returns the given methods with every method of a limited namespace wrapped, so that its
+    /// calls count towards the limit of the namespace.
+    pub fn rate_limit_methods(&self, methods: impl Into<Methods>) -> Methods {
+        let methods = methods.into();
+        let mut limited = Methods::new();
+        for name in methods.method_names() {
+            let (name, callback) = methods.method_with_name(name).expect("method exists");
+            let callback = match name.split_once('_') {
+                Some((namespace, _)) if self.is_limited(namespace) => {
+                    self.wrap(namespace.to_string(), callback.clone())
+                }
+                _ => callback.clone(),
+            };
+            limited.verify_and_insert(name, callback).expect("method names are unique");
+        }
+        limited
+    }
+
+    /// Returns `true` if there's a limit for the given namespace.
+    fn is_limited(&self, namespace: &str) -> bool {
+        self.windows.lock().unwrap().contains_key(namespace)
+    }
+
+    /// Wraps the callback of a method of the given namespace.
+    ///
+    /// Subscriptions are returned as they are.
+    fn wrap(&self, namespace: String, callback: MethodCallback) -> MethodCallback {
+        let limiter = self.clone();
+        match callback {
+            MethodCallback::Sync(callback) => MethodCallback::Sync(Arc::new(
+                move |id: Id<'_>, params: Params<'_>, max_response_size: usize| match limiter
+                    .try_acquire(&namespace)
+                {
+                    Ok(()) => callback(id, params, max_response_size),
+                    Err(err) => MethodResponse::error(id, err),
+                },
+            )),
+            MethodCallback::Async(callback) => MethodCallback::Async(Arc::new(
+                move |id: Id<'static>,
+                      params: Params<'static>,
+                      conn_id: usize,
+                      max_response_size: usize| {
+                    match limiter.try_acquire(&namespace) {
+                        Ok(()) => callback(id, params, conn_id, max_response_size),
+                        Err(err) => futures::future::ready(MethodResponse::error(id, err)).boxed(),
+                    }
+                },
+            )),
+            subscription => subscription,
+        }
+    }
+
+    /// Counts a call towards the limit of the given namespace.
+    ///
+    /// Returns the error the call is answered with if the namespace exceeded its limit.
+    fn try_acquire(&self, namespace: &str) -> Result<(), ErrorObject<'static>> {
+        let mut windows = self.windows.lock().unwrap();
+        let Some(window) = windows.get_mut(namespace) else { return Ok(()) };
+        if window.try_acquire(Instant::now()) {
+            Ok(())
+        } else {
+            Err(rpc_error_with_code(
+                LIMIT_EXCEEDED_ERROR_CODE,
+                format!("rate limit of the {namespace} namespace exceeded"),
+            ))
+        }
+    }
+}
+
+/// A fixed window of one second in which at most `requests_per_second` calls are allowed.
+#[derive(Debug)]
+struct RateLimitWindow {
+    requests_per_second: u32,
+    /// When the current window started, `None` if there weren't any calls yet.
+    started_at: Option<Instant>,
+    /// The number of calls in the current window.
+    requests: u32,
+}
+
+impl RateLimitWindow {
+    fn new(requests_per_second: u32) -> Self {
+        Self { requests_per_second, started_at: None, requests: 0 }
+    }
+
+    /// Counts a call at the given time, returns `false` if the limit of the window is exceeded.
+    fn try_acquire(&mut self, now: Instant) -> bool {
+        let expired = self.started_at.map_or(true, |started_at| {
+            now.saturating_duration_since(started_at) >= Duration::from_secs(1)
+        });
+        if expired {
+            self.started_at = Some(now);
+            self.requests = 0;
+        }
+
+        if self.requests >= self.requests_per_second {
+            return false
+        }
+        self.requests += 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_resets_after_one_second() {
+        let mut window = RateLimitWindow::new(2);
+        let now = Instant::now();
+
+        assert!(window.try_acquire(now));
+        assert!(window.try_acquire(now + Duration::from_millis(500)));
+        assert!(!window.try_acquire(now + Duration::from_millis(999)));
+        assert!(window.try_acquire(now + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn limits_namespaces_independently() {
+        let limiter = RateLimiter::new([("debug".to_string(), 1)]);
+
+        assert!(limiter.is_limited("debug"));
+        assert!(!limiter.is_limited("eth"));
+
+        assert!(limiter.try_acquire("debug").is_ok());
+        let err = limiter.try_acquire("debug").unwrap_err();
+        assert_eq!(err.code(), LIMIT_EXCEEDED_ERROR_CODE);
+
+        // namespaces without a limit are never rejected
+        for _ in 0..5 {
+            assert!(limiter.try_acquire("eth").is_ok());
+        }
+
+        // clones share the limits
+        assert!(limiter.clone().try_acquire("debug").is_err());
+    }
+}
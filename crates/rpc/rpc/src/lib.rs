@@ -38,10 +38,13 @@ mod web3;
 
 pub use admin::AdminApi;
 pub use call_guard::TracingCallGuard;
-pub use debug::DebugApi;
+pub use debug::{DebugApi, StructLogLimits};
 pub use engine::{EngineApi, EngineEthApi};
 pub use eth::{EthApi, EthApiSpec, EthFilter, EthPubSub, EthSubscriptionIdProvider};
-pub use layers::{AuthLayer, AuthValidator, Claims, JwtAuthValidator, JwtError, JwtSecret};
+pub use layers::{
+    AuthLayer, AuthValidator, Claims, JwtAuthValidator, JwtError, JwtSecret, RateLimiter,
+    LIMIT_EXCEEDED_ERROR_CODE,
+};
 pub use net::NetApi;
 pub use rpc::RPCApi;
 pub use trace::TraceApi;
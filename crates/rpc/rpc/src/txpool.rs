@@ -34,10 +34,10 @@ where
         #[inline]
         fn insert<T: PoolTransaction>(
             tx: &T,
-            content: &mut BTreeMap<Address, BTreeMap<String, Transaction>>,
+            content: &mut BTreeMap<Address, BTreeMap<u64, Transaction>>,
         ) {
             let entry = content.entry(tx.sender()).or_default();
-            let key = tx.nonce().to_string();
+            let key = tx.nonce();
             let tx = tx.to_recovered_transaction();
             let tx = Transaction::from_recovered(tx);
             entry.insert(key, tx);
@@ -133,9 +133,9 @@ where
     /// block(s), as well as the ones that are being scheduled for future execution only.
     ///
     /// See [here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_content) for more details
-    /// Handler for `txpool_inspect`
+    /// Handler for `txpool_content`
     async fn txpool_content(&self) -> Result<TxpoolContent> {
-        trace!(target: "rpc::eth", "Serving txpool_inspect");
+        trace!(target: "rpc::eth", "Serving txpool_content");
         Ok(self.content())
     }
 }
@@ -145,3 +145,68 @@ impl<Pool> std::fmt::Debug for TxPoolApi<Pool> {
         f.debug_struct("TxpoolApi").finish_non_exhaustive()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_transaction_pool::{
+        test_utils::{testing_pool, MockTransaction},
+        TransactionOrigin,
+    };
+
+    #[tokio::test]
+    async fn content_and_status() {
+        let pool = testing_pool();
+        let api = TxPoolApi::new(pool.clone());
+
+        let tx = MockTransaction::eip1559();
+        let sender = tx.get_sender();
+        let replaced = tx.next();
+        let replacement = replaced.inc_price_by(1_000_000_000).rng_hash();
+        // nonce gap
+        let queued = tx.skip(2);
+        for tx in [tx.clone(), replaced, replacement.clone(), queued.clone()] {
+            pool.add_transaction(TransactionOrigin::External, tx).await.unwrap();
+        }
+
+        let status = api.txpool_status().await.unwrap();
+        assert_eq!(status, TxpoolStatus { pending: U64::from(2), queued: U64::from(1) });
+
+        let content = api.txpool_content().await.unwrap();
+        let pending = &content.pending[&sender];
+        assert_eq!(pending.keys().copied().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(pending[&0].hash, tx.get_hash());
+        // only the replacement of the transaction with nonce 1 is included
+        assert_eq!(pending[&1].hash, replacement.get_hash());
+        assert_eq!(content.queued[&sender].keys().copied().collect::<Vec<_>>(), vec![3]);
+        assert_eq!(content.queued[&sender][&3].hash, queued.get_hash());
+    }
+
+    #[tokio::test]
+    async fn content_nonces_are_ascending() {
+        let pool = testing_pool();
+        let api = TxPoolApi::new(pool.clone());
+
+        let mut tx = MockTransaction::eip1559();
+        let sender = tx.get_sender();
+        let mut hashes = Vec::new();
+        for _ in 0..11 {
+            hashes.push(tx.get_hash());
+            pool.add_transaction(TransactionOrigin::External, tx.clone()).await.unwrap();
+            tx = tx.next();
+        }
+
+        let content = api.txpool_content().await.unwrap();
+        let pending = &content.pending[&sender];
+        assert_eq!(pending.keys().copied().collect::<Vec<_>>(), (0..11).collect::<Vec<_>>());
+        for (nonce, hash) in hashes.iter().enumerate() {
+            assert_eq!(pending[&(nonce as u64)].hash, *hash);
+        }
+
+        // the nonces are serialized as decimal strings in ascending order
+        let json = serde_json::to_string(&api.txpool_content_from(sender).await.unwrap()).unwrap();
+        let nonce_9 = json.find(r#""9":"#).unwrap();
+        let nonce_10 = json.find(r#""10":"#).unwrap();
+        assert!(nonce_9 < nonce_10);
+    }
+}
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::{pipeline::BoxedStage, Pipeline, Stage, StageSet};
 use reth_db::database::Database;
+use reth_interfaces::p2p::download::DownloadClient;
 use reth_primitives::{stage::StageId, BlockNumber, ChainSpec, H256};
 use tokio::sync::watch;
 
@@ -17,6 +18,8 @@ where
     max_block: Option<BlockNumber>,
     /// A receiver for the current chain tip to sync to.
     tip_tx: Option<watch::Sender<H256>>,
+    /// The minimum number of connected peers required to run the downloading stages.
+    min_peers: Option<(usize, Arc<dyn DownloadClient>)>,
 }
 
 impl<DB> PipelineBuilder<DB>
@@ -60,17 +63,30 @@ where
         self
     }
 
+    /// Set the minimum number of peers the client has to be connected to before the downloading
+    /// stages are run.
+    ///
+    /// See also [StageId::is_downloading_stage].
+    pub fn with_min_peers<C>(mut self, min_peers: usize, client: C) -> Self
+    where
+        C: DownloadClient + 'static,
+    {
+        self.min_peers = Some((min_peers, Arc::new(client)));
+        self
+    }
+
     /// Builds the final [`Pipeline`] using the given database.
     ///
     /// Note: it's expected that this is either an [Arc](std::sync::Arc) or an Arc wrapper type.
     pub fn build(self, db: DB, chain_spec: Arc<ChainSpec>) -> Pipeline<DB> {
-        let Self { stages, max_block, tip_tx } = self;
+        let Self { stages, max_block, tip_tx, min_peers } = self;
         Pipeline {
             db,
             chain_spec,
             stages,
             max_block,
             tip_tx,
+            min_peers,
             listeners: Default::default(),
             progress: Default::default(),
             metrics: Default::default(),
@@ -80,7 +96,7 @@ where
 
 impl<DB: Database> Default for PipelineBuilder<DB> {
     fn default() -> Self {
-        Self { stages: Vec::new(), max_block: None, tip_tx: None }
+        Self { stages: Vec::new(), max_block: None, tip_tx: None, min_peers: None }
     }
 }
 
@@ -89,6 +105,7 @@ impl<DB: Database> std::fmt::Debug for PipelineBuilder<DB> {
         f.debug_struct("PipelineBuilder")
             .field("stages", &self.stages.iter().map(|stage| stage.id()).collect::<Vec<StageId>>())
             .field("max_block", &self.max_block)
+            .field("min_peers", &self.min_peers.as_ref().map(|(min_peers, _)| min_peers))
             .finish()
     }
 }
@@ -1,13 +1,13 @@
 use crate::{error::*, ExecInput, ExecOutput, Stage, StageError, UnwindInput};
 use futures_util::Future;
 use reth_db::database::Database;
-use reth_interfaces::executor::BlockExecutionError;
+use reth_interfaces::{executor::BlockExecutionError, p2p::download::DownloadClient};
 use reth_primitives::{
     constants::BEACON_CONSENSUS_REORG_UNWIND_DEPTH, listener::EventListeners, stage::StageId,
     BlockNumber, ChainSpec, H256,
 };
 use reth_provider::{providers::get_stage_checkpoint, ProviderFactory};
-use std::{pin::Pin, sync::Arc};
+use std::{pin::Pin, sync::Arc, time::Duration};
 use tokio::sync::watch;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use tracing::*;
@@ -26,6 +26,9 @@ use progress::*;
 pub use set::*;
 use sync_metrics::*;
 
+/// The interval in which the number of connected peers is checked while waiting for peers.
+const MIN_PEERS_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 /// A container for a queued stage.
 pub(crate) type BoxedStage<DB> = Box<dyn Stage<DB>>;
 
@@ -105,6 +108,8 @@ pub struct Pipeline<DB: Database> {
     progress: PipelineProgress,
     /// A receiver for the current chain tip to sync to.
     tip_tx: Option<watch::Sender<H256>>,
+    /// The minimum number of connected peers required to run the downloading stages.
+    min_peers: Option<(usize, Arc<dyn DownloadClient>)>,
     metrics: Metrics,
 }
 
@@ -311,6 +316,14 @@ where
     ) -> Result<ControlFlow, PipelineError> {
         let total_stages = self.stages.len();
 
+        let stage_id = self.stages[stage_index].id();
+        if let Some((min_peers, client)) =
+            self.min_peers.clone().filter(|_| stage_id.is_downloading_stage())
+        {
+            // there's no point in downloading without enough peers to download from
+            wait_for_min_peers(stage_id, min_peers, client.as_ref()).await;
+        }
+
         let stage = &mut self.stages[stage_index];
         let stage_id = stage.id();
         let mut made_progress = false;
@@ -456,11 +469,24 @@ where
     }
 }
 
+/// Waits until the client is connected to at least `min_peers` peers.
+async fn wait_for_min_peers(stage_id: StageId, min_peers: usize, client: &dyn DownloadClient) {
+    let mut connected_peers = client.num_connected_peers();
+    if connected_peers < min_peers {
+        info!(target: "sync::pipeline", stage = %stage_id, connected_peers, min_peers, "Waiting for peers");
+    }
+    while connected_peers < min_peers {
+        tokio::time::sleep(MIN_PEERS_POLL_INTERVAL).await;
+        connected_peers = client.num_connected_peers();
+    }
+}
+
 impl<DB: Database> std::fmt::Debug for Pipeline<DB> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Pipeline")
             .field("stages", &self.stages.iter().map(|stage| stage.id()).collect::<Vec<StageId>>())
             .field("max_block", &self.max_block)
+            .field("min_peers", &self.min_peers.as_ref().map(|(min_peers, _)| min_peers))
             .field("listeners", &self.listeners)
             .finish()
     }
@@ -475,7 +501,8 @@ mod tests {
     use reth_interfaces::{
         consensus, provider::ProviderError, test_utils::generators::random_header,
     };
-    use reth_primitives::{stage::StageCheckpoint, MAINNET};
+    use reth_primitives::{stage::StageCheckpoint, PeerId, MAINNET};
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use tokio_stream::StreamExt;
 
     #[test]
@@ -560,6 +587,80 @@ mod tests {
         );
     }
 
+    /// A download client that reports a configurable number of connected peers.
+    #[derive(Debug, Default)]
+    struct TestPeersClient {
+        connected_peers: AtomicUsize,
+    }
+
+    impl DownloadClient for TestPeersClient {
+        fn report_bad_message(&self, _peer_id: PeerId) {}
+
+        fn num_connected_peers(&self) -> usize {
+            self.connected_peers.load(Ordering::Relaxed)
+        }
+    }
+
+    /// Runs the downloading stages only once enough peers are connected.
+    #[tokio::test]
+    async fn run_pipeline_waits_for_min_peers() {
+        let db = test_utils::create_test_db::<mdbx::WriteMap>(EnvKind::RW);
+
+        let client = Arc::new(TestPeersClient::default());
+        client.connected_peers.store(1, Ordering::Relaxed);
+
+        let mut pipeline = Pipeline::builder()
+            .add_stage(
+                TestStage::new(StageId::Other("A"))
+                    .add_exec(Ok(ExecOutput { checkpoint: StageCheckpoint::new(10), done: true })),
+            )
+            .add_stage(
+                TestStage::new(StageId::Headers)
+                    .add_exec(Ok(ExecOutput { checkpoint: StageCheckpoint::new(10), done: true })),
+            )
+            .with_max_block(10)
+            .with_min_peers(3, Arc::clone(&client))
+            .build(db, MAINNET.clone());
+        let mut events = pipeline.events();
+
+        // Run pipeline
+        tokio::spawn(async move {
+            pipeline.run().await.unwrap();
+        });
+
+        // Stages that don't download are not gated
+        assert_matches!(
+            events.next().await,
+            Some(PipelineEvent::Running { stage_id: StageId::Other("A"), .. })
+        );
+        assert_matches!(
+            events.next().await,
+            Some(PipelineEvent::Ran { stage_id: StageId::Other("A"), .. })
+        );
+
+        // The headers stage waits for peers
+        assert!(tokio::time::timeout(MIN_PEERS_POLL_INTERVAL * 3, events.next()).await.is_err());
+
+        client.connected_peers.store(3, Ordering::Relaxed);
+        assert_eq!(
+            events.collect::<Vec<PipelineEvent>>().await,
+            vec![
+                PipelineEvent::Running {
+                    pipeline_position: 2,
+                    pipeline_total: 2,
+                    stage_id: StageId::Headers,
+                    checkpoint: None
+                },
+                PipelineEvent::Ran {
+                    pipeline_position: 2,
+                    pipeline_total: 2,
+                    stage_id: StageId::Headers,
+                    result: ExecOutput { checkpoint: StageCheckpoint::new(10), done: true },
+                },
+            ]
+        );
+    }
+
     /// Unwinds a simple pipeline.
     #[tokio::test]
     async fn unwind_pipeline() {
@@ -99,6 +99,13 @@ impl<H, B, EF> DefaultStages<H, B, EF> {
             executor_factory,
         }
     }
+
+    /// Configures whether the headers already stored in the database are trusted, see
+    /// [HeaderStage::with_trust_stored_headers].
+    pub fn with_trust_stored_headers(mut self, trust_stored_headers: bool) -> Self {
+        self.online = self.online.with_trust_stored_headers(trust_stored_headers);
+        self
+    }
 }
 
 impl<H, B, EF> DefaultStages<H, B, EF>
@@ -140,6 +147,8 @@ pub struct OnlineStages<H, B> {
     header_downloader: H,
     /// The block body downloader
     body_downloader: B,
+    /// Whether the headers already stored in the database are trusted.
+    trust_stored_headers: bool,
 }
 
 impl<H, B> OnlineStages<H, B> {
@@ -150,7 +159,20 @@ impl<H, B> OnlineStages<H, B> {
         header_downloader: H,
         body_downloader: B,
     ) -> Self {
-        Self { header_mode, consensus, header_downloader, body_downloader }
+        Self {
+            header_mode,
+            consensus,
+            header_downloader,
+            body_downloader,
+            trust_stored_headers: false,
+        }
+    }
+
+    /// Configures whether the headers already stored in the database are trusted, see
+    /// [HeaderStage::with_trust_stored_headers].
+    pub fn with_trust_stored_headers(mut self, trust_stored_headers: bool) -> Self {
+        self.trust_stored_headers = trust_stored_headers;
+        self
     }
 }
 
@@ -193,7 +215,10 @@ where
 {
     fn builder(self) -> StageSetBuilder<DB> {
         StageSetBuilder::default()
-            .add_stage(HeaderStage::new(self.header_downloader, self.header_mode))
+            .add_stage(
+                HeaderStage::new(self.header_downloader, self.header_mode)
+                    .with_trust_stored_headers(self.trust_stored_headers),
+            )
             .add_stage(TotalDifficultyStage::new(self.consensus.clone()))
             .add_stage(BodyStage { downloader: self.body_downloader, consensus: self.consensus })
     }
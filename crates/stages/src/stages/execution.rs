@@ -69,12 +69,27 @@ pub struct ExecutionStage<EF: ExecutorFactory> {
     executor_factory: EF,
     /// The commit thresholds of the execution stage.
     thresholds: ExecutionStageThresholds,
+    /// The number of most recent blocks to keep receipts for, if receipts are pruned.
+    receipt_retention: Option<u64>,
 }
 
 impl<EF: ExecutorFactory> ExecutionStage<EF> {
     /// Create new execution stage with specified config.
     pub fn new(executor_factory: EF, thresholds: ExecutionStageThresholds) -> Self {
-        Self { metrics: ExecutionStageMetrics::default(), executor_factory, thresholds }
+        Self {
+            metrics: ExecutionStageMetrics::default(),
+            executor_factory,
+            thresholds,
+            receipt_retention: None,
+        }
+    }
+
+    /// Set the number of most recent blocks to keep receipts for.
+    ///
+    /// If set, the receipts of older blocks are deleted each time the stage commits.
+    pub fn with_receipt_retention(mut self, receipt_retention: Option<u64>) -> Self {
+        self.receipt_retention = receipt_retention;
+        self
     }
 
     /// Create an execution stage with the provided  executor factory.
@@ -151,6 +166,9 @@ impl<EF: ExecutorFactory> ExecutionStage<EF> {
         state.write_to_db(provider.tx_ref())?;
         trace!(target: "sync::stages::execution", took = ?start.elapsed(), "Wrote state");
 
+        // Prune receipts outside of the retention window of the executed tip
+        self.prune_receipts(provider, stage_progress)?;
+
         let done = stage_progress == max_block;
         Ok(ExecOutput {
             checkpoint: StageCheckpoint::new(stage_progress)
@@ -158,6 +176,37 @@ impl<EF: ExecutorFactory> ExecutionStage<EF> {
             done,
         })
     }
+
+    /// Deletes the receipts of all blocks below `tip - receipt_retention`.
+    ///
+    /// `tip` is the last executed block, so receipts above the execution checkpoint are never
+    /// touched.
+    fn prune_receipts<DB: Database>(
+        &self,
+        provider: &DatabaseProviderRW<'_, &DB>,
+        tip: BlockNumber,
+    ) -> Result<(), StageError> {
+        let Some(retention) = self.receipt_retention else { return Ok(()) };
+        let prune_below = tip.saturating_sub(retention);
+        if prune_below == 0 {
+            return Ok(())
+        }
+
+        // receipts are keyed by transaction number, so everything before the first transaction
+        // of the oldest retained block can be deleted
+        let first_retained_tx = provider.block_body_indices(prune_below)?.first_tx_num();
+        let mut cursor = provider.tx_ref().cursor_write::<tables::Receipts>()?;
+        let mut walker = cursor.walk(None)?;
+        while let Some((tx_number, _)) = walker.next().transpose()? {
+            if tx_number >= first_retained_tx {
+                break
+            }
+            walker.delete_current()?;
+        }
+
+        trace!(target: "sync::stages::execution", prune_below, "Pruned receipts");
+        Ok(())
+    }
 }
 
 fn execution_checkpoint<DB: Database>(
@@ -418,9 +467,10 @@ mod tests {
         mdbx::{test_utils::create_test_db, EnvKind, WriteMap},
         models::AccountBeforeTx,
     };
+    use reth_interfaces::test_utils::generators::random_block_range;
     use reth_primitives::{
         hex_literal::hex, keccak256, stage::StageUnitCheckpoint, Account, Bytecode,
-        ChainSpecBuilder, SealedBlock, StorageEntry, H160, H256, MAINNET, U256,
+        ChainSpecBuilder, Receipt, SealedBlock, StorageEntry, H160, H256, MAINNET, U256,
     };
     use reth_provider::{insert_canonical_block, ProviderFactory};
     use reth_revm::Factory;
@@ -561,6 +611,51 @@ mod tests {
         }) if total == block.gas_used);
     }
 
+    #[test]
+    fn prune_receipts_outside_retention() {
+        let state_db = create_test_db::<WriteMap>(EnvKind::RW);
+        let factory = ProviderFactory::new(state_db.as_ref(), MAINNET.clone());
+        let provider = factory.provider_rw().unwrap();
+
+        let mut body_indices = Vec::new();
+        for block in random_block_range(0..=9, H256::zero(), 1..3) {
+            let indices = insert_canonical_block(provider.tx_ref(), block, None).unwrap();
+            for tx_number in indices.tx_num_range() {
+                provider.tx_ref().put::<tables::Receipts>(tx_number, Receipt::default()).unwrap();
+            }
+            body_indices.push(indices);
+        }
+        let stored_receipts = || {
+            provider
+                .tx_ref()
+                .cursor_read::<tables::Receipts>()
+                .unwrap()
+                .walk(None)
+                .unwrap()
+                .map(|entry| entry.unwrap().0)
+                .collect::<Vec<_>>()
+        };
+
+        // without a retention, no receipts are pruned
+        stage().prune_receipts(&provider, 9).unwrap();
+        assert_eq!(stored_receipts(), (0..=body_indices[9].last_tx_num()).collect::<Vec<_>>());
+
+        // only the receipts of blocks 6..=9 are retained
+        let stage = stage().with_receipt_retention(Some(3));
+        stage.prune_receipts(&provider, 9).unwrap();
+        assert_eq!(
+            stored_receipts(),
+            (body_indices[6].first_tx_num()..=body_indices[9].last_tx_num()).collect::<Vec<_>>()
+        );
+
+        // the window never reaches beyond the executed tip
+        stage.prune_receipts(&provider, 2).unwrap();
+        assert_eq!(
+            stored_receipts(),
+            (body_indices[6].first_tx_num()..=body_indices[9].last_tx_num()).collect::<Vec<_>>()
+        );
+    }
+
     #[tokio::test]
     async fn sanity_execution_of_block() {
         // TODO cleanup the setup after https://github.com/paradigmxyz/reth/issues/332
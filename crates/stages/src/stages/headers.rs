@@ -7,6 +7,7 @@ use reth_db::{
     transaction::{DbTx, DbTxMut},
 };
 use reth_interfaces::{
+    consensus::ConsensusError,
     p2p::headers::{
         downloader::{HeaderDownloader, SyncTarget},
         error::HeadersDownloaderError,
@@ -54,6 +55,14 @@ pub struct HeaderStage<D: HeaderDownloader> {
     downloader: D,
     /// The sync mode for the stage.
     mode: HeaderSyncMode,
+    /// Whether the headers that are already stored in the database are trusted.
+    ///
+    /// Stored headers were fully validated before they were written, so if this is enabled, the
+    /// stored chain is only checked for parent hash continuity once, instead of re-validating
+    /// every header.
+    trust_stored_headers: bool,
+    /// Whether the stored headers have already been checked for continuity.
+    stored_headers_verified: bool,
 }
 
 // === impl HeaderStage ===
@@ -64,7 +73,70 @@ where
 {
     /// Create a new header stage
     pub fn new(downloader: D, mode: HeaderSyncMode) -> Self {
-        Self { downloader, mode }
+        Self { downloader, mode, trust_stored_headers: false, stored_headers_verified: false }
+    }
+
+    /// Configures whether the headers already stored in the database are trusted.
+    ///
+    /// If enabled, the stored canonical chain is checked for parent hash continuity on the first
+    /// execution instead of re-validating every stored header.
+    pub fn with_trust_stored_headers(mut self, trust_stored_headers: bool) -> Self {
+        self.trust_stored_headers = trust_stored_headers;
+        self
+    }
+
+    /// Verifies the continuity of the stored canonical header chain up to the given checkpoint.
+    ///
+    /// This only checks that every header links to the hash of its canonical parent, using a
+    /// single walk over the stored headers, and does not re-validate the headers themselves.
+    fn verify_stored_headers<DB: Database>(
+        &self,
+        tx: &<DB as reth_db::database::DatabaseGAT<'_>>::TXMut,
+        checkpoint: u64,
+    ) -> Result<(), StageError> {
+        let mut canonical_cursor = tx.cursor_read::<tables::CanonicalHeaders>()?;
+        let mut header_cursor = tx.cursor_read::<tables::Headers>()?;
+
+        let mut headers = header_cursor.walk_range(..=checkpoint)?;
+        let mut canonical_headers = canonical_cursor.walk_range(..=checkpoint)?;
+
+        let mut parent_hash = None;
+        loop {
+            let (entry, canonical) = match (headers.next(), canonical_headers.next()) {
+                (Some(entry), Some(canonical)) => (entry, canonical),
+                (None, None) => break,
+                // one of the tables has more entries than the other
+                (Some(entry), None) => {
+                    let (number, _) = entry?;
+                    return Err(ProviderError::HeaderNotFound(number.into()).into())
+                }
+                (None, Some(canonical)) => {
+                    let (number, _) = canonical?;
+                    return Err(ProviderError::HeaderNotFound(number.into()).into())
+                }
+            };
+            let (number, header) = entry?;
+            let (canonical_number, hash) = canonical?;
+            if number != canonical_number {
+                return Err(ProviderError::HeaderNotFound(number.min(canonical_number).into()).into())
+            }
+
+            if let Some(expected_parent_hash) = parent_hash {
+                if header.parent_hash != expected_parent_hash {
+                    let got_parent_hash = header.parent_hash;
+                    return Err(StageError::Validation {
+                        block: header.seal(hash),
+                        error: ConsensusError::ParentHashMismatch {
+                            expected_parent_hash,
+                            got_parent_hash,
+                        },
+                    })
+                }
+            }
+            parent_hash = Some(hash);
+        }
+
+        Ok(())
     }
 
     fn is_stage_done<DB: Database>(
@@ -202,6 +274,12 @@ where
         let tx = provider.tx_ref();
         let current_checkpoint = input.checkpoint();
 
+        // Check the stored headers once, e.g. after a restart
+        if self.trust_stored_headers && !self.stored_headers_verified {
+            self.verify_stored_headers::<DB>(tx, current_checkpoint.block_number)?;
+            self.stored_headers_verified = true;
+        }
+
         // Lookup the head and tip of the sync range
         let gap = self.get_sync_gap(provider.deref(), current_checkpoint.block_number).await?;
         let local_head = gap.local_head.number;
@@ -388,8 +466,9 @@ mod tests {
         stage_test_suite, ExecuteStageTestRunner, StageTestRunner, UnwindStageTestRunner,
     };
     use assert_matches::assert_matches;
-    use reth_interfaces::test_utils::generators::random_header;
-    use reth_primitives::{stage::StageUnitCheckpoint, H256, MAINNET};
+    use reth_db::mdbx::{Env, WriteMap};
+    use reth_interfaces::test_utils::generators::{random_header, random_header_range};
+    use reth_primitives::{stage::StageUnitCheckpoint, BlockHashOrNumber, H256, MAINNET};
     use reth_provider::ProviderFactory;
     use test_runner::HeadersTestRunner;
 
@@ -439,10 +518,10 @@ mod tests {
             }
 
             fn stage(&self) -> Self::S {
-                HeaderStage {
-                    mode: HeaderSyncMode::Tip(self.channel.1.clone()),
-                    downloader: (*self.downloader_factory)(),
-                }
+                HeaderStage::new(
+                    (*self.downloader_factory)(),
+                    HeaderSyncMode::Tip(self.channel.1.clone()),
+                )
             }
         }
 
@@ -716,4 +795,59 @@ mod tests {
             processed == checkpoint + headers.len() as u64 - 1 && total == tip.number);
         assert!(runner.validate_execution(input, result.ok()).is_ok(), "validation failed");
     }
+
+    /// Check the continuity of the stored headers
+    #[tokio::test]
+    async fn verify_stored_headers() {
+        let runner = HeadersTestRunner::default();
+        let factory = ProviderFactory::new(runner.tx().tx.as_ref(), MAINNET.clone());
+        let provider = factory.provider_rw().unwrap();
+        let tx = provider.tx_ref();
+        let stage = runner.stage().with_trust_stored_headers(true);
+
+        let mut headers = random_header_range(0..10, H256::zero());
+        let write = |headers: &[SealedHeader]| {
+            for header in headers {
+                tx.put::<tables::CanonicalHeaders>(header.number, header.hash())
+                    .expect("failed to write canonical");
+                tx.put::<tables::Headers>(header.number, header.clone().unseal())
+                    .expect("failed to write header");
+            }
+        };
+
+        // Contiguous chain
+        write(&headers);
+        assert_matches!(stage.verify_stored_headers::<Env<WriteMap>>(tx, 9), Ok(()));
+
+        // Plant a parent hash break
+        headers[5] = random_header(5, Some(H256::random()));
+        write(&headers[5..6]);
+        assert_matches!(
+            stage.verify_stored_headers::<Env<WriteMap>>(tx, 9),
+            Err(StageError::Validation { block, error: ConsensusError::ParentHashMismatch { .. } })
+                if block.number == 5
+        );
+
+        // The break is above the checkpoint
+        assert_matches!(stage.verify_stored_headers::<Env<WriteMap>>(tx, 4), Ok(()));
+
+        // The canonical hash of a stored header is missing
+        tx.delete::<tables::CanonicalHeaders>(4, None).expect("failed to delete canonical");
+        assert_matches!(
+            stage.verify_stored_headers::<Env<WriteMap>>(tx, 4),
+            Err(StageError::DatabaseIntegrity(ProviderError::HeaderNotFound(
+                BlockHashOrNumber::Number(4)
+            )))
+        );
+
+        // A canonical hash without a stored header
+        write(&headers[4..5]);
+        tx.delete::<tables::Headers>(4, None).expect("failed to delete header");
+        assert_matches!(
+            stage.verify_stored_headers::<Env<WriteMap>>(tx, 4),
+            Err(StageError::DatabaseIntegrity(ProviderError::HeaderNotFound(
+                BlockHashOrNumber::Number(4)
+            )))
+        );
+    }
 }
@@ -14,7 +14,7 @@ use reth_primitives::{
 };
 use reth_provider::{DatabaseProviderRW, HeaderProvider, ProviderError};
 use reth_trie::{IntermediateStateRootState, StateRoot, StateRootProgress};
-use std::fmt::Debug;
+use std::{fmt::Debug, ops::RangeInclusive};
 use tracing::*;
 
 /// The merkle hashing stage uses input from
@@ -45,6 +45,9 @@ pub enum MerkleStage {
         /// The threshold for switching from incremental trie building
         /// of changes to whole rebuild. Num of transitions.
         clean_threshold: u64,
+        /// If set, the state roots of all blocks that are a multiple of this interval are recorded
+        /// in [tables::StateRootCheckpoints].
+        state_root_checkpoint_interval: Option<u64>,
     },
     /// The unwind portion of the merkle stage.
     Unwind,
@@ -52,13 +55,18 @@ pub enum MerkleStage {
     /// Able to execute and unwind. Used for tests
     #[cfg(any(test, feature = "test-utils"))]
     #[allow(missing_docs)]
-    Both { clean_threshold: u64 },
+    Both { clean_threshold: u64, state_root_checkpoint_interval: Option<u64> },
 }
 
 impl MerkleStage {
     /// Stage default for the Execution variant.
     pub fn default_execution() -> Self {
-        Self::Execution { clean_threshold: 50_000 }
+        Self::Execution { clean_threshold: 50_000, state_root_checkpoint_interval: None }
+    }
+
+    /// Execution variant that records a state root checkpoint every `interval` blocks, if set.
+    pub fn execution_with_state_root_checkpoints(interval: Option<u64>) -> Self {
+        Self::Execution { clean_threshold: 50_000, state_root_checkpoint_interval: interval }
     }
 
     /// Stage default for the Unwind variant.
@@ -121,6 +129,30 @@ impl MerkleStage {
         }
         Ok(provider.save_stage_checkpoint_progress(StageId::MerkleExecute, buf)?)
     }
+
+    /// Records the state roots of all blocks in the range that are a multiple of the interval.
+    ///
+    /// The roots are taken from the headers, so this must only be called once the state root of
+    /// the last block in the range has been validated.
+    fn save_state_root_checkpoints<DB: Database>(
+        &self,
+        provider: &DatabaseProviderRW<'_, &DB>,
+        range: RangeInclusive<BlockNumber>,
+        interval: u64,
+    ) -> Result<(), StageError> {
+        let (from_block, to_block) = range.into_inner();
+        let first_checkpoint = from_block + (interval - from_block % interval) % interval;
+        for block_number in (first_checkpoint..=to_block).step_by(interval as usize) {
+            let header = provider
+                .header_by_number(block_number)?
+                .ok_or_else(|| ProviderError::HeaderNotFound(block_number.into()))?;
+            provider
+                .tx_ref()
+                .put::<tables::StateRootCheckpoints>(block_number, header.state_root)?;
+        }
+        debug!(target: "sync::stages::merkle::exec", ?from_block, ?to_block, interval, "Saved state root checkpoints");
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -141,14 +173,18 @@ impl<DB: Database> Stage<DB> for MerkleStage {
         provider: &mut DatabaseProviderRW<'_, &DB>,
         input: ExecInput,
     ) -> Result<ExecOutput, StageError> {
-        let threshold = match self {
+        let (threshold, checkpoint_interval) = match self {
             MerkleStage::Unwind => {
                 info!(target: "sync::stages::merkle::unwind", "Stage is always skipped");
                 return Ok(ExecOutput::done(StageCheckpoint::new(input.target())))
             }
-            MerkleStage::Execution { clean_threshold } => *clean_threshold,
+            MerkleStage::Execution { clean_threshold, state_root_checkpoint_interval } => {
+                (*clean_threshold, *state_root_checkpoint_interval)
+            }
             #[cfg(any(test, feature = "test-utils"))]
-            MerkleStage::Both { clean_threshold } => *clean_threshold,
+            MerkleStage::Both { clean_threshold, state_root_checkpoint_interval } => {
+                (*clean_threshold, *state_root_checkpoint_interval)
+            }
         };
 
         let range = input.next_block_range();
@@ -240,7 +276,7 @@ impl<DB: Database> Stage<DB> for MerkleStage {
         } else {
             debug!(target: "sync::stages::merkle::exec", current = ?current_block, target = ?to_block, "Updating trie");
             let (root, updates) =
-                StateRoot::incremental_root_with_updates(provider.tx_ref(), range)
+                StateRoot::incremental_root_with_updates(provider.tx_ref(), range.clone())
                     .map_err(|e| StageError::Fatal(Box::new(e)))?;
             updates.flush(provider.tx_ref())?;
 
@@ -264,6 +300,12 @@ impl<DB: Database> Stage<DB> for MerkleStage {
 
         self.validate_state_root(trie_root, block.seal_slow(), to_block)?;
 
+        if let Some(interval) = checkpoint_interval.filter(|interval| *interval > 0) {
+            if !range.is_empty() {
+                self.save_state_root_checkpoints(provider, range, interval)?;
+            }
+        }
+
         Ok(ExecOutput {
             checkpoint: StageCheckpoint::new(to_block)
                 .with_entities_stage_checkpoint(entities_checkpoint),
@@ -277,6 +319,9 @@ impl<DB: Database> Stage<DB> for MerkleStage {
         provider: &mut DatabaseProviderRW<'_, &DB>,
         input: UnwindInput,
     ) -> Result<UnwindOutput, StageError> {
+        // Checkpoints above the unwind target no longer belong to the canonical chain
+        provider.unwind_table_by_num::<tables::StateRootCheckpoints>(input.unwind_to)?;
+
         let tx = provider.tx_ref();
         let range = input.unwind_block_range();
         if matches!(self, MerkleStage::Execution { .. }) {
@@ -431,14 +476,59 @@ mod tests {
         assert!(runner.validate_execution(input, result.ok()).is_ok(), "execution validation");
     }
 
+    /// Record state root checkpoints at the configured interval
+    #[tokio::test]
+    async fn execute_records_state_root_checkpoints() {
+        let (previous_stage, stage_progress) = (20, 0);
+
+        // Set up the runner
+        let mut runner = MerkleTestRunner {
+            state_root_checkpoint_interval: Some(5),
+            ..MerkleTestRunner::default()
+        };
+        let input = ExecInput {
+            target: Some(previous_stage),
+            checkpoint: Some(StageCheckpoint::new(stage_progress)),
+        };
+
+        runner.seed_execution(input).expect("failed to seed execution");
+
+        let rx = runner.execute(input);
+
+        // Assert the successful result
+        let result = rx.await.unwrap();
+        assert_matches!(result, Ok(ExecOutput { done: true, .. }));
+
+        let headers = runner.tx.table::<tables::Headers>().unwrap();
+        let checkpoints = runner.tx.table::<tables::StateRootCheckpoints>().unwrap();
+        assert_eq!(
+            checkpoints,
+            [5, 10, 15, 20]
+                .into_iter()
+                .map(|number| (number, headers[number as usize].1.state_root))
+                .collect::<Vec<_>>()
+        );
+
+        let provider = runner.tx.inner();
+        assert_eq!(provider.nearest_state_root_checkpoint(3).unwrap(), None);
+        assert_eq!(provider.nearest_state_root_checkpoint(10).unwrap(), Some(checkpoints[1]));
+        assert_eq!(provider.nearest_state_root_checkpoint(14).unwrap(), Some(checkpoints[1]));
+        assert_eq!(provider.nearest_state_root_checkpoint(100).unwrap(), Some(checkpoints[3]));
+    }
+
     struct MerkleTestRunner {
         tx: TestTransaction,
         clean_threshold: u64,
+        state_root_checkpoint_interval: Option<u64>,
     }
 
     impl Default for MerkleTestRunner {
         fn default() -> Self {
-            Self { tx: TestTransaction::default(), clean_threshold: 10000 }
+            Self {
+                tx: TestTransaction::default(),
+                clean_threshold: 10000,
+                state_root_checkpoint_interval: None,
+            }
         }
     }
 
@@ -450,7 +540,10 @@ mod tests {
         }
 
         fn stage(&self) -> Self::S {
-            Self::S::Both { clean_threshold: self.clean_threshold }
+            Self::S::Both {
+                clean_threshold: self.clean_threshold,
+                state_root_checkpoint_interval: self.state_root_checkpoint_interval,
+            }
         }
     }
 
@@ -0,0 +1,196 @@
+use crate::{ExecInput, ExecOutput, Stage, StageError, UnwindInput, UnwindOutput};
+use reth_db::{cursor::DbCursorRO, database::Database, tables, transaction::DbTxMut};
+use reth_primitives::stage::{StageCheckpoint, StageId};
+use reth_provider::DatabaseProviderRW;
+use tracing::*;
+
+/// The receipts pruning stage.
+///
+/// This stage deletes the [`tables::Receipts`] of all blocks that are more than `prune_before`
+/// blocks older than the target of the pipeline, for nodes that don't need to serve old receipts.
+///
+/// The checkpoint of the stage is the target it pruned for, so the receipts of the blocks before
+/// `checkpoint - prune_before` are already deleted and the next run continues from there.
+#[derive(Debug, Clone)]
+pub struct PruneReceiptsStage {
+    /// The number of most recent blocks whose receipts are kept.
+    prune_before: u64,
+    /// The number of blocks to prune receipts of at once.
+    commit_threshold: u64,
+}
+
+impl PruneReceiptsStage {
+    /// Create new instance of [PruneReceiptsStage] that keeps the receipts of the given number of
+    /// most recent blocks.
+    pub fn new(prune_before: u64) -> Self {
+        Self { prune_before, commit_threshold: 100_000 }
+    }
+
+    /// Set the number of blocks to prune receipts of at once.
+    pub fn with_commit_threshold(mut self, commit_threshold: u64) -> Self {
+        self.commit_threshold = commit_threshold;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<DB: Database> Stage<DB> for PruneReceiptsStage {
+    /// Return the id of the stage
+    fn id(&self) -> StageId {
+        StageId::Other("PruneReceipts")
+    }
+
+    /// Delete the receipts of the blocks that are older than `prune_before` blocks.
+    async fn execute(
+        &mut self,
+        provider: &mut DatabaseProviderRW<'_, &DB>,
+        input: ExecInput,
+    ) -> Result<ExecOutput, StageError> {
+        if input.target_reached() {
+            return Ok(ExecOutput::done(input.checkpoint()))
+        }
+
+        let (range, is_final_range) = input.next_block_range_with_threshold(self.commit_threshold);
+
+        // the receipts of the blocks before `checkpoint - prune_before` were pruned by the
+        // previous run
+        let block_range = input.checkpoint().block_number.saturating_sub(self.prune_before)..
+            range.end().saturating_sub(self.prune_before);
+
+        if !block_range.is_empty() {
+            let first_tx_num = provider.block_body_indices(block_range.start)?.first_tx_num();
+            let next_tx_num = provider.block_body_indices(block_range.end - 1)?.next_tx_num();
+
+            debug!(target: "sync::stages::prune_receipts", ?block_range, "Pruning receipts");
+
+            let mut cursor = provider.tx_ref().cursor_write::<tables::Receipts>()?;
+            let mut walker = cursor.walk_range(first_tx_num..next_tx_num)?;
+            while let Some(entry) = walker.next() {
+                entry?;
+                walker.delete_current()?;
+            }
+        }
+
+        Ok(ExecOutput { checkpoint: StageCheckpoint::new(*range.end()), done: is_final_range })
+    }
+
+    /// Pruned receipts can't be restored, so unwinding only moves the checkpoint.
+    async fn unwind(
+        &mut self,
+        _provider: &mut DatabaseProviderRW<'_, &DB>,
+        input: UnwindInput,
+    ) -> Result<UnwindOutput, StageError> {
+        Ok(UnwindOutput { checkpoint: StageCheckpoint::new(input.unwind_to) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestTransaction;
+    use reth_interfaces::test_utils::generators::random_block_range;
+    use reth_primitives::{Receipt, SealedBlock, TxNumber, H256, MAINNET};
+    use reth_provider::ProviderFactory;
+
+    /// Inserts ten blocks with a receipt for every transaction.
+    fn setup(tx: &TestTransaction) -> Vec<SealedBlock> {
+        let blocks = random_block_range(0..=9, H256::zero(), 1..4);
+        tx.insert_blocks(blocks.iter(), None).unwrap();
+
+        let tx_count = blocks.iter().map(|block| block.body.len()).sum::<usize>() as TxNumber;
+        tx.commit(|tx| {
+            (0..tx_count)
+                .try_for_each(|tx_num| tx.put::<tables::Receipts>(tx_num, Receipt::default()))
+        })
+        .unwrap();
+
+        blocks
+    }
+
+    /// Returns the transaction numbers of the receipts that are expected after pruning for the
+    /// given target.
+    fn expected_receipts(blocks: &[SealedBlock], target: u64, prune_before: u64) -> Vec<TxNumber> {
+        let mut tx_num = 0;
+        let mut expected = Vec::new();
+        for block in blocks {
+            for _ in &block.body {
+                if block.number >= target.saturating_sub(prune_before) {
+                    expected.push(tx_num);
+                }
+                tx_num += 1;
+            }
+        }
+        expected
+    }
+
+    fn receipts(tx: &TestTransaction) -> Vec<TxNumber> {
+        tx.table::<tables::Receipts>().unwrap().into_iter().map(|(tx_num, _)| tx_num).collect()
+    }
+
+    async fn run(
+        tx: &TestTransaction,
+        stage: &mut PruneReceiptsStage,
+        input: ExecInput,
+    ) -> ExecOutput {
+        let factory = ProviderFactory::new(tx.tx.as_ref(), MAINNET.clone());
+        let mut provider = factory.provider_rw().unwrap();
+        let out = stage.execute(&mut provider, input).await.unwrap();
+        provider.commit().unwrap();
+        out
+    }
+
+    #[tokio::test]
+    async fn prune_old_receipts() {
+        let tx = TestTransaction::default();
+        let blocks = setup(&tx);
+
+        let mut stage = PruneReceiptsStage::new(3);
+        let input = ExecInput { target: Some(9), ..Default::default() };
+        let out = run(&tx, &mut stage, input).await;
+        assert_eq!(out, ExecOutput { checkpoint: StageCheckpoint::new(9), done: true });
+
+        // only the receipts of blocks 6 to 9 are left
+        assert_eq!(receipts(&tx), expected_receipts(&blocks, 9, 3));
+
+        // pruned receipts stay pruned on unwind
+        let factory = ProviderFactory::new(tx.tx.as_ref(), MAINNET.clone());
+        let mut provider = factory.provider_rw().unwrap();
+        let input =
+            UnwindInput { checkpoint: StageCheckpoint::new(9), unwind_to: 5, ..Default::default() };
+        let out = stage.unwind(&mut provider, input).await.unwrap();
+        assert_eq!(out, UnwindOutput { checkpoint: StageCheckpoint::new(5) });
+        provider.commit().unwrap();
+        assert_eq!(receipts(&tx), expected_receipts(&blocks, 9, 3));
+    }
+
+    #[tokio::test]
+    async fn prune_receipts_resumes_from_checkpoint() {
+        let tx = TestTransaction::default();
+        let blocks = setup(&tx);
+
+        let mut stage = PruneReceiptsStage::new(3).with_commit_threshold(4);
+
+        // the first run stops after four blocks and prunes the receipts of block 0
+        let input = ExecInput { target: Some(9), ..Default::default() };
+        let out = run(&tx, &mut stage, input).await;
+        assert_eq!(out, ExecOutput { checkpoint: StageCheckpoint::new(4), done: false });
+        assert_eq!(receipts(&tx), expected_receipts(&blocks, 4, 3));
+
+        let input = ExecInput { target: Some(9), checkpoint: Some(out.checkpoint) };
+        let out = run(&tx, &mut stage, input).await;
+        assert_eq!(out, ExecOutput { checkpoint: StageCheckpoint::new(8), done: false });
+
+        let input = ExecInput { target: Some(9), checkpoint: Some(out.checkpoint) };
+        let out = run(&tx, &mut stage, input).await;
+        assert_eq!(out, ExecOutput { checkpoint: StageCheckpoint::new(9), done: true });
+        assert_eq!(receipts(&tx), expected_receipts(&blocks, 9, 3));
+
+        // nothing to prune if there are fewer blocks than the pruning distance
+        let tx = TestTransaction::default();
+        let blocks = setup(&tx);
+        let mut stage = PruneReceiptsStage::new(20);
+        let input = ExecInput { target: Some(9), ..Default::default() };
+        run(&tx, &mut stage, input).await;
+        assert_eq!(receipts(&tx), expected_receipts(&blocks, 9, 20));
+    }
+}
@@ -112,15 +112,7 @@ fn generate_from_compact(fields: &FieldList, ident: &Ident, is_zstd: bool) -> To
                     #decompressor.with(|decompressor| {
                         let mut decompressor = decompressor.borrow_mut();
 
-                        let mut tmp: Vec<u8> = Vec::with_capacity(300);
-
-                        while let Err(err) = decompressor.decompress_to_buffer(&buf[..], &mut tmp) {
-                            let err = err.to_string();
-                            if !err.contains("Destination buffer is too small") {
-                                panic!("Failed to decompress: {}", err);
-                            }
-                            tmp.reserve(tmp.capacity() + 10_000);
-                        }
+                        let tmp = decompressor.decompress_with_version(&buf[..]);
                         let mut original_buf = buf;
 
                         let mut buf: &[u8] = tmp.as_slice();
@@ -188,7 +180,7 @@ fn generate_to_compact(fields: &FieldList, ident: &Ident, is_zstd: bool) -> Vec<
                 #compressor.with(|compressor| {
                     let mut compressor = compressor.borrow_mut();
 
-                    let compressed = compressor.compress(&buffer).expect("Failed to compress.");
+                    let compressed = compressor.compress(&buffer);
                     buf.put(compressed.as_slice());
                 });
             } else {
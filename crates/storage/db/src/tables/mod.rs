@@ -49,7 +49,7 @@ pub enum TableType {
 }
 
 /// Number of tables that should be present inside database.
-pub const NUM_TABLES: usize = 25;
+pub const NUM_TABLES: usize = 26;
 
 /// Default tables that should be present inside database.
 pub const TABLES: [(TableType, &str); NUM_TABLES] = [
@@ -75,6 +75,7 @@ pub const TABLES: [(TableType, &str); NUM_TABLES] = [
     (TableType::DupSort, HashedStorage::const_name()),
     (TableType::Table, AccountsTrie::const_name()),
     (TableType::DupSort, StoragesTrie::const_name()),
+    (TableType::Table, StateRootCheckpoints::const_name()),
     (TableType::Table, TxSenders::const_name()),
     (TableType::Table, SyncStage::const_name()),
     (TableType::Table, SyncStageProgress::const_name()),
@@ -292,6 +293,13 @@ dupsort!(
     ( StoragesTrie ) H256 | [StoredNibblesSubKey] StorageTrieEntry
 );
 
+table!(
+    /// Stores the state root of canonical blocks at a configurable block interval.
+    ///
+    /// Only populated if the merkle stage is configured to record state root checkpoints.
+    ( StateRootCheckpoints ) BlockNumber | H256
+);
+
 table!(
     /// Stores the transaction sender for each canonical transaction.
     /// It is needed to speed up execution stage and allows fetching signer without doing
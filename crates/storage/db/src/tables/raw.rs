@@ -100,6 +100,11 @@ impl<V: Value> RawValue<V> {
     pub fn value(&self) -> Result<V, DatabaseError> {
         V::decompress(&self.value)
     }
+
+    /// Returns the stored bytes of the value, without decompressing them.
+    pub fn raw_value(&self) -> &[u8] {
+        &self.value
+    }
 }
 
 impl AsRef<[u8]> for RawValue<Vec<u8>> {
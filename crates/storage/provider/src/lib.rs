@@ -14,10 +14,11 @@ pub use traits::{
     AccountExtProvider, AccountProvider, BlockExecutor, BlockHashProvider, BlockIdProvider,
     BlockNumProvider, BlockProvider, BlockProviderIdExt, BlockSource,
     BlockchainTreePendingStateProvider, CanonChainTracker, CanonStateNotification,
-    CanonStateNotificationSender, CanonStateNotifications, CanonStateSubscriptions, EvmEnvProvider,
-    ExecutorFactory, HeaderProvider, PostStateDataProvider, ReceiptProvider, ReceiptProviderIdExt,
-    StageCheckpointProvider, StateProvider, StateProviderBox, StateProviderFactory,
-    StateRootProvider, TransactionsProvider, WithdrawalsProvider,
+    CanonStateNotificationSender, CanonStateNotifications, CanonStateSubscriptions,
+    ChainSpecProvider, EvmEnvProvider, ExecutorFactory, HeaderProvider, PostStateDataProvider,
+    ReceiptProvider, ReceiptProviderIdExt, StageCheckpointProvider, StateProvider,
+    StateProviderBox, StateProviderFactory, StateRootProvider, TransactionsProvider,
+    WithdrawalsProvider,
 };
 
 /// Provider trait implementations.
@@ -1,20 +1,23 @@
 use crate::{
     providers::state::{historical::HistoricalStateProvider, latest::LatestStateProvider},
     traits::{BlockSource, ReceiptProvider},
-    BlockHashProvider, BlockNumProvider, BlockProvider, EvmEnvProvider, HeaderProvider,
-    ProviderError, StageCheckpointProvider, StateProviderBox, TransactionsProvider,
+    BlockHashProvider, BlockNumProvider, BlockProvider, ChainSpecProvider, EvmEnvProvider,
+    HeaderProvider, ProviderError, StageCheckpointProvider, StateProviderBox, TransactionsProvider,
     WithdrawalsProvider,
 };
 use reth_db::{database::Database, models::StoredBlockBodyIndices, tables, transaction::DbTx};
 use reth_interfaces::Result;
 use reth_primitives::{
     stage::{StageCheckpoint, StageId},
-    Address, Block, BlockHash, BlockHashOrNumber, BlockNumber, BlockWithSenders, ChainInfo,
+    Address, Block, BlockHash, BlockHashOrNumber, BlockNumber, BlockWithSenders, Bytes, ChainInfo,
     ChainSpec, Header, Receipt, SealedBlock, SealedHeader, TransactionMeta, TransactionSigned,
     TransactionSignedNoHash, TxHash, TxNumber, Withdrawal, H256, U256,
 };
 use reth_revm_primitives::primitives::{BlockEnv, CfgEnv};
-use std::{ops::RangeBounds, sync::Arc};
+use std::{
+    ops::{RangeBounds, RangeInclusive},
+    sync::Arc,
+};
 use tracing::trace;
 
 mod provider;
@@ -96,6 +99,12 @@ impl<DB: Database> ProviderFactory<DB> {
             .block_number(block_hash)?
             .ok_or(ProviderError::BlockHashNotFound(block_hash))?;
 
+        // the hash could belong to a stored header that is not part of the canonical chain, the
+        // state at its number would then be the state of another block
+        if provider.block_hash(block_number)? != Some(block_hash) {
+            return Err(ProviderError::NonCanonicalBlockHash(block_hash).into())
+        }
+
         if block_number == provider.best_block_number().unwrap_or_default() &&
             block_number == provider.last_block_number().unwrap_or_default()
         {
@@ -142,6 +151,18 @@ impl<DB: Database> HeaderProvider for ProviderFactory<DB> {
     fn sealed_header(&self, number: BlockNumber) -> Result<Option<SealedHeader>> {
         self.provider()?.sealed_header(number)
     }
+
+    fn burned_fees(&self, range: impl RangeBounds<BlockNumber>) -> Result<U256> {
+        self.provider()?.burned_fees(range)
+    }
+
+    fn cumulative_gas_used_at(&self, block_number: BlockNumber) -> Result<u64> {
+        self.provider()?.cumulative_gas_used_at(block_number)
+    }
+
+    fn gas_limits(&self, range: impl RangeBounds<BlockNumber>) -> Result<Vec<(BlockNumber, u64)>> {
+        self.provider()?.gas_limits(range)
+    }
 }
 
 impl<DB: Database> BlockHashProvider for ProviderFactory<DB> {
@@ -170,6 +191,10 @@ impl<DB: Database> BlockNumProvider for ProviderFactory<DB> {
     fn block_number(&self, hash: H256) -> Result<Option<BlockNumber>> {
         self.provider()?.block_number(hash)
     }
+
+    fn find_canonical_gaps(&self) -> Result<Vec<RangeInclusive<BlockNumber>>> {
+        self.provider()?.find_canonical_gaps()
+    }
 }
 
 impl<DB: Database> BlockProvider for ProviderFactory<DB> {
@@ -196,6 +221,14 @@ impl<DB: Database> BlockProvider for ProviderFactory<DB> {
     fn block_with_senders(&self, number: BlockNumber) -> Result<Option<BlockWithSenders>> {
         self.provider()?.block_with_senders(number)
     }
+
+    fn block_body_rlp(&self, number: BlockNumber) -> Result<Option<Bytes>> {
+        self.provider()?.block_body_rlp(number)
+    }
+
+    fn block_range(&self, range: RangeInclusive<BlockNumber>) -> Result<Vec<SealedBlock>> {
+        self.provider()?.block_range(range)
+    }
 }
 
 impl<DB: Database> TransactionsProvider for ProviderFactory<DB> {
@@ -260,6 +293,20 @@ impl<DB: Database> ReceiptProvider for ProviderFactory<DB> {
     fn receipts_by_block(&self, block: BlockHashOrNumber) -> Result<Option<Vec<Receipt>>> {
         self.provider()?.receipts_by_block(block)
     }
+
+    fn receipts_by_tx_range(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+    ) -> Result<Vec<(TxNumber, Receipt)>> {
+        self.provider()?.receipts_by_tx_range(range)
+    }
+
+    fn receipts_by_block_range(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+    ) -> Result<Vec<(BlockNumber, Vec<Receipt>)>> {
+        self.provider()?.receipts_by_block_range(range)
+    }
 }
 
 impl<DB: Database> WithdrawalsProvider for ProviderFactory<DB> {
@@ -274,12 +321,26 @@ impl<DB: Database> WithdrawalsProvider for ProviderFactory<DB> {
     fn latest_withdrawal(&self) -> Result<Option<Withdrawal>> {
         self.provider()?.latest_withdrawal()
     }
+
+    fn withdrawal_by_index(&self, index: u64) -> Result<Option<Withdrawal>> {
+        self.provider()?.withdrawal_by_index(index)
+    }
 }
 
 impl<DB: Database> StageCheckpointProvider for ProviderFactory<DB> {
     fn get_stage_checkpoint(&self, id: StageId) -> Result<Option<StageCheckpoint>> {
         self.provider()?.get_stage_checkpoint(id)
     }
+
+    fn execution_block_range(&self) -> Result<Option<RangeInclusive<BlockNumber>>> {
+        self.provider()?.execution_block_range()
+    }
+}
+
+impl<DB: Database> ChainSpecProvider for ProviderFactory<DB> {
+    fn chain_spec(&self) -> Arc<ChainSpec> {
+        self.chain_spec.clone()
+    }
 }
 
 impl<DB: Database> EvmEnvProvider for ProviderFactory<DB> {
@@ -333,24 +394,57 @@ where
 #[cfg(test)]
 mod tests {
     use super::ProviderFactory;
-    use crate::{BlockHashProvider, BlockNumProvider};
-    use reth_db::mdbx::{test_utils::create_test_db, EnvKind, WriteMap};
-    use reth_primitives::{ChainSpecBuilder, H256};
-    use std::sync::Arc;
+    use crate::{
+        insert_canonical_block, test_utils::blocks::BlockChainTestData, AccountExtProvider,
+        AccountProvider, BlockHashProvider, BlockNumProvider, BlockProvider, HeaderProvider,
+        ProviderError, ReceiptProvider, StageCheckpointProvider, StateProvider, TransactionError,
+        TransactionsProvider, WithdrawalsProvider,
+    };
+    use reth_db::{
+        cursor::DbCursorRO,
+        mdbx::{test_utils::create_test_rw_db, Env, WriteMap},
+        models::{
+            storage_sharded_key::StorageShardedKey, AccountBeforeTx, ShardedKey,
+            StoredBlockBodyIndices, StoredBlockWithdrawals,
+        },
+        table::Decompress,
+        tables,
+        transaction::{DbTx, DbTxMut},
+        BlockNumberList,
+    };
+    use reth_interfaces::{consensus::ConsensusError, test_utils::generators::random_block};
+    use reth_primitives::{
+        block_bloom,
+        bloom::logs_bloom,
+        gas_limit::validate_gas_limit,
+        keccak256,
+        proofs::{calculate_receipt_root_ref, calculate_transaction_root},
+        stage::{StageCheckpoint, StageId},
+        AccessList, AccessListItem, Account, Address, BlockBody, BlockHashOrNumber,
+        ChainSpecBuilder, ForkCondition, Hardfork, Header, Log, Receipt, Signature, StorageEntry,
+        Transaction, TransactionKind, TransactionSigned, TransactionSignedNoHash, TxEip1559,
+        TxLegacy, TxType, Withdrawal, H256, U256,
+    };
+    use reth_rlp::Decodable;
+    use reth_trie::StateRoot;
+    use std::{collections::BTreeSet, sync::Arc};
+
+    /// Returns a [ProviderFactory] for mainnet backed by a new test database.
+    fn test_provider_factory() -> ProviderFactory<Arc<Env<WriteMap>>> {
+        let chain_spec = ChainSpecBuilder::mainnet().build();
+        let db = create_test_rw_db();
+        ProviderFactory::new(db, Arc::new(chain_spec))
+    }
 
     #[test]
     fn common_history_provider() {
-        let chain_spec = ChainSpecBuilder::mainnet().build();
-        let db = create_test_db::<WriteMap>(EnvKind::RW);
-        let provider = ProviderFactory::new(db, Arc::new(chain_spec));
+        let provider = test_provider_factory();
         let _ = provider.latest();
     }
 
     #[test]
     fn default_chain_info() {
-        let chain_spec = ChainSpecBuilder::mainnet().build();
-        let db = create_test_db::<WriteMap>(EnvKind::RW);
-        let factory = ProviderFactory::new(db, Arc::new(chain_spec));
+        let factory = test_provider_factory();
         let provider = factory.provider().unwrap();
 
         let chain_info = provider.chain_info().expect("should be ok");
@@ -360,13 +454,1343 @@ mod tests {
 
     #[test]
     fn provider_flow() {
-        let chain_spec = ChainSpecBuilder::mainnet().build();
-        let db = create_test_db::<WriteMap>(EnvKind::RW);
-        let factory = ProviderFactory::new(db, Arc::new(chain_spec));
+        let factory = test_provider_factory();
         let provider = factory.provider().unwrap();
         provider.block_hash(0).unwrap();
         let provider_rw = factory.provider_rw().unwrap();
         provider_rw.block_hash(0).unwrap();
         provider.block_hash(0).unwrap();
     }
+
+    #[test]
+    fn block_body_rlp() {
+        let chain_spec = ChainSpecBuilder::mainnet().shanghai_activated().build();
+        let db = create_test_rw_db();
+        let factory = ProviderFactory::new(db.as_ref(), Arc::new(chain_spec));
+        let provider = factory.provider_rw().unwrap();
+
+        let data = BlockChainTestData::default();
+        let (block, _) = data.blocks[0].clone();
+        insert_canonical_block(provider.tx_ref(), data.genesis, None).unwrap();
+        insert_canonical_block(provider.tx_ref(), block.block.clone(), None).unwrap();
+
+        let rlp = provider.block_body_rlp(block.number).unwrap().unwrap();
+        let body = BlockBody::decode(&mut rlp.as_ref()).unwrap();
+        assert_eq!(calculate_transaction_root(&body.transactions), block.transactions_root);
+        assert_eq!(body.transactions, block.body);
+        assert_eq!(body.withdrawals, block.withdrawals);
+
+        assert_eq!(provider.block_body_rlp(block.number + 1).unwrap(), None);
+    }
+
+    #[test]
+    fn block_range() {
+        // blocks 0 and 1 are before Shanghai, blocks 2 and 3 after
+        let chain_spec = ChainSpecBuilder::mainnet()
+            .with_fork(Hardfork::Shanghai, ForkCondition::Timestamp(100))
+            .build();
+        let db = create_test_rw_db();
+        let factory = ProviderFactory::new(db.as_ref(), Arc::new(chain_spec));
+        let provider = factory.provider_rw().unwrap();
+
+        let mut parent = None;
+        let mut blocks = Vec::new();
+        for number in 0..4 {
+            let shanghai = number >= 2;
+            let mut block = random_block(number, parent, Some(2), Some(!shanghai as u8));
+            if shanghai {
+                let header = Header { timestamp: 100, ..block.header.clone().unseal() };
+                block.header = header.seal_slow();
+                block.withdrawals = Some(Vec::new());
+            }
+            if number == 2 {
+                let withdrawal = Withdrawal {
+                    index: 0,
+                    validator_index: 1,
+                    address: Address::random(),
+                    amount: 2,
+                };
+                block.withdrawals = Some(vec![withdrawal]);
+            }
+            parent = Some(block.hash());
+            insert_canonical_block(provider.tx_ref(), block.clone(), None).unwrap();
+            blocks.push(block);
+        }
+
+        assert_eq!(provider.block_range(0..=3).unwrap(), blocks);
+        assert_eq!(provider.block_range(1..=2).unwrap(), blocks[1..=2]);
+        assert_eq!(provider.block_range(2..=10).unwrap(), blocks[2..]);
+        assert!(provider.block_range(4..=10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn transaction_raw_stored() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let block = random_block(0, None, Some(3), Some(0));
+        insert_canonical_block(provider.tx_ref(), block.clone(), None).unwrap();
+        provider.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        for (id, transaction) in block.body.iter().enumerate() {
+            let raw = provider.transaction_raw_stored(id as u64).unwrap().unwrap();
+            let decoded: TransactionSigned =
+                TransactionSignedNoHash::decompress(raw.as_slice()).unwrap().into();
+            assert_eq!(&decoded, transaction);
+        }
+        assert_eq!(provider.transaction_raw_stored(block.body.len() as u64).unwrap(), None);
+    }
+
+    #[test]
+    fn tx_number_range() {
+        let factory = test_provider_factory();
+        assert_eq!(factory.provider().unwrap().tx_number_range().unwrap(), None);
+
+        let provider = factory.provider_rw().unwrap();
+
+        let mut parent = None;
+        let mut tx_count = 0;
+        for (number, count) in [2u8, 0, 5, 3].into_iter().enumerate() {
+            let block = random_block(number as u64, parent, Some(count), Some(0));
+            parent = Some(block.hash());
+            insert_canonical_block(provider.tx_ref(), block, None).unwrap();
+            tx_count += count as u64;
+        }
+        provider.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.tx_number_range().unwrap(), Some(0..=tx_count - 1));
+        let last_body = provider.block_body_indices(3).unwrap().unwrap();
+        assert_eq!(last_body.last_tx_num(), tx_count - 1);
+    }
+
+    #[test]
+    fn burned_fees() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        // blocks 0..5 are pre-London, blocks 5..10 have a base fee
+        let headers = (0..10u64)
+            .map(|number| Header {
+                number,
+                gas_used: 21_000 * (number + 1),
+                base_fee_per_gas: (number >= 5).then_some(7 + number),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        for header in &headers {
+            provider.tx_ref().put::<tables::Headers>(header.number, header.clone()).unwrap();
+        }
+
+        let expected = headers[5..].iter().fold(U256::ZERO, |burned, header| {
+            burned + U256::from(header.base_fee_per_gas.unwrap()) * U256::from(header.gas_used)
+        });
+        assert_eq!(provider.burned_fees(5..10).unwrap(), expected);
+        assert_eq!(provider.burned_fees(..).unwrap(), expected);
+        assert_eq!(provider.burned_fees(0..5).unwrap(), U256::ZERO);
+    }
+
+    #[test]
+    fn iter_bytecode_hashes() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        // two contracts share the same code, EOAs have no code
+        let (shared, other) = (H256::random(), H256::random());
+        let accounts = [Some(shared), Some(other), Some(shared), None];
+        for bytecode_hash in accounts {
+            let account = Account { bytecode_hash, ..Default::default() };
+            provider.tx_ref().put::<tables::PlainAccountState>(Address::random(), account).unwrap();
+        }
+
+        let mut expected = vec![shared, other];
+        expected.sort();
+        assert_eq!(provider.iter_bytecode_hashes().unwrap(), expected);
+    }
+
+    #[test]
+    fn block_with_senders_recover_on_miss() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let block = random_block(0, None, Some(3), Some(0));
+        let senders = block.senders().unwrap();
+        insert_canonical_block(provider.tx_ref(), block, None).unwrap();
+        assert_eq!(provider.block_with_senders(0).unwrap().unwrap().senders, senders);
+
+        // senders were pruned
+        provider.tx_ref().clear::<tables::TxSenders>().unwrap();
+        assert_eq!(
+            provider.block_with_senders(0),
+            Err(ProviderError::BlockSendersNotFound(0).into())
+        );
+        let recovered = provider.block_with_senders_or_recover(0, true).unwrap().unwrap();
+        assert_eq!(recovered.senders, senders);
+        assert!(provider.senders_by_tx_range(..).unwrap().is_empty());
+
+        // recovered senders are stored again
+        assert_eq!(provider.block_with_senders_cached(0).unwrap().unwrap().senders, senders);
+        assert_eq!(provider.senders_by_tx_range(..).unwrap(), senders);
+        assert_eq!(provider.block_with_senders(0).unwrap().unwrap().senders, senders);
+    }
+
+    #[test]
+    fn state_root_at() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let block = random_block(0, None, Some(1), Some(0));
+        insert_canonical_block(provider.tx_ref(), block.clone(), None).unwrap();
+
+        assert_eq!(provider.state_root_at(block.hash.into()).unwrap(), Some(block.state_root));
+        assert_eq!(provider.state_root_at(block.number.into()).unwrap(), Some(block.state_root));
+        assert_eq!(provider.state_root_at(H256::random().into()).unwrap(), None);
+        assert_eq!(provider.state_root_at(1u64.into()).unwrap(), None);
+    }
+
+    #[test]
+    fn execution_block_range() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        // nothing executed yet
+        assert_eq!(provider.execution_block_range().unwrap(), None);
+
+        provider.save_stage_checkpoint(StageId::Execution, StageCheckpoint::new(5)).unwrap();
+        let checkpoint = provider.get_stage_checkpoint(StageId::Execution).unwrap().unwrap();
+        assert_eq!(provider.execution_block_range().unwrap(), Some(0..=checkpoint.block_number));
+        assert_eq!(provider.execution_block_range().unwrap(), Some(0..=5));
+    }
+
+    #[test]
+    fn execution_block_range_after_pruning() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        // one transaction in every block, except for the empty block 2
+        for number in 0..=5 {
+            let tx_count = if number == 2 { 0 } else { 1 };
+            let block = random_block(number, None, Some(tx_count), Some(0));
+            insert_canonical_block(provider.tx_ref(), block, None).unwrap();
+        }
+        for tx_number in 0..5 {
+            provider.tx_ref().put::<tables::Receipts>(tx_number, Receipt::default()).unwrap();
+        }
+        provider.save_stage_checkpoint(StageId::Execution, StageCheckpoint::new(5)).unwrap();
+        assert_eq!(provider.execution_block_range().unwrap(), Some(0..=5));
+
+        // the receipts of blocks 0 and 1 are pruned, the empty block 2 is still complete
+        provider.tx_ref().delete::<tables::Receipts>(0, None).unwrap();
+        provider.tx_ref().delete::<tables::Receipts>(1, None).unwrap();
+        assert_eq!(provider.execution_block_range().unwrap(), Some(2..=5));
+
+        // all receipts are pruned
+        for tx_number in 2..5 {
+            provider.tx_ref().delete::<tables::Receipts>(tx_number, None).unwrap();
+        }
+        assert!(provider.execution_block_range().unwrap().unwrap().is_empty());
+    }
+
+    #[test]
+    fn withdrawal_by_index() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        assert_eq!(provider.withdrawal_by_index(0).unwrap(), None);
+
+        // blocks without withdrawals are not stored
+        let block_indices = [(1u64, 0..3u64), (4, 3..5), (5, 5..6), (9, 6..10)];
+        for (block_number, indices) in block_indices {
+            let withdrawals = indices
+                .map(|index| Withdrawal {
+                    index,
+                    validator_index: index * 2,
+                    address: Address::random(),
+                    amount: 1,
+                })
+                .collect();
+            provider
+                .tx_ref()
+                .put::<tables::BlockWithdrawals>(
+                    block_number,
+                    StoredBlockWithdrawals { withdrawals },
+                )
+                .unwrap();
+        }
+
+        for index in 0..10 {
+            let withdrawal = provider.withdrawal_by_index(index).unwrap().unwrap();
+            assert_eq!(withdrawal.index, index);
+            assert_eq!(withdrawal.validator_index, index * 2);
+        }
+        assert_eq!(provider.withdrawal_by_index(10).unwrap(), None);
+    }
+
+    #[test]
+    fn cumulative_gas_used_at() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let headers = (0..10u64)
+            .map(|number| Header { number, gas_used: 21_000 * number, ..Default::default() })
+            .collect::<Vec<_>>();
+        for header in &headers {
+            provider.tx_ref().put::<tables::Headers>(header.number, header.clone()).unwrap();
+        }
+
+        for header in &headers {
+            let expected =
+                headers[..=header.number as usize].iter().map(|h| h.gas_used).sum::<u64>();
+            assert_eq!(provider.cumulative_gas_used_at(header.number).unwrap(), expected);
+        }
+        assert_eq!(
+            provider.cumulative_gas_used_at(10),
+            Err(ProviderError::HeaderNotFound(10.into()).into())
+        );
+    }
+
+    #[test]
+    fn headers_from() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let headers =
+            (0..10u64).map(|number| Header { number, ..Default::default() }).collect::<Vec<_>>();
+        for header in &headers {
+            provider.tx_ref().put::<tables::Headers>(header.number, header.clone()).unwrap();
+            provider
+                .tx_ref()
+                .put::<tables::HeaderNumbers>(header.hash_slow(), header.number)
+                .unwrap();
+        }
+
+        let numbers = |start: BlockHashOrNumber, limit: usize, skip: u64, reverse: bool| {
+            provider
+                .headers_from(start, limit, skip, reverse)
+                .unwrap()
+                .into_iter()
+                .map(|header| header.number)
+                .collect::<Vec<_>>()
+        };
+
+        // forward
+        assert_eq!(numbers(BlockHashOrNumber::Number(2), 3, 0, false), vec![2, 3, 4]);
+        assert_eq!(numbers(BlockHashOrNumber::Number(2), 3, 2, false), vec![2, 5, 8]);
+        // the tip is reached before the limit
+        assert_eq!(numbers(BlockHashOrNumber::Number(2), 5, 2, false), vec![2, 5, 8]);
+        assert_eq!(numbers(BlockHashOrNumber::Number(9), 5, 0, false), vec![9]);
+
+        // reverse
+        assert_eq!(numbers(BlockHashOrNumber::Number(8), 3, 0, true), vec![8, 7, 6]);
+        assert_eq!(numbers(BlockHashOrNumber::Number(8), 3, 1, true), vec![8, 6, 4]);
+        // the genesis block is reached before the limit
+        assert_eq!(numbers(BlockHashOrNumber::Number(8), 10, 3, true), vec![8, 4, 0]);
+        assert_eq!(numbers(BlockHashOrNumber::Number(7), 10, 3, true), vec![7, 3]);
+        assert_eq!(numbers(BlockHashOrNumber::Number(0), 10, 0, true), vec![0]);
+
+        // start by hash
+        assert_eq!(numbers(headers[5].hash_slow().into(), 2, 1, true), vec![5, 3]);
+
+        // unknown start and empty limit
+        assert!(numbers(BlockHashOrNumber::Number(10), 5, 0, false).is_empty());
+        assert!(numbers(H256::random().into(), 5, 0, false).is_empty());
+        assert!(numbers(BlockHashOrNumber::Number(2), 0, 0, false).is_empty());
+    }
+
+    #[test]
+    fn total_difficulty_delta() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let difficulties = (0..10u64).map(|number| U256::from(1_000 + number)).collect::<Vec<_>>();
+        let mut td = U256::ZERO;
+        for (number, difficulty) in difficulties.iter().enumerate() {
+            td += *difficulty;
+            provider.tx_ref().put::<tables::HeaderTD>(number as u64, td.into()).unwrap();
+        }
+
+        let sum = |range: std::ops::RangeInclusive<usize>| {
+            difficulties[range].iter().fold(U256::ZERO, |sum, difficulty| sum + *difficulty)
+        };
+        assert_eq!(provider.total_difficulty_delta(3..=7).unwrap(), sum(3..=7));
+        assert_eq!(provider.total_difficulty_delta(5..=5).unwrap(), difficulties[5]);
+        // the range starting at genesis includes the genesis difficulty
+        assert_eq!(provider.total_difficulty_delta(0..=9).unwrap(), sum(0..=9));
+        assert_eq!(
+            provider.total_difficulty_delta(8..=10),
+            Err(ProviderError::TotalDifficultyNotFound { number: 10 }.into())
+        );
+    }
+
+    #[test]
+    fn gas_limits() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        // the gas limit of block 3 jumps by more than 1/1024 of its parent's gas limit
+        let gas_limits = [30_000_000, 30_029_000, 30_000_000, 31_000_000, 31_000_000];
+        for (number, gas_limit) in gas_limits.into_iter().enumerate() {
+            let header = Header { number: number as u64, gas_limit, ..Default::default() };
+            provider.tx_ref().put::<tables::Headers>(header.number, header).unwrap();
+        }
+
+        let trajectory = provider.gas_limits(1..4).unwrap();
+        assert_eq!(trajectory, vec![(1, 30_029_000), (2, 30_000_000), (3, 31_000_000)]);
+
+        let invalid = provider
+            .gas_limits(..)
+            .unwrap()
+            .windows(2)
+            .filter(|pair| !validate_gas_limit(pair[0].1, pair[1].1))
+            .map(|pair| pair[1].0)
+            .collect::<Vec<_>>();
+        assert_eq!(invalid, vec![3]);
+    }
+
+    #[test]
+    fn find_canonical_gaps() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        for number in 0..10 {
+            provider
+                .tx_ref()
+                .put::<tables::CanonicalHeaders>(number, H256::from_low_u64_be(number))
+                .unwrap();
+        }
+        assert_eq!(provider.find_canonical_gaps().unwrap(), vec![]);
+
+        provider.tx_ref().delete::<tables::CanonicalHeaders>(4, None).unwrap();
+        provider.tx_ref().delete::<tables::CanonicalHeaders>(5, None).unwrap();
+        provider.tx_ref().delete::<tables::CanonicalHeaders>(8, None).unwrap();
+        assert_eq!(provider.find_canonical_gaps().unwrap(), vec![4..=5, 8..=8]);
+    }
+
+    #[test]
+    fn rebuild_hashed_state() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+        let tx = provider.tx_ref();
+
+        let accounts = (1..=10u64)
+            .map(|i| {
+                let account = Account { nonce: i, balance: U256::from(i), bytecode_hash: None };
+                (Address::from_low_u64_be(i), account)
+            })
+            .collect::<Vec<_>>();
+        let storage = (1..=10u64)
+            .map(|i| StorageEntry { key: H256::from_low_u64_be(i), value: U256::from(i) })
+            .collect::<Vec<_>>();
+        for (address, account) in &accounts {
+            tx.put::<tables::PlainAccountState>(*address, *account).unwrap();
+        }
+        for entry in &storage {
+            tx.put::<tables::PlainStorageState>(accounts[0].0, *entry).unwrap();
+        }
+        provider
+            .insert_account_for_hashing(accounts.iter().map(|(a, acc)| (*a, Some(*acc))))
+            .unwrap();
+        provider
+            .insert_storage_for_hashing([(
+                accounts[0].0,
+                storage.iter().map(|entry| (entry.key, entry.value)),
+            )])
+            .unwrap();
+        let state_root = StateRoot::new(tx).root().unwrap();
+
+        let header = Header { state_root, ..Default::default() }.seal_slow();
+        tx.put::<tables::CanonicalHeaders>(header.number, header.hash()).unwrap();
+        tx.put::<tables::Headers>(header.number, header.header.clone()).unwrap();
+
+        // corrupt the hashed state
+        tx.put::<tables::HashedAccount>(H256::random(), Account::default()).unwrap();
+        tx.delete::<tables::HashedAccount>(keccak256(accounts[1].0), None).unwrap();
+        tx.delete::<tables::HashedStorage>(keccak256(accounts[0].0), None).unwrap();
+        assert_ne!(StateRoot::new(tx).root().unwrap(), state_root);
+
+        provider.rebuild_hashed_state().unwrap();
+        assert_eq!(provider.table::<tables::HashedAccount>().unwrap().len(), accounts.len());
+        assert_eq!(provider.table::<tables::HashedStorage>().unwrap().len(), storage.len());
+        assert_eq!(StateRoot::new(tx).root().unwrap(), state_root);
+
+        // the rebuilt state root must match the tip header
+        tx.put::<tables::PlainAccountState>(Address::random(), Account::default()).unwrap();
+        assert!(matches!(
+            provider.rebuild_hashed_state(),
+            Err(TransactionError::StateRootMismatch { expected, .. }) if expected == state_root
+        ));
+    }
+
+    #[test]
+    fn gas_used_for_tx() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let block = random_block(0, None, Some(3), Some(0));
+        insert_canonical_block(provider.tx_ref(), block.clone(), None).unwrap();
+
+        let gas_used = [21_000, 50_000, 30_000];
+        let mut cumulative_gas_used = 0;
+        for (tx_num, (tx, gas)) in block.body.iter().zip(gas_used).enumerate() {
+            cumulative_gas_used += gas;
+            let receipt =
+                Receipt { tx_type: tx.tx_type(), success: true, cumulative_gas_used, logs: vec![] };
+            provider.tx_ref().put::<tables::Receipts>(tx_num as u64, receipt).unwrap();
+        }
+
+        for (tx, gas) in block.body.iter().zip(gas_used) {
+            assert_eq!(provider.gas_used_for_tx(tx.hash()).unwrap(), Some(gas));
+        }
+        assert_eq!(provider.gas_used_for_tx(H256::random()).unwrap(), None);
+    }
+
+    #[test]
+    fn receipts_by_tx_range() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let receipts = (0..10u64)
+            .map(|tx_num| Receipt {
+                tx_type: TxType::Legacy,
+                success: true,
+                cumulative_gas_used: 21_000 * (tx_num + 1),
+                logs: vec![],
+            })
+            .collect::<Vec<_>>();
+        for (tx_num, receipt) in receipts.iter().enumerate() {
+            provider.tx_ref().put::<tables::Receipts>(tx_num as u64, receipt.clone()).unwrap();
+        }
+
+        let range = provider.receipts_by_tx_range(3..7).unwrap();
+        assert_eq!(range.iter().map(|(tx_num, _)| *tx_num).collect::<Vec<_>>(), vec![3, 4, 5, 6]);
+        for (tx_num, receipt) in range {
+            assert_eq!(receipt, receipts[tx_num as usize]);
+        }
+
+        assert_eq!(provider.receipts_by_tx_range(8..).unwrap().len(), 2);
+        assert!(provider.receipts_by_tx_range(5..5).unwrap().is_empty());
+        assert!(provider.receipts_by_tx_range(10..20).unwrap().is_empty());
+    }
+
+    #[test]
+    fn receipts_by_block_range() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        // blocks 1 and 3 are empty
+        let tx_counts = [2u64, 0, 3, 0, 1];
+        let mut first_tx_num = 0;
+        for (number, tx_count) in tx_counts.into_iter().enumerate() {
+            let indices = StoredBlockBodyIndices { first_tx_num, tx_count };
+            provider.tx_ref().put::<tables::BlockBodyIndices>(number as u64, indices).unwrap();
+            first_tx_num += tx_count;
+        }
+        let receipts = (0..first_tx_num)
+            .map(|tx_num| Receipt {
+                tx_type: TxType::Legacy,
+                success: true,
+                cumulative_gas_used: 21_000 * (tx_num + 1),
+                logs: vec![],
+            })
+            .collect::<Vec<_>>();
+        for (tx_num, receipt) in receipts.iter().enumerate() {
+            provider.tx_ref().put::<tables::Receipts>(tx_num as u64, receipt.clone()).unwrap();
+        }
+
+        let range = provider.receipts_by_block_range(..).unwrap();
+        assert_eq!(
+            range,
+            vec![
+                (0, receipts[0..2].to_vec()),
+                (1, vec![]),
+                (2, receipts[2..5].to_vec()),
+                (3, vec![]),
+                (4, receipts[5..6].to_vec()),
+            ]
+        );
+        for (number, block_receipts) in &range {
+            assert_eq!(
+                provider.receipts_by_block((*number).into()).unwrap().as_ref(),
+                Some(block_receipts)
+            );
+        }
+
+        let range = provider.receipts_by_block_range(1..=3).unwrap();
+        assert_eq!(range, vec![(1, vec![]), (2, receipts[2..5].to_vec()), (3, vec![])]);
+        assert_eq!(provider.receipts_by_block_range(3..4).unwrap(), vec![(3, vec![])]);
+        assert!(provider.receipts_by_block_range(5..).unwrap().is_empty());
+    }
+
+    #[test]
+    fn verify_receipts_cumulative_gas() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let block = random_block(0, None, Some(4), Some(0));
+        insert_canonical_block(provider.tx_ref(), block.clone(), None).unwrap();
+        let header = Header { gas_used: 84_000, ..block.header.clone().unseal() };
+        provider.tx_ref().put::<tables::Headers>(0, header).unwrap();
+
+        let put_receipts = |cumulative_gas_used: [u64; 4]| {
+            for (tx_num, cumulative_gas_used) in cumulative_gas_used.into_iter().enumerate() {
+                let receipt = Receipt {
+                    tx_type: TxType::Legacy,
+                    success: true,
+                    cumulative_gas_used,
+                    logs: vec![],
+                };
+                provider.tx_ref().put::<tables::Receipts>(tx_num as u64, receipt).unwrap();
+            }
+        };
+
+        put_receipts([21_000, 42_000, 63_000, 84_000]);
+        assert_eq!(provider.verify_receipts_cumulative_gas(0).unwrap(), None);
+
+        // receipt 2 has a lower cumulative gas used than its predecessor
+        put_receipts([21_000, 42_000, 21_000, 84_000]);
+        assert_eq!(provider.verify_receipts_cumulative_gas(0).unwrap(), Some(2));
+
+        // the last receipt doesn't match the gas used of the header
+        put_receipts([21_000, 42_000, 63_000, 80_000]);
+        assert_eq!(provider.verify_receipts_cumulative_gas(0).unwrap(), Some(3));
+    }
+
+    #[test]
+    fn verify_block_receipts() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        // the receipts root is only checked from byzantium on
+        let number = 4_370_000;
+        let block = random_block(number, None, Some(2), Some(0));
+        insert_canonical_block(provider.tx_ref(), block.clone(), None).unwrap();
+
+        let log = |address| Log { address, topics: vec![H256::random()], data: Default::default() };
+        let receipts = vec![
+            Receipt {
+                tx_type: TxType::Legacy,
+                success: true,
+                cumulative_gas_used: 21_000,
+                logs: vec![log(Address::random())],
+            },
+            Receipt {
+                tx_type: TxType::EIP1559,
+                success: true,
+                cumulative_gas_used: 42_000,
+                logs: vec![log(Address::random()), log(Address::random())],
+            },
+        ];
+        for (tx_num, receipt) in receipts.iter().enumerate() {
+            provider.tx_ref().put::<tables::Receipts>(tx_num as u64, receipt.clone()).unwrap();
+        }
+
+        let receipts_root = calculate_receipt_root_ref(&receipts);
+        let bloom = block_bloom(&receipts);
+        let header = Header { receipts_root, logs_bloom: bloom, ..block.header.clone().unseal() };
+        provider.tx_ref().put::<tables::Headers>(number, header).unwrap();
+        assert_eq!(provider.verify_block_receipts(number), Ok(()));
+
+        // a tampered receipt changes the receipts root
+        let mut tampered = receipts[1].clone();
+        tampered.success = false;
+        provider.tx_ref().put::<tables::Receipts>(1, tampered.clone()).unwrap();
+        let tampered_root = calculate_receipt_root_ref(&[receipts[0].clone(), tampered]);
+        assert_eq!(
+            provider.verify_block_receipts(number),
+            Err(ConsensusError::BodyReceiptsRootDiff {
+                got: tampered_root,
+                expected: receipts_root
+            })
+        );
+        provider.tx_ref().put::<tables::Receipts>(1, receipts[1].clone()).unwrap();
+
+        // the header bloom doesn't match the logs of the receipts
+        let header = Header {
+            receipts_root,
+            logs_bloom: Default::default(),
+            ..block.header.clone().unseal()
+        };
+        provider.tx_ref().put::<tables::Headers>(number, header).unwrap();
+        assert_eq!(
+            provider.verify_block_receipts(number),
+            Err(ConsensusError::BodyLogsBloomDiff {
+                got: Box::new(bloom),
+                expected: Box::default(),
+            })
+        );
+
+        // missing receipts are reported as unavailable
+        assert!(matches!(
+            provider.verify_block_receipts(number + 1),
+            Err(ConsensusError::BlockReceiptsUnavailable { number: n, .. }) if n == number + 1
+        ));
+    }
+
+    #[test]
+    fn verify_block_receipts_before_byzantium() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let block = random_block(0, None, Some(1), Some(0));
+        insert_canonical_block(provider.tx_ref(), block.clone(), None).unwrap();
+        let receipt = Receipt {
+            tx_type: TxType::Legacy,
+            success: true,
+            cumulative_gas_used: 21_000,
+            logs: vec![],
+        };
+        provider.tx_ref().put::<tables::Receipts>(0, receipt).unwrap();
+
+        // the stored receipts can't reproduce a pre-byzantium receipts root
+        let header = Header {
+            receipts_root: H256::random(),
+            logs_bloom: Default::default(),
+            ..block.header.clone().unseal()
+        };
+        provider.tx_ref().put::<tables::Headers>(0, header).unwrap();
+        assert_eq!(provider.verify_block_receipts(0), Ok(()));
+    }
+
+    #[test]
+    fn verify_bodies_present() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        for number in 0..5 {
+            provider
+                .tx_ref()
+                .put::<tables::CanonicalHeaders>(number, H256::from_low_u64_be(number))
+                .unwrap();
+            provider
+                .tx_ref()
+                .put::<tables::BlockBodyIndices>(number, StoredBlockBodyIndices::default())
+                .unwrap();
+        }
+        assert_eq!(provider.verify_bodies_present(0..=4).unwrap(), vec![]);
+
+        provider.tx_ref().delete::<tables::BlockBodyIndices>(3, None).unwrap();
+        assert_eq!(provider.verify_bodies_present(0..=4).unwrap(), vec![3]);
+        assert_eq!(provider.verify_bodies_present(0..=2).unwrap(), vec![]);
+
+        // blocks without a canonical header are not reported
+        assert_eq!(provider.verify_bodies_present(0..=10).unwrap(), vec![3]);
+    }
+
+    #[test]
+    fn compact_history_shards() {
+        let factory = test_provider_factory();
+
+        // 7 shards of 500 indices each, for an account and one of its storage slots
+        let address = Address::random();
+        let storage_key = H256::random();
+        let indices = (0..3_500).collect::<Vec<usize>>();
+        let provider = factory.provider_rw().unwrap();
+        let tx = provider.tx_ref();
+        let shards = indices.chunks(500).collect::<Vec<_>>();
+        for (i, shard) in shards.iter().enumerate() {
+            let highest =
+                if i == shards.len() - 1 { u64::MAX } else { *shard.last().unwrap() as u64 };
+            let list = BlockNumberList::new(shard.to_vec()).unwrap();
+            tx.put::<tables::AccountHistory>(ShardedKey::new(address, highest), list.clone())
+                .unwrap();
+            tx.put::<tables::StorageHistory>(
+                StorageShardedKey::new(address, storage_key, highest),
+                list,
+            )
+            .unwrap();
+        }
+        // shards of other keys are not touched
+        let other = Address::random();
+        tx.put::<tables::AccountHistory>(
+            ShardedKey::new(other, u64::MAX),
+            BlockNumberList::new([1]).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(provider.compact_account_history(address).unwrap(), 5);
+        assert_eq!(provider.compact_storage_history(address, storage_key).unwrap(), 5);
+
+        let account_shards = tx
+            .cursor_read::<tables::AccountHistory>()
+            .unwrap()
+            .walk(None)
+            .unwrap()
+            .map(|entry| entry.unwrap())
+            .filter(|(key, _)| key.key == address)
+            .map(|(key, list)| (key.highest_block_number, list.iter(0).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        let storage_shards = tx
+            .cursor_read::<tables::StorageHistory>()
+            .unwrap()
+            .walk(None)
+            .unwrap()
+            .map(|entry| entry.unwrap())
+            .map(|(key, list)| {
+                (key.sharded_key.highest_block_number, list.iter(0).collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+        let expected =
+            vec![(1_999, indices[..2_000].to_vec()), (u64::MAX, indices[2_000..].to_vec())];
+        assert_eq!(account_shards, expected);
+        assert_eq!(storage_shards, expected);
+        assert_eq!(
+            tx.get::<tables::AccountHistory>(ShardedKey::new(other, u64::MAX)).unwrap(),
+            Some(BlockNumberList::new([1]).unwrap())
+        );
+
+        // already compacted shards are left as is
+        assert_eq!(provider.compact_account_history(address).unwrap(), 0);
+    }
+
+    #[test]
+    fn balance_history() {
+        let factory = test_provider_factory();
+
+        // the account is created in block 2 and its balance changes in blocks 5 and 9
+        let address = Address::random();
+        let account = |balance: u64| Account { balance: U256::from(balance), ..Default::default() };
+        let provider = factory.provider_rw().unwrap();
+        let tx = provider.tx_ref();
+        tx.put::<tables::AccountChangeSet>(2, AccountBeforeTx { address, info: None }).unwrap();
+        tx.put::<tables::AccountChangeSet>(
+            5,
+            AccountBeforeTx { address, info: Some(account(100)) },
+        )
+        .unwrap();
+        tx.put::<tables::AccountChangeSet>(
+            9,
+            AccountBeforeTx { address, info: Some(account(200)) },
+        )
+        .unwrap();
+        tx.put::<tables::PlainAccountState>(address, account(300)).unwrap();
+        tx.put::<tables::AccountHistory>(
+            ShardedKey::new(address, u64::MAX),
+            BlockNumberList::new([2, 5, 9]).unwrap(),
+        )
+        .unwrap();
+        provider.commit().unwrap();
+
+        let history = factory.provider().unwrap().balance_history(address, 0..=10).unwrap();
+        assert_eq!(history, vec![(2, U256::from(100)), (5, U256::from(200)), (9, U256::from(300))]);
+        for (block_number, balance) in history {
+            let state = factory.history_by_block_number(block_number).unwrap();
+            assert_eq!(state.basic_account(address).unwrap().unwrap().balance, balance);
+        }
+
+        let history = factory.provider().unwrap().balance_history(address, 3..=8).unwrap();
+        assert_eq!(history, vec![(5, U256::from(200))]);
+        assert!(factory.provider().unwrap().balance_history(address, 10..=20).unwrap().is_empty());
+    }
+
+    #[test]
+    fn sender_transaction_count() {
+        let factory = test_provider_factory();
+
+        // the account sends its first transaction in block 2 and the next ones in blocks 5 and 9
+        let address = Address::random();
+        let account = |nonce: u64| Account { nonce, ..Default::default() };
+        let provider = factory.provider_rw().unwrap();
+        let tx = provider.tx_ref();
+        tx.put::<tables::AccountChangeSet>(2, AccountBeforeTx { address, info: None }).unwrap();
+        tx.put::<tables::AccountChangeSet>(5, AccountBeforeTx { address, info: Some(account(1)) })
+            .unwrap();
+        tx.put::<tables::AccountChangeSet>(9, AccountBeforeTx { address, info: Some(account(2)) })
+            .unwrap();
+        tx.put::<tables::PlainAccountState>(address, account(3)).unwrap();
+        tx.put::<tables::AccountHistory>(
+            ShardedKey::new(address, u64::MAX),
+            BlockNumberList::new([2, 5, 9]).unwrap(),
+        )
+        .unwrap();
+        provider.commit().unwrap();
+
+        let count_at = |block_number| {
+            factory.provider().unwrap().sender_transaction_count(address, block_number).unwrap()
+        };
+        assert_eq!(count_at(1), 0);
+        for (block_number, count) in [(2, 1), (4, 1), (5, 2), (8, 2), (9, 3), (20, 3)] {
+            assert_eq!(count_at(block_number), count);
+            let state = factory.history_by_block_number(block_number).unwrap();
+            assert_eq!(state.basic_account(address).unwrap().unwrap().nonce, count);
+        }
+    }
+
+    #[test]
+    fn storage_slot_history() {
+        let factory = test_provider_factory();
+
+        // the slot is set in block 3 and changed in block 7
+        let address = Address::random();
+        let slot = H256::random();
+        let entry = |value: u64| StorageEntry { key: slot, value: U256::from(value) };
+        let provider = factory.provider_rw().unwrap();
+        let tx = provider.tx_ref();
+        tx.put::<tables::StorageChangeSet>((3, address).into(), entry(0)).unwrap();
+        tx.put::<tables::StorageChangeSet>((7, address).into(), entry(10)).unwrap();
+        tx.put::<tables::PlainStorageState>(address, entry(20)).unwrap();
+        tx.put::<tables::StorageHistory>(
+            StorageShardedKey::new(address, slot, u64::MAX),
+            BlockNumberList::new([3, 7]).unwrap(),
+        )
+        .unwrap();
+        provider.commit().unwrap();
+
+        let history =
+            factory.provider().unwrap().storage_slot_history(address, slot, 0..=10).unwrap();
+        assert_eq!(history, vec![(3, U256::from(10)), (7, U256::from(20))]);
+        for (block_number, value) in history {
+            let state = factory.history_by_block_number(block_number).unwrap();
+            assert_eq!(state.storage(address, slot).unwrap().unwrap_or_default(), value);
+        }
+
+        let history =
+            factory.provider().unwrap().storage_slot_history(address, slot, 4..=10).unwrap();
+        assert_eq!(history, vec![(7, U256::from(20))]);
+        let other_slot = H256::random();
+        assert!(factory
+            .provider()
+            .unwrap()
+            .storage_slot_history(address, other_slot, 0..=10)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn unwind_account_history_shards() {
+        // the account changed in blocks 1..=9, indexed in the shards [1, 2, 3], [4, 5, 6] and
+        // [7, 8, 9], the other account is never unwound
+        let other = Address::from_low_u64_be(1);
+        let address = Address::from_low_u64_be(2);
+        let shards = [(3, vec![1, 2, 3]), (6, vec![4, 5, 6]), (u64::MAX, vec![7, 8, 9])];
+
+        for (unwind_to, expected) in [
+            // partial shard
+            (5, vec![(3, vec![1, 2, 3]), (u64::MAX, vec![4])]),
+            // full shards, the last remaining shard becomes the last shard
+            (4, vec![(u64::MAX, vec![1, 2, 3])]),
+            (7, vec![(3, vec![1, 2, 3]), (u64::MAX, vec![4, 5, 6])]),
+            // all shards
+            (1, vec![]),
+        ] {
+            let factory = test_provider_factory();
+            let provider = factory.provider_rw().unwrap();
+            let tx = provider.tx_ref();
+            tx.put::<tables::AccountHistory>(
+                ShardedKey::new(other, u64::MAX),
+                BlockNumberList::new([1, 9]).unwrap(),
+            )
+            .unwrap();
+            for (highest_block_number, list) in &shards {
+                tx.put::<tables::AccountHistory>(
+                    ShardedKey::new(address, *highest_block_number),
+                    BlockNumberList::new(list).unwrap(),
+                )
+                .unwrap();
+            }
+            for block_number in 1..=9 {
+                tx.put::<tables::AccountChangeSet>(
+                    block_number,
+                    AccountBeforeTx { address, info: None },
+                )
+                .unwrap();
+            }
+
+            provider.unwind_account_history_indices(unwind_to..=9).unwrap();
+
+            let mut cursor = tx.cursor_read::<tables::AccountHistory>().unwrap();
+            let stored = cursor
+                .walk(None)
+                .unwrap()
+                .map(|entry| {
+                    let (key, list) = entry.unwrap();
+                    (key.key, key.highest_block_number, list.iter(0).collect::<Vec<_>>())
+                })
+                .collect::<Vec<_>>();
+            let expected = std::iter::once((other, u64::MAX, vec![1, 9]))
+                .chain(expected.into_iter().map(|(highest, list)| (address, highest, list)))
+                .collect::<Vec<_>>();
+            assert_eq!(stored, expected, "unwind to {unwind_to}");
+        }
+    }
+
+    #[test]
+    fn unwind_storage_history_shards() {
+        // the slot changed in blocks 1..=9, indexed in the shards [1, 2, 3], [4, 5, 6] and
+        // [7, 8, 9], the other slot of the same account is never unwound
+        let address = Address::random();
+        let other_slot = H256::from_low_u64_be(1);
+        let slot = H256::from_low_u64_be(2);
+        let shards = [(3, vec![1, 2, 3]), (6, vec![4, 5, 6]), (u64::MAX, vec![7, 8, 9])];
+
+        for (unwind_to, expected) in [
+            // partial shard
+            (5, vec![(3, vec![1, 2, 3]), (u64::MAX, vec![4])]),
+            // full shards, the last remaining shard becomes the last shard
+            (4, vec![(u64::MAX, vec![1, 2, 3])]),
+            (7, vec![(3, vec![1, 2, 3]), (u64::MAX, vec![4, 5, 6])]),
+            // all shards
+            (1, vec![]),
+        ] {
+            let factory = test_provider_factory();
+            let provider = factory.provider_rw().unwrap();
+            let tx = provider.tx_ref();
+            tx.put::<tables::StorageHistory>(
+                StorageShardedKey::new(address, other_slot, u64::MAX),
+                BlockNumberList::new([1, 9]).unwrap(),
+            )
+            .unwrap();
+            for (highest_block_number, list) in &shards {
+                tx.put::<tables::StorageHistory>(
+                    StorageShardedKey::new(address, slot, *highest_block_number),
+                    BlockNumberList::new(list).unwrap(),
+                )
+                .unwrap();
+            }
+            for block_number in 1..=9 {
+                tx.put::<tables::StorageChangeSet>(
+                    (block_number, address).into(),
+                    StorageEntry { key: slot, value: U256::ZERO },
+                )
+                .unwrap();
+            }
+
+            provider
+                .unwind_storage_history_indices(
+                    (unwind_to, Address::zero()).into()..(10, Address::zero()).into(),
+                )
+                .unwrap();
+
+            let mut cursor = tx.cursor_read::<tables::StorageHistory>().unwrap();
+            let stored = cursor
+                .walk(None)
+                .unwrap()
+                .map(|entry| {
+                    let (key, list) = entry.unwrap();
+                    (
+                        key.sharded_key.key,
+                        key.sharded_key.highest_block_number,
+                        list.iter(0).collect::<Vec<_>>(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let expected = std::iter::once((other_slot, u64::MAX, vec![1, 9]))
+                .chain(expected.into_iter().map(|(highest, list)| (slot, highest, list)))
+                .collect::<Vec<_>>();
+            assert_eq!(stored, expected, "unwind to {unwind_to}");
+        }
+    }
+
+    #[test]
+    fn addresses_changed_in_block() {
+        let factory = test_provider_factory();
+
+        let (alice, bob, carol) = (Address::random(), Address::random(), Address::random());
+        let provider = factory.provider_rw().unwrap();
+        let tx = provider.tx_ref();
+        for (number, address) in [(1, alice), (2, alice), (2, bob), (3, carol)] {
+            tx.put::<tables::AccountChangeSet>(number, AccountBeforeTx { address, info: None })
+                .unwrap();
+        }
+        provider.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.addresses_changed_in_block(1).unwrap(), BTreeSet::from([alice]));
+        assert_eq!(provider.addresses_changed_in_block(2).unwrap(), BTreeSet::from([alice, bob]));
+        assert_eq!(provider.addresses_changed_in_block(3).unwrap(), BTreeSet::from([carol]));
+        assert!(provider.addresses_changed_in_block(4).unwrap().is_empty());
+    }
+
+    #[test]
+    fn storage_range() {
+        let factory = test_provider_factory();
+
+        let (address, other) = (Address::random(), Address::random());
+        let provider = factory.provider_rw().unwrap();
+        let tx = provider.tx_ref();
+        for key in (2..=10).step_by(2) {
+            let entry =
+                StorageEntry { key: H256::from_low_u64_be(key), value: U256::from(key * 10) };
+            tx.put::<tables::PlainStorageState>(address, entry).unwrap();
+        }
+        tx.put::<tables::PlainStorageState>(
+            other,
+            StorageEntry { key: H256::zero(), value: U256::from(1) },
+        )
+        .unwrap();
+        provider.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        let slot = |key: u64| (H256::from_low_u64_be(key), U256::from(key * 10));
+
+        // page through the storage
+        let (storage, next_key) = provider.storage_range(address, H256::zero(), 2).unwrap();
+        assert_eq!(storage, vec![slot(2), slot(4)]);
+        assert_eq!(next_key, Some(H256::from_low_u64_be(6)));
+        let (storage, next_key) = provider.storage_range(address, next_key.unwrap(), 2).unwrap();
+        assert_eq!(storage, vec![slot(6), slot(8)]);
+        let (storage, next_key) = provider.storage_range(address, next_key.unwrap(), 2).unwrap();
+        assert_eq!(storage, vec![slot(10)]);
+        assert_eq!(next_key, None);
+
+        // the start key doesn't need to exist
+        let (storage, next_key) =
+            provider.storage_range(address, H256::from_low_u64_be(5), 10).unwrap();
+        assert_eq!(storage, vec![slot(6), slot(8), slot(10)]);
+        assert_eq!(next_key, None);
+
+        // empty storage
+        let (storage, next_key) =
+            provider.storage_range(Address::random(), H256::zero(), 10).unwrap();
+        assert!(storage.is_empty());
+        assert_eq!(next_key, None);
+    }
+
+    #[test]
+    fn recent_transactions_for_sender() {
+        let factory = test_provider_factory();
+
+        let (alice, bob) = (Address::random(), Address::random());
+        let provider = factory.provider_rw().unwrap();
+        let mut parent = None;
+        let mut blocks = Vec::new();
+        for (number, senders) in
+            [vec![], vec![alice, bob, alice], vec![bob, bob], vec![alice, alice]]
+                .into_iter()
+                .enumerate()
+        {
+            let block = random_block(number as u64, parent, Some(senders.len() as u8), Some(0));
+            parent = Some(block.hash());
+            insert_canonical_block(provider.tx_ref(), block.clone(), Some(senders)).unwrap();
+            blocks.push(block);
+        }
+        provider
+            .tx_ref()
+            .put::<tables::AccountHistory>(
+                ShardedKey::new(alice, u64::MAX),
+                BlockNumberList::new([1, 3]).unwrap(),
+            )
+            .unwrap();
+        provider.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        let hashes = |n| {
+            provider
+                .recent_transactions_for_sender(alice, n)
+                .unwrap()
+                .into_iter()
+                .map(|tx| (tx.signer(), tx.hash()))
+                .collect::<Vec<_>>()
+        };
+        let newest = vec![
+            (alice, blocks[3].body[1].hash()),
+            (alice, blocks[3].body[0].hash()),
+            (alice, blocks[1].body[2].hash()),
+            (alice, blocks[1].body[0].hash()),
+        ];
+
+        assert_eq!(hashes(3), newest[..3]);
+        assert_eq!(hashes(10), newest);
+        assert!(hashes(0).is_empty());
+    }
+
+    #[test]
+    fn transactions_to_address() {
+        let factory = test_provider_factory();
+
+        let (sender, recipient, other) = (Address::random(), Address::random(), Address::random());
+        let tx = |nonce, to| {
+            TransactionSigned::from_transaction_and_signature(
+                Transaction::Legacy(TxLegacy { nonce, to, ..Default::default() }),
+                Signature::default(),
+            )
+        };
+        let provider = factory.provider_rw().unwrap();
+        let mut parent = None;
+        let mut blocks = Vec::new();
+        for (number, body) in [
+            vec![tx(0, TransactionKind::Call(recipient))],
+            vec![tx(1, TransactionKind::Call(other))],
+            vec![
+                tx(2, TransactionKind::Create),
+                tx(3, TransactionKind::Call(recipient)),
+                tx(4, TransactionKind::Call(other)),
+            ],
+            vec![tx(5, TransactionKind::Create)],
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let mut block = random_block(number as u64, parent, Some(0), Some(0));
+            block.body = body;
+            parent = Some(block.hash());
+            let senders = vec![sender; block.body.len()];
+            insert_canonical_block(provider.tx_ref(), block.clone(), Some(senders)).unwrap();
+            blocks.push(block);
+        }
+        // the calls aren't recorded in the account history index, e.g. because they didn't
+        // transfer any value
+        provider.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        let hashes = |range| {
+            provider
+                .transactions_to_address(recipient, range)
+                .unwrap()
+                .into_iter()
+                .map(|tx| (tx.signer(), tx.hash()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            hashes(0..=3),
+            vec![(sender, blocks[0].body[0].hash()), (sender, blocks[2].body[1].hash())]
+        );
+        assert_eq!(hashes(1..=3), vec![(sender, blocks[2].body[1].hash())]);
+        assert!(hashes(3..=3).is_empty());
+    }
+
+    #[test]
+    fn declared_access_list_roundtrip() {
+        let factory = test_provider_factory();
+
+        let access_list = AccessList(vec![AccessListItem {
+            address: Address::random(),
+            storage_keys: vec![H256::random(), H256::random()],
+        }]);
+        let transaction = TransactionSigned::from_transaction_and_signature(
+            Transaction::Eip1559(TxEip1559 {
+                chain_id: 1,
+                access_list: access_list.clone(),
+                ..Default::default()
+            }),
+            Signature::default(),
+        );
+        let mut block = random_block(0, None, Some(0), Some(0));
+        block.body = vec![transaction.clone()];
+        let provider = factory.provider_rw().unwrap();
+        insert_canonical_block(provider.tx_ref(), block, Some(vec![Address::random()])).unwrap();
+        provider.commit().unwrap();
+
+        let mined =
+            factory.provider().unwrap().transaction_by_hash(transaction.hash()).unwrap().unwrap();
+        assert_eq!(mined.access_list(), Some(&access_list));
+    }
+
+    #[test]
+    fn logs_by_address() {
+        let factory = test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let (contract, other) = (Address::random(), Address::random());
+        let log = |address, data: u8| Log {
+            address,
+            topics: vec![H256::from_low_u64_be(data as u64 % 2)],
+            data: vec![data].into(),
+        };
+        // one transaction per block, the contract emits logs in blocks 1 and 4
+        let block_logs = [
+            vec![],
+            vec![log(contract, 1)],
+            vec![log(other, 2)],
+            vec![],
+            vec![log(other, 3), log(contract, 4), log(contract, 5)],
+            vec![log(other, 6)],
+        ];
+        let mut parent = None;
+        for (number, logs) in block_logs.into_iter().enumerate() {
+            let block = random_block(number as u64, parent, Some(1), Some(0));
+            parent = Some(block.hash());
+            let header =
+                Header { logs_bloom: logs_bloom(logs.iter()), ..block.header.clone().unseal() };
+            insert_canonical_block(provider.tx_ref(), block, None).unwrap();
+            provider.tx_ref().put::<tables::Headers>(number as u64, header).unwrap();
+
+            let receipt =
+                Receipt { tx_type: TxType::Legacy, success: true, cumulative_gas_used: 0, logs };
+            provider.tx_ref().put::<tables::Receipts>(number as u64, receipt).unwrap();
+        }
+
+        assert_eq!(
+            provider.logs_by_address(contract, 0..=5).unwrap(),
+            vec![log(contract, 1), log(contract, 4), log(contract, 5)]
+        );
+        assert_eq!(
+            provider.logs_by_address(contract, 2..=5).unwrap(),
+            vec![log(contract, 4), log(contract, 5)]
+        );
+        assert!(provider.logs_by_address(contract, 2..=3).unwrap().is_empty());
+        assert!(provider.logs_by_address(Address::random(), 0..=5).unwrap().is_empty());
+
+        for range in [0..=5, 2..=5, 2..=3] {
+            assert_eq!(
+                provider.log_count(range.clone(), contract, &[]).unwrap(),
+                provider.logs_by_address(contract, range).unwrap().len()
+            );
+        }
+        // logs 1 and 5 have the odd topic
+        let odd = H256::from_low_u64_be(1);
+        assert_eq!(provider.log_count(0..=5, contract, &[Some(odd)]).unwrap(), 2);
+        assert_eq!(provider.log_count(0..=5, contract, &[None]).unwrap(), 3);
+        assert_eq!(provider.log_count(0..=5, contract, &[None, Some(odd)]).unwrap(), 0);
+    }
+
+    #[test]
+    fn history_by_block_hash() {
+        let factory = test_provider_factory();
+
+        let provider = factory.provider_rw().unwrap();
+        let mut parent = None;
+        let mut blocks = Vec::new();
+        for number in 0..3 {
+            let block = random_block(number, parent, Some(0), Some(0));
+            parent = Some(block.hash());
+            insert_canonical_block(provider.tx_ref(), block.clone(), None).unwrap();
+            blocks.push(block);
+        }
+        // the account is changed in block 2
+        let address = Address::random();
+        let before = Account { nonce: 1, ..Default::default() };
+        let after = Account { nonce: 2, ..Default::default() };
+        provider
+            .tx_ref()
+            .put::<tables::AccountChangeSet>(2, AccountBeforeTx { address, info: Some(before) })
+            .unwrap();
+        provider.tx_ref().put::<tables::PlainAccountState>(address, after).unwrap();
+        provider
+            .tx_ref()
+            .put::<tables::AccountHistory>(
+                ShardedKey::new(address, u64::MAX),
+                BlockNumberList::new([2]).unwrap(),
+            )
+            .unwrap();
+        // a stored header at the tip height that is not part of the canonical chain
+        let non_canonical = H256::random();
+        provider.tx_ref().put::<tables::HeaderNumbers>(non_canonical, 2).unwrap();
+        provider.commit().unwrap();
+
+        // the state at a block hash is the state after that block
+        let state = factory.history_by_block_hash(blocks[1].hash()).unwrap();
+        assert_eq!(state.basic_account(address), Ok(Some(before)));
+        assert_eq!(state.basic_account(Address::random()), Ok(None));
+        let state = factory.history_by_block_hash(blocks[2].hash()).unwrap();
+        assert_eq!(state.basic_account(address), Ok(Some(after)));
+
+        // proofs are requested from the state at the historical block
+        let state = factory.history_by_block_hash(blocks[1].hash()).unwrap();
+        assert_eq!(
+            state.proof(Address::random(), &[]),
+            Err(ProviderError::StateRootNotAvailableForHistoricalBlock.into())
+        );
+
+        assert_eq!(
+            factory.history_by_block_hash(non_canonical).err(),
+            Some(ProviderError::NonCanonicalBlockHash(non_canonical).into())
+        );
+        let unknown = H256::random();
+        assert_eq!(
+            factory.history_by_block_hash(unknown).err(),
+            Some(ProviderError::BlockHashNotFound(unknown).into())
+        );
+    }
 }
@@ -3,8 +3,8 @@ use crate::{
     post_state::StorageChangeset,
     traits::{AccountExtProvider, BlockSource, ReceiptProvider},
     AccountProvider, BlockHashProvider, BlockNumProvider, BlockProvider, EvmEnvProvider,
-    HeaderProvider, PostState, ProviderError, StageCheckpointProvider, TransactionError,
-    TransactionsProvider, WithdrawalsProvider,
+    HeaderProvider, HistoricalStateProviderRef, PostState, ProviderError, StageCheckpointProvider,
+    StateProvider, TransactionError, TransactionsProvider, WithdrawalsProvider,
 };
 use itertools::{izip, Itertools};
 use reth_db::{
@@ -15,26 +15,30 @@ use reth_db::{
         sharded_key,
         storage_sharded_key::{self, StorageShardedKey},
         AccountBeforeTx, BlockNumberAddress, ShardedKey, StoredBlockBodyIndices,
+        StoredBlockWithdrawals,
     },
     table::Table,
     tables,
     transaction::{DbTx, DbTxMut, DbTxMutGAT},
-    BlockNumberList, DatabaseError,
+    BlockNumberList, DatabaseError, RawKey, RawTable,
 };
-use reth_interfaces::Result;
+use reth_interfaces::{consensus::ConsensusError, Result};
 use reth_primitives::{
-    keccak256,
+    block_bloom, keccak256,
+    proofs::calculate_receipt_root_ref,
     stage::{StageCheckpoint, StageId},
-    Account, Address, Block, BlockHash, BlockHashOrNumber, BlockNumber, BlockWithSenders,
-    ChainInfo, ChainSpec, Hardfork, Head, Header, Receipt, SealedBlock, SealedBlockWithSenders,
-    SealedHeader, StorageEntry, TransactionMeta, TransactionSigned, TransactionSignedEcRecovered,
-    TransactionSignedNoHash, TxHash, TxNumber, Withdrawal, H256, U256,
+    Account, Address, Block, BlockBody, BlockHash, BlockHashOrNumber, BlockNumber,
+    BlockWithSenders, Bytes, ChainInfo, ChainSpec, Hardfork, Head, Header, Receipt, SealedBlock,
+    SealedBlockWithSenders, SealedHeader, StorageEntry, TransactionKind, TransactionMeta,
+    TransactionSigned, TransactionSignedEcRecovered, TransactionSignedNoHash, TxHash, TxNumber,
+    Withdrawal, H256, U256,
 };
 use reth_revm_primitives::{
     config::revm_spec,
     env::{fill_block_env, fill_cfg_and_block_env, fill_cfg_env},
     primitives::{BlockEnv, CfgEnv, SpecId},
 };
+use reth_rlp::Encodable;
 use reth_trie::StateRoot;
 use std::{
     collections::{btree_map::Entry, BTreeMap, BTreeSet},
@@ -105,16 +109,16 @@ impl<'this, TX: DbTxMut<'this>> DatabaseProvider<'this, TX> {
 }
 
 /// Unwind all history shards. For boundary shard, remove it from database and
-/// return last part of shard with still valid items. If all full shard were removed, return list
-/// would be empty.
+/// return last part of shard with still valid items. If all full shard were removed, `None` is
+/// returned.
 fn unwind_account_history_shards<'a, TX: reth_db::transaction::DbTxMutGAT<'a>>(
     cursor: &mut <TX as DbTxMutGAT<'a>>::CursorMut<tables::AccountHistory>,
     address: Address,
     block_number: BlockNumber,
-) -> std::result::Result<Vec<usize>, TransactionError> {
+) -> std::result::Result<Option<BlockNumberList>, TransactionError> {
     let mut item = cursor.seek_exact(ShardedKey::new(address, u64::MAX))?;
 
-    while let Some((sharded_key, list)) = item {
+    while let Some((sharded_key, mut list)) = item {
         // there is no more shard for address
         if sharded_key.key != address {
             break
@@ -129,28 +133,26 @@ fn unwind_account_history_shards<'a, TX: reth_db::transaction::DbTxMutGAT<'a>>(
         } else if block_number <= sharded_key.highest_block_number {
             // if first element is in scope whole list would be removed.
             // so at least this first element is present.
-            return Ok(list.iter(0).take_while(|i| *i < block_number as usize).collect::<Vec<_>>())
-        } else {
-            let new_list = list.iter(0).collect::<Vec<_>>();
-            return Ok(new_list)
+            list.remove_range(block_number..=u64::MAX);
         }
+        return Ok(Some(list))
     }
-    Ok(Vec::new())
+    Ok(None)
 }
 
 /// Unwind all history shards. For boundary shard, remove it from database and
 /// return last part of shard with still valid items. If all full shard were removed, return list
-/// would be empty but this does not mean that there is none shard left but that there is no
+/// would be `None` but this does not mean that there is none shard left but that there is no
 /// split shards.
 fn unwind_storage_history_shards<'a, TX: reth_db::transaction::DbTxMutGAT<'a>>(
     cursor: &mut <TX as DbTxMutGAT<'a>>::CursorMut<tables::StorageHistory>,
     address: Address,
     storage_key: H256,
     block_number: BlockNumber,
-) -> std::result::Result<Vec<usize>, TransactionError> {
+) -> std::result::Result<Option<BlockNumberList>, TransactionError> {
     let mut item = cursor.seek_exact(StorageShardedKey::new(address, storage_key, u64::MAX))?;
 
-    while let Some((storage_sharded_key, list)) = item {
+    while let Some((storage_sharded_key, mut list)) = item {
         // there is no more shard for address
         if storage_sharded_key.address != address ||
             storage_sharded_key.sharded_key.key != storage_key
@@ -168,12 +170,11 @@ fn unwind_storage_history_shards<'a, TX: reth_db::transaction::DbTxMutGAT<'a>>(
         } else if block_number <= storage_sharded_key.sharded_key.highest_block_number {
             // if first element is in scope whole list would be removed.
             // so at least this first element is present.
-            return Ok(list.iter(0).take_while(|i| *i < block_number as usize).collect::<Vec<_>>())
-        } else {
-            return Ok(list.iter(0).collect::<Vec<_>>())
+            list.remove_range(block_number..=u64::MAX);
         }
+        return Ok(Some(list))
     }
-    Ok(Vec::new())
+    Ok(None)
 }
 
 impl<'this, TX: DbTx<'this>> DatabaseProvider<'this, TX> {
@@ -252,6 +253,37 @@ impl<'this, TX: DbTx<'this>> DatabaseProvider<'this, TX> {
             .collect::<std::result::Result<Vec<(_, _)>, _>>()
     }
 
+    /// Returns up to `max_results` storage slots of the account from [tables::PlainStorageState],
+    /// starting at `start_key` and sorted by key.
+    ///
+    /// The second element is the key of the next slot, if there are more, and can be used as the
+    /// `start_key` of the next page. This mirrors geth's `debug_storageRangeAt`.
+    #[allow(clippy::type_complexity)]
+    pub fn storage_range(
+        &self,
+        address: Address,
+        start_key: H256,
+        max_results: usize,
+    ) -> std::result::Result<(Vec<(H256, U256)>, Option<H256>), DatabaseError> {
+        let mut plain_storage = self.tx.cursor_dup_read::<tables::PlainStorageState>()?;
+
+        // `max_results` is user controlled, so the preallocation is capped
+        let mut storage = Vec::with_capacity(max_results.min(1024));
+        for entry in plain_storage.walk_dup(Some(address), Some(start_key))? {
+            let (_, StorageEntry { key, value }) = entry?;
+            // zero values are not expected in plain state, but they are not storage either
+            if value == U256::ZERO {
+                continue
+            }
+            if storage.len() == max_results {
+                return Ok((storage, Some(key)))
+            }
+            storage.push((key, value));
+        }
+
+        Ok((storage, None))
+    }
+
     /// Get all transaction ids where account got changed.
     ///
     /// NOTE: Get inclusive range of blocks.
@@ -317,6 +349,21 @@ impl<'this, TX: DbTx<'this>> DatabaseProvider<'this, TX> {
         )
     }
 
+    /// Returns the addresses of all accounts that were changed in the given block.
+    pub fn addresses_changed_in_block(
+        &self,
+        number: BlockNumber,
+    ) -> std::result::Result<BTreeSet<Address>, DatabaseError> {
+        self.tx
+            .cursor_dup_read::<tables::AccountChangeSet>()?
+            .walk_dup(Some(number), None)?
+            .try_fold(BTreeSet::new(), |mut accounts: BTreeSet<Address>, entry| {
+                let (_, account_before) = entry?;
+                accounts.insert(account_before.address);
+                Ok(accounts)
+            })
+    }
+
     /// Get plainstate account from iterator
     pub fn get_plainstate_accounts(
         &self,
@@ -328,6 +375,222 @@ impl<'this, TX: DbTx<'this>> DatabaseProvider<'this, TX> {
             .map(|address| plain_accounts.seek_exact(address).map(|a| (address, a.map(|(_, v)| v))))
             .collect::<std::result::Result<Vec<_>, _>>()?)
     }
+
+    /// Returns the distinct bytecode hashes referenced by the accounts in
+    /// [tables::PlainAccountState], in ascending order.
+    ///
+    /// This can be used to find orphaned entries of [tables::Bytecodes].
+    pub fn iter_bytecode_hashes(&self) -> std::result::Result<Vec<H256>, DatabaseError> {
+        let mut hashes = BTreeSet::new();
+        for entry in self.tx.cursor_read::<tables::PlainAccountState>()?.walk(None)? {
+            let (_, account) = entry?;
+            hashes.extend(account.bytecode_hash);
+        }
+        Ok(hashes.into_iter().collect())
+    }
+
+    /// Returns the block with senders, see [BlockProvider::block_with_senders].
+    ///
+    /// If the senders of the block are not stored, e.g. because they were pruned, they are
+    /// recovered from the transaction signatures if `recover_on_miss` is set. Otherwise
+    /// [ProviderError::BlockSendersNotFound] is returned.
+    pub fn block_with_senders_or_recover(
+        &self,
+        block_number: BlockNumber,
+        recover_on_miss: bool,
+    ) -> Result<Option<BlockWithSenders>> {
+        let header = self
+            .header_by_number(block_number)?
+            .ok_or_else(|| ProviderError::HeaderNotFound(block_number.into()))?;
+
+        let ommers = self.ommers(block_number.into())?.unwrap_or_default();
+        let withdrawals = self.withdrawals_by_block(block_number.into(), header.timestamp)?;
+
+        // Get the block body
+        let body = self
+            .block_body_indices(block_number)?
+            .ok_or(ProviderError::BlockBodyIndicesNotFound(block_number))?;
+        let tx_range = body.tx_num_range();
+        let tx_range_start = tx_range.start;
+
+        let (transactions, senders) = if tx_range.is_empty() {
+            (vec![], vec![])
+        } else {
+            (self.transactions_by_tx_range(tx_range.clone())?, self.senders_by_tx_range(tx_range)?)
+        };
+
+        let body = transactions
+            .into_iter()
+            .map(|tx| {
+                TransactionSigned {
+                    // TODO: This is the fastest way right now to make everything just work with
+                    // a dummy transaction hash.
+                    hash: Default::default(),
+                    signature: tx.signature,
+                    transaction: tx.transaction,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let senders = if senders.len() == body.len() {
+            senders
+        } else if recover_on_miss {
+            body.iter()
+                .zip(tx_range_start..)
+                .map(|(tx, tx_id)| {
+                    tx.recover_signer().ok_or(ProviderError::SenderRecoveryFailed { tx_id })
+                })
+                .collect::<std::result::Result<Vec<_>, _>>()?
+        } else {
+            return Err(ProviderError::BlockSendersNotFound(block_number).into())
+        };
+
+        Ok(Some(Block { header, body, ommers, withdrawals }.with_senders(senders)))
+    }
+
+    /// Verifies the stored receipts of the block with the given number against its header.
+    ///
+    /// The receipts root and the logs bloom are recomputed from the receipts and compared to the
+    /// `receipts_root` and `logs_bloom` of the header. A mismatch is returned as
+    /// [ConsensusError::BodyReceiptsRootDiff] or [ConsensusError::BodyLogsBloomDiff].
+    ///
+    /// Before Byzantium receipts committed to the intermediate state root instead of the status,
+    /// see [EIP-658](https://eips.ethereum.org/EIPS/eip-658), so the receipts root of those blocks
+    /// can't be recomputed and is not checked.
+    pub fn verify_block_receipts(
+        &self,
+        block_number: BlockNumber,
+    ) -> std::result::Result<(), ConsensusError> {
+        let unavailable =
+            |error: reth_interfaces::Error| ConsensusError::BlockReceiptsUnavailable {
+                number: block_number,
+                error: Box::new(error),
+            };
+        let header =
+            self.header_by_number(block_number).map_err(unavailable)?.ok_or_else(|| {
+                unavailable(ProviderError::HeaderNotFound(block_number.into()).into())
+            })?;
+        let receipts =
+            self.receipts_by_block(block_number.into()).map_err(unavailable)?.ok_or_else(|| {
+                unavailable(ProviderError::BlockBodyIndicesNotFound(block_number).into())
+            })?;
+
+        if self.chain_spec.fork(Hardfork::Byzantium).active_at_block(block_number) {
+            let receipts_root = calculate_receipt_root_ref(&receipts);
+            if receipts_root != header.receipts_root {
+                return Err(ConsensusError::BodyReceiptsRootDiff {
+                    got: receipts_root,
+                    expected: header.receipts_root,
+                })
+            }
+        }
+
+        let logs_bloom = block_bloom(&receipts);
+        if logs_bloom != header.logs_bloom {
+            return Err(ConsensusError::BodyLogsBloomDiff {
+                got: Box::new(logs_bloom),
+                expected: Box::new(header.logs_bloom),
+            })
+        }
+
+        Ok(())
+    }
+
+    /// Returns the blocks within the given range that changed the account, as recorded by the
+    /// account history index.
+    fn account_history_blocks(
+        &self,
+        address: Address,
+        range: &RangeInclusive<BlockNumber>,
+    ) -> std::result::Result<Vec<BlockNumber>, DatabaseError> {
+        let mut changed_blocks = Vec::new();
+        let start_key = ShardedKey::new(address, *range.start());
+        for entry in self.tx.cursor_read::<tables::AccountHistory>()?.walk(Some(start_key))? {
+            let (key, list) = entry?;
+            if key.key != address {
+                break
+            }
+            changed_blocks.extend(
+                list.iter(0)
+                    .map(|block_number| block_number as BlockNumber)
+                    .filter(|block_number| range.contains(block_number)),
+            );
+            if key.highest_block_number >= *range.end() {
+                break
+            }
+        }
+        Ok(changed_blocks)
+    }
+
+    /// Returns the blocks within the given range that changed the storage slot, as recorded by
+    /// the storage history index.
+    fn storage_history_blocks(
+        &self,
+        address: Address,
+        slot: H256,
+        range: &RangeInclusive<BlockNumber>,
+    ) -> std::result::Result<Vec<BlockNumber>, DatabaseError> {
+        let mut changed_blocks = Vec::new();
+        let start_key = StorageShardedKey::new(address, slot, *range.start());
+        for entry in self.tx.cursor_read::<tables::StorageHistory>()?.walk(Some(start_key))? {
+            let (key, list) = entry?;
+            if key.address != address || key.sharded_key.key != slot {
+                break
+            }
+            changed_blocks.extend(
+                list.iter(0)
+                    .map(|block_number| block_number as BlockNumber)
+                    .filter(|block_number| range.contains(block_number)),
+            );
+            if key.sharded_key.highest_block_number >= *range.end() {
+                break
+            }
+        }
+        Ok(changed_blocks)
+    }
+
+    /// Returns the state root checkpoint at or below the given block number, if any.
+    ///
+    /// Checkpoints are only recorded if the merkle stage is configured with a state root checkpoint
+    /// interval, see [tables::StateRootCheckpoints].
+    pub fn nearest_state_root_checkpoint(
+        &self,
+        block_number: BlockNumber,
+    ) -> std::result::Result<Option<(BlockNumber, H256)>, DatabaseError> {
+        let mut cursor = self.tx.cursor_read::<tables::StateRootCheckpoints>()?;
+        match cursor.seek(block_number)? {
+            Some((number, root)) if number == block_number => Ok(Some((number, root))),
+            Some(_) => cursor.prev(),
+            None => cursor.last(),
+        }
+    }
+
+    /// Returns the compact encoded transaction as stored in [tables::Transactions], without
+    /// decoding it.
+    ///
+    /// This is intended for debugging, e.g. to diagnose transactions that fail to decode.
+    pub fn transaction_raw_stored(
+        &self,
+        id: TxNumber,
+    ) -> std::result::Result<Option<Vec<u8>>, DatabaseError> {
+        Ok(self
+            .tx
+            .get::<RawTable<tables::Transactions>>(RawKey::new(id))?
+            .map(|tx| tx.raw_value().to_vec()))
+    }
+
+    /// Returns the range of transaction numbers stored in [tables::Transactions], from the first to
+    /// the last key.
+    ///
+    /// Returns `None` if there are no transactions.
+    pub fn tx_number_range(
+        &self,
+    ) -> std::result::Result<Option<RangeInclusive<TxNumber>>, DatabaseError> {
+        let mut cursor = self.tx.cursor_read::<tables::Transactions>()?;
+        let Some((first, _)) = cursor.first()? else { return Ok(None) };
+        let (last, _) = cursor.last()?.expect("the table is not empty");
+        Ok(Some(first..=last))
+    }
 }
 
 impl<'this, TX: DbTxMut<'this> + DbTx<'this>> DatabaseProvider<'this, TX> {
@@ -336,6 +599,31 @@ impl<'this, TX: DbTxMut<'this> + DbTx<'this>> DatabaseProvider<'this, TX> {
         Ok(self.tx.commit()?)
     }
 
+    /// Returns the block with senders, recovering the senders if they are not stored.
+    ///
+    /// Recovered senders are written to [tables::TxSenders], so subsequent reads don't need to
+    /// recover them again.
+    pub fn block_with_senders_cached(
+        &self,
+        block_number: BlockNumber,
+    ) -> Result<Option<BlockWithSenders>> {
+        let Some(block) = self.block_with_senders_or_recover(block_number, true)? else {
+            return Ok(None)
+        };
+
+        let Some(body) = self.tx.get::<tables::BlockBodyIndices>(block_number)? else {
+            return Ok(Some(block))
+        };
+        let tx_range = body.tx_num_range();
+        if self.senders_by_tx_range(tx_range.clone())?.len() != block.senders.len() {
+            for (tx_number, sender) in tx_range.zip(block.senders.iter()) {
+                self.tx.put::<tables::TxSenders>(tx_number, *sender)?;
+            }
+        }
+
+        Ok(Some(block))
+    }
+
     // TODO(joshie) TEMPORARY should be moved to trait providers
 
     /// Get range of blocks and its execution result
@@ -356,6 +644,44 @@ impl<'this, TX: DbTxMut<'this> + DbTx<'this>> DatabaseProvider<'this, TX> {
         self.get_take_block_and_execution_range::<true>(chain_spec, range)
     }
 
+    /// Checks that an unwind to `block_number` left the database consistent.
+    ///
+    /// This is a lightweight check to detect partial unwinds: no stage checkpoint may be ahead of
+    /// `block_number` and no transaction data may be stored above the last transaction of
+    /// `block_number`.
+    pub fn check_unwind_consistency(
+        &self,
+        block_number: BlockNumber,
+    ) -> std::result::Result<(), TransactionError> {
+        for entry in self.tx.cursor_read::<tables::SyncStage>()?.walk(None)? {
+            let (stage, checkpoint) = entry?;
+            if checkpoint.block_number > block_number {
+                return Err(TransactionError::UnwindStageCheckpointAhead {
+                    stage,
+                    checkpoint: checkpoint.block_number,
+                    block_number,
+                })
+            }
+        }
+
+        let next_tx_num = self
+            .tx
+            .get::<tables::BlockBodyIndices>(block_number)?
+            .unwrap_or_default()
+            .next_tx_num();
+        let last_tx_nums = [
+            self.tx.cursor_read::<tables::Transactions>()?.last()?.map(|(tx_num, _)| tx_num),
+            self.tx.cursor_read::<tables::TxSenders>()?.last()?.map(|(tx_num, _)| tx_num),
+            self.tx.cursor_read::<tables::TransactionBlock>()?.last()?.map(|(tx_num, _)| tx_num),
+            self.tx.cursor_read::<tables::Receipts>()?.last()?.map(|(tx_num, _)| tx_num),
+        ];
+        if let Some(tx_number) = last_tx_nums.into_iter().flatten().find(|n| *n >= next_tx_num) {
+            return Err(TransactionError::UnwindDanglingTransaction { tx_number, block_number })
+        }
+
+        Ok(())
+    }
+
     /// Unwind and clear account hashing
     pub fn unwind_account_hashing(
         &self,
@@ -479,12 +805,11 @@ impl<'this, TX: DbTxMut<'this> + DbTx<'this>> DatabaseProvider<'this, TX> {
             let shard_part = unwind_account_history_shards::<TX>(&mut cursor, address, rem_index)?;
 
             // check last shard_part, if present, items needs to be reinserted.
-            if !shard_part.is_empty() {
+            if let Some(shard_part) = shard_part {
                 // there are items in list
                 self.tx.put::<tables::AccountHistory>(
                     ShardedKey::new(address, u64::MAX),
-                    BlockNumberList::new(shard_part)
-                        .expect("There is at least one element in list and it is sorted."),
+                    shard_part,
                 )?;
             }
         }
@@ -526,12 +851,11 @@ impl<'this, TX: DbTxMut<'this> + DbTx<'this>> DatabaseProvider<'this, TX> {
                 unwind_storage_history_shards::<TX>(&mut cursor, address, storage_key, rem_index)?;
 
             // check last shard_part, if present, items needs to be reinserted.
-            if !shard_part.is_empty() {
+            if let Some(shard_part) = shard_part {
                 // there are items in list
                 self.tx.put::<tables::StorageHistory>(
                     StorageShardedKey::new(address, storage_key, u64::MAX),
-                    BlockNumberList::new(shard_part)
-                        .expect("There is at least one element in list and it is sorted."),
+                    shard_part,
                 )?;
             }
         }
@@ -561,7 +885,7 @@ impl<'this, TX: DbTxMut<'this> + DbTx<'this>> DatabaseProvider<'this, TX> {
     ///     3. Set the local state to the value in the changeset
     ///
     /// If `TAKE` is `true`, the local state will be written to the plain state tables.
-    /// 5. Get all receipts from table
+    /// 5. Get all receipts from table, blocks whose receipts were pruned don't have any
     fn get_take_block_execution_result_range<const TAKE: bool>(
         &self,
         range: RangeInclusive<BlockNumber>,
@@ -704,12 +1028,14 @@ impl<'this, TX: DbTxMut<'this> + DbTx<'this>> DatabaseProvider<'this, TX> {
         }
 
         // iterate over block body and create ExecutionResult
-        let mut receipt_iter = receipts.into_iter();
+        //
+        // receipts are matched by transaction number, because the receipts of pruned blocks are
+        // missing
+        let mut receipts = BTreeMap::from_iter(receipts);
 
-        // loop break if we are at the end of the blocks.
         for (block_number, block_body) in block_bodies.into_iter() {
-            for _ in block_body.tx_num_range() {
-                if let Some((_, receipt)) = receipt_iter.next() {
+            for tx_num in block_body.tx_num_range() {
+                if let Some(receipt) = receipts.remove(&tx_num) {
                     block_states
                         .entry(block_number)
                         .or_default()
@@ -954,8 +1280,10 @@ impl<'this, TX: DbTxMut<'this> + DbTx<'this>> DatabaseProvider<'this, TX> {
             };
 
             // withdrawal can be missing
-            let shanghai_is_active =
-                chain_spec.fork(Hardfork::Shanghai).active_at_timestamp(header.timestamp);
+            let shanghai_is_active = chain_spec.is_active(
+                Hardfork::Shanghai,
+                Head { number: header.number, timestamp: header.timestamp, ..Default::default() },
+            );
             let mut withdrawals = Some(Vec::new());
             if shanghai_is_active {
                 if let Some((block_number, _)) = block_withdrawals.as_ref() {
@@ -1076,6 +1404,69 @@ impl<'this, TX: DbTxMut<'this> + DbTx<'this>> DatabaseProvider<'this, TX> {
         Ok(())
     }
 
+    /// Rewrites all history shards of the account as full shards of
+    /// [`NUM_OF_INDICES_IN_SHARD`](sharded_key::NUM_OF_INDICES_IN_SHARD) indices.
+    ///
+    /// Returns the number of removed shards.
+    pub fn compact_account_history(
+        &self,
+        address: Address,
+    ) -> std::result::Result<usize, TransactionError> {
+        let shards = self
+            .tx
+            .cursor_read::<tables::AccountHistory>()?
+            .walk(Some(ShardedKey::new(address, 0)))?
+            .take_while(|entry| entry.as_ref().map_or(true, |(key, _)| key.key == address))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let old_shards = shards.len();
+
+        // shards are ordered by their highest block number, so the indices stay sorted
+        let mut indices = Vec::new();
+        for (key, list) in shards {
+            indices.extend(list.iter(0).map(|i| i as u64));
+            self.tx.delete::<tables::AccountHistory>(key, None)?;
+        }
+        let new_shards = (indices.len() + sharded_key::NUM_OF_INDICES_IN_SHARD - 1) /
+            sharded_key::NUM_OF_INDICES_IN_SHARD;
+
+        self.insert_account_history_index(BTreeMap::from([(address, indices)]))?;
+        Ok(old_shards - new_shards)
+    }
+
+    /// Rewrites all history shards of the storage slot as full shards of
+    /// [`NUM_OF_INDICES_IN_SHARD`](storage_sharded_key::NUM_OF_INDICES_IN_SHARD) indices.
+    ///
+    /// Returns the number of removed shards.
+    pub fn compact_storage_history(
+        &self,
+        address: Address,
+        storage_key: H256,
+    ) -> std::result::Result<usize, TransactionError> {
+        let shards = self
+            .tx
+            .cursor_read::<tables::StorageHistory>()?
+            .walk(Some(StorageShardedKey::new(address, storage_key, 0)))?
+            .take_while(|entry| {
+                entry.as_ref().map_or(true, |(key, _)| {
+                    key.address == address && key.sharded_key.key == storage_key
+                })
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let old_shards = shards.len();
+
+        // shards are ordered by their highest block number, so the indices stay sorted
+        let mut indices = Vec::new();
+        for (key, list) in shards {
+            indices.extend(list.iter(0).map(|i| i as u64));
+            self.tx.delete::<tables::StorageHistory>(key, None)?;
+        }
+        let new_shards = (indices.len() + storage_sharded_key::NUM_OF_INDICES_IN_SHARD - 1) /
+            storage_sharded_key::NUM_OF_INDICES_IN_SHARD;
+
+        self.insert_storage_history_index(BTreeMap::from([((address, storage_key), indices)]))?;
+        Ok(old_shards - new_shards)
+    }
+
     /// Get the stage checkpoint.
     pub fn get_stage_checkpoint(
         &self,
@@ -1428,6 +1819,54 @@ impl<'this, TX: DbTxMut<'this> + DbTx<'this>> DatabaseProvider<'this, TX> {
         }
         Ok(())
     }
+
+    /// Rebuild the hashed state tables from the plain state.
+    ///
+    /// Clears [tables::HashedAccount] and [tables::HashedStorage] and repopulates them by hashing
+    /// every entry in [tables::PlainAccountState] and [tables::PlainStorageState]. The state trie
+    /// is then recomputed from scratch and its root is verified against the state root of the
+    /// tip header.
+    ///
+    /// This is a recovery tool (e.g. after an import or database corruption), the pipeline keeps
+    /// the hashed state up to date incrementally.
+    pub fn rebuild_hashed_state(&self) -> std::result::Result<(), TransactionError> {
+        self.tx.clear::<tables::HashedAccount>()?;
+        self.tx.clear::<tables::HashedStorage>()?;
+
+        let mut hashed_accounts = self.tx.cursor_write::<tables::HashedAccount>()?;
+        for entry in self.tx.cursor_read::<tables::PlainAccountState>()?.walk(None)? {
+            let (address, account) = entry?;
+            hashed_accounts.upsert(keccak256(address), account)?;
+        }
+
+        let mut hashed_storage = self.tx.cursor_dup_write::<tables::HashedStorage>()?;
+        for entry in self.tx.cursor_dup_read::<tables::PlainStorageState>()?.walk(None)? {
+            let (address, StorageEntry { key, value }) = entry?;
+            if value != U256::ZERO {
+                hashed_storage
+                    .upsert(keccak256(address), StorageEntry { key: keccak256(key), value })?;
+            }
+        }
+
+        // the intermediate trie nodes can't be trusted either, recompute the root from scratch
+        self.tx.clear::<tables::AccountsTrie>()?;
+        self.tx.clear::<tables::StoragesTrie>()?;
+        let (state_root, trie_updates) = StateRoot::new(&self.tx).root_with_updates()?;
+
+        let tip = self.tip_number()?;
+        let expected_state_root = self.get_header(tip)?.state_root;
+        if state_root != expected_state_root {
+            return Err(TransactionError::StateRootMismatch {
+                got: state_root,
+                expected: expected_state_root,
+                block_number: tip,
+                block_hash: self.get_block_hash(tip)?,
+            })
+        }
+        trie_updates.flush(&self.tx)?;
+
+        Ok(())
+    }
 }
 
 impl<'this, TX: DbTx<'this>> AccountProvider for DatabaseProvider<'this, TX> {
@@ -1460,6 +1899,132 @@ impl<'this, TX: DbTx<'this>> AccountExtProvider for DatabaseProvider<'this, TX>
             .map(|address| plain_accounts.seek_exact(address).map(|a| (address, a.map(|(_, v)| v))))
             .collect::<std::result::Result<Vec<_>, _>>()?)
     }
+
+    fn balance_history(
+        &self,
+        address: Address,
+        range: RangeInclusive<BlockNumber>,
+    ) -> Result<Vec<(BlockNumber, U256)>> {
+        self.account_history_blocks(address, &range)?
+            .into_iter()
+            .map(|block_number| {
+                // the state after the block is the state at the beginning of the next block
+                let state = HistoricalStateProviderRef::new(&self.tx, block_number + 1);
+                let balance = state
+                    .basic_account(address)?
+                    .map(|account| account.balance)
+                    .unwrap_or_default();
+                Ok((block_number, balance))
+            })
+            .collect()
+    }
+
+    fn storage_slot_history(
+        &self,
+        address: Address,
+        slot: H256,
+        range: RangeInclusive<BlockNumber>,
+    ) -> Result<Vec<(BlockNumber, U256)>> {
+        self.storage_history_blocks(address, slot, &range)?
+            .into_iter()
+            .map(|block_number| {
+                // the state after the block is the state at the beginning of the next block
+                let state = HistoricalStateProviderRef::new(&self.tx, block_number + 1);
+                let value = state.storage(address, slot)?.unwrap_or_default();
+                Ok((block_number, value))
+            })
+            .collect()
+    }
+
+    fn sender_transaction_count(&self, address: Address, at_block: BlockNumber) -> Result<u64> {
+        // the state after the block is the state at the beginning of the next block
+        let state = HistoricalStateProviderRef::new(&self.tx, at_block.saturating_add(1));
+        Ok(state.basic_account(address)?.map(|account| account.nonce).unwrap_or_default())
+    }
+
+    fn recent_transactions_for_sender(
+        &self,
+        address: Address,
+        n: usize,
+    ) -> Result<Vec<TransactionSignedEcRecovered>> {
+        let mut transactions = Vec::with_capacity(n);
+        if n == 0 {
+            return Ok(transactions)
+        }
+
+        let mut history = self.tx.cursor_read::<tables::AccountHistory>()?;
+        let mut senders = self.tx.cursor_read::<tables::TxSenders>()?;
+
+        // walk the history shards of the account from the newest block to the oldest
+        for entry in history.walk_back(Some(ShardedKey::new(address, u64::MAX)))? {
+            let (key, list) = entry?;
+            if key.key != address {
+                break
+            }
+
+            for block_number in list.iter(0).collect::<Vec<_>>().into_iter().rev() {
+                let Some(body) = self.block_body_indices(block_number as BlockNumber)? else {
+                    continue
+                };
+
+                let sent = senders
+                    .walk_range(body.tx_num_range())?
+                    .filter_map(|entry| match entry {
+                        Ok((tx_id, sender)) => (sender == address).then_some(Ok(tx_id)),
+                        Err(err) => Some(Err(err)),
+                    })
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+
+                for tx_id in sent.into_iter().rev() {
+                    let transaction = self
+                        .transaction_by_id(tx_id)?
+                        .ok_or(ProviderError::MismatchOfTransactionAndSenderId { tx_id })?;
+                    transactions.push(TransactionSignedEcRecovered::from_signed_transaction(
+                        transaction,
+                        address,
+                    ));
+                    if transactions.len() == n {
+                        return Ok(transactions)
+                    }
+                }
+            }
+        }
+
+        Ok(transactions)
+    }
+
+    fn transactions_to_address(
+        &self,
+        address: Address,
+        range: RangeInclusive<BlockNumber>,
+    ) -> Result<Vec<TransactionSignedEcRecovered>> {
+        let mut transactions = Vec::new();
+        let mut tx_cursor = self.tx.cursor_read::<tables::Transactions>()?;
+        let mut senders = self.tx.cursor_read::<tables::TxSenders>()?;
+
+        // the account history index only records blocks that changed the account, which misses
+        // calls that leave it untouched, so all transactions of the range are scanned
+        for entry in self.tx.cursor_read::<tables::BlockBodyIndices>()?.walk_range(range)? {
+            let (_, body) = entry?;
+
+            for entry in tx_cursor.walk_range(body.tx_num_range())? {
+                let (tx_id, transaction) = entry?;
+                if transaction.transaction.kind() != &TransactionKind::Call(address) {
+                    continue
+                }
+
+                let (_, signer) = senders
+                    .seek_exact(tx_id)?
+                    .ok_or(ProviderError::MismatchOfTransactionAndSenderId { tx_id })?;
+                transactions.push(TransactionSignedEcRecovered::from_signed_transaction(
+                    transaction.into(),
+                    signer,
+                ));
+            }
+        }
+
+        Ok(transactions)
+    }
 }
 
 impl<'this, TX: DbTx<'this>> HeaderProvider for DatabaseProvider<'this, TX> {
@@ -1526,6 +2091,39 @@ impl<'this, TX: DbTx<'this>> HeaderProvider for DatabaseProvider<'this, TX> {
             Ok(None)
         }
     }
+
+    fn burned_fees(&self, range: impl RangeBounds<BlockNumber>) -> Result<U256> {
+        let mut burned = U256::ZERO;
+        for entry in self.tx.cursor_read::<tables::Headers>()?.walk_range(range)? {
+            let (_, header) = entry?;
+            burned += header.burned_fees();
+        }
+        Ok(burned)
+    }
+
+    fn cumulative_gas_used_at(&self, block_number: BlockNumber) -> Result<u64> {
+        let mut cumulative_gas_used = 0;
+        let mut last_block = None;
+        for entry in self.tx.cursor_read::<tables::Headers>()?.walk_range(..=block_number)? {
+            let (number, header) = entry?;
+            cumulative_gas_used += header.gas_used;
+            last_block = Some(number);
+        }
+        if last_block != Some(block_number) {
+            return Err(ProviderError::HeaderNotFound(block_number.into()).into())
+        }
+        Ok(cumulative_gas_used)
+    }
+
+    fn gas_limits(&self, range: impl RangeBounds<BlockNumber>) -> Result<Vec<(BlockNumber, u64)>> {
+        self.tx
+            .cursor_read::<tables::Headers>()?
+            .walk_range(range)?
+            .map(|entry| {
+                entry.map(|(number, header)| (number, header.gas_limit)).map_err(Into::into)
+            })
+            .collect()
+    }
 }
 
 impl<'this, TX: DbTx<'this>> BlockHashProvider for DatabaseProvider<'this, TX> {
@@ -1564,6 +2162,19 @@ impl<'this, TX: DbTx<'this>> BlockNumProvider for DatabaseProvider<'this, TX> {
     fn block_number(&self, hash: H256) -> Result<Option<BlockNumber>> {
         Ok(self.tx.get::<tables::HeaderNumbers>(hash)?)
     }
+
+    fn find_canonical_gaps(&self) -> Result<Vec<RangeInclusive<BlockNumber>>> {
+        let mut gaps = Vec::new();
+        let mut previous: Option<BlockNumber> = None;
+        for entry in self.tx.cursor_read::<tables::CanonicalHeaders>()?.walk(None)? {
+            let (number, _) = entry?;
+            if let Some(previous) = previous.filter(|previous| number > previous + 1) {
+                gaps.push(previous + 1..=number - 1);
+            }
+            previous = Some(number);
+        }
+        Ok(gaps)
+    }
 }
 
 impl<'this, TX: DbTx<'this>> BlockProvider for DatabaseProvider<'this, TX> {
@@ -1617,39 +2228,74 @@ impl<'this, TX: DbTx<'this>> BlockProvider for DatabaseProvider<'this, TX> {
     ///
     /// Returns `None` if block is not found.
     fn block_with_senders(&self, block_number: BlockNumber) -> Result<Option<BlockWithSenders>> {
-        let header = self
-            .header_by_number(block_number)?
-            .ok_or_else(|| ProviderError::HeaderNotFound(block_number.into()))?;
+        self.block_with_senders_or_recover(block_number, false)
+    }
 
-        let ommers = self.ommers(block_number.into())?.unwrap_or_default();
-        let withdrawals = self.withdrawals_by_block(block_number.into(), header.timestamp)?;
+    fn block_body_rlp(&self, number: BlockNumber) -> Result<Option<Bytes>> {
+        let Some(header) = self.header_by_number(number)? else { return Ok(None) };
 
-        // Get the block body
-        let body = self
-            .block_body_indices(block_number)?
-            .ok_or(ProviderError::BlockBodyIndicesNotFound(block_number))?;
-        let tx_range = body.tx_num_range();
+        let withdrawals = self.withdrawals_by_block(number.into(), header.timestamp)?;
+        let ommers = if withdrawals.is_none() { self.ommers(number.into())? } else { None }
+            .unwrap_or_default();
+        let transactions = self
+            .transactions_by_block(number.into())?
+            .ok_or(ProviderError::BlockBodyIndicesNotFound(number))?;
 
-        let (transactions, senders) = if tx_range.is_empty() {
-            (vec![], vec![])
-        } else {
-            (self.transactions_by_tx_range(tx_range.clone())?, self.senders_by_tx_range(tx_range)?)
-        };
+        let body = BlockBody { transactions, ommers, withdrawals };
+        let mut buf = Vec::with_capacity(body.length());
+        body.encode(&mut buf);
+        Ok(Some(buf.into()))
+    }
 
-        let body = transactions
-            .into_iter()
-            .map(|tx| {
-                TransactionSigned {
-                    // TODO: This is the fastest way right now to make everything just work with
-                    // a dummy transaction hash.
-                    hash: Default::default(),
-                    signature: tx.signature,
-                    transaction: tx.transaction,
-                }
-            })
-            .collect();
+    fn block_range(&self, range: RangeInclusive<BlockNumber>) -> Result<Vec<SealedBlock>> {
+        let mut header_cursor = self.tx.cursor_read::<tables::Headers>()?;
+        let mut body_cursor = self.tx.cursor_read::<tables::BlockBodyIndices>()?;
+        let mut tx_cursor = self.tx.cursor_read::<tables::Transactions>()?;
+        let mut ommers_cursor = self.tx.cursor_read::<tables::BlockOmmers>()?;
+        let mut withdrawals_cursor = self.tx.cursor_read::<tables::BlockWithdrawals>()?;
 
-        Ok(Some(Block { header, body, ommers, withdrawals }.with_senders(senders)))
+        let mut blocks = Vec::new();
+        for entry in self.tx.cursor_read::<tables::CanonicalHeaders>()?.walk_range(range)? {
+            let (number, hash) = entry?;
+            let (_, header) = header_cursor
+                .seek_exact(number)?
+                .ok_or(ProviderError::HeaderNotFound(number.into()))?;
+            let (_, body) = body_cursor
+                .seek_exact(number)?
+                .ok_or(ProviderError::BlockBodyIndicesNotFound(number))?;
+
+            let tx_num_range = body.tx_num_range();
+            let transactions = if tx_num_range.is_empty() {
+                Vec::new()
+            } else {
+                tx_cursor
+                    .walk_range(tx_num_range)?
+                    .map(|result| result.map(|(_, tx)| tx.into()))
+                    .collect::<std::result::Result<Vec<_>, _>>()?
+            };
+
+            // If we are past shanghai, then all blocks should have a withdrawal list, even if
+            // empty, and no ommers
+            let (ommers, withdrawals) = if self.chain_spec.is_active(
+                Hardfork::Shanghai,
+                Head { timestamp: header.timestamp, ..Default::default() },
+            ) {
+                let withdrawals =
+                    withdrawals_cursor.seek_exact(number)?.map(|(_, w)| w.withdrawals);
+                (Vec::new(), Some(withdrawals.unwrap_or_default()))
+            } else {
+                let ommers = ommers_cursor.seek_exact(number)?.map(|(_, o)| o.ommers);
+                (ommers.unwrap_or_default(), None)
+            };
+
+            blocks.push(SealedBlock {
+                header: header.seal(hash),
+                body: transactions,
+                ommers,
+                withdrawals,
+            });
+        }
+        Ok(blocks)
     }
 }
 
@@ -1812,6 +2458,41 @@ impl<'this, TX: DbTx<'this>> ReceiptProvider for DatabaseProvider<'this, TX> {
         }
         Ok(None)
     }
+
+    fn receipts_by_tx_range(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+    ) -> Result<Vec<(TxNumber, Receipt)>> {
+        Ok(self
+            .tx
+            .cursor_read::<tables::Receipts>()?
+            .walk_range(range)?
+            .collect::<std::result::Result<Vec<_>, _>>()?)
+    }
+
+    fn receipts_by_block_range(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+    ) -> Result<Vec<(BlockNumber, Vec<Receipt>)>> {
+        let mut body_cursor = self.tx.cursor_read::<tables::BlockBodyIndices>()?;
+        let mut receipts_cursor = self.tx.cursor_read::<tables::Receipts>()?;
+
+        let mut results = Vec::new();
+        for entry in body_cursor.walk_range(range)? {
+            let (number, body) = entry?;
+            let block_receipts = if body.is_empty() {
+                Vec::new()
+            } else {
+                receipts_cursor
+                    .walk_range(body.tx_num_range())?
+                    .map(|result| result.map(|(_, receipt)| receipt))
+                    .collect::<std::result::Result<Vec<_>, _>>()?
+            };
+            results.push((number, block_receipts));
+        }
+
+        Ok(results)
+    }
 }
 
 impl<'this, TX: DbTx<'this>> WithdrawalsProvider for DatabaseProvider<'this, TX> {
@@ -1820,7 +2501,7 @@ impl<'this, TX: DbTx<'this>> WithdrawalsProvider for DatabaseProvider<'this, TX>
         id: BlockHashOrNumber,
         timestamp: u64,
     ) -> Result<Option<Vec<Withdrawal>>> {
-        if self.chain_spec.is_shanghai_activated_at_timestamp(timestamp) {
+        if self.chain_spec.is_active(Hardfork::Shanghai, Head { timestamp, ..Default::default() }) {
             if let Some(number) = self.convert_hash_or_number(id)? {
                 // If we are past shanghai, then all blocks should have a withdrawal list, even if
                 // empty
@@ -1840,6 +2521,46 @@ impl<'this, TX: DbTx<'this>> WithdrawalsProvider for DatabaseProvider<'this, TX>
         Ok(latest_block_withdrawal
             .and_then(|(_, mut block_withdrawal)| block_withdrawal.withdrawals.pop()))
     }
+
+    fn withdrawal_by_index(&self, index: u64) -> Result<Option<Withdrawal>> {
+        let last_index = |block_withdrawals: &StoredBlockWithdrawals| {
+            block_withdrawals.withdrawals.last().map(|withdrawal| withdrawal.index)
+        };
+
+        let mut cursor = self.tx.cursor_read::<tables::BlockWithdrawals>()?;
+        let (Some((mut low, _)), Some((mut high, _))) = (cursor.first()?, cursor.last()?) else {
+            return Ok(None)
+        };
+
+        // Withdrawal indices increase monotonically, so binary search for the first block with
+        // withdrawals whose last withdrawal index is at least `index`. Blocks without withdrawals
+        // are not stored.
+        while low <= high {
+            let mid = low + (high - low) / 2;
+            let Some((number, block_withdrawals)) = cursor.seek(mid)? else { break };
+            if last_index(&block_withdrawals) < Some(index) {
+                low = number + 1;
+                continue
+            }
+
+            // the block is the first one unless the previous block with withdrawals also matches
+            match cursor.prev()? {
+                Some((prev, prev_withdrawals))
+                    if prev >= low && last_index(&prev_withdrawals) >= Some(index) =>
+                {
+                    high = prev
+                }
+                _ => {
+                    return Ok(block_withdrawals
+                        .withdrawals
+                        .into_iter()
+                        .find(|withdrawal| withdrawal.index == index))
+                }
+            }
+        }
+
+        Ok(None)
+    }
 }
 
 impl<'this, TX: DbTx<'this>> EvmEnvProvider for DatabaseProvider<'this, TX> {
@@ -1913,4 +2634,28 @@ impl<'this, TX: DbTx<'this>> StageCheckpointProvider for DatabaseProvider<'this,
     fn get_stage_checkpoint(&self, id: StageId) -> Result<Option<StageCheckpoint>> {
         Ok(self.tx.get::<tables::SyncStage>(id.to_string())?)
     }
+
+    fn execution_block_range(&self) -> Result<Option<RangeInclusive<BlockNumber>>> {
+        let Some(checkpoint) = self.get_stage_checkpoint(StageId::Execution)? else {
+            return Ok(None)
+        };
+
+        // the receipts of all transactions before the first remaining receipt were pruned, if
+        // there are no receipts left, the receipts of all executed transactions were pruned
+        let pruned_tx_end = match self.tx.cursor_read::<tables::Receipts>()?.first()? {
+            Some((tx_number, _)) => tx_number,
+            None => self
+                .tx
+                .get::<tables::BlockBodyIndices>(checkpoint.block_number)?
+                .map_or(0, |indices| indices.next_tx_num()),
+        };
+        let pruned_floor = match pruned_tx_end.checked_sub(1) {
+            Some(last_pruned_tx) => {
+                self.transaction_block(last_pruned_tx)?.map_or(0, |block| block + 1)
+            }
+            None => 0,
+        };
+
+        Ok(Some(pruned_floor..=checkpoint.block_number))
+    }
 }
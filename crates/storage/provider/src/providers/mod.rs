@@ -1,9 +1,9 @@
 use crate::{
     BlockHashProvider, BlockIdProvider, BlockNumProvider, BlockProvider, BlockProviderIdExt,
     BlockchainTreePendingStateProvider, CanonChainTracker, CanonStateNotifications,
-    CanonStateSubscriptions, EvmEnvProvider, HeaderProvider, PostStateDataProvider, ProviderError,
-    ReceiptProvider, StageCheckpointProvider, StateProviderBox, StateProviderFactory,
-    TransactionsProvider, WithdrawalsProvider,
+    CanonStateSubscriptions, ChainSpecProvider, EvmEnvProvider, HeaderProvider,
+    PostStateDataProvider, ProviderError, ReceiptProvider, StageCheckpointProvider,
+    StateProviderBox, StateProviderFactory, TransactionsProvider, WithdrawalsProvider,
 };
 use reth_db::{database::Database, models::StoredBlockBodyIndices};
 use reth_interfaces::{
@@ -14,7 +14,7 @@ use reth_interfaces::{
 use reth_primitives::{
     stage::{StageCheckpoint, StageId},
     Address, Block, BlockHash, BlockHashOrNumber, BlockId, BlockNumHash, BlockNumber,
-    BlockNumberOrTag, BlockWithSenders, ChainInfo, Header, Receipt, SealedBlock,
+    BlockNumberOrTag, BlockWithSenders, Bytes, ChainInfo, ChainSpec, Header, Receipt, SealedBlock,
     SealedBlockWithSenders, SealedHeader, TransactionMeta, TransactionSigned,
     TransactionSignedNoHash, TxHash, TxNumber, Withdrawal, H256, U256,
 };
@@ -25,7 +25,8 @@ pub use state::{
 };
 use std::{
     collections::{BTreeMap, HashSet},
-    ops::RangeBounds,
+    ops::{RangeBounds, RangeInclusive},
+    sync::Arc,
     time::Instant,
 };
 use tracing::trace;
@@ -140,6 +141,18 @@ where
     fn sealed_header(&self, number: BlockNumber) -> Result<Option<SealedHeader>> {
         self.database.provider()?.sealed_header(number)
     }
+
+    fn burned_fees(&self, range: impl RangeBounds<BlockNumber>) -> Result<U256> {
+        self.database.provider()?.burned_fees(range)
+    }
+
+    fn cumulative_gas_used_at(&self, block_number: BlockNumber) -> Result<u64> {
+        self.database.provider()?.cumulative_gas_used_at(block_number)
+    }
+
+    fn gas_limits(&self, range: impl RangeBounds<BlockNumber>) -> Result<Vec<(BlockNumber, u64)>> {
+        self.database.provider()?.gas_limits(range)
+    }
 }
 
 impl<DB, Tree> BlockHashProvider for BlockchainProvider<DB, Tree>
@@ -176,6 +189,10 @@ where
     fn block_number(&self, hash: H256) -> Result<Option<BlockNumber>> {
         self.database.provider()?.block_number(hash)
     }
+
+    fn find_canonical_gaps(&self) -> Result<Vec<RangeInclusive<BlockNumber>>> {
+        self.database.provider()?.find_canonical_gaps()
+    }
 }
 
 impl<DB, Tree> BlockIdProvider for BlockchainProvider<DB, Tree>
@@ -248,6 +265,14 @@ where
     fn block_with_senders(&self, number: BlockNumber) -> Result<Option<BlockWithSenders>> {
         self.database.provider()?.block_with_senders(number)
     }
+
+    fn block_body_rlp(&self, number: BlockNumber) -> Result<Option<Bytes>> {
+        self.database.provider()?.block_body_rlp(number)
+    }
+
+    fn block_range(&self, range: RangeInclusive<BlockNumber>) -> Result<Vec<SealedBlock>> {
+        self.database.provider()?.block_range(range)
+    }
 }
 
 impl<DB, Tree> TransactionsProvider for BlockchainProvider<DB, Tree>
@@ -320,6 +345,20 @@ where
     fn receipts_by_block(&self, block: BlockHashOrNumber) -> Result<Option<Vec<Receipt>>> {
         self.database.provider()?.receipts_by_block(block)
     }
+
+    fn receipts_by_tx_range(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+    ) -> Result<Vec<(TxNumber, Receipt)>> {
+        self.database.provider()?.receipts_by_tx_range(range)
+    }
+
+    fn receipts_by_block_range(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+    ) -> Result<Vec<(BlockNumber, Vec<Receipt>)>> {
+        self.database.provider()?.receipts_by_block_range(range)
+    }
 }
 
 impl<DB, Tree> WithdrawalsProvider for BlockchainProvider<DB, Tree>
@@ -338,6 +377,10 @@ where
     fn latest_withdrawal(&self) -> Result<Option<Withdrawal>> {
         self.database.provider()?.latest_withdrawal()
     }
+
+    fn withdrawal_by_index(&self, index: u64) -> Result<Option<Withdrawal>> {
+        self.database.provider()?.withdrawal_by_index(index)
+    }
 }
 
 impl<DB, Tree> StageCheckpointProvider for BlockchainProvider<DB, Tree>
@@ -348,6 +391,20 @@ where
     fn get_stage_checkpoint(&self, id: StageId) -> Result<Option<StageCheckpoint>> {
         self.database.provider()?.get_stage_checkpoint(id)
     }
+
+    fn execution_block_range(&self) -> Result<Option<RangeInclusive<BlockNumber>>> {
+        self.database.provider()?.execution_block_range()
+    }
+}
+
+impl<DB, Tree> ChainSpecProvider for BlockchainProvider<DB, Tree>
+where
+    DB: Database,
+    Tree: Send + Sync,
+{
+    fn chain_spec(&self) -> Arc<ChainSpec> {
+        self.database.chain_spec()
+    }
 }
 
 impl<DB, Tree> EvmEnvProvider for BlockchainProvider<DB, Tree>
@@ -96,4 +96,12 @@ impl<SP: StateProvider, PSDP: PostStateDataProvider> StateProvider for PostState
     ) -> Result<(Vec<Bytes>, H256, Vec<Vec<Bytes>>)> {
         Err(ProviderError::StateRootNotAvailableForHistoricalBlock.into())
     }
+
+    fn account_range(
+        &self,
+        _start: H256,
+        _limit: usize,
+    ) -> Result<(Vec<(H256, Account)>, Vec<Bytes>)> {
+        Err(ProviderError::StateRootNotAvailableForHistoricalBlock.into())
+    }
 }
@@ -188,6 +188,14 @@ impl<'a, 'b, TX: DbTx<'a>> StateProvider for HistoricalStateProviderRef<'a, 'b,
     ) -> Result<(Vec<Bytes>, H256, Vec<Vec<Bytes>>)> {
         Err(ProviderError::StateRootNotAvailableForHistoricalBlock.into())
     }
+
+    fn account_range(
+        &self,
+        _start: H256,
+        _limit: usize,
+    ) -> Result<(Vec<(H256, Account)>, Vec<Bytes>)> {
+        Err(ProviderError::StateRootNotAvailableForHistoricalBlock.into())
+    }
 }
 
 /// State provider for a given transition
@@ -7,10 +7,11 @@ use reth_db::{
     tables,
     transaction::DbTx,
 };
-use reth_interfaces::{provider::ProviderError, Result};
+use reth_interfaces::Result;
 use reth_primitives::{
-    keccak256, Account, Address, BlockNumber, Bytecode, Bytes, StorageKey, StorageValue, H256,
+    Account, Address, BlockNumber, Bytecode, Bytes, StorageKey, StorageValue, H256,
 };
+use reth_trie::Proof;
 use std::marker::PhantomData;
 
 /// State provider over latest state that takes tx reference.
@@ -83,18 +84,21 @@ impl<'a, 'b, TX: DbTx<'a>> StateProvider for LatestStateProviderRef<'a, 'b, TX>
     fn proof(
         &self,
         address: Address,
-        _keys: &[H256],
+        keys: &[H256],
     ) -> Result<(Vec<Bytes>, H256, Vec<Vec<Bytes>>)> {
-        let _hashed_address = keccak256(address);
-        let _root = self
-            .db
-            .cursor_read::<tables::Headers>()?
-            .last()?
-            .ok_or_else(|| ProviderError::HeaderNotFound(0.into()))?
-            .1
-            .state_root;
-
-        unimplemented!()
+        Proof::new(self.db)
+            .account_proof(address, keys)
+            .map_err(|err| reth_interfaces::Error::Database(err.into()))
+    }
+
+    fn account_range(
+        &self,
+        start: H256,
+        limit: usize,
+    ) -> Result<(Vec<(H256, Account)>, Vec<Bytes>)> {
+        Proof::new(self.db)
+            .account_range(start, limit)
+            .map_err(|err| reth_interfaces::Error::Database(err.into()))
     }
 }
 
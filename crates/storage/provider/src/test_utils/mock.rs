@@ -1,19 +1,21 @@
 use crate::{
     traits::{BlockSource, ReceiptProvider},
     AccountProvider, BlockHashProvider, BlockIdProvider, BlockNumProvider, BlockProvider,
-    BlockProviderIdExt, EvmEnvProvider, HeaderProvider, PostState, PostStateDataProvider,
-    StateProvider, StateProviderBox, StateProviderFactory, StateRootProvider, TransactionsProvider,
-    WithdrawalsProvider,
+    BlockProviderIdExt, ChainSpecProvider, EvmEnvProvider, HeaderProvider, PostState,
+    PostStateDataProvider, StateProvider, StateProviderBox, StateProviderFactory,
+    StateRootProvider, TransactionsProvider, WithdrawalsProvider,
 };
 use parking_lot::Mutex;
 use reth_db::models::StoredBlockBodyIndices;
 use reth_interfaces::{provider::ProviderError, Result};
 use reth_primitives::{
-    keccak256, Account, Address, Block, BlockHash, BlockHashOrNumber, BlockId, BlockNumber,
-    BlockWithSenders, Bytecode, Bytes, ChainInfo, Header, Receipt, SealedBlock, SealedHeader,
-    StorageKey, StorageValue, TransactionMeta, TransactionSigned, TxHash, TxNumber, H256, U256,
+    keccak256, Account, Address, Block, BlockBody, BlockHash, BlockHashOrNumber, BlockId,
+    BlockNumHash, BlockNumber, BlockWithSenders, Bytecode, Bytes, ChainInfo, ChainSpec, Header,
+    Receipt, SealedBlock, SealedHeader, StorageKey, StorageValue, TransactionMeta,
+    TransactionSigned, TxHash, TxNumber, H256, MAINNET, U256,
 };
 use reth_revm_primitives::primitives::{BlockEnv, CfgEnv};
+use reth_rlp::Encodable;
 use std::{
     collections::{BTreeMap, HashMap},
     ops::RangeBounds,
@@ -29,6 +31,12 @@ pub struct MockEthProvider {
     pub headers: Arc<Mutex<HashMap<H256, Header>>>,
     /// Local account store
     pub accounts: Arc<Mutex<HashMap<Address, ExtendedAccount>>>,
+    /// Local receipt store, keyed by block hash
+    pub receipts: Arc<Mutex<HashMap<H256, Vec<Receipt>>>>,
+    /// The safe block, as set by a forkchoice update
+    pub safe_block: Arc<Mutex<Option<BlockNumHash>>>,
+    /// The finalized block, as set by a forkchoice update
+    pub finalized_block: Arc<Mutex<Option<BlockNumHash>>>,
 }
 
 /// An extended account for local store
@@ -83,6 +91,16 @@ impl MockEthProvider {
         }
     }
 
+    /// Set the safe block
+    pub fn set_safe_block(&self, num_hash: BlockNumHash) {
+        *self.safe_block.lock() = Some(num_hash);
+    }
+
+    /// Set the finalized block
+    pub fn set_finalized_block(&self, num_hash: BlockNumHash) {
+        *self.finalized_block.lock() = Some(num_hash);
+    }
+
     /// Add header to local header store
     pub fn add_header(&self, hash: H256, header: Header) {
         self.headers.lock().insert(hash, header);
@@ -95,6 +113,11 @@ impl MockEthProvider {
         }
     }
 
+    /// Add the receipts of a block to local receipt store
+    pub fn add_receipts(&self, hash: H256, receipts: Vec<Receipt>) {
+        self.receipts.lock().insert(hash, receipts);
+    }
+
     /// Add account to local account store
     pub fn add_account(&self, address: Address, account: ExtendedAccount) {
         self.accounts.lock().insert(address, account);
@@ -230,8 +253,39 @@ impl ReceiptProvider for MockEthProvider {
         Ok(None)
     }
 
-    fn receipts_by_block(&self, _block: BlockHashOrNumber) -> Result<Option<Vec<Receipt>>> {
-        Ok(None)
+    fn receipts_by_block(&self, block: BlockHashOrNumber) -> Result<Option<Vec<Receipt>>> {
+        let hash = match block {
+            BlockHashOrNumber::Hash(hash) => hash,
+            BlockHashOrNumber::Number(number) => match self.block_hash(number)? {
+                Some(hash) => hash,
+                None => return Ok(None),
+            },
+        };
+        Ok(self.receipts.lock().get(&hash).cloned())
+    }
+
+    fn receipts_by_tx_range(
+        &self,
+        _range: impl RangeBounds<TxNumber>,
+    ) -> Result<Vec<(TxNumber, Receipt)>> {
+        Ok(vec![])
+    }
+
+    fn receipts_by_block_range(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+    ) -> Result<Vec<(BlockNumber, Vec<Receipt>)>> {
+        let receipts = self.receipts.lock();
+
+        // init btreemap so we can return in order
+        let mut map = BTreeMap::new();
+        for (hash, block) in self.blocks.lock().iter() {
+            if range.contains(&block.number) {
+                map.insert(block.number, receipts.get(hash).cloned().unwrap_or_default());
+            }
+        }
+
+        Ok(map.into_iter().collect())
     }
 }
 
@@ -293,11 +347,11 @@ impl BlockIdProvider for MockEthProvider {
     }
 
     fn safe_block_num_hash(&self) -> Result<Option<reth_primitives::BlockNumHash>> {
-        Ok(None)
+        Ok(*self.safe_block.lock())
     }
 
     fn finalized_block_num_hash(&self) -> Result<Option<reth_primitives::BlockNumHash>> {
-        Ok(None)
+        Ok(*self.finalized_block.lock())
     }
 }
 
@@ -329,6 +383,20 @@ impl BlockProvider for MockEthProvider {
     fn block_with_senders(&self, _number: BlockNumber) -> Result<Option<BlockWithSenders>> {
         Ok(None)
     }
+
+    fn block_body_rlp(&self, number: BlockNumber) -> Result<Option<Bytes>> {
+        let lock = self.blocks.lock();
+        Ok(lock.values().find(|b| b.number == number).map(|block| {
+            let body = BlockBody {
+                transactions: block.body.clone(),
+                ommers: block.ommers.clone(),
+                withdrawals: block.withdrawals.clone(),
+            };
+            let mut buf = Vec::new();
+            body.encode(&mut buf);
+            buf.into()
+        }))
+    }
 }
 
 impl BlockProviderIdExt for MockEthProvider {
@@ -395,6 +463,20 @@ impl StateProvider for MockEthProvider {
     ) -> Result<(Vec<Bytes>, H256, Vec<Vec<Bytes>>)> {
         todo!()
     }
+
+    fn account_range(
+        &self,
+        _start: H256,
+        _limit: usize,
+    ) -> Result<(Vec<(H256, Account)>, Vec<Bytes>)> {
+        Ok((vec![], vec![]))
+    }
+}
+
+impl ChainSpecProvider for MockEthProvider {
+    fn chain_spec(&self) -> Arc<ChainSpec> {
+        MAINNET.clone()
+    }
 }
 
 impl EvmEnvProvider for MockEthProvider {
@@ -506,4 +588,12 @@ impl WithdrawalsProvider for MockEthProvider {
     ) -> Result<Option<Vec<reth_primitives::Withdrawal>>> {
         unimplemented!()
     }
+    fn withdrawal_by_index(&self, index: u64) -> Result<Option<reth_primitives::Withdrawal>> {
+        let lock = self.blocks.lock();
+        Ok(lock
+            .values()
+            .flat_map(|block| block.withdrawals.iter().flatten())
+            .find(|withdrawal| withdrawal.index == index)
+            .cloned())
+    }
 }
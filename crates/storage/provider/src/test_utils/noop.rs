@@ -1,20 +1,20 @@
 use crate::{
     traits::{BlockSource, ReceiptProvider},
     AccountProvider, BlockHashProvider, BlockIdProvider, BlockNumProvider, BlockProvider,
-    BlockProviderIdExt, EvmEnvProvider, HeaderProvider, PostState, StageCheckpointProvider,
-    StateProvider, StateProviderBox, StateProviderFactory, StateRootProvider, TransactionsProvider,
-    WithdrawalsProvider,
+    BlockProviderIdExt, ChainSpecProvider, EvmEnvProvider, HeaderProvider, PostState,
+    StageCheckpointProvider, StateProvider, StateProviderBox, StateProviderFactory,
+    StateRootProvider, TransactionsProvider, WithdrawalsProvider,
 };
 use reth_db::models::StoredBlockBodyIndices;
 use reth_interfaces::Result;
 use reth_primitives::{
     stage::{StageCheckpoint, StageId},
     Account, Address, Block, BlockHash, BlockHashOrNumber, BlockId, BlockNumber, Bytecode, Bytes,
-    ChainInfo, Header, Receipt, SealedBlock, SealedHeader, StorageKey, StorageValue,
-    TransactionMeta, TransactionSigned, TxHash, TxNumber, H256, KECCAK_EMPTY, U256,
+    ChainInfo, ChainSpec, Header, Receipt, SealedBlock, SealedHeader, StorageKey, StorageValue,
+    TransactionMeta, TransactionSigned, TxHash, TxNumber, H256, KECCAK_EMPTY, MAINNET, U256,
 };
 use reth_revm_primitives::primitives::{BlockEnv, CfgEnv};
-use std::ops::RangeBounds;
+use std::{ops::RangeBounds, sync::Arc};
 
 /// Supports various api interfaces for testing purposes.
 #[derive(Debug, Clone, Default, Copy)]
@@ -77,6 +77,10 @@ impl BlockProvider for NoopProvider {
     ) -> Result<Option<reth_primitives::BlockWithSenders>> {
         Ok(None)
     }
+
+    fn block_body_rlp(&self, _number: BlockNumber) -> Result<Option<Bytes>> {
+        Ok(None)
+    }
 }
 
 impl BlockProviderIdExt for NoopProvider {
@@ -173,6 +177,20 @@ impl ReceiptProvider for NoopProvider {
     fn receipts_by_block(&self, _block: BlockHashOrNumber) -> Result<Option<Vec<Receipt>>> {
         Ok(None)
     }
+
+    fn receipts_by_tx_range(
+        &self,
+        _range: impl RangeBounds<TxNumber>,
+    ) -> Result<Vec<(TxNumber, Receipt)>> {
+        Ok(vec![])
+    }
+
+    fn receipts_by_block_range(
+        &self,
+        _range: impl RangeBounds<BlockNumber>,
+    ) -> Result<Vec<(BlockNumber, Vec<Receipt>)>> {
+        Ok(vec![])
+    }
 }
 
 impl HeaderProvider for NoopProvider {
@@ -236,6 +254,20 @@ impl StateProvider for NoopProvider {
     ) -> Result<(Vec<Bytes>, H256, Vec<Vec<Bytes>>)> {
         Ok((vec![], KECCAK_EMPTY, vec![]))
     }
+
+    fn account_range(
+        &self,
+        _start: H256,
+        _limit: usize,
+    ) -> Result<(Vec<(H256, Account)>, Vec<Bytes>)> {
+        Ok((vec![], vec![]))
+    }
+}
+
+impl ChainSpecProvider for NoopProvider {
+    fn chain_spec(&self) -> Arc<ChainSpec> {
+        MAINNET.clone()
+    }
 }
 
 impl EvmEnvProvider for NoopProvider {
@@ -324,4 +356,7 @@ impl WithdrawalsProvider for NoopProvider {
     ) -> Result<Option<Vec<reth_primitives::Withdrawal>>> {
         Ok(None)
     }
+    fn withdrawal_by_index(&self, _index: u64) -> Result<Option<reth_primitives::Withdrawal>> {
+        Ok(None)
+    }
 }
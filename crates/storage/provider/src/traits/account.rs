@@ -1,7 +1,10 @@
 use auto_impl::auto_impl;
 use reth_interfaces::Result;
-use reth_primitives::{Account, Address, BlockNumber};
-use std::{collections::BTreeSet, ops::RangeBounds};
+use reth_primitives::{Account, Address, BlockNumber, TransactionSignedEcRecovered, H256, U256};
+use std::{
+    collections::BTreeSet,
+    ops::{RangeBounds, RangeInclusive},
+};
 
 /// Account provider
 #[auto_impl(&, Arc, Box)]
@@ -29,4 +32,55 @@ pub trait AccountExtProvider: Send + Sync {
         &self,
         _iter: impl IntoIterator<Item = Address>,
     ) -> Result<Vec<(Address, Option<Account>)>>;
+
+    /// Get the balance history of an account within the given block range.
+    ///
+    /// Returns the balance of the account after each block in the range that changed the account,
+    /// as recorded by the account history index. Blocks that don't change the account are skipped.
+    fn balance_history(
+        &self,
+        address: Address,
+        range: RangeInclusive<BlockNumber>,
+    ) -> Result<Vec<(BlockNumber, U256)>>;
+
+    /// Get the value history of a storage slot within the given block range.
+    ///
+    /// Returns the value of the slot after each block in the range that changed the slot, as
+    /// recorded by the storage history index. Blocks that don't change the slot are skipped.
+    fn storage_slot_history(
+        &self,
+        address: Address,
+        slot: H256,
+        range: RangeInclusive<BlockNumber>,
+    ) -> Result<Vec<(BlockNumber, U256)>>;
+
+    /// Get the number of transactions sent by the given address up to and including the given
+    /// block.
+    ///
+    /// This is the nonce of the account in the state after the block, which is `0` if the account
+    /// doesn't exist. Note that contract accounts also bump their nonce when creating contracts.
+    fn sender_transaction_count(&self, address: Address, at_block: BlockNumber) -> Result<u64>;
+
+    /// Get the `n` most recent transactions sent by the given address, newest first.
+    ///
+    /// Only the blocks that changed the account, as recorded by the account history index, are
+    /// scanned for transactions of the sender.
+    fn recent_transactions_for_sender(
+        &self,
+        address: Address,
+        n: usize,
+    ) -> Result<Vec<TransactionSignedEcRecovered>>;
+
+    /// Get the transactions within the given block range that call the given address, oldest
+    /// first.
+    ///
+    /// The transactions are matched by their `to`, so this also returns calls that leave the
+    /// recipient's account untouched, e.g. zero-value calls. Contract creations are never returned.
+    ///
+    /// Note: this scans all transactions of the range.
+    fn transactions_to_address(
+        &self,
+        address: Address,
+        range: RangeInclusive<BlockNumber>,
+    ) -> Result<Vec<TransactionSignedEcRecovered>>;
 }
@@ -3,11 +3,13 @@ use crate::{
     WithdrawalsProvider,
 };
 use reth_db::models::StoredBlockBodyIndices;
-use reth_interfaces::Result;
+use reth_interfaces::{provider::ProviderError, Result};
 use reth_primitives::{
-    Block, BlockHashOrNumber, BlockId, BlockNumber, BlockNumberOrTag, BlockWithSenders, Header,
-    SealedBlock, SealedHeader, H256,
+    bloom::Input as BloomInput, Address, Block, BlockHashOrNumber, BlockId, BlockNumber,
+    BlockNumberOrTag, BlockWithSenders, Bloom, Bytes, Header, Log, SealedBlock, SealedHeader,
+    TxHash, TxIndex, H256,
 };
+use std::ops::RangeInclusive;
 
 /// A helper enum that represents the origin of the requested block.
 ///
@@ -100,6 +102,208 @@ pub trait BlockProvider:
     ///
     /// Returns `None` if block is not found.
     fn block_with_senders(&self, number: BlockNumber) -> Result<Option<BlockWithSenders>>;
+
+    /// Returns the RLP encoded body (transactions, ommers and withdrawals) of the block with
+    /// matching number from the database, as it is sent in response to `GetBlockBodies`.
+    ///
+    /// Returns `None` if block is not found.
+    fn block_body_rlp(&self, number: BlockNumber) -> Result<Option<Bytes>>;
+
+    /// Returns the sealed blocks in the given range, in ascending order.
+    ///
+    /// Blocks without a canonical hash are skipped, so fewer blocks are returned if the range
+    /// extends past the tip.
+    fn block_range(&self, range: RangeInclusive<BlockNumber>) -> Result<Vec<SealedBlock>> {
+        let mut blocks = Vec::new();
+        for number in range {
+            let Some(hash) = self.block_hash(number)? else { continue };
+            let block =
+                self.block(number.into())?.ok_or(ProviderError::HeaderNotFound(number.into()))?;
+            blocks.push(block.seal(hash));
+        }
+        Ok(blocks)
+    }
+
+    /// Returns the inclusive range of canonical block numbers whose timestamps fall within
+    /// `[start_ts, end_ts]`.
+    ///
+    /// This performs a binary search over the header timestamps, which are expected to be
+    /// monotonically increasing.
+    ///
+    /// Returns an empty range if no block falls within the given timestamps, for example if the
+    /// range ends before genesis or starts after the tip.
+    fn block_range_by_timestamp(
+        &self,
+        start_ts: u64,
+        end_ts: u64,
+    ) -> Result<RangeInclusive<BlockNumber>> {
+        let tip = self.last_block_number()?;
+
+        // returns the number of blocks with a timestamp strictly lower than `ts`
+        let partition_point = |ts: u64| -> Result<BlockNumber> {
+            let (mut low, mut high) = (0, tip + 1);
+            while low < high {
+                let mid = low + (high - low) / 2;
+                let header =
+                    self.header_by_number(mid)?.ok_or(ProviderError::HeaderNotFound(mid.into()))?;
+                if header.timestamp < ts {
+                    low = mid + 1;
+                } else {
+                    high = mid;
+                }
+            }
+            Ok(low)
+        };
+
+        let first = partition_point(start_ts)?;
+        let end = partition_point(end_ts.saturating_add(1))?;
+        if first >= end {
+            return Ok(RangeInclusive::new(1, 0))
+        }
+        Ok(first..=end - 1)
+    }
+
+    /// Returns the gas used by the transaction with the given hash.
+    ///
+    /// Receipts only store the cumulative gas used within the block, so this is the difference
+    /// to the cumulative gas used of the preceding transaction in the same block.
+    ///
+    /// Returns `None` if the transaction or its receipt is not found.
+    fn gas_used_for_tx(&self, tx_hash: TxHash) -> Result<Option<u64>> {
+        let Some((_, meta)) = self.transaction_by_hash_with_meta(tx_hash)? else { return Ok(None) };
+        let Some(receipts) = self.receipts_by_block(meta.block_hash.into())? else {
+            return Ok(None)
+        };
+
+        let index = meta.index as usize;
+        let Some(receipt) = receipts.get(index) else { return Ok(None) };
+        let preceding_gas_used = index
+            .checked_sub(1)
+            .and_then(|preceding| receipts.get(preceding))
+            .map_or(0, |receipt| receipt.cumulative_gas_used);
+
+        Ok(Some(receipt.cumulative_gas_used - preceding_gas_used))
+    }
+
+    /// Verifies the stored receipts of the block with the given number.
+    ///
+    /// The `cumulative_gas_used` of the receipts must be non-decreasing and the cumulative gas used
+    /// of the last receipt must equal the gas used of the block header.
+    ///
+    /// Returns the index of the first offending receipt, or `None` if the receipts are consistent.
+    /// If only the gas used of the header doesn't match, the index of the last receipt is returned.
+    fn verify_receipts_cumulative_gas(&self, block_number: BlockNumber) -> Result<Option<TxIndex>> {
+        let header = self
+            .header_by_number(block_number)?
+            .ok_or(ProviderError::HeaderNotFound(block_number.into()))?;
+        let receipts = self
+            .receipts_by_block(block_number.into())?
+            .ok_or(ProviderError::BlockBodyIndicesNotFound(block_number))?;
+
+        let mut cumulative_gas_used = 0;
+        for (index, receipt) in receipts.iter().enumerate() {
+            if receipt.cumulative_gas_used < cumulative_gas_used {
+                return Ok(Some(index as TxIndex))
+            }
+            cumulative_gas_used = receipt.cumulative_gas_used;
+        }
+
+        if cumulative_gas_used != header.gas_used {
+            return Ok(Some(receipts.len().saturating_sub(1) as TxIndex))
+        }
+        Ok(None)
+    }
+
+    /// Returns the numbers of the blocks in the given range that have a canonical header but no
+    /// block body indices.
+    ///
+    /// An empty result means the bodies are complete for all canonical headers in the range. Any
+    /// reported block indicates a gap left behind by the bodies stage.
+    fn verify_bodies_present(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> Result<Vec<BlockNumber>> {
+        let mut missing = Vec::new();
+        for number in range {
+            if self.block_hash(number)?.is_some() && self.block_body_indices(number)?.is_none() {
+                missing.push(number);
+            }
+        }
+        Ok(missing)
+    }
+
+    /// Returns all logs emitted by the given address within the given block range, in the order
+    /// they were emitted.
+    ///
+    /// The receipts of a block are only read if the logs bloom of the block contains the address.
+    fn logs_by_address(
+        &self,
+        address: Address,
+        range: RangeInclusive<BlockNumber>,
+    ) -> Result<Vec<Log>> {
+        let address_bloom = Bloom::from(BloomInput::Raw(address.as_bytes()));
+
+        let mut logs = Vec::new();
+        for header in self.headers_range(range)? {
+            if !header.logs_bloom.contains_bloom(&address_bloom) {
+                continue
+            }
+
+            let receipts = self
+                .receipts_by_block(header.number.into())?
+                .ok_or(ProviderError::BlockBodyIndicesNotFound(header.number))?;
+            logs.extend(
+                receipts
+                    .into_iter()
+                    .flat_map(|receipt| receipt.logs)
+                    .filter(|log| log.address == address),
+            );
+        }
+        Ok(logs)
+    }
+
+    /// Returns the number of logs emitted by the given address within the given block range whose
+    /// topics match the given topics, without collecting the logs.
+    ///
+    /// A `None` topic matches any topic at its position, but logs with fewer topics than given
+    /// never match, the same as for `eth_getLogs`. Same as [Self::logs_by_address], the
+    /// receipts of a block are only read if the logs bloom of the block contains the address and
+    /// all given topics.
+    fn log_count(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+        address: Address,
+        topics: &[Option<H256>],
+    ) -> Result<usize> {
+        let mut filter_bloom = Bloom::from(BloomInput::Raw(address.as_bytes()));
+        for topic in topics.iter().flatten() {
+            filter_bloom.accrue(BloomInput::Raw(topic.as_bytes()));
+        }
+        let matches_topics = |log: &Log| {
+            log.topics.len() >= topics.len() &&
+                topics
+                    .iter()
+                    .zip(&log.topics)
+                    .all(|(topic, log_topic)| topic.map_or(true, |topic| topic == *log_topic))
+        };
+
+        let mut count = 0;
+        for header in self.headers_range(range)? {
+            if !header.logs_bloom.contains_bloom(&filter_bloom) {
+                continue
+            }
+
+            let receipts = self
+                .receipts_by_block(header.number.into())?
+                .ok_or(ProviderError::BlockBodyIndicesNotFound(header.number))?;
+            count += receipts
+                .iter()
+                .flat_map(|receipt| &receipt.logs)
+                .filter(|log| log.address == address && matches_topics(log))
+                .count();
+        }
+        Ok(count)
+    }
 }
 
 /// Trait extension for `BlockProvider`, for types that implement `BlockId` conversion.
@@ -195,3 +399,31 @@ pub trait BlockProviderIdExt: BlockProvider + BlockIdProvider {
     /// Returns `None` if block is not found.
     fn ommers_by_id(&self, id: BlockId) -> Result<Option<Vec<Header>>>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockEthProvider;
+
+    #[test]
+    fn block_range_by_timestamp() {
+        let provider = MockEthProvider::default();
+        // blocks 0..=9 with timestamps 100, 110, ..., 190
+        for number in 0..10 {
+            let header = Header { number, timestamp: 100 + number * 10, ..Default::default() };
+            provider.add_header(H256::from_low_u64_be(number), header);
+        }
+
+        assert_eq!(provider.block_range_by_timestamp(120, 150).unwrap(), 2..=5);
+        assert_eq!(provider.block_range_by_timestamp(115, 155).unwrap(), 2..=5);
+        assert_eq!(provider.block_range_by_timestamp(190, 190).unwrap(), 9..=9);
+        assert_eq!(provider.block_range_by_timestamp(0, u64::MAX).unwrap(), 0..=9);
+
+        // before genesis
+        assert!(provider.block_range_by_timestamp(0, 99).unwrap().is_empty());
+        // after tip
+        assert!(provider.block_range_by_timestamp(191, 300).unwrap().is_empty());
+        // in between two blocks
+        assert!(provider.block_range_by_timestamp(111, 119).unwrap().is_empty());
+    }
+}
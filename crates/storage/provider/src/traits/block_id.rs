@@ -1,6 +1,7 @@
 use super::BlockHashProvider;
 use reth_interfaces::Result;
 use reth_primitives::{BlockHashOrNumber, BlockId, BlockNumber, BlockNumberOrTag, ChainInfo, H256};
+use std::ops::RangeInclusive;
 
 /// Client trait for getting important block numbers (such as the latest block number), converting
 /// block hashes to numbers, and fetching a block hash from its block number.
@@ -37,6 +38,26 @@ pub trait BlockNumProvider: BlockHashProvider + Send + Sync {
             BlockHashOrNumber::Number(num) => self.block_hash(num),
         }
     }
+
+    /// Returns the ranges of block numbers that are missing from the canonical chain, between the
+    /// earliest canonical block and the tip.
+    ///
+    /// An empty result means the canonical chain is contiguous. Any gap indicates an incomplete or
+    /// corrupt sync.
+    fn find_canonical_gaps(&self) -> Result<Vec<RangeInclusive<BlockNumber>>> {
+        let mut gaps = Vec::new();
+        let mut previous: Option<BlockNumber> = None;
+        for number in 0..=self.last_block_number()? {
+            if self.block_hash(number)?.is_none() {
+                continue
+            }
+            if let Some(previous) = previous.filter(|previous| number > previous + 1) {
+                gaps.push(previous + 1..=number - 1);
+            }
+            previous = Some(number);
+        }
+        Ok(gaps)
+    }
 }
 
 /// Client trait for transforming [BlockId] into block numbers or hashes.
@@ -1,7 +1,9 @@
 use auto_impl::auto_impl;
-use reth_interfaces::Result;
-use reth_primitives::{BlockHash, BlockHashOrNumber, BlockNumber, Header, SealedHeader, U256};
-use std::ops::RangeBounds;
+use reth_interfaces::{provider::ProviderError, Result};
+use reth_primitives::{
+    BlockHash, BlockHashOrNumber, BlockNumber, Header, SealedHeader, H256, U256,
+};
+use std::ops::{RangeBounds, RangeInclusive};
 
 /// Client trait for fetching `Header` related data.
 #[auto_impl(&, Arc)]
@@ -25,15 +27,82 @@ pub trait HeaderProvider: Send + Sync {
         }
     }
 
+    /// Get the state root of the block by block number or hash
+    fn state_root_at(&self, block: BlockHashOrNumber) -> Result<Option<H256>> {
+        Ok(self.header_by_hash_or_number(block)?.map(|header| header.state_root))
+    }
+
     /// Get total difficulty by block hash.
     fn header_td(&self, hash: &BlockHash) -> Result<Option<U256>>;
 
     /// Get total difficulty by block number.
     fn header_td_by_number(&self, number: BlockNumber) -> Result<Option<U256>>;
 
+    /// Get the total difficulty added by the blocks in the inclusive range of block numbers.
+    ///
+    /// This is the total difficulty at the end of the range minus the total difficulty before its
+    /// start, which equals the summed difficulties of all blocks in the range.
+    ///
+    /// Returns an error if the total difficulty of a boundary block is not found.
+    fn total_difficulty_delta(&self, range: RangeInclusive<BlockNumber>) -> Result<U256> {
+        if range.is_empty() {
+            return Ok(U256::ZERO)
+        }
+        let td_at = |number| -> Result<U256> {
+            self.header_td_by_number(number)?
+                .ok_or_else(|| ProviderError::TotalDifficultyNotFound { number }.into())
+        };
+
+        let end_td = td_at(*range.end())?;
+        // the genesis block has no parent, so its difficulty counts in full
+        let start_td = match range.start().checked_sub(1) {
+            Some(parent) => td_at(parent)?,
+            None => U256::ZERO,
+        };
+        Ok(end_td - start_td)
+    }
+
     /// Get headers in range of block numbers
     fn headers_range(&self, range: impl RangeBounds<BlockNumber>) -> Result<Vec<Header>>;
 
+    /// Get up to `limit` headers starting at the given block, with `skip` blocks left out between
+    /// consecutive headers, as requested by `GetBlockHeaders`.
+    ///
+    /// The headers are in descending order if `reverse` is set, and in ascending order otherwise.
+    /// Fewer headers are returned if the genesis block is reached or a header is not found, e.g.
+    /// past the tip. Returns no headers if the start block is not found.
+    fn headers_from(
+        &self,
+        start: BlockHashOrNumber,
+        limit: usize,
+        skip: u64,
+        reverse: bool,
+    ) -> Result<Vec<Header>> {
+        let mut headers = Vec::new();
+        if limit == 0 {
+            return Ok(headers)
+        }
+        let Some(mut header) = self.header_by_hash_or_number(start)? else { return Ok(headers) };
+
+        let step = skip.saturating_add(1);
+        loop {
+            let next = if reverse {
+                header.number.checked_sub(step)
+            } else {
+                header.number.checked_add(step)
+            };
+            headers.push(header);
+            if headers.len() >= limit {
+                break
+            }
+
+            let Some(next) = next else { break };
+            let Some(next) = self.header_by_number(next)? else { break };
+            header = next;
+        }
+        Ok(headers)
+    }
+
     /// Get headers in range of block numbers
     fn sealed_headers_range(
         &self,
@@ -42,4 +111,38 @@ pub trait HeaderProvider: Send + Sync {
 
     /// Get a single sealed header by block number
     fn sealed_header(&self, number: BlockNumber) -> Result<Option<SealedHeader>>;
+
+    /// Get the total amount of fees burned (EIP-1559) in range of block numbers.
+    ///
+    /// This is the sum of `base_fee_per_gas * gas_used` of all headers in the range. Pre-London
+    /// headers do not have a base fee and therefore do not contribute.
+    fn burned_fees(&self, range: impl RangeBounds<BlockNumber>) -> Result<U256> {
+        Ok(self
+            .headers_range(range)?
+            .iter()
+            .fold(U256::ZERO, |burned, header| burned + header.burned_fees()))
+    }
+
+    /// Get the total gas used by all blocks from genesis up to and including the given block.
+    ///
+    /// Returns an error if the header of the given block is not found.
+    fn cumulative_gas_used_at(&self, block_number: BlockNumber) -> Result<u64> {
+        let headers = self.headers_range(..=block_number)?;
+        if headers.last().map(|header| header.number) != Some(block_number) {
+            return Err(ProviderError::HeaderNotFound(block_number.into()).into())
+        }
+        Ok(headers.iter().map(|header| header.gas_used).sum())
+    }
+
+    /// Get the gas limits of all headers in range of block numbers, in ascending order.
+    ///
+    /// The gas limits of successive blocks can be checked with
+    /// [validate_gas_limit](reth_primitives::gas_limit::validate_gas_limit).
+    fn gas_limits(&self, range: impl RangeBounds<BlockNumber>) -> Result<Vec<(BlockNumber, u64)>> {
+        Ok(self
+            .headers_range(range)?
+            .into_iter()
+            .map(|header| (header.number, header.gas_limit))
+            .collect())
+    }
 }
@@ -1,5 +1,6 @@
 use reth_interfaces::Result;
-use reth_primitives::{BlockHashOrNumber, BlockId, Receipt, TxHash, TxNumber};
+use reth_primitives::{BlockHashOrNumber, BlockId, BlockNumber, Receipt, TxHash, TxNumber};
+use std::ops::RangeBounds;
 
 use crate::BlockIdProvider;
 
@@ -14,6 +15,20 @@ pub trait ReceiptProvider: Send + Sync {
 
     /// Get receipts by block num or hash.
     fn receipts_by_block(&self, block: BlockHashOrNumber) -> Result<Option<Vec<Receipt>>>;
+
+    /// Get receipts with their transaction numbers by transaction number range.
+    fn receipts_by_tx_range(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+    ) -> Result<Vec<(TxNumber, Receipt)>>;
+
+    /// Get the receipts of every block in the range, in ascending block order.
+    ///
+    /// Blocks without transactions are included with an empty list of receipts.
+    fn receipts_by_block_range(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+    ) -> Result<Vec<(BlockNumber, Vec<Receipt>)>>;
 }
 
 /// Trait extension for `ReceiptProvider`, for types that implement `BlockId` conversion.
@@ -0,0 +1,9 @@
+use reth_primitives::ChainSpec;
+use std::sync::Arc;
+
+/// A provider of the [ChainSpec] of the chain it serves.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait ChainSpecProvider: Send + Sync {
+    /// Returns the [ChainSpec].
+    fn chain_spec(&self) -> Arc<ChainSpec>;
+}
@@ -1,9 +1,28 @@
 use reth_interfaces::Result;
-use reth_primitives::stage::{StageCheckpoint, StageId};
+use reth_primitives::{
+    stage::{StageCheckpoint, StageId},
+    BlockNumber,
+};
+use std::ops::RangeInclusive;
 
 /// The trait for fetching stage checkpoint related data.
 #[auto_impl::auto_impl(&, Arc)]
 pub trait StageCheckpointProvider: Send + Sync {
     /// Fetch the checkpoint for the given stage.
     fn get_stage_checkpoint(&self, id: StageId) -> Result<Option<StageCheckpoint>>;
+
+    /// Returns the range of blocks that have been executed and whose execution results are
+    /// available.
+    ///
+    /// The range ends at the [StageId::Execution] checkpoint and starts at the first block whose
+    /// receipts were not pruned. The range is empty if the receipts of all executed blocks were
+    /// pruned. The default implementation assumes that nothing was pruned, so its range always
+    /// starts at genesis.
+    ///
+    /// Returns `None` if the execution stage has no checkpoint yet.
+    fn execution_block_range(&self) -> Result<Option<RangeInclusive<BlockNumber>>> {
+        Ok(self
+            .get_stage_checkpoint(StageId::Execution)?
+            .map(|checkpoint| 0..=checkpoint.block_number))
+    }
 }
@@ -3,8 +3,8 @@ use crate::{post_state::PostState, BlockHashProvider, BlockIdProvider};
 use auto_impl::auto_impl;
 use reth_interfaces::{provider::ProviderError, Result};
 use reth_primitives::{
-    Address, BlockHash, BlockId, BlockNumHash, BlockNumber, BlockNumberOrTag, Bytecode, Bytes,
-    StorageKey, StorageValue, H256, KECCAK_EMPTY, U256,
+    Account, Address, BlockHash, BlockId, BlockNumHash, BlockNumber, BlockNumberOrTag, Bytecode,
+    Bytes, StorageKey, StorageValue, H256, KECCAK_EMPTY, U256,
 };
 
 /// Type alias of boxed [StateProvider].
@@ -25,6 +25,14 @@ pub trait StateProvider:
     fn proof(&self, address: Address, keys: &[H256])
         -> Result<(Vec<Bytes>, H256, Vec<Vec<Bytes>>)>;
 
+    /// Get up to `limit` consecutive hashed accounts starting at the `start` hash, and the proof
+    /// nodes of the boundaries of the range.
+    fn account_range(
+        &self,
+        start: H256,
+        limit: usize,
+    ) -> Result<(Vec<(H256, Account)>, Vec<Bytes>)>;
+
     /// Get account code by its address.
     ///
     /// Returns `None` if the account doesn't exist or account is not a contract
@@ -13,4 +13,9 @@ pub trait WithdrawalsProvider: Send + Sync {
 
     /// Get latest withdrawal from this block or earlier .
     fn latest_withdrawal(&self) -> Result<Option<Withdrawal>>;
+
+    /// Get the withdrawal with the given global index.
+    ///
+    /// Returns `None` if no stored block contains a withdrawal with this index.
+    fn withdrawal_by_index(&self, index: u64) -> Result<Option<Withdrawal>>;
 }
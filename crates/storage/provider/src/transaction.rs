@@ -1,5 +1,5 @@
 use reth_interfaces::{db::DatabaseError as DbError, provider::ProviderError};
-use reth_primitives::{BlockHash, BlockNumber, H256};
+use reth_primitives::{BlockHash, BlockNumber, TxNumber, H256};
 use reth_trie::StateRootError;
 use std::fmt::Debug;
 
@@ -39,10 +39,31 @@ pub enum TransactionError {
         /// Block hash
         block_hash: BlockHash,
     },
+    /// A stage checkpoint is ahead of the block the database was unwound to
+    #[error(
+        "Stage {stage} checkpoint #{checkpoint} is ahead of the unwind target #{block_number}"
+    )]
+    UnwindStageCheckpointAhead {
+        /// The stage id
+        stage: String,
+        /// The checkpoint block number of the stage
+        checkpoint: BlockNumber,
+        /// Target block number
+        block_number: BlockNumber,
+    },
+    /// Transaction data is left above the block the database was unwound to
+    #[error("Transaction {tx_number} is left after unwinding to #{block_number}")]
+    UnwindDanglingTransaction {
+        /// The transaction number
+        tx_number: TxNumber,
+        /// Target block number
+        block_number: BlockNumber,
+    },
 }
 
 #[cfg(test)]
 mod test {
+    use super::TransactionError;
     use crate::{
         insert_canonical_block, test_utils::blocks::*, ProviderFactory, TransactionsProvider,
     };
@@ -50,8 +71,12 @@ mod test {
         mdbx::test_utils::create_test_rw_db,
         models::{storage_sharded_key::StorageShardedKey, ShardedKey},
         tables,
+        transaction::DbTxMut,
+    };
+    use reth_primitives::{
+        stage::{StageCheckpoint, StageId},
+        ChainSpecBuilder, IntegerList, H160, MAINNET, U256,
     };
-    use reth_primitives::{ChainSpecBuilder, IntegerList, H160, MAINNET, U256};
     use std::sync::Arc;
 
     #[test]
@@ -234,4 +259,83 @@ mod test {
         // assert genesis state
         assert_genesis_block(&provider, genesis);
     }
+
+    #[test]
+    fn check_unwind_consistency() {
+        let db = create_test_rw_db();
+        let chain_spec = ChainSpecBuilder::default()
+            .chain(MAINNET.chain)
+            .genesis(MAINNET.genesis.clone())
+            .shanghai_activated()
+            .build();
+
+        let factory = ProviderFactory::new(db.as_ref(), Arc::new(chain_spec.clone()));
+        let provider = factory.provider_rw().unwrap();
+
+        let data = BlockChainTestData::default();
+        let (block1, exec_res1) = data.blocks[0].clone();
+        let (block2, exec_res2) = data.blocks[1].clone();
+
+        insert_canonical_block(provider.tx_ref(), data.genesis, None).unwrap();
+        provider.append_blocks_with_post_state(vec![block1], exec_res1).unwrap();
+        provider.append_blocks_with_post_state(vec![block2], exec_res2).unwrap();
+        provider.save_stage_checkpoint(StageId::Execution, StageCheckpoint::new(2)).unwrap();
+
+        // a complete unwind leaves nothing behind
+        provider.take_block_and_execution_range(&chain_spec, 2..=2).unwrap();
+        assert_eq!(provider.check_unwind_consistency(1), Ok(()));
+
+        // a stage that was not unwound
+        provider.save_stage_checkpoint(StageId::Execution, StageCheckpoint::new(2)).unwrap();
+        assert_eq!(
+            provider.check_unwind_consistency(1),
+            Err(TransactionError::UnwindStageCheckpointAhead {
+                stage: StageId::Execution.to_string(),
+                checkpoint: 2,
+                block_number: 1,
+            })
+        );
+        provider.save_stage_checkpoint(StageId::Execution, StageCheckpoint::new(1)).unwrap();
+
+        // a transaction of the unwound block that was left behind
+        provider.tx_ref().put::<tables::TransactionBlock>(1, 2).unwrap();
+        assert_eq!(
+            provider.check_unwind_consistency(1),
+            Err(TransactionError::UnwindDanglingTransaction { tx_number: 1, block_number: 1 })
+        );
+    }
+
+    #[test]
+    fn take_blocks_with_pruned_receipts() {
+        let db = create_test_rw_db();
+        let chain_spec = ChainSpecBuilder::default()
+            .chain(MAINNET.chain)
+            .genesis(MAINNET.genesis.clone())
+            .shanghai_activated()
+            .build();
+
+        let factory = ProviderFactory::new(db.as_ref(), Arc::new(chain_spec.clone()));
+        let provider = factory.provider_rw().unwrap();
+
+        let data = BlockChainTestData::default();
+        let genesis = data.genesis.clone();
+        let (block1, exec_res1) = data.blocks[0].clone();
+        let (block2, exec_res2) = data.blocks[1].clone();
+
+        insert_canonical_block(provider.tx_ref(), data.genesis, None).unwrap();
+        provider.append_blocks_with_post_state(vec![block1.clone()], exec_res1).unwrap();
+        provider.append_blocks_with_post_state(vec![block2.clone()], exec_res2.clone()).unwrap();
+
+        // prune the receipt of the only transaction of block 1
+        provider.tx_ref().delete::<tables::Receipts>(0, None).unwrap();
+
+        // the receipt of block 2 isn't assigned to the transaction of block 1
+        let take = provider.take_block_and_execution_range(&chain_spec, 1..=2).unwrap();
+        assert_eq!(take[0].0, block1);
+        assert!(take[0].1.receipts(1).is_empty());
+        assert_eq!(take[1], (block2, exec_res2));
+
+        assert_eq!(provider.table::<tables::Receipts>().unwrap(), vec![]);
+        assert_genesis_block(&provider, genesis);
+    }
 }
@@ -0,0 +1,183 @@
+//! Support for persisting the transactions of the pool across restarts.
+//!
+//! On shutdown, the pending and queued transactions are written to disk with [save_transactions].
+//! On startup, [load_transactions] adds them to the pool again, which validates every transaction
+//! against the current state.
+
+use crate::{error::PoolError, TransactionOrigin, TransactionPool};
+use reth_primitives::{FromRecoveredTransaction, IntoRecoveredTransaction, TransactionSigned};
+use reth_rlp::{Decodable, DecodeError, Encodable, RlpDecodable, RlpEncodable};
+use std::{io, path::Path};
+use tracing::{debug, trace};
+
+/// Errors that can occur while saving or restoring the transactions of the pool.
+#[derive(Debug, thiserror::Error)]
+pub enum TransactionsBackupError {
+    /// Failed to read or write the backup file.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// The backup file is not a valid RLP encoded backup.
+    #[error("failed to decode transactions backup: {0}")]
+    Decode(#[from] DecodeError),
+    /// Failed to add the restored transactions to the pool.
+    #[error(transparent)]
+    Pool(#[from] PoolError),
+}
+
+/// The transactions of the pool, grouped by their origin.
+#[derive(Debug, Default, RlpEncodable, RlpDecodable)]
+struct TransactionsBackup {
+    local: Vec<TransactionSigned>,
+    external: Vec<TransactionSigned>,
+}
+
+/// Writes all pending and queued transactions of the pool to the file at the given path.
+///
+/// Returns the number of saved transactions.
+pub fn save_transactions<P>(pool: &P, path: &Path) -> Result<usize, TransactionsBackupError>
+where
+    P: TransactionPool,
+{
+    let all = pool.all_transactions();
+
+    let mut backup = TransactionsBackup::default();
+    for tx in all.pending.iter().chain(all.queued.iter()) {
+        let signed = tx.to_recovered_transaction().into_signed();
+        if tx.is_local() {
+            backup.local.push(signed);
+        } else {
+            backup.external.push(signed);
+        }
+    }
+    let num_transactions = backup.local.len() + backup.external.len();
+
+    let mut buf = Vec::with_capacity(backup.length());
+    backup.encode(&mut buf);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, buf)?;
+
+    trace!(target: "txpool", ?path, num_transactions, "saved transactions");
+    Ok(num_transactions)
+}
+
+/// Adds the transactions saved by [save_transactions] to the pool and removes the file.
+///
+/// Every transaction goes through validation again. Transactions that became invalid in the
+/// meantime, e.g. because their nonce is now too low, are dropped.
+///
+/// The file is only removed after the transactions were imported, so it's kept if the import
+/// fails.
+///
+/// Returns the number of transactions that were added to the pool, or `0` if there's no file at
+/// the given path.
+pub async fn load_transactions<P>(pool: &P, path: &Path) -> Result<usize, TransactionsBackupError>
+where
+    P: TransactionPool,
+{
+    let buf = match std::fs::read(path) {
+        Ok(buf) => buf,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(err) => return Err(err.into()),
+    };
+    let backup = TransactionsBackup::decode(&mut buf.as_slice())?;
+
+    let mut num_added = 0;
+    for (origin, transactions) in
+        [(TransactionOrigin::Local, backup.local), (TransactionOrigin::External, backup.external)]
+    {
+        let transactions = transactions
+            .into_iter()
+            .filter_map(TransactionSigned::into_ecrecovered)
+            .map(P::Transaction::from_recovered_transaction)
+            .collect::<Vec<_>>();
+        if transactions.is_empty() {
+            continue
+        }
+        for result in pool.add_transactions(origin, transactions).await? {
+            match result {
+                Ok(_) => num_added += 1,
+                Err(err) => debug!(target: "txpool", %err, "dropped restored transaction"),
+            }
+        }
+    }
+    std::fs::remove_file(path)?;
+
+    trace!(target: "txpool", ?path, num_added, "restored transactions");
+    Ok(num_added)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EthTransactionValidator, Pool, PooledTransaction};
+    use reth_primitives::{
+        sign_message, Transaction, TransactionKind, TxLegacy, H256, MAINNET, U256,
+    };
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+
+    fn signed_transaction(secret: H256, nonce: u64) -> TransactionSigned {
+        let transaction = Transaction::Legacy(TxLegacy {
+            chain_id: Some(1),
+            nonce,
+            gas_price: 1_000_000_000,
+            gas_limit: 21_000,
+            to: TransactionKind::Call(Default::default()),
+            value: 1,
+            input: Default::default(),
+        });
+        let signature = sign_message(secret, transaction.signature_hash()).unwrap();
+        TransactionSigned::from_transaction_and_signature(transaction, signature)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn save_and_load_transactions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("txpool").join("transactions.rlp");
+
+        let secret = H256::from_low_u64_be(1);
+        let transactions =
+            [0, 1, 2, 4].map(|nonce| signed_transaction(secret, nonce).into_ecrecovered().unwrap());
+        let sender = transactions[0].signer();
+
+        let provider = MockEthProvider::default();
+        provider.add_account(sender, ExtendedAccount::new(0, U256::MAX));
+        let pool = Pool::eth_pool(
+            EthTransactionValidator::new(provider.clone(), MAINNET.clone()),
+            Default::default(),
+        );
+        let results = pool
+            .add_transactions(
+                TransactionOrigin::External,
+                transactions
+                    .iter()
+                    .cloned()
+                    .map(PooledTransaction::from_recovered_transaction)
+                    .collect(),
+            )
+            .await
+            .unwrap();
+        assert!(results.iter().all(Result::is_ok));
+
+        assert_eq!(save_transactions(&pool, &path).unwrap(), 4);
+
+        // the first two transactions were mined in the meantime
+        provider.add_account(sender, ExtendedAccount::new(2, U256::MAX));
+        let pool = Pool::eth_pool(
+            EthTransactionValidator::new(provider, MAINNET.clone()),
+            Default::default(),
+        );
+        assert_eq!(load_transactions(&pool, &path).await.unwrap(), 2);
+        assert!(!path.exists());
+
+        let all = pool.all_transactions();
+        let mut nonces =
+            all.pending.iter().chain(all.queued.iter()).map(|tx| tx.nonce()).collect::<Vec<_>>();
+        nonces.sort_unstable();
+        assert_eq!(nonces, vec![2, 4]);
+
+        // nothing to restore
+        assert_eq!(load_transactions(&pool, &path).await.unwrap(), 0);
+    }
+}
@@ -1,6 +1,9 @@
 /// Guarantees max transactions for one sender, compatible with geth/erigon
 pub(crate) const MAX_ACCOUNT_SLOTS_PER_SENDER: usize = 16;
 
+/// The default maximum combined size (in bytes) of all transactions in the pool: 64MB
+pub(crate) const TXPOOL_MAX_POOL_BYTES_DEFAULT: usize = 64 * 1024 * 1024;
+
 /// Configuration options for the Transaction pool.
 #[derive(Debug, Clone)]
 pub struct PoolConfig {
@@ -12,6 +15,63 @@ pub struct PoolConfig {
     pub queued_limit: SubPoolLimit,
     /// Max number of executable transaction slots guaranteed per account
     pub max_account_slots: usize,
+    /// Max combined size (in bytes) of all transactions across all sub-pools.
+    ///
+    /// New transactions that would exceed this limit are rejected, unless they replace an
+    /// existing transaction.
+    pub max_pool_bytes: usize,
+    /// Whether legacy transactions without a chain id, which aren't replay protected (pre
+    /// EIP-155), are accepted by the
+    /// [EthTransactionValidator](crate::EthTransactionValidator) of the pool.
+    ///
+    /// These are rejected by default.
+    pub allow_unprotected_txs: bool,
+}
+
+// === impl PoolConfig ===
+
+impl PoolConfig {
+    /// Max number of transactions in the pending sub-pool.
+    #[inline]
+    pub fn max_pending_count(&self) -> usize {
+        self.pending_limit.max_txs
+    }
+
+    /// Max number of transactions in the queued sub-pool.
+    #[inline]
+    pub fn max_queued_count(&self) -> usize {
+        self.queued_limit.max_txs
+    }
+
+    /// Sets the max number of transactions in the pending sub-pool.
+    pub fn with_max_pending_count(mut self, max_txs: usize) -> Self {
+        self.pending_limit.max_txs = max_txs;
+        self
+    }
+
+    /// Sets the max number of transactions in the queued sub-pool.
+    pub fn with_max_queued_count(mut self, max_txs: usize) -> Self {
+        self.queued_limit.max_txs = max_txs;
+        self
+    }
+
+    /// Sets the max combined size (in bytes) of all transactions in the pool.
+    pub fn with_max_pool_bytes(mut self, max_pool_bytes: usize) -> Self {
+        self.max_pool_bytes = max_pool_bytes;
+        self
+    }
+
+    /// Sets whether legacy transactions without a chain id (pre EIP-155) are accepted.
+    pub fn with_unprotected_txs(mut self, allow_unprotected_txs: bool) -> Self {
+        self.allow_unprotected_txs = allow_unprotected_txs;
+        self
+    }
+
+    /// Returns whether the combined size of all transactions exceeds [PoolConfig::max_pool_bytes].
+    #[inline]
+    pub fn is_pool_bytes_exceeded(&self, size: usize) -> bool {
+        self.max_pool_bytes < size
+    }
 }
 
 impl Default for PoolConfig {
@@ -21,6 +81,8 @@ impl Default for PoolConfig {
             basefee_limit: Default::default(),
             queued_limit: Default::default(),
             max_account_slots: MAX_ACCOUNT_SLOTS_PER_SENDER,
+            max_pool_bytes: TXPOOL_MAX_POOL_BYTES_DEFAULT,
+            allow_unprotected_txs: false,
         }
     }
 }
@@ -24,6 +24,10 @@ pub enum PoolError {
     /// respect the size limits of the pool.
     #[error("[{0:?}] Transaction discarded outright due to pool size constraints.")]
     DiscardedOnInsert(TxHash),
+    /// Thrown when a new transaction is rejected because the pool is full and the transaction
+    /// does not replace an existing one.
+    #[error("[{0:?}] Transaction rejected, pool limit reached.")]
+    PoolLimitReached(TxHash),
     /// Thrown when the transaction is considered invalid.
     #[error("[{0:?}] {1:?}")]
     InvalidTransaction(TxHash, InvalidPoolTransactionError),
@@ -44,6 +48,7 @@ impl PoolError {
             PoolError::FeeCapBelowMinimumProtocolFeeCap(hash, _) => hash,
             PoolError::SpammerExceededCapacity(_, hash) => hash,
             PoolError::DiscardedOnInsert(hash) => hash,
+            PoolError::PoolLimitReached(hash) => hash,
             PoolError::InvalidTransaction(hash, _) => hash,
             PoolError::Other(hash, _) => hash,
         }
@@ -90,6 +95,10 @@ impl PoolError {
                 // valid tx but dropped due to size constraints
                 false
             }
+            PoolError::PoolLimitReached(_) => {
+                // valid tx but the pool is currently full
+                false
+            }
             PoolError::InvalidTransaction(_, err) => {
                 // transaction rejected because it violates constraints
                 err.is_bad_transaction()
@@ -126,6 +135,10 @@ pub enum InvalidPoolTransactionError {
     /// Thrown if the transaction's fee is below the minimum fee
     #[error("transaction underpriced")]
     Underpriced,
+    /// Thrown if the transaction has no chain id (pre EIP-155), but only replay protected
+    /// transactions are accepted.
+    #[error("only replay-protected (EIP-155) transactions allowed")]
+    Unprotected,
 }
 
 // === impl InvalidPoolTransactionError ===
@@ -178,6 +191,10 @@ impl InvalidPoolTransactionError {
                 // local setting
                 false
             }
+            InvalidPoolTransactionError::Unprotected => {
+                // local setting
+                false
+            }
         }
     }
 }
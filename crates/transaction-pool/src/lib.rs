@@ -106,6 +106,7 @@ use std::{collections::HashMap, sync::Arc};
 use tokio::sync::mpsc::Receiver;
 use tracing::{instrument, trace};
 
+pub mod backup;
 mod config;
 pub mod error;
 mod identifier;
@@ -221,10 +222,14 @@ where
 {
     /// Returns a new [Pool] that uses the default [EthTransactionValidator] when validating
     /// [PooledTransaction]s and ords via [CostOrdering]
+    ///
+    /// The validator is configured to accept unprotected transactions according to
+    /// [PoolConfig::allow_unprotected_txs].
     pub fn eth_pool(
         validator: EthTransactionValidator<Client, PooledTransaction>,
         config: PoolConfig,
     ) -> Self {
+        let validator = validator.with_unprotected_txs(config.allow_unprotected_txs);
         Self::new(validator, CostOrdering::default(), config)
     }
 }
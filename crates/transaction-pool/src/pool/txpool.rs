@@ -300,6 +300,13 @@ impl<T: TransactionOrdering> TxPool<T> {
             return Err(PoolError::AlreadyImported(*tx.hash()))
         }
 
+        // Reject the transaction if the pool is full, unless it replaces an existing transaction
+        if !self.all_transactions.txs.contains_key(tx.id()) &&
+            self.config.is_pool_bytes_exceeded(self.size().total_size() + tx.size())
+        {
+            return Err(PoolError::PoolLimitReached(*tx.hash()))
+        }
+
         // Update sender info with balance and nonce
         self.sender_info
             .entry(tx.sender_id())
@@ -530,19 +537,24 @@ impl<T: TransactionOrdering> TxPool<T> {
     pub(crate) fn discard_worst(&mut self) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
         let mut removed = Vec::new();
 
-        // Helper macro that discards the worst transactions for the pools
+        // Helper macro that discards the worst transactions for the pools, while the pool exceeds
+        // its limit or the combined size of all transactions exceeds the limit of the pool
         macro_rules! discard_worst {
             ($this:ident, $removed:ident,  [$($limit:ident => $pool:ident),*]  ) => {
                 $ (
                 while $this
                         .config
                         .$limit
-                        .is_exceeded($this.$pool.len(), $this.$pool.size())
+                        .is_exceeded($this.$pool.len(), $this.$pool.size()) ||
+                    $this.config.is_pool_bytes_exceeded($this.size().total_size())
                     {
                         if let Some(tx) = $this.$pool.pop_worst() {
                             let id = tx.transaction_id;
+                            $this.all_transactions.remove_transaction(&id);
                             removed.push(tx);
                             $this.remove_descendants(&id, &mut $removed);
+                        } else {
+                            break
                         }
                     }
 
@@ -550,11 +562,13 @@ impl<T: TransactionOrdering> TxPool<T> {
             };
         }
 
+        // the lowest priority pools come first, so they're drained first if the combined size
+        // exceeds the limit
         discard_worst!(
             self, removed, [
-                pending_limit  => pending_pool,
+                queued_limit  => queued_pool,
                 basefee_limit  => basefee_pool,
-                queued_limit  => queued_pool
+                pending_limit  => pending_pool
             ]
         );
 
@@ -1592,4 +1606,102 @@ mod tests {
 
         assert_eq!(pool.all_transactions.txs.get(&id).unwrap().subpool, SubPool::BaseFee)
     }
+
+    #[test]
+    fn evicts_worst_pending_over_count_limit() {
+        let mut f = MockTransactionFactory::default();
+        let config = PoolConfig::default().with_max_pending_count(2);
+        let mut pool = TxPool::new(MockOrdering::default(), config);
+
+        let tx = MockTransaction::eip1559().inc_price().inc_limit();
+        pool.add_transaction(f.validated(tx), U256::MAX, 0).unwrap();
+        for i in 1..=2 {
+            let better = MockTransaction::eip1559().inc_price_by(i * 10).inc_limit();
+            pool.add_transaction(f.validated(better), U256::MAX, 0).unwrap();
+        }
+        assert_eq!(pool.pending_pool.len(), 3);
+
+        let removed = pool.discard_worst();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(pool.pending_pool.len(), 2);
+        assert_eq!(pool.len(), 2);
+        assert!(!pool.contains(removed[0].hash()));
+    }
+
+    #[test]
+    fn evicts_worst_queued_over_count_limit() {
+        let mut f = MockTransactionFactory::default();
+        let config = PoolConfig::default().with_max_queued_count(1);
+        let mut pool = TxPool::new(MockOrdering::default(), config);
+
+        // nonce gaps park both transactions in the queued pool
+        let worst = f.validated(MockTransaction::eip1559().inc_nonce().inc_price().inc_limit());
+        let worst_hash = *worst.hash();
+        pool.add_transaction(worst, U256::MAX, 0).unwrap();
+        let better =
+            f.validated(MockTransaction::eip1559().inc_nonce().inc_price_by(10).inc_limit());
+        let better_hash = *better.hash();
+        pool.add_transaction(better, U256::MAX, 0).unwrap();
+        assert_eq!(pool.queued_pool.len(), 2);
+
+        let removed = pool.discard_worst();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(*removed[0].hash(), worst_hash);
+        assert_eq!(pool.queued_pool.len(), 1);
+        assert!(pool.contains(&better_hash));
+        assert!(!pool.contains(&worst_hash));
+    }
+
+    #[test]
+    fn rejects_new_tx_over_bytes_limit() {
+        let mut f = MockTransactionFactory::default();
+        let tx_size = f.validated(MockTransaction::eip1559()).size();
+        let config = PoolConfig::default().with_max_pool_bytes(2 * tx_size);
+        let mut pool = TxPool::new(MockOrdering::default(), config);
+
+        let tx = MockTransaction::eip1559().inc_price().inc_limit();
+        pool.add_transaction(f.validated(tx.clone()), U256::MAX, 0).unwrap();
+        pool.add_transaction(f.validated(MockTransaction::eip1559()), U256::MAX, 0).unwrap();
+        assert_eq!(pool.size().total_size(), 2 * tx_size);
+
+        let rejected = f.validated(MockTransaction::eip1559());
+        let rejected_hash = *rejected.hash();
+        match pool.add_transaction(rejected, U256::MAX, 0).unwrap_err() {
+            PoolError::PoolLimitReached(hash) => assert_eq!(hash, rejected_hash),
+            err => unreachable!("unexpected error: {err:?}"),
+        }
+        assert_eq!(pool.len(), 2);
+
+        // replacing an existing transaction is still possible
+        let replacement = f.validated(tx.rng_hash().inc_price());
+        let replacement_hash = *replacement.hash();
+        pool.add_transaction(replacement, U256::MAX, 0).unwrap();
+        assert!(pool.contains(&replacement_hash));
+        assert_eq!(pool.len(), 2);
+        assert!(pool.discard_worst().is_empty());
+    }
+
+    #[test]
+    fn evicts_queued_first_over_bytes_limit() {
+        let mut f = MockTransactionFactory::default();
+        let mut pool = TxPool::new(MockOrdering::default(), Default::default());
+
+        let pending = f.validated(MockTransaction::eip1559());
+        let pending_hash = *pending.hash();
+        let tx_size = pending.size();
+        pool.add_transaction(pending, U256::MAX, 0).unwrap();
+        // a nonce gap parks the transaction in the queued pool
+        let queued = f.validated(MockTransaction::eip1559().inc_nonce());
+        let queued_hash = *queued.hash();
+        pool.add_transaction(queued, U256::MAX, 0).unwrap();
+        assert_eq!(pool.pending_pool.len(), 1);
+        assert_eq!(pool.queued_pool.len(), 1);
+
+        pool.config.max_pool_bytes = tx_size;
+        let removed = pool.discard_worst();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(*removed[0].hash(), queued_hash);
+        assert!(pool.contains(&pending_hash));
+        assert_eq!(pool.size().total_size(), tx_size);
+    }
 }
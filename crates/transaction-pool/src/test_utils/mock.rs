@@ -361,7 +361,7 @@ impl PoolTransaction for MockTransaction {
     }
 
     fn size(&self) -> usize {
-        0
+        std::mem::size_of::<Self>()
     }
 
     fn tx_type(&self) -> u8 {
@@ -423,7 +423,7 @@ impl FromRecoveredTransaction for MockTransaction {
                 to,
                 value: U256::from(value),
             },
-            Transaction::Eip2930 { .. } => {
+            Transaction::Eip2930 { .. } | Transaction::Eip4844 { .. } => {
                 unimplemented!()
             }
         }
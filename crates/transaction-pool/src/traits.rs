@@ -498,6 +498,7 @@ impl PoolTransaction for PooledTransaction {
             Transaction::Legacy(tx) => tx.gas_price,
             Transaction::Eip2930(tx) => tx.gas_price,
             Transaction::Eip1559(tx) => tx.max_fee_per_gas,
+            Transaction::Eip4844(tx) => tx.max_fee_per_gas,
         }
     }
 
@@ -509,6 +510,7 @@ impl PoolTransaction for PooledTransaction {
             Transaction::Legacy(_) => None,
             Transaction::Eip2930(_) => None,
             Transaction::Eip1559(tx) => Some(tx.max_priority_fee_per_gas),
+            Transaction::Eip4844(tx) => Some(tx.max_priority_fee_per_gas),
         }
     }
 
@@ -558,6 +560,13 @@ impl FromRecoveredTransaction for PooledTransaction {
                 let effective_gas_price = t.max_priority_fee_per_gas;
                 (cost, effective_gas_price)
             }
+            Transaction::Eip4844(t) => {
+                let cost = U256::from(t.max_fee_per_gas) * U256::from(t.gas_limit) +
+                    U256::from(t.max_fee_per_blob_gas) * U256::from(t.blob_gas()) +
+                    U256::from(t.value);
+                let effective_gas_price = t.max_priority_fee_per_gas;
+                (cost, effective_gas_price)
+            }
         };
 
         PooledTransaction { transaction: tx, cost, effective_gas_price }
@@ -587,6 +596,20 @@ pub struct PoolSize {
     pub queued_size: usize,
 }
 
+// === impl PoolSize ===
+
+impl PoolSize {
+    /// Total number of transactions across all sub-pools.
+    pub fn total(&self) -> usize {
+        self.pending + self.basefee + self.queued
+    }
+
+    /// Combined reported size of transactions across all sub-pools.
+    pub fn total_size(&self) -> usize {
+        self.pending_size + self.basefee_size + self.queued_size
+    }
+}
+
 /// Represents the current status of the pool.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct BlockInfo {
@@ -121,6 +121,8 @@ pub struct EthTransactionValidator<Client, T> {
     block_gas_limit: u64,
     /// Minimum priority fee to enforce for acceptance into the pool.
     minimum_priority_fee: Option<u128>,
+    /// Whether legacy transactions without a chain id (pre EIP-155) are accepted.
+    allow_unprotected_txs: bool,
     /// Marker for the transaction type
     _marker: PhantomData<T>,
 }
@@ -140,6 +142,7 @@ impl<Client, Tx> EthTransactionValidator<Client, Tx> {
             eip1559: true,
             block_gas_limit: 30_000_000,
             minimum_priority_fee: None,
+            allow_unprotected_txs: false,
             _marker: Default::default(),
         }
     }
@@ -148,6 +151,16 @@ impl<Client, Tx> EthTransactionValidator<Client, Tx> {
     pub fn chain_id(&self) -> u64 {
         self.chain_spec.chain().id()
     }
+
+    /// Configures whether legacy transactions without a chain id, which aren't replay protected
+    /// (pre EIP-155), are accepted.
+    ///
+    /// These are rejected by default. [Pool::eth_pool](crate::Pool::eth_pool) configures this from
+    /// [PoolConfig::allow_unprotected_txs](crate::PoolConfig::allow_unprotected_txs).
+    pub fn with_unprotected_txs(mut self, allow_unprotected_txs: bool) -> Self {
+        self.allow_unprotected_txs = allow_unprotected_txs;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -242,12 +255,22 @@ where
         }
 
         // Checks for chainid
-        if let Some(chain_id) = transaction.chain_id() {
-            if chain_id != self.chain_id() {
-                return TransactionValidationOutcome::Invalid(
-                    transaction,
-                    InvalidTransactionError::ChainIdMismatch.into(),
-                )
+        match transaction.chain_id() {
+            Some(chain_id) => {
+                if chain_id != self.chain_id() {
+                    return TransactionValidationOutcome::Invalid(
+                        transaction,
+                        InvalidTransactionError::ChainIdMismatch.into(),
+                    )
+                }
+            }
+            None => {
+                if !self.allow_unprotected_txs {
+                    return TransactionValidationOutcome::Invalid(
+                        transaction,
+                        InvalidPoolTransactionError::Unprotected,
+                    )
+                }
             }
         }
 
@@ -414,3 +437,100 @@ impl<T: PoolTransaction> fmt::Debug for ValidPoolTransaction<T> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::PoolError, Pool, PoolConfig, PooledTransaction, TransactionPool};
+    use reth_primitives::{
+        sign_message, FromRecoveredTransaction, Transaction, TransactionSigned, TxLegacy, H256,
+        MAINNET,
+    };
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+
+    /// Returns a legacy transfer with the given chain id, sent by an account that can pay for it.
+    fn transaction(provider: &MockEthProvider, chain_id: Option<u64>) -> PooledTransaction {
+        let transaction = Transaction::Legacy(TxLegacy {
+            chain_id,
+            nonce: 0,
+            gas_price: 1_000_000_000,
+            gas_limit: 21_000,
+            to: TransactionKind::Call(Default::default()),
+            value: 1,
+            input: Default::default(),
+        });
+        let signature =
+            sign_message(H256::from_low_u64_be(1), transaction.signature_hash()).unwrap();
+        let transaction = TransactionSigned::from_transaction_and_signature(transaction, signature)
+            .into_ecrecovered()
+            .unwrap();
+        provider.add_account(transaction.signer(), ExtendedAccount::new(0, U256::MAX));
+        PooledTransaction::from_recovered_transaction(transaction)
+    }
+
+    #[tokio::test]
+    async fn validate_chain_id() {
+        let provider = MockEthProvider::default();
+        let validator = EthTransactionValidator::new(provider.clone(), MAINNET.clone());
+
+        let outcome = validator
+            .validate_transaction(TransactionOrigin::External, transaction(&provider, Some(1)))
+            .await;
+        assert!(matches!(outcome, TransactionValidationOutcome::Valid { .. }));
+
+        let outcome = validator
+            .validate_transaction(TransactionOrigin::External, transaction(&provider, Some(5)))
+            .await;
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::Consensus(InvalidTransactionError::ChainIdMismatch)
+            )
+        ));
+    }
+
+    #[tokio::test]
+    async fn validate_unprotected_transaction() {
+        let provider = MockEthProvider::default();
+
+        // rejected by default
+        let validator = EthTransactionValidator::new(provider.clone(), MAINNET.clone());
+        let outcome = validator
+            .validate_transaction(TransactionOrigin::External, transaction(&provider, None))
+            .await;
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(_, InvalidPoolTransactionError::Unprotected)
+        ));
+
+        let validator = validator.with_unprotected_txs(true);
+        let outcome = validator
+            .validate_transaction(TransactionOrigin::External, transaction(&provider, None))
+            .await;
+        assert!(matches!(outcome, TransactionValidationOutcome::Valid { .. }));
+    }
+
+    #[tokio::test]
+    async fn pool_config_unprotected_transactions() {
+        let provider = MockEthProvider::default();
+
+        // rejected by default
+        let pool = Pool::eth_pool(
+            EthTransactionValidator::new(provider.clone(), MAINNET.clone()),
+            Default::default(),
+        );
+        let result = pool.add_external_transaction(transaction(&provider, None)).await;
+        assert!(matches!(
+            result,
+            Err(PoolError::InvalidTransaction(_, InvalidPoolTransactionError::Unprotected))
+        ));
+
+        // the validator is configured from the pool config
+        let pool = Pool::eth_pool(
+            EthTransactionValidator::new(provider.clone(), MAINNET.clone()),
+            PoolConfig::default().with_unprotected_txs(true),
+        );
+        assert!(pool.add_external_transaction(transaction(&provider, None)).await.is_ok());
+    }
+}
@@ -20,6 +20,26 @@ impl From<StateRootError> for reth_db::DatabaseError {
     }
 }
 
+/// Proof error.
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum ProofError {
+    /// Internal database error.
+    #[error(transparent)]
+    DB(#[from] reth_db::DatabaseError),
+    /// Storage root error.
+    #[error(transparent)]
+    StorageRootError(#[from] StorageRootError),
+}
+
+impl From<ProofError> for reth_db::DatabaseError {
+    fn from(err: ProofError) -> Self {
+        match err {
+            ProofError::DB(err) => err,
+            ProofError::StorageRootError(StorageRootError::DB(err)) => err,
+        }
+    }
+}
+
 /// Storage root error.
 #[derive(Error, PartialEq, Eq, Clone, Debug)]
 pub enum StorageRootError {
@@ -0,0 +1,528 @@
+use crate::{
+    account::EthAccount,
+    hashed_cursor::{HashedAccountCursor, HashedCursorFactory, HashedStorageCursor},
+    prefix_set::PrefixSet,
+    trie_cursor::{AccountTrieCursor, StorageTrieCursor},
+    walker::TrieWalker,
+    ProofError, StorageRoot, StorageRootError,
+};
+use reth_db::{tables, transaction::DbTx};
+use reth_primitives::{
+    keccak256,
+    proofs::EMPTY_ROOT,
+    trie::{hash_builder::ProofRetainer, HashBuilder, Nibbles},
+    Account, Address, Bytes, StorageEntry, H256,
+};
+use reth_rlp::Encodable;
+use std::collections::BTreeMap;
+
+/// Proof is used to generate the Merkle proofs of an account and its storage slots, as served by
+/// `eth_getProof`.
+///
+/// The proofs are generated from the hashed state and the intermediate trie nodes, the same way
+/// [StateRoot](crate::StateRoot) computes the root, while retaining the nodes along the paths of
+/// the target keys.
+pub struct Proof<'a, TX> {
+    /// A reference to the database transaction.
+    pub tx: &'a TX,
+}
+
+impl<'a, 'tx, TX> Proof<'a, TX>
+where
+    TX: DbTx<'tx> + HashedCursorFactory<'a>,
+{
+    /// Create a new [Proof] instance.
+    pub fn new(tx: &'a TX) -> Self {
+        Self { tx }
+    }
+
+    /// Generate the proof of the account and the given storage slots.
+    ///
+    /// # Returns
+    ///
+    /// The account proof nodes ordered from the root, the storage root of the account and the
+    /// proof nodes of every storage slot, in the order of the given slots.
+    pub fn account_proof(
+        &self,
+        address: Address,
+        slots: &[H256],
+    ) -> Result<(Vec<Bytes>, H256, Vec<Vec<Bytes>>), ProofError> {
+        let target_hashed_address = keccak256(address);
+        let target_nibbles = Nibbles::unpack(target_hashed_address);
+
+        let mut hashed_account_cursor = self.tx.hashed_account_cursor()?;
+        let mut trie_cursor =
+            AccountTrieCursor::new(self.tx.cursor_read::<tables::AccountsTrie>()?);
+
+        // Only the path to the target account is walked, the rest of the trie is taken from the
+        // intermediate nodes.
+        let mut prefix_set = PrefixSet::default();
+        prefix_set.insert(target_nibbles.clone());
+        let mut walker = TrieWalker::new(&mut trie_cursor, prefix_set);
+
+        let retainer = ProofRetainer::new(vec![target_nibbles]);
+        let mut hash_builder = HashBuilder::default().with_proof_retainer(retainer);
+
+        let mut storage_root = EMPTY_ROOT;
+        let mut storage_proofs = vec![Vec::new(); slots.len()];
+        let mut account_rlp = Vec::with_capacity(128);
+        while let Some(key) = walker.key() {
+            if walker.can_skip_current_node {
+                hash_builder.add_branch(key, walker.hash().unwrap(), walker.children_are_in_trie());
+            }
+
+            let seek_key = match walker.next_unprocessed_key() {
+                Some(key) => key,
+                None => break, // no more keys
+            };
+
+            let next_key = walker.advance()?;
+            let mut account_entry = hashed_account_cursor.seek(seek_key)?;
+            while let Some((hashed_address, account)) = account_entry {
+                let account_nibbles = Nibbles::unpack(hashed_address);
+                if let Some(ref key) = next_key {
+                    if key < &account_nibbles {
+                        break
+                    }
+                }
+
+                let account_storage_root = if hashed_address == target_hashed_address {
+                    (storage_root, storage_proofs) =
+                        self.storage_root_with_proofs(hashed_address, slots)?;
+                    storage_root
+                } else {
+                    StorageRoot::new_hashed(self.tx, hashed_address).root()?
+                };
+
+                let account = EthAccount::from(account).with_storage_root(account_storage_root);
+
+                account_rlp.clear();
+                account.encode(&mut &mut account_rlp);
+
+                hash_builder.add_leaf(account_nibbles, &account_rlp);
+                account_entry = hashed_account_cursor.next()?;
+            }
+        }
+
+        let _ = hash_builder.root();
+        let account_proof = hash_builder.take_proofs().into_values().collect();
+
+        Ok((account_proof, storage_root, storage_proofs))
+    }
+
+    /// Generate a range proof of the hashed accounts, as served for `GetAccountRange` requests.
+    ///
+    /// Returns up to `limit` consecutive accounts, starting at the first hashed address that is
+    /// greater than or equal to `start`, and the proof nodes of the boundaries of the range: the
+    /// paths to `start` and to the last returned account. Together with the state root, the
+    /// proof nodes prove that no accounts were omitted from the range.
+    ///
+    /// # Returns
+    ///
+    /// The accounts keyed by hashed address and the deduplicated proof nodes of both boundaries,
+    /// ordered by their path.
+    pub fn account_range(
+        &self,
+        start: H256,
+        limit: usize,
+    ) -> Result<(Vec<(H256, Account)>, Vec<Bytes>), ProofError> {
+        let mut hashed_account_cursor = self.tx.hashed_account_cursor()?;
+
+        let mut accounts = Vec::with_capacity(limit.min(1024));
+        let mut account_entry = hashed_account_cursor.seek(start)?;
+        while let Some(entry) = account_entry {
+            if accounts.len() >= limit {
+                break
+            }
+            accounts.push(entry);
+            account_entry = hashed_account_cursor.next()?;
+        }
+
+        let mut targets = vec![Nibbles::unpack(start)];
+        if let Some((last, _)) = accounts.last() {
+            targets.push(Nibbles::unpack(last));
+        }
+
+        let mut trie_cursor =
+            AccountTrieCursor::new(self.tx.cursor_read::<tables::AccountsTrie>()?);
+
+        // Only the paths to the boundaries are walked, the rest of the trie is taken from the
+        // intermediate nodes.
+        let mut prefix_set = PrefixSet::default();
+        for target in &targets {
+            prefix_set.insert(target.clone());
+        }
+        let mut walker = TrieWalker::new(&mut trie_cursor, prefix_set);
+
+        let retainer = ProofRetainer::new(targets);
+        let mut hash_builder = HashBuilder::default().with_proof_retainer(retainer);
+
+        let mut account_rlp = Vec::with_capacity(128);
+        while let Some(key) = walker.key() {
+            if walker.can_skip_current_node {
+                hash_builder.add_branch(key, walker.hash().unwrap(), walker.children_are_in_trie());
+            }
+
+            let seek_key = match walker.next_unprocessed_key() {
+                Some(key) => key,
+                None => break, // no more keys
+            };
+
+            let next_key = walker.advance()?;
+            let mut account_entry = hashed_account_cursor.seek(seek_key)?;
+            while let Some((hashed_address, account)) = account_entry {
+                let account_nibbles = Nibbles::unpack(hashed_address);
+                if let Some(ref key) = next_key {
+                    if key < &account_nibbles {
+                        break
+                    }
+                }
+
+                let storage_root = StorageRoot::new_hashed(self.tx, hashed_address).root()?;
+                let account = EthAccount::from(account).with_storage_root(storage_root);
+
+                account_rlp.clear();
+                account.encode(&mut &mut account_rlp);
+
+                hash_builder.add_leaf(account_nibbles, &account_rlp);
+                account_entry = hashed_account_cursor.next()?;
+            }
+        }
+
+        let _ = hash_builder.root();
+        let proof = hash_builder.take_proofs().into_values().collect();
+
+        Ok((accounts, proof))
+    }
+
+    /// Computes the storage root of the account while retaining the proofs of the given slots.
+    fn storage_root_with_proofs(
+        &self,
+        hashed_address: H256,
+        slots: &[H256],
+    ) -> Result<(H256, Vec<Vec<Bytes>>), StorageRootError> {
+        let mut hashed_storage_cursor = self.tx.hashed_storage_cursor()?;
+
+        // short circuit on empty storage
+        if hashed_storage_cursor.is_storage_empty(hashed_address)? {
+            return Ok((EMPTY_ROOT, vec![Vec::new(); slots.len()]))
+        }
+
+        let mut trie_cursor = StorageTrieCursor::new(
+            self.tx.cursor_dup_read::<tables::StoragesTrie>()?,
+            hashed_address,
+        );
+
+        let target_nibbles =
+            slots.iter().map(|slot| Nibbles::unpack(keccak256(slot))).collect::<Vec<_>>();
+        let mut prefix_set = PrefixSet::default();
+        for target in &target_nibbles {
+            prefix_set.insert(target.clone());
+        }
+        let mut walker = TrieWalker::new(&mut trie_cursor, prefix_set);
+
+        let retainer = ProofRetainer::new(target_nibbles.clone());
+        let mut hash_builder = HashBuilder::default().with_proof_retainer(retainer);
+
+        while let Some(key) = walker.key() {
+            if walker.can_skip_current_node {
+                hash_builder.add_branch(key, walker.hash().unwrap(), walker.children_are_in_trie());
+            }
+
+            let seek_key = match walker.next_unprocessed_key() {
+                Some(key) => key,
+                None => break, // no more keys
+            };
+
+            let next_key = walker.advance()?;
+            let mut storage = hashed_storage_cursor.seek(hashed_address, seek_key)?;
+            while let Some(StorageEntry { key: hashed_key, value }) = storage {
+                let storage_key_nibbles = Nibbles::unpack(hashed_key);
+                if let Some(ref key) = next_key {
+                    if key < &storage_key_nibbles {
+                        break
+                    }
+                }
+                hash_builder
+                    .add_leaf(storage_key_nibbles, reth_rlp::encode_fixed_size(&value).as_ref());
+                storage = hashed_storage_cursor.next()?;
+            }
+        }
+
+        let root = hash_builder.root();
+        let proofs = hash_builder.take_proofs();
+        let storage_proofs =
+            target_nibbles.iter().map(|target| proof_for_target(&proofs, target)).collect();
+
+        Ok((root, storage_proofs))
+    }
+}
+
+/// Returns the retained nodes on the path to the target, ordered from the root.
+fn proof_for_target(proofs: &BTreeMap<Nibbles, Bytes>, target: &Nibbles) -> Vec<Bytes> {
+    proofs
+        .iter()
+        .filter(|(path, _)| target.has_prefix(path))
+        .map(|(_, node)| node.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StateRoot;
+    use reth_db::{mdbx::test_utils::create_test_rw_db, transaction::DbTxMut};
+    use reth_primitives::{MAINNET, U256};
+    use reth_provider::ProviderFactory;
+    use reth_rlp::Header;
+    use std::collections::HashMap;
+
+    /// Asserts that the proof starts at the root and that every node is referenced by its parent.
+    fn assert_proof_links(root: H256, proof: &[Bytes]) {
+        assert_eq!(keccak256(&proof[0]), root);
+        for window in proof.windows(2) {
+            let (parent, child) = (&window[0], &window[1]);
+            let reference =
+                if child.len() < 32 { child.to_vec() } else { keccak256(child).to_vec() };
+            assert!(parent.windows(reference.len()).any(|w| w == reference.as_slice()));
+        }
+    }
+
+    #[test]
+    fn account_and_storage_proofs() {
+        let db = create_test_rw_db();
+        let factory = ProviderFactory::new(db.as_ref(), MAINNET.clone());
+        let provider = factory.provider_rw().unwrap();
+        let tx = provider.tx_ref();
+
+        let target = Address::random();
+        let slots = (0..10u64).map(H256::from_low_u64_be).collect::<Vec<_>>();
+        let account = Account { nonce: 1, balance: U256::from(10), bytecode_hash: None };
+        for address in (0..20).map(|_| Address::random()).chain([target]) {
+            tx.put::<tables::HashedAccount>(keccak256(address), account).unwrap();
+        }
+        for (i, slot) in slots.iter().enumerate() {
+            let entry = StorageEntry { key: keccak256(slot), value: U256::from(i + 1) };
+            tx.put::<tables::HashedStorage>(keccak256(target), entry).unwrap();
+        }
+
+        let state_root = StateRoot::new(tx).root().unwrap();
+        let storage_root = StorageRoot::new(tx, target).root().unwrap();
+
+        // the last slot doesn't exist, its proof shows the path to where it would be
+        let mut proven_slots = slots[..3].to_vec();
+        proven_slots.push(H256::from_low_u64_be(100));
+        let (account_proof, proof_storage_root, storage_proofs) =
+            Proof::new(tx).account_proof(target, &proven_slots).unwrap();
+
+        assert_eq!(proof_storage_root, storage_root);
+        assert_proof_links(state_root, &account_proof);
+        assert_eq!(storage_proofs.len(), proven_slots.len());
+        for proof in &storage_proofs {
+            assert_proof_links(storage_root, proof);
+        }
+
+        // the account leaf commits to the storage root
+        let leaf = account_proof.last().unwrap();
+        assert!(leaf.windows(32).any(|w| w == storage_root.as_bytes()));
+
+        // an account without storage has empty storage proofs
+        let empty = Address::random();
+        tx.put::<tables::HashedAccount>(keccak256(empty), account).unwrap();
+        let (_, storage_root, storage_proofs) =
+            Proof::new(tx).account_proof(empty, &slots[..2]).unwrap();
+        assert_eq!(storage_root, EMPTY_ROOT);
+        assert_eq!(storage_proofs, vec![Vec::<Bytes>::new(); 2]);
+    }
+
+    /// Returns the RLP of the leaf value of an account without storage.
+    fn account_leaf_rlp(account: Account) -> Vec<u8> {
+        let mut rlp = Vec::new();
+        EthAccount::from(account).with_storage_root(EMPTY_ROOT).encode(&mut rlp);
+        rlp
+    }
+
+    /// Returns the payloads of the items of an RLP encoded trie node.
+    fn node_items(mut node: &[u8]) -> Vec<&[u8]> {
+        let header = Header::decode(&mut node).unwrap();
+        assert!(header.list);
+        let mut items = Vec::new();
+        while !node.is_empty() {
+            let item = Header::decode(&mut node).unwrap();
+            items.push(&node[..item.payload_length]);
+            node = &node[item.payload_length..];
+        }
+        items
+    }
+
+    /// Decodes the hex-prefix encoded path of a leaf or extension node, returns the path and
+    /// whether the node is a leaf.
+    fn decode_path(encoded: &[u8]) -> (Nibbles, bool) {
+        let is_leaf = encoded[0] & 0x20 != 0;
+        // odd paths start in the flag byte, even paths are padded with a zero nibble
+        let skip = if encoded[0] & 0x10 != 0 { 1 } else { 2 };
+        (Nibbles::unpack(encoded).slice_from(skip), is_leaf)
+    }
+
+    /// The position of a subtree relative to the proven range.
+    enum Position {
+        Before,
+        After,
+        Inside,
+        Boundary,
+    }
+
+    /// Returns the position of the subtree at the given path relative to the range `left..=right`,
+    /// an empty range if `right` is `None`.
+    fn position(path: &Nibbles, left: &Nibbles, right: Option<&Nibbles>) -> Position {
+        let len = path.len();
+        if path < &left.slice(0, len) {
+            Position::Before
+        } else if right.map_or(false, |right| path > &right.slice(0, len)) {
+            Position::After
+        } else if left.has_prefix(path) || right.map_or(false, |right| right.has_prefix(path)) {
+            Position::Boundary
+        } else {
+            Position::Inside
+        }
+    }
+
+    /// Walks the proof nodes along the boundaries of the range and collects the paths and hashes
+    /// of the subtrees before and after the range, in trie order.
+    ///
+    /// Returns `None` if a node on a boundary is missing from the proof.
+    fn collect_outside(
+        nodes: &HashMap<H256, Bytes>,
+        hash: H256,
+        path: Nibbles,
+        left: &Nibbles,
+        right: Option<&Nibbles>,
+        outside: &mut Vec<(Nibbles, H256)>,
+    ) -> Option<()> {
+        let items = node_items(nodes.get(&hash)?);
+        let children = if items.len() == 17 {
+            (0..16u8)
+                .filter(|nibble| !items[*nibble as usize].is_empty())
+                .map(|nibble| (path.join(&Nibbles::from_hex(vec![nibble])), items[nibble as usize]))
+                .collect::<Vec<_>>()
+        } else {
+            let (key, is_leaf) = decode_path(items[0]);
+            let key = path.join(&key);
+            if is_leaf {
+                // leaves inside of the range are covered by the accounts
+                if &key < left || right.map_or(false, |right| &key > right) {
+                    outside.push((path, hash));
+                }
+                return Some(())
+            }
+            // the child of an extension node is rebuilt with the extension by the hash builder
+            vec![(key, items[1])]
+        };
+
+        for (child_path, child) in children {
+            // account trie nodes are always referenced by their hash
+            let child = H256::from_slice(child);
+            match position(&child_path, left, right) {
+                Position::Before | Position::After => outside.push((child_path, child)),
+                Position::Boundary => {
+                    collect_outside(nodes, child, child_path, left, right, outside)?
+                }
+                Position::Inside => {}
+            }
+        }
+        Some(())
+    }
+
+    /// Verifies a range proof like a `GetAccountRange` client: the subtrees before and after the
+    /// range are taken from the boundary proofs, the rest of the trie is rebuilt from the accounts
+    /// of the range, and the resulting root has to be the state root.
+    fn verify_account_range(
+        root: H256,
+        start: H256,
+        accounts: &[(H256, Account)],
+        proof: &[Bytes],
+    ) -> bool {
+        let sorted = accounts.windows(2).all(|window| window[0].0 < window[1].0);
+        if !sorted || accounts.first().map_or(false, |(first, _)| *first < start) {
+            return false
+        }
+
+        let nodes = proof.iter().map(|node| (keccak256(node), node.clone())).collect();
+        let left = Nibbles::unpack(start);
+        let right = accounts.last().map(|(last, _)| Nibbles::unpack(last));
+        let mut outside = Vec::new();
+        if collect_outside(&nodes, root, Nibbles::default(), &left, right.as_ref(), &mut outside)
+            .is_none()
+        {
+            return false
+        }
+
+        let (before, after): (Vec<_>, Vec<_>) =
+            outside.into_iter().partition(|(path, _)| path < &left);
+        let mut hash_builder = HashBuilder::default();
+        for (path, hash) in before {
+            hash_builder.add_branch(path, hash, false);
+        }
+        for (hashed_address, account) in accounts {
+            hash_builder.add_leaf(Nibbles::unpack(hashed_address), &account_leaf_rlp(*account));
+        }
+        for (path, hash) in after {
+            hash_builder.add_branch(path, hash, false);
+        }
+        hash_builder.root() == root
+    }
+
+    #[test]
+    fn account_range_proofs() {
+        let db = create_test_rw_db();
+        let factory = ProviderFactory::new(db.as_ref(), MAINNET.clone());
+        let provider = factory.provider_rw().unwrap();
+        let tx = provider.tx_ref();
+
+        let mut accounts = (0..200u64)
+            .map(|i| {
+                let account = Account { nonce: i, balance: U256::from(i), bytecode_hash: None };
+                (keccak256(Address::random()), account)
+            })
+            .collect::<Vec<_>>();
+        accounts.sort_by_key(|(hashed_address, _)| *hashed_address);
+        for (hashed_address, account) in &accounts {
+            tx.put::<tables::HashedAccount>(*hashed_address, *account).unwrap();
+        }
+
+        // store the intermediate nodes, so that the proofs are generated from the trie tables
+        let (state_root, updates) = StateRoot::new(tx).root_with_updates().unwrap();
+        updates.flush(tx).unwrap();
+
+        // a range in the middle of the trie
+        let start = accounts[10].0;
+        let (range, proof) = Proof::new(tx).account_range(start, 15).unwrap();
+        assert_eq!(range, accounts[10..25]);
+        assert!(verify_account_range(state_root, start, &range, &proof));
+
+        // the proof fails if accounts of the range are withheld
+        let mut incomplete = range.clone();
+        incomplete.remove(7);
+        assert!(!verify_account_range(state_root, start, &incomplete, &proof));
+        assert!(!verify_account_range(state_root, start, &[], &proof));
+
+        // the range starts at the first account after a missing start hash
+        let start = H256(
+            (U256::from_be_bytes(accounts[20].0.to_fixed_bytes()) + U256::from(1)).to_be_bytes(),
+        );
+        let (range, proof) = Proof::new(tx).account_range(start, 5).unwrap();
+        assert_eq!(range, accounts[21..26]);
+        assert!(verify_account_range(state_root, start, &range, &proof));
+
+        // the full range
+        let (range, proof) = Proof::new(tx).account_range(H256::zero(), usize::MAX).unwrap();
+        assert_eq!(range, accounts);
+        assert!(verify_account_range(state_root, H256::zero(), &range, &proof));
+
+        // a start after the last account proves that there are no more accounts
+        let start = H256::repeat_byte(0xff);
+        let (range, proof) = Proof::new(tx).account_range(start, 10).unwrap();
+        assert!(range.is_empty());
+        assert!(verify_account_range(state_root, start, &range, &proof));
+    }
+}
@@ -8,15 +8,18 @@ use crate::{
     walker::TrieWalker,
     StateRootError, StorageRootError,
 };
-use reth_db::{tables, transaction::DbTx};
+use reth_db::{cursor::DbCursorRO, tables, transaction::DbTx};
 use reth_primitives::{
     keccak256,
     proofs::EMPTY_ROOT,
     trie::{HashBuilder, Nibbles},
-    Address, BlockNumber, StorageEntry, H256,
+    Address, BlockNumber, StorageEntry, H256, U256,
 };
 use reth_rlp::Encodable;
-use std::{collections::HashMap, ops::RangeInclusive};
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::RangeInclusive,
+};
 
 /// StateRoot is used to compute the root node of a state trie.
 pub struct StateRoot<'a, 'b, TX, H> {
@@ -157,6 +160,54 @@ where
         tracing::debug!(target: "loader", "incremental state root with progress");
         Self::incremental_root_calculator(tx, range)?.root_with_progress()
     }
+
+    /// Computes the state root by hashing the plain state entries on the fly, without reading the
+    /// hashed state or the existing trie nodes.
+    ///
+    /// The whole hashed state is collected in memory first, so this is only suitable for small
+    /// states, e.g. to obtain a one-shot root before the hashed tables are populated.
+    ///
+    /// # Returns
+    ///
+    /// The state root hash.
+    pub fn plain_state_root(tx: &'a TX) -> Result<H256, StateRootError> {
+        tracing::debug!(target: "loader", "calculating state root from plain state");
+
+        let mut storages = BTreeMap::<H256, BTreeMap<H256, U256>>::new();
+        for entry in tx.cursor_dup_read::<tables::PlainStorageState>()?.walk(None)? {
+            let (address, StorageEntry { key, value }) = entry?;
+            if value != U256::ZERO {
+                storages.entry(keccak256(address)).or_default().insert(keccak256(key), value);
+            }
+        }
+
+        let mut accounts = BTreeMap::new();
+        for entry in tx.cursor_read::<tables::PlainAccountState>()?.walk(None)? {
+            let (address, account) = entry?;
+            accounts.insert(keccak256(address), account);
+        }
+
+        let mut hash_builder = HashBuilder::default();
+        let mut account_rlp = Vec::with_capacity(128);
+        for (hashed_address, account) in accounts {
+            let mut storage_hash_builder = HashBuilder::default();
+            for (hashed_slot, value) in storages.remove(&hashed_address).unwrap_or_default() {
+                storage_hash_builder.add_leaf(
+                    Nibbles::unpack(hashed_slot),
+                    reth_rlp::encode_fixed_size(&value).as_ref(),
+                );
+            }
+
+            let account = EthAccount::from(account).with_storage_root(storage_hash_builder.root());
+
+            account_rlp.clear();
+            account.encode(&mut &mut account_rlp);
+
+            hash_builder.add_leaf(Nibbles::unpack(hashed_address), &account_rlp);
+        }
+
+        Ok(hash_builder.root())
+    }
 }
 
 impl<'a, 'b, 'tx, TX, H> StateRoot<'a, 'b, TX, H>
@@ -799,6 +850,68 @@ mod tests {
         assert_eq!(expected, got);
     }
 
+    #[test]
+    fn plain_state_root() {
+        let state: State = BTreeMap::from([
+            (
+                Address::random(),
+                (
+                    Account { nonce: 0, balance: U256::from(0), bytecode_hash: None },
+                    BTreeMap::from([(H256::from_low_u64_be(0x4), U256::from(12))]),
+                ),
+            ),
+            (
+                Address::random(),
+                (
+                    Account { nonce: 1, balance: U256::from(10), bytecode_hash: None },
+                    BTreeMap::new(),
+                ),
+            ),
+            (
+                Address::random(),
+                (
+                    Account {
+                        nonce: 155,
+                        balance: U256::from(414241124u32),
+                        bytecode_hash: Some(keccak256("test")),
+                    },
+                    BTreeMap::from([
+                        (H256::zero(), U256::from(3)),
+                        (H256::from_low_u64_be(2), U256::from(1)),
+                    ]),
+                ),
+            ),
+        ]);
+
+        let db = create_test_rw_db();
+        let factory = ProviderFactory::new(db.as_ref(), MAINNET.clone());
+        let tx = factory.provider_rw().unwrap();
+
+        for (address, (account, storage)) in &state {
+            tx.tx_ref().put::<tables::PlainAccountState>(*address, *account).unwrap();
+            for (key, value) in storage {
+                tx.tx_ref()
+                    .put::<tables::PlainStorageState>(
+                        *address,
+                        StorageEntry { key: *key, value: *value },
+                    )
+                    .unwrap();
+            }
+        }
+        tx.commit().unwrap();
+
+        // the hashed tables are not populated yet
+        let mut tx = factory.provider_rw().unwrap();
+        let expected = state_root(state.clone().into_iter());
+        assert_eq!(StateRoot::plain_state_root(tx.tx_mut()).unwrap(), expected);
+        assert_eq!(StateRoot::new(tx.tx_mut()).root().unwrap(), EMPTY_ROOT);
+
+        for (address, (account, storage)) in &state {
+            insert_account(tx.tx_mut(), *address, *account, storage)
+        }
+        assert_eq!(StateRoot::new(tx.tx_mut()).root().unwrap(), expected);
+    }
+
     fn encode_account(account: Account, storage_root: Option<H256>) -> Vec<u8> {
         let mut account = EthAccount::from(account);
         if let Some(storage_root) = storage_root {